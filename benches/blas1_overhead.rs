@@ -7,6 +7,7 @@
 
 use std::ffi::c_char;
 use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 
 // Link against OpenBLAS
@@ -34,6 +35,23 @@ extern "C" {
 
     fn dscal_(n: *const i32, alpha: *const f64, x: *mut f64, incx: *const i32);
 
+    // Level 3: symmetric rank-2k update, representative of the SYR2K/GEMM-style
+    // wrappers whose small-n overhead this benchmark also now tracks.
+    fn dsyr2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i32,
+        k: *const i32,
+        alpha: *const f64,
+        a: *const f64,
+        lda: *const i32,
+        b: *const f64,
+        ldb: *const i32,
+        beta: *const f64,
+        c: *mut f64,
+        ldc: *const i32,
+    );
+
     // CBLAS (for comparison)
     fn cblas_daxpy(n: i32, alpha: f64, x: *const f64, incx: i32, y: *mut f64, incy: i32);
 
@@ -67,12 +85,34 @@ type Dnrm2FnPtr = unsafe extern "C" fn(n: *const i32, x: *const f64, incx: *cons
 type DscalFnPtr =
     unsafe extern "C" fn(n: *const i32, alpha: *const f64, x: *mut f64, incx: *const i32);
 
+type Dsyr2kFnPtr = unsafe extern "C" fn(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const i32,
+    k: *const i32,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const i32,
+    b: *const f64,
+    ldb: *const i32,
+    beta: *const f64,
+    c: *mut f64,
+    ldc: *const i32,
+);
+
 // Simulated trampoline (function pointer indirection)
 static mut DAXPY_PTR: Option<DaxpyFnPtr> = None;
 static mut DDOT_PTR: Option<DdotFnPtr> = None;
 static mut DNRM2_PTR: Option<Dnrm2FnPtr> = None;
 static mut DSCAL_PTR: Option<DscalFnPtr> = None;
 
+// "Before": dispatch modeled on the hand-written OnceLock-based `get_*` functions
+// still used by most of the crate (an `Option`/`OnceLock::get` read per call).
+static mut DSYR2K_PTR_BEFORE: Option<Dsyr2kFnPtr> = None;
+// "After": dispatch modeled on the `blas_routine!`-generated `get_*` functions (a
+// single `AtomicUsize` load, no `Option` unwrap).
+static DSYR2K_PTR_AFTER: AtomicUsize = AtomicUsize::new(0);
+
 unsafe fn trampoline_daxpy(n: i32, alpha: f64, x: *const f64, incx: i32, y: *mut f64, incy: i32) {
     let f = DAXPY_PTR.unwrap();
     f(&n, &alpha, x, &incx, y, &incy);
@@ -93,6 +133,44 @@ unsafe fn trampoline_dscal(n: i32, alpha: f64, x: *mut f64, incx: i32) {
     f(&n, &alpha, x, &incx)
 }
 
+#[allow(clippy::too_many_arguments)]
+unsafe fn trampoline_dsyr2k_before(
+    uplo: c_char,
+    trans: c_char,
+    n: i32,
+    k: i32,
+    alpha: f64,
+    a: *const f64,
+    lda: i32,
+    b: *const f64,
+    ldb: i32,
+    beta: f64,
+    c: *mut f64,
+    ldc: i32,
+) {
+    let f = DSYR2K_PTR_BEFORE.unwrap();
+    f(&uplo, &trans, &n, &k, &alpha, a, &lda, b, &ldb, &beta, c, &ldc);
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn trampoline_dsyr2k_after(
+    uplo: c_char,
+    trans: c_char,
+    n: i32,
+    k: i32,
+    alpha: f64,
+    a: *const f64,
+    lda: i32,
+    b: *const f64,
+    ldb: i32,
+    beta: f64,
+    c: *mut f64,
+    ldc: i32,
+) {
+    let f: Dsyr2kFnPtr = std::mem::transmute(DSYR2K_PTR_AFTER.load(Ordering::SeqCst) as *const ());
+    f(&uplo, &trans, &n, &k, &alpha, a, &lda, b, &ldb, &beta, c, &ldc);
+}
+
 // Pure Rust implementations for reference
 fn rust_daxpy(n: usize, alpha: f64, x: &[f64], y: &mut [f64]) {
     for i in 0..n {
@@ -147,6 +225,8 @@ fn main() {
         DDOT_PTR = Some(std::mem::transmute(ddot_ as *const ()));
         DNRM2_PTR = Some(std::mem::transmute(dnrm2_ as *const ()));
         DSCAL_PTR = Some(std::mem::transmute(dscal_ as *const ()));
+        DSYR2K_PTR_BEFORE = Some(std::mem::transmute(dsyr2k_ as *const ()));
+        DSYR2K_PTR_AFTER.store(dsyr2k_ as *const () as usize, Ordering::SeqCst);
     }
 
     let sizes = [10, 100, 1000, 10000, 100000, 1000000];
@@ -329,4 +409,65 @@ fn main() {
 
         println!();
     }
+
+    // SYR2K benchmark: a Level-3 wrapper, to show that the per-call dispatch overhead
+    // the rest of this file measures for Level-1 routines matters less here (the O(n*k)
+    // body dwarfs a single pointer read) but still matters most at small n - exactly the
+    // size range the "before"/"after" dispatch strategies are compared over.
+    println!("SYR2K Dispatch Overhead (before: OnceLock-style Option read, after: AtomicUsize read)");
+    println!("=====================================================================================");
+    println!("Iterations per measurement: {}", iterations);
+    println!();
+
+    let syr2k_dims = [(2, 2), (8, 8), (64, 64), (256, 256)];
+    let uplo = b'U' as c_char;
+    let trans = b'N' as c_char;
+    let alpha = 1.3;
+    let beta = 0.5;
+
+    for &(n, k) in &syr2k_dims {
+        let a: Vec<f64> = (0..n * k).map(|i| i as f64 * 0.001).collect();
+        let b: Vec<f64> = (0..n * k).map(|i| (n * k - i) as f64 * 0.001).collect();
+        let mut c: Vec<f64> = (0..n * n).map(|i| i as f64 * 0.002).collect();
+        let (n_i32, k_i32) = (n as i32, k as i32);
+
+        let direct = benchmark("direct", iterations, || unsafe {
+            dsyr2k_(
+                &uplo, &trans, &n_i32, &k_i32, &alpha, a.as_ptr(), &n_i32, b.as_ptr(), &n_i32,
+                &beta, c.as_mut_ptr(), &n_i32,
+            );
+            black_box(&c);
+        });
+
+        let before = benchmark("before", iterations, || unsafe {
+            trampoline_dsyr2k_before(
+                uplo, trans, n_i32, k_i32, alpha, a.as_ptr(), n_i32, b.as_ptr(), n_i32, beta,
+                c.as_mut_ptr(), n_i32,
+            );
+            black_box(&c);
+        });
+
+        let after = benchmark("after", iterations, || unsafe {
+            trampoline_dsyr2k_after(
+                uplo, trans, n_i32, k_i32, alpha, a.as_ptr(), n_i32, b.as_ptr(), n_i32, beta,
+                c.as_mut_ptr(), n_i32,
+            );
+            black_box(&c);
+        });
+
+        let direct_ns = direct.as_nanos() as f64 / iterations as f64;
+        let before_ns = before.as_nanos() as f64 / iterations as f64;
+        let after_ns = after.as_nanos() as f64 / iterations as f64;
+
+        println!("n = {}, k = {}", n, k);
+        println!(
+            "  DSYR2K: direct={:.1}ns, before={:.1}ns, after={:.1}ns",
+            direct_ns, before_ns, after_ns
+        );
+        println!(
+            "          dispatch overhead: before={:.1}%, after={:.1}%",
+            (before_ns - direct_ns) / direct_ns * 100.0,
+            (after_ns - direct_ns) / direct_ns * 100.0
+        );
+    }
 }
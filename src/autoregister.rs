@@ -3,1220 +3,890 @@
 //! This module uses the `ctor` crate to automatically register Fortran BLAS
 //! function pointers when the library is loaded. This is required for the
 //! cdylib build to work with OpenBLAS ctest.
+//!
+//! Every `n`/`inc*`/`lda`/`ldb`/`ldc`/`m`/`k` parameter below is typed as [`blasint`],
+//! so building with the `ilp64` feature switches this entire extern block (and the
+//! probe in [`crate::backend::probe_complex_return_style`]) from 32-bit to 64-bit
+//! Fortran integers, matching the width [`crate::backend`]'s `*FnPtr` types already
+//! expect. This only covers OpenBLAS ILP64 builds that keep the conventional
+//! unsuffixed symbol names (`dgemm_`, not `dgemm_64_`); distros that ship suffixed
+//! ILP64 symbols (e.g. Debian's
+//! `libopenblas64-0`) aren't linkable through this `#[link(name = "openblas")]` block at
+//! all, since the symbol names themselves differ. Use [`crate::registry::register_all`]
+//! with a resolver that appends the distro's suffix to each name instead.
+//!
+//! The extern declarations below are generated from a small set of `macro_rules!` shapes
+//! (one per distinct Fortran argument list), the same way [`crate::reference`] generates
+//! its reference kernels: each shape is written once and instantiated per precision, so
+//! every s/d/c/z variant of a routine shares the same argument layout. The `register_*`
+//! calls in [`register_all_blas`] are a separate hand-written list of `reg!` lines; the
+//! compiler does not check that every declared symbol also has a registration call.
 
 use crate::backend::*;
-use crate::types::ComplexReturnStyle;
+use crate::types::{blasint, ComplexReturnStyle};
 use num_complex::{Complex32, Complex64};
 
+// Shapes shared by multiple BLAS Level 1 routines.
+macro_rules! extern_rot {
+    ($name:ident, $vty:ty, $sty:ty) => {
+        fn $name(
+            n: *const blasint,
+            x: *mut $vty,
+            incx: *const blasint,
+            y: *mut $vty,
+            incy: *const blasint,
+            c: *const $sty,
+            s: *const $sty,
+        );
+    };
+}
+
+macro_rules! extern_rotg {
+    ($name:ident, $ty:ty) => {
+        fn $name(a: *mut $ty, b: *mut $ty, c: *mut $ty, s: *mut $ty);
+    };
+}
+
+macro_rules! extern_rotg_complex {
+    ($name:ident, $ty:ty, $real_ty:ty) => {
+        fn $name(a: *mut $ty, b: *const $ty, c: *mut $real_ty, s: *mut $ty);
+    };
+}
+
+macro_rules! extern_rotm {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            n: *const blasint,
+            x: *mut $ty,
+            incx: *const blasint,
+            y: *mut $ty,
+            incy: *const blasint,
+            param: *const $ty,
+        );
+    };
+}
+
+macro_rules! extern_rotmg {
+    ($name:ident, $ty:ty) => {
+        fn $name(d1: *mut $ty, d2: *mut $ty, x1: *mut $ty, y1: *const $ty, param: *mut $ty);
+    };
+}
+
+macro_rules! extern_swap {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            n: *const blasint,
+            x: *mut $ty,
+            incx: *const blasint,
+            y: *mut $ty,
+            incy: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_copy {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            n: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *mut $ty,
+            incy: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_axpy {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            n: *const blasint,
+            alpha: *const $ty,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *mut $ty,
+            incy: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_scal {
+    ($name:ident, $alpha_ty:ty, $ty:ty) => {
+        fn $name(n: *const blasint, alpha: *const $alpha_ty, x: *mut $ty, incx: *const blasint);
+    };
+}
+
+macro_rules! extern_dot {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            n: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *const $ty,
+            incy: *const blasint,
+        ) -> $ty;
+    };
+}
+
+macro_rules! extern_nrm2 {
+    ($name:ident, $vty:ty, $rty:ty) => {
+        fn $name(n: *const blasint, x: *const $vty, incx: *const blasint) -> $rty;
+    };
+}
+
+macro_rules! extern_iamax {
+    ($name:ident, $ty:ty) => {
+        fn $name(n: *const blasint, x: *const $ty, incx: *const blasint) -> blasint;
+    };
+}
+
+macro_rules! extern_cabs1 {
+    ($name:ident, $ty:ty, $rty:ty) => {
+        fn $name(z: *const $ty) -> $rty;
+    };
+}
+
+// Shapes shared by multiple BLAS Level 2 routines.
+macro_rules! extern_gemv {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            trans: *const i8,
+            m: *const blasint,
+            n: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_gbmv {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            trans: *const i8,
+            m: *const blasint,
+            n: *const blasint,
+            kl: *const blasint,
+            ku: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_symv {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            uplo: *const i8,
+            n: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_sbmv {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            uplo: *const i8,
+            n: *const blasint,
+            k: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_trmv {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            uplo: *const i8,
+            trans: *const i8,
+            diag: *const i8,
+            n: *const blasint,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *mut $ty,
+            incx: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_tbmv {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            uplo: *const i8,
+            trans: *const i8,
+            diag: *const i8,
+            n: *const blasint,
+            k: *const blasint,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *mut $ty,
+            incx: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_ger {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            m: *const blasint,
+            n: *const blasint,
+            alpha: *const $ty,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *const $ty,
+            incy: *const blasint,
+            a: *mut $ty,
+            lda: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_syr {
+    ($name:ident, $alpha_ty:ty, $ty:ty) => {
+        fn $name(
+            uplo: *const i8,
+            n: *const blasint,
+            alpha: *const $alpha_ty,
+            x: *const $ty,
+            incx: *const blasint,
+            a: *mut $ty,
+            lda: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_syr2 {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            uplo: *const i8,
+            n: *const blasint,
+            alpha: *const $ty,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *const $ty,
+            incy: *const blasint,
+            a: *mut $ty,
+            lda: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_spmv {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            uplo: *const i8,
+            n: *const blasint,
+            alpha: *const $ty,
+            ap: *const $ty,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_tpmv {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            uplo: *const i8,
+            trans: *const i8,
+            diag: *const i8,
+            n: *const blasint,
+            ap: *const $ty,
+            x: *mut $ty,
+            incx: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_spr {
+    ($name:ident, $alpha_ty:ty, $ty:ty) => {
+        fn $name(
+            uplo: *const i8,
+            n: *const blasint,
+            alpha: *const $alpha_ty,
+            x: *const $ty,
+            incx: *const blasint,
+            ap: *mut $ty,
+        );
+    };
+}
+
+macro_rules! extern_spr2 {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            uplo: *const i8,
+            n: *const blasint,
+            alpha: *const $ty,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *const $ty,
+            incy: *const blasint,
+            ap: *mut $ty,
+        );
+    };
+}
+
+// Shapes shared by multiple BLAS Level 3 routines.
+macro_rules! extern_gemm {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            transa: *const i8,
+            transb: *const i8,
+            m: *const blasint,
+            n: *const blasint,
+            k: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            b: *const $ty,
+            ldb: *const blasint,
+            beta: *const $ty,
+            c: *mut $ty,
+            ldc: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_symm {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            side: *const i8,
+            uplo: *const i8,
+            m: *const blasint,
+            n: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            b: *const $ty,
+            ldb: *const blasint,
+            beta: *const $ty,
+            c: *mut $ty,
+            ldc: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_syrk {
+    ($name:ident, $alpha_ty:ty, $ty:ty) => {
+        fn $name(
+            uplo: *const i8,
+            trans: *const i8,
+            n: *const blasint,
+            k: *const blasint,
+            alpha: *const $alpha_ty,
+            a: *const $ty,
+            lda: *const blasint,
+            beta: *const $alpha_ty,
+            c: *mut $ty,
+            ldc: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_syr2k {
+    ($name:ident, $alpha_ty:ty, $beta_ty:ty, $ty:ty) => {
+        fn $name(
+            uplo: *const i8,
+            trans: *const i8,
+            n: *const blasint,
+            k: *const blasint,
+            alpha: *const $alpha_ty,
+            a: *const $ty,
+            lda: *const blasint,
+            b: *const $ty,
+            ldb: *const blasint,
+            beta: *const $beta_ty,
+            c: *mut $ty,
+            ldc: *const blasint,
+        );
+    };
+}
+
+macro_rules! extern_trmm {
+    ($name:ident, $ty:ty) => {
+        fn $name(
+            side: *const i8,
+            uplo: *const i8,
+            transa: *const i8,
+            diag: *const i8,
+            m: *const blasint,
+            n: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            b: *mut $ty,
+            ldb: *const blasint,
+        );
+    };
+}
+
 // Fortran BLAS declarations (linked from OpenBLAS)
 #[link(name = "openblas")]
 extern "C" {
-    // BLAS Level 1 - Single precision
-    fn srot_(
-        n: *const i32,
-        x: *mut f32,
-        incx: *const i32,
-        y: *mut f32,
-        incy: *const i32,
-        c: *const f32,
-        s: *const f32,
-    );
-    fn srotg_(a: *mut f32, b: *mut f32, c: *mut f32, s: *mut f32);
-    fn srotm_(
-        n: *const i32,
-        x: *mut f32,
-        incx: *const i32,
-        y: *mut f32,
-        incy: *const i32,
-        param: *const f32,
-    );
-    fn srotmg_(d1: *mut f32, d2: *mut f32, x1: *mut f32, y1: *const f32, param: *mut f32);
-    fn sswap_(n: *const i32, x: *mut f32, incx: *const i32, y: *mut f32, incy: *const i32);
-    fn scopy_(n: *const i32, x: *const f32, incx: *const i32, y: *mut f32, incy: *const i32);
-    fn saxpy_(
-        n: *const i32,
-        alpha: *const f32,
-        x: *const f32,
-        incx: *const i32,
-        y: *mut f32,
-        incy: *const i32,
-    );
-    fn sscal_(n: *const i32, alpha: *const f32, x: *mut f32, incx: *const i32);
-    fn sdot_(
-        n: *const i32,
-        x: *const f32,
-        incx: *const i32,
-        y: *const f32,
-        incy: *const i32,
-    ) -> f32;
+    // BLAS Level 1 - rotations
+    extern_rot!(srot_, f32, f32);
+    extern_rot!(drot_, f64, f64);
+    extern_rot!(csrot_, Complex32, f32);
+    extern_rot!(zdrot_, Complex64, f64);
+    extern_rotg!(srotg_, f32);
+    extern_rotg!(drotg_, f64);
+    extern_rotg_complex!(crotg_, Complex32, f32);
+    extern_rotg_complex!(zrotg_, Complex64, f64);
+    extern_rotm!(srotm_, f32);
+    extern_rotm!(drotm_, f64);
+    extern_rotmg!(srotmg_, f32);
+    extern_rotmg!(drotmg_, f64);
+
+    // BLAS Level 1 - swap/copy/axpy/scal
+    extern_swap!(sswap_, f32);
+    extern_swap!(dswap_, f64);
+    extern_swap!(cswap_, Complex32);
+    extern_swap!(zswap_, Complex64);
+    extern_copy!(scopy_, f32);
+    extern_copy!(dcopy_, f64);
+    extern_copy!(ccopy_, Complex32);
+    extern_copy!(zcopy_, Complex64);
+    extern_axpy!(saxpy_, f32);
+    extern_axpy!(daxpy_, f64);
+    extern_axpy!(caxpy_, Complex32);
+    extern_axpy!(zaxpy_, Complex64);
+    extern_scal!(sscal_, f32, f32);
+    extern_scal!(dscal_, f64, f64);
+    extern_scal!(cscal_, Complex32, Complex32);
+    extern_scal!(zscal_, Complex64, Complex64);
+    extern_scal!(csscal_, f32, Complex32);
+    extern_scal!(zdscal_, f64, Complex64);
+
+    // BLAS Level 1 - dot products, norms, index-of-max
+    extern_dot!(sdot_, f32);
+    extern_dot!(ddot_, f64);
+    // sdsdot_/dsdot_ mix precisions (f32 inputs, with the accumulation and result done in
+    // f64 for dsdot_) in a way no other routine does, so they don't fit extern_dot!'s
+    // single-type shape and are declared by hand.
     fn sdsdot_(
-        n: *const i32,
+        n: *const blasint,
         sb: *const f32,
         x: *const f32,
-        incx: *const i32,
+        incx: *const blasint,
         y: *const f32,
-        incy: *const i32,
+        incy: *const blasint,
     ) -> f32;
-    fn snrm2_(n: *const i32, x: *const f32, incx: *const i32) -> f32;
-    fn sasum_(n: *const i32, x: *const f32, incx: *const i32) -> f32;
-    fn isamax_(n: *const i32, x: *const f32, incx: *const i32) -> i32;
-
-    // BLAS Level 1 - Double precision
-    fn drot_(
-        n: *const i32,
-        x: *mut f64,
-        incx: *const i32,
-        y: *mut f64,
-        incy: *const i32,
-        c: *const f64,
-        s: *const f64,
-    );
-    fn drotg_(a: *mut f64, b: *mut f64, c: *mut f64, s: *mut f64);
-    fn drotm_(
-        n: *const i32,
-        x: *mut f64,
-        incx: *const i32,
-        y: *mut f64,
-        incy: *const i32,
-        param: *const f64,
-    );
-    fn drotmg_(d1: *mut f64, d2: *mut f64, x1: *mut f64, y1: *const f64, param: *mut f64);
-    fn dswap_(n: *const i32, x: *mut f64, incx: *const i32, y: *mut f64, incy: *const i32);
-    fn dcopy_(n: *const i32, x: *const f64, incx: *const i32, y: *mut f64, incy: *const i32);
-    fn daxpy_(
-        n: *const i32,
-        alpha: *const f64,
-        x: *const f64,
-        incx: *const i32,
-        y: *mut f64,
-        incy: *const i32,
-    );
-    fn dscal_(n: *const i32, alpha: *const f64, x: *mut f64, incx: *const i32);
-    fn ddot_(
-        n: *const i32,
-        x: *const f64,
-        incx: *const i32,
-        y: *const f64,
-        incy: *const i32,
-    ) -> f64;
     fn dsdot_(
-        n: *const i32,
+        n: *const blasint,
         x: *const f32,
-        incx: *const i32,
+        incx: *const blasint,
         y: *const f32,
-        incy: *const i32,
+        incy: *const blasint,
     ) -> f64;
-    fn dnrm2_(n: *const i32, x: *const f64, incx: *const i32) -> f64;
-    fn dasum_(n: *const i32, x: *const f64, incx: *const i32) -> f64;
-    fn idamax_(n: *const i32, x: *const f64, incx: *const i32) -> i32;
-
-    // BLAS Level 1 - Single complex
-    fn cswap_(n: *const i32, x: *mut (), incx: *const i32, y: *mut (), incy: *const i32);
-    fn ccopy_(n: *const i32, x: *const (), incx: *const i32, y: *mut (), incy: *const i32);
-    fn caxpy_(
-        n: *const i32,
-        alpha: *const (),
-        x: *const (),
-        incx: *const i32,
-        y: *mut (),
-        incy: *const i32,
-    );
-    fn cscal_(n: *const i32, alpha: *const (), x: *mut (), incx: *const i32);
-    fn csscal_(n: *const i32, alpha: *const f32, x: *mut (), incx: *const i32);
-    // Note: On ARM64/x86_64 with gfortran, complex dot products return value by register
+    // Declared with a by-value return; the actual ABI in use is detected at load time
+    // by probe_complex_return_style() and may in fact be the hidden-argument convention.
     fn cdotu_(
-        n: *const i32,
-        x: *const (),
-        incx: *const i32,
-        y: *const (),
-        incy: *const i32,
+        n: *const blasint,
+        x: *const Complex32,
+        incx: *const blasint,
+        y: *const Complex32,
+        incy: *const blasint,
     ) -> Complex32;
     fn cdotc_(
-        n: *const i32,
-        x: *const (),
-        incx: *const i32,
-        y: *const (),
-        incy: *const i32,
+        n: *const blasint,
+        x: *const Complex32,
+        incx: *const blasint,
+        y: *const Complex32,
+        incy: *const blasint,
     ) -> Complex32;
-    fn scnrm2_(n: *const i32, x: *const (), incx: *const i32) -> f32;
-    fn scasum_(n: *const i32, x: *const (), incx: *const i32) -> f32;
-    fn icamax_(n: *const i32, x: *const (), incx: *const i32) -> i32;
-
-    // BLAS Level 1 - Double complex
-    fn zswap_(n: *const i32, x: *mut (), incx: *const i32, y: *mut (), incy: *const i32);
-    fn zcopy_(n: *const i32, x: *const (), incx: *const i32, y: *mut (), incy: *const i32);
-    fn zaxpy_(
-        n: *const i32,
-        alpha: *const (),
-        x: *const (),
-        incx: *const i32,
-        y: *mut (),
-        incy: *const i32,
-    );
-    fn zscal_(n: *const i32, alpha: *const (), x: *mut (), incx: *const i32);
-    fn zdscal_(n: *const i32, alpha: *const f64, x: *mut (), incx: *const i32);
-    // Note: On ARM64/x86_64 with gfortran, complex dot products return value by register
     fn zdotu_(
-        n: *const i32,
-        x: *const (),
-        incx: *const i32,
-        y: *const (),
-        incy: *const i32,
+        n: *const blasint,
+        x: *const Complex64,
+        incx: *const blasint,
+        y: *const Complex64,
+        incy: *const blasint,
     ) -> Complex64;
     fn zdotc_(
-        n: *const i32,
-        x: *const (),
-        incx: *const i32,
-        y: *const (),
-        incy: *const i32,
+        n: *const blasint,
+        x: *const Complex64,
+        incx: *const blasint,
+        y: *const Complex64,
+        incy: *const blasint,
     ) -> Complex64;
-    fn dznrm2_(n: *const i32, x: *const (), incx: *const i32) -> f64;
-    fn dzasum_(n: *const i32, x: *const (), incx: *const i32) -> f64;
-    fn izamax_(n: *const i32, x: *const (), incx: *const i32) -> i32;
-
-    // BLAS Level 3
-    fn sgemm_(
-        transa: *const i8,
-        transb: *const i8,
-        m: *const i32,
-        n: *const i32,
-        k: *const i32,
-        alpha: *const f32,
-        a: *const f32,
-        lda: *const i32,
-        b: *const f32,
-        ldb: *const i32,
-        beta: *const f32,
-        c: *mut f32,
-        ldc: *const i32,
-    );
-    fn dgemm_(
-        transa: *const i8,
-        transb: *const i8,
-        m: *const i32,
-        n: *const i32,
-        k: *const i32,
-        alpha: *const f64,
-        a: *const f64,
-        lda: *const i32,
-        b: *const f64,
-        ldb: *const i32,
-        beta: *const f64,
-        c: *mut f64,
-        ldc: *const i32,
-    );
-    fn cgemm_(
-        transa: *const i8,
-        transb: *const i8,
-        m: *const i32,
-        n: *const i32,
-        k: *const i32,
-        alpha: *const (),
-        a: *const (),
-        lda: *const i32,
-        b: *const (),
-        ldb: *const i32,
-        beta: *const (),
-        c: *mut (),
-        ldc: *const i32,
-    );
-    fn zgemm_(
-        transa: *const i8,
-        transb: *const i8,
-        m: *const i32,
-        n: *const i32,
-        k: *const i32,
-        alpha: *const (),
-        a: *const (),
-        lda: *const i32,
-        b: *const (),
-        ldb: *const i32,
-        beta: *const (),
-        c: *mut (),
-        ldc: *const i32,
-    );
-
-    fn dsymm_(
-        side: *const i8,
-        uplo: *const i8,
-        m: *const i32,
-        n: *const i32,
-        alpha: *const f64,
-        a: *const f64,
-        lda: *const i32,
-        b: *const f64,
-        ldb: *const i32,
-        beta: *const f64,
-        c: *mut f64,
-        ldc: *const i32,
-    );
-    fn dsyrk_(
-        uplo: *const i8,
-        trans: *const i8,
-        n: *const i32,
-        k: *const i32,
-        alpha: *const f64,
-        a: *const f64,
-        lda: *const i32,
-        beta: *const f64,
-        c: *mut f64,
-        ldc: *const i32,
-    );
-    fn dsyr2k_(
-        uplo: *const i8,
-        trans: *const i8,
-        n: *const i32,
-        k: *const i32,
-        alpha: *const f64,
-        a: *const f64,
-        lda: *const i32,
-        b: *const f64,
-        ldb: *const i32,
-        beta: *const f64,
-        c: *mut f64,
-        ldc: *const i32,
-    );
-    fn dtrmm_(
-        side: *const i8,
-        uplo: *const i8,
-        transa: *const i8,
-        diag: *const i8,
-        m: *const i32,
-        n: *const i32,
-        alpha: *const f64,
-        a: *const f64,
-        lda: *const i32,
-        b: *mut f64,
-        ldb: *const i32,
-    );
-    fn dtrsm_(
-        side: *const i8,
-        uplo: *const i8,
-        transa: *const i8,
-        diag: *const i8,
-        m: *const i32,
-        n: *const i32,
-        alpha: *const f64,
-        a: *const f64,
-        lda: *const i32,
-        b: *mut f64,
-        ldb: *const i32,
-    );
-
-    // BLAS Level 2 - General matrix-vector multiply
-    fn sgemv_(
-        trans: *const i8,
-        m: *const i32,
-        n: *const i32,
-        alpha: *const f32,
-        a: *const f32,
-        lda: *const i32,
-        x: *const f32,
-        incx: *const i32,
-        beta: *const f32,
-        y: *mut f32,
-        incy: *const i32,
-    );
-    fn dgemv_(
-        trans: *const i8,
-        m: *const i32,
-        n: *const i32,
-        alpha: *const f64,
-        a: *const f64,
-        lda: *const i32,
-        x: *const f64,
-        incx: *const i32,
-        beta: *const f64,
-        y: *mut f64,
-        incy: *const i32,
-    );
-    fn cgemv_(
-        trans: *const i8,
-        m: *const i32,
-        n: *const i32,
-        alpha: *const (),
-        a: *const (),
-        lda: *const i32,
-        x: *const (),
-        incx: *const i32,
-        beta: *const (),
-        y: *mut (),
-        incy: *const i32,
-    );
-    fn zgemv_(
-        trans: *const i8,
-        m: *const i32,
-        n: *const i32,
-        alpha: *const (),
-        a: *const (),
-        lda: *const i32,
-        x: *const (),
-        incx: *const i32,
-        beta: *const (),
-        y: *mut (),
-        incy: *const i32,
-    );
-
-    // BLAS Level 2 - General band matrix-vector multiply
-    fn sgbmv_(
-        trans: *const i8,
-        m: *const i32,
-        n: *const i32,
-        kl: *const i32,
-        ku: *const i32,
-        alpha: *const f32,
-        a: *const f32,
-        lda: *const i32,
-        x: *const f32,
-        incx: *const i32,
-        beta: *const f32,
-        y: *mut f32,
-        incy: *const i32,
-    );
-    fn dgbmv_(
-        trans: *const i8,
-        m: *const i32,
-        n: *const i32,
-        kl: *const i32,
-        ku: *const i32,
-        alpha: *const f64,
-        a: *const f64,
-        lda: *const i32,
-        x: *const f64,
-        incx: *const i32,
-        beta: *const f64,
-        y: *mut f64,
-        incy: *const i32,
-    );
-    fn cgbmv_(
-        trans: *const i8,
-        m: *const i32,
-        n: *const i32,
-        kl: *const i32,
-        ku: *const i32,
-        alpha: *const (),
-        a: *const (),
-        lda: *const i32,
-        x: *const (),
-        incx: *const i32,
-        beta: *const (),
-        y: *mut (),
-        incy: *const i32,
-    );
-    fn zgbmv_(
-        trans: *const i8,
-        m: *const i32,
-        n: *const i32,
-        kl: *const i32,
-        ku: *const i32,
-        alpha: *const (),
-        a: *const (),
-        lda: *const i32,
-        x: *const (),
-        incx: *const i32,
-        beta: *const (),
-        y: *mut (),
-        incy: *const i32,
-    );
-
-    // BLAS Level 2 - Symmetric/Hermitian matrix-vector
-    fn ssymv_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f32,
-        a: *const f32,
-        lda: *const i32,
-        x: *const f32,
-        incx: *const i32,
-        beta: *const f32,
-        y: *mut f32,
-        incy: *const i32,
-    );
-    fn dsymv_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f64,
-        a: *const f64,
-        lda: *const i32,
-        x: *const f64,
-        incx: *const i32,
-        beta: *const f64,
-        y: *mut f64,
-        incy: *const i32,
-    );
-    fn chemv_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const (),
-        a: *const (),
-        lda: *const i32,
-        x: *const (),
-        incx: *const i32,
-        beta: *const (),
-        y: *mut (),
-        incy: *const i32,
-    );
-    fn zhemv_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const (),
-        a: *const (),
-        lda: *const i32,
-        x: *const (),
-        incx: *const i32,
-        beta: *const (),
-        y: *mut (),
-        incy: *const i32,
-    );
-
-    // BLAS Level 2 - Symmetric/Hermitian band matrix-vector
-    fn ssbmv_(
-        uplo: *const i8,
-        n: *const i32,
-        k: *const i32,
-        alpha: *const f32,
-        a: *const f32,
-        lda: *const i32,
-        x: *const f32,
-        incx: *const i32,
-        beta: *const f32,
-        y: *mut f32,
-        incy: *const i32,
-    );
-    fn dsbmv_(
-        uplo: *const i8,
-        n: *const i32,
-        k: *const i32,
-        alpha: *const f64,
-        a: *const f64,
-        lda: *const i32,
-        x: *const f64,
-        incx: *const i32,
-        beta: *const f64,
-        y: *mut f64,
-        incy: *const i32,
-    );
-    fn chbmv_(
-        uplo: *const i8,
-        n: *const i32,
-        k: *const i32,
-        alpha: *const (),
-        a: *const (),
-        lda: *const i32,
-        x: *const (),
-        incx: *const i32,
-        beta: *const (),
-        y: *mut (),
-        incy: *const i32,
-    );
-    fn zhbmv_(
-        uplo: *const i8,
-        n: *const i32,
-        k: *const i32,
-        alpha: *const (),
-        a: *const (),
-        lda: *const i32,
-        x: *const (),
-        incx: *const i32,
-        beta: *const (),
-        y: *mut (),
-        incy: *const i32,
-    );
-
-    // BLAS Level 2 - Triangular matrix-vector
-    fn strmv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        a: *const f32,
-        lda: *const i32,
-        x: *mut f32,
-        incx: *const i32,
-    );
-    fn dtrmv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        a: *const f64,
-        lda: *const i32,
-        x: *mut f64,
-        incx: *const i32,
-    );
-    fn ctrmv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        a: *const (),
-        lda: *const i32,
-        x: *mut (),
-        incx: *const i32,
-    );
-    fn ztrmv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        a: *const (),
-        lda: *const i32,
-        x: *mut (),
-        incx: *const i32,
-    );
-
-    // BLAS Level 2 - Triangular solve
-    fn strsv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        a: *const f32,
-        lda: *const i32,
-        x: *mut f32,
-        incx: *const i32,
-    );
-    fn dtrsv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        a: *const f64,
-        lda: *const i32,
-        x: *mut f64,
-        incx: *const i32,
-    );
-    fn ctrsv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        a: *const (),
-        lda: *const i32,
-        x: *mut (),
-        incx: *const i32,
-    );
-    fn ztrsv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        a: *const (),
-        lda: *const i32,
-        x: *mut (),
-        incx: *const i32,
-    );
-
-    // BLAS Level 2 - Triangular band matrix-vector multiply
-    fn stbmv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        k: *const i32,
-        a: *const f32,
-        lda: *const i32,
-        x: *mut f32,
-        incx: *const i32,
-    );
-    fn dtbmv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        k: *const i32,
-        a: *const f64,
-        lda: *const i32,
-        x: *mut f64,
-        incx: *const i32,
-    );
-    fn ctbmv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        k: *const i32,
-        a: *const (),
-        lda: *const i32,
-        x: *mut (),
-        incx: *const i32,
-    );
-    fn ztbmv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        k: *const i32,
-        a: *const (),
-        lda: *const i32,
-        x: *mut (),
-        incx: *const i32,
-    );
-
-    // BLAS Level 2 - Triangular band solve
-    fn stbsv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        k: *const i32,
-        a: *const f32,
-        lda: *const i32,
-        x: *mut f32,
-        incx: *const i32,
-    );
-    fn dtbsv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        k: *const i32,
-        a: *const f64,
-        lda: *const i32,
-        x: *mut f64,
-        incx: *const i32,
-    );
-    fn ctbsv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        k: *const i32,
-        a: *const (),
-        lda: *const i32,
-        x: *mut (),
-        incx: *const i32,
-    );
-    fn ztbsv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        k: *const i32,
-        a: *const (),
-        lda: *const i32,
-        x: *mut (),
-        incx: *const i32,
-    );
-
-    // BLAS Level 2 - Rank-1 update (GER)
-    fn sger_(
-        m: *const i32,
-        n: *const i32,
-        alpha: *const f32,
-        x: *const f32,
-        incx: *const i32,
-        y: *const f32,
-        incy: *const i32,
-        a: *mut f32,
-        lda: *const i32,
-    );
-    fn dger_(
-        m: *const i32,
-        n: *const i32,
-        alpha: *const f64,
-        x: *const f64,
-        incx: *const i32,
-        y: *const f64,
-        incy: *const i32,
-        a: *mut f64,
-        lda: *const i32,
-    );
-    fn cgeru_(
-        m: *const i32,
-        n: *const i32,
-        alpha: *const (),
-        x: *const (),
-        incx: *const i32,
-        y: *const (),
-        incy: *const i32,
-        a: *mut (),
-        lda: *const i32,
-    );
-    fn cgerc_(
-        m: *const i32,
-        n: *const i32,
-        alpha: *const (),
-        x: *const (),
-        incx: *const i32,
-        y: *const (),
-        incy: *const i32,
-        a: *mut (),
-        lda: *const i32,
-    );
-    fn zgeru_(
-        m: *const i32,
-        n: *const i32,
-        alpha: *const (),
-        x: *const (),
-        incx: *const i32,
-        y: *const (),
-        incy: *const i32,
-        a: *mut (),
-        lda: *const i32,
-    );
-    fn zgerc_(
-        m: *const i32,
-        n: *const i32,
-        alpha: *const (),
-        x: *const (),
-        incx: *const i32,
-        y: *const (),
-        incy: *const i32,
-        a: *mut (),
-        lda: *const i32,
-    );
-
-    // BLAS Level 2 - Symmetric/Hermitian rank-1 update (SYR/HER)
-    fn ssyr_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f32,
-        x: *const f32,
-        incx: *const i32,
-        a: *mut f32,
-        lda: *const i32,
-    );
-    fn dsyr_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f64,
-        x: *const f64,
-        incx: *const i32,
-        a: *mut f64,
-        lda: *const i32,
-    );
-    fn cher_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f32,
-        x: *const (),
-        incx: *const i32,
-        a: *mut (),
-        lda: *const i32,
-    );
-    fn zher_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f64,
-        x: *const (),
-        incx: *const i32,
-        a: *mut (),
-        lda: *const i32,
-    );
-
-    // BLAS Level 2 - Symmetric/Hermitian rank-2 update (SYR2/HER2)
-    fn ssyr2_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f32,
-        x: *const f32,
-        incx: *const i32,
-        y: *const f32,
-        incy: *const i32,
-        a: *mut f32,
-        lda: *const i32,
-    );
-    fn dsyr2_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f64,
-        x: *const f64,
-        incx: *const i32,
-        y: *const f64,
-        incy: *const i32,
-        a: *mut f64,
-        lda: *const i32,
-    );
-    fn cher2_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const (),
-        x: *const (),
-        incx: *const i32,
-        y: *const (),
-        incy: *const i32,
-        a: *mut (),
-        lda: *const i32,
-    );
-    fn zher2_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const (),
-        x: *const (),
-        incx: *const i32,
-        y: *const (),
-        incy: *const i32,
-        a: *mut (),
-        lda: *const i32,
-    );
-
-    // BLAS Level 2 - Symmetric/Hermitian packed matrix-vector multiply
-    fn sspmv_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f32,
-        ap: *const f32,
-        x: *const f32,
-        incx: *const i32,
-        beta: *const f32,
-        y: *mut f32,
-        incy: *const i32,
-    );
-    fn dspmv_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f64,
-        ap: *const f64,
-        x: *const f64,
-        incx: *const i32,
-        beta: *const f64,
-        y: *mut f64,
-        incy: *const i32,
-    );
-    fn chpmv_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const (),
-        ap: *const (),
-        x: *const (),
-        incx: *const i32,
-        beta: *const (),
-        y: *mut (),
-        incy: *const i32,
-    );
-    fn zhpmv_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const (),
-        ap: *const (),
-        x: *const (),
-        incx: *const i32,
-        beta: *const (),
-        y: *mut (),
-        incy: *const i32,
-    );
-
-    // BLAS Level 2 - Triangular packed matrix-vector multiply
-    fn stpmv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        ap: *const f32,
-        x: *mut f32,
-        incx: *const i32,
-    );
-    fn dtpmv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        ap: *const f64,
-        x: *mut f64,
-        incx: *const i32,
-    );
-    fn ctpmv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        ap: *const (),
-        x: *mut (),
-        incx: *const i32,
-    );
-    fn ztpmv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        ap: *const (),
-        x: *mut (),
-        incx: *const i32,
-    );
-
-    // BLAS Level 2 - Triangular packed solve
-    fn stpsv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        ap: *const f32,
-        x: *mut f32,
-        incx: *const i32,
-    );
-    fn dtpsv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        ap: *const f64,
-        x: *mut f64,
-        incx: *const i32,
-    );
-    fn ctpsv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        ap: *const (),
-        x: *mut (),
-        incx: *const i32,
-    );
-    fn ztpsv_(
-        uplo: *const i8,
-        trans: *const i8,
-        diag: *const i8,
-        n: *const i32,
-        ap: *const (),
-        x: *mut (),
-        incx: *const i32,
-    );
-
-    // BLAS Level 2 - Symmetric/Hermitian packed rank-1 update
-    fn sspr_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f32,
-        x: *const f32,
-        incx: *const i32,
-        ap: *mut f32,
-    );
-    fn dspr_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f64,
-        x: *const f64,
-        incx: *const i32,
-        ap: *mut f64,
-    );
-    fn chpr_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f32,
-        x: *const (),
-        incx: *const i32,
-        ap: *mut (),
-    );
-    fn zhpr_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f64,
-        x: *const (),
-        incx: *const i32,
-        ap: *mut (),
-    );
-
-    // BLAS Level 2 - Symmetric/Hermitian packed rank-2 update
-    fn sspr2_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f32,
-        x: *const f32,
-        incx: *const i32,
-        y: *const f32,
-        incy: *const i32,
-        ap: *mut f32,
-    );
-    fn dspr2_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const f64,
-        x: *const f64,
-        incx: *const i32,
-        y: *const f64,
-        incy: *const i32,
-        ap: *mut f64,
-    );
-    fn chpr2_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const (),
-        x: *const (),
-        incx: *const i32,
-        y: *const (),
-        incy: *const i32,
-        ap: *mut (),
-    );
-    fn zhpr2_(
-        uplo: *const i8,
-        n: *const i32,
-        alpha: *const (),
-        x: *const (),
-        incx: *const i32,
-        y: *const (),
-        incy: *const i32,
-        ap: *mut (),
-    );
+    extern_nrm2!(snrm2_, f32, f32);
+    extern_nrm2!(dnrm2_, f64, f64);
+    extern_nrm2!(scnrm2_, Complex32, f32);
+    extern_nrm2!(dznrm2_, Complex64, f64);
+    extern_nrm2!(sasum_, f32, f32);
+    extern_nrm2!(dasum_, f64, f64);
+    extern_nrm2!(scasum_, Complex32, f32);
+    extern_nrm2!(dzasum_, Complex64, f64);
+    extern_iamax!(isamax_, f32);
+    extern_iamax!(idamax_, f64);
+    extern_iamax!(icamax_, Complex32);
+    extern_iamax!(izamax_, Complex64);
+    extern_cabs1!(scabs1_, Complex32, f32);
+    extern_cabs1!(dcabs1_, Complex64, f64);
+
+    // BLAS Level 2 - GEMV/GBMV
+    extern_gemv!(sgemv_, f32);
+    extern_gemv!(dgemv_, f64);
+    extern_gemv!(cgemv_, Complex32);
+    extern_gemv!(zgemv_, Complex64);
+    extern_gbmv!(sgbmv_, f32);
+    extern_gbmv!(dgbmv_, f64);
+    extern_gbmv!(cgbmv_, Complex32);
+    extern_gbmv!(zgbmv_, Complex64);
+
+    // BLAS Level 2 - SYMV/HEMV, SBMV/HBMV
+    extern_symv!(ssymv_, f32);
+    extern_symv!(dsymv_, f64);
+    extern_symv!(chemv_, Complex32);
+    extern_symv!(zhemv_, Complex64);
+    extern_sbmv!(ssbmv_, f32);
+    extern_sbmv!(dsbmv_, f64);
+    extern_sbmv!(chbmv_, Complex32);
+    extern_sbmv!(zhbmv_, Complex64);
+
+    // BLAS Level 2 - TRMV/TRSV, TBMV/TBSV (same argument shape within each pair)
+    extern_trmv!(strmv_, f32);
+    extern_trmv!(dtrmv_, f64);
+    extern_trmv!(ctrmv_, Complex32);
+    extern_trmv!(ztrmv_, Complex64);
+    extern_trmv!(strsv_, f32);
+    extern_trmv!(dtrsv_, f64);
+    extern_trmv!(ctrsv_, Complex32);
+    extern_trmv!(ztrsv_, Complex64);
+    extern_tbmv!(stbmv_, f32);
+    extern_tbmv!(dtbmv_, f64);
+    extern_tbmv!(ctbmv_, Complex32);
+    extern_tbmv!(ztbmv_, Complex64);
+    extern_tbmv!(stbsv_, f32);
+    extern_tbmv!(dtbsv_, f64);
+    extern_tbmv!(ctbsv_, Complex32);
+    extern_tbmv!(ztbsv_, Complex64);
+
+    // BLAS Level 2 - GER (rank-1 update)
+    extern_ger!(sger_, f32);
+    extern_ger!(dger_, f64);
+    extern_ger!(cgeru_, Complex32);
+    extern_ger!(cgerc_, Complex32);
+    extern_ger!(zgeru_, Complex64);
+    extern_ger!(zgerc_, Complex64);
+
+    // BLAS Level 2 - SYR/HER, SYR2/HER2 (alpha is real for {s,d,c,z}her, even though A is
+    // complex for cher/zher)
+    extern_syr!(ssyr_, f32, f32);
+    extern_syr!(dsyr_, f64, f64);
+    extern_syr!(cher_, f32, Complex32);
+    extern_syr!(zher_, f64, Complex64);
+    extern_syr2!(ssyr2_, f32);
+    extern_syr2!(dsyr2_, f64);
+    extern_syr2!(cher2_, Complex32);
+    extern_syr2!(zher2_, Complex64);
+
+    // BLAS Level 2 - SPMV/HPMV, TPMV/TPSV
+    extern_spmv!(sspmv_, f32);
+    extern_spmv!(dspmv_, f64);
+    extern_spmv!(chpmv_, Complex32);
+    extern_spmv!(zhpmv_, Complex64);
+    extern_tpmv!(stpmv_, f32);
+    extern_tpmv!(dtpmv_, f64);
+    extern_tpmv!(ctpmv_, Complex32);
+    extern_tpmv!(ztpmv_, Complex64);
+    extern_tpmv!(stpsv_, f32);
+    extern_tpmv!(dtpsv_, f64);
+    extern_tpmv!(ctpsv_, Complex32);
+    extern_tpmv!(ztpsv_, Complex64);
+
+    // BLAS Level 2 - SPR/HPR, SPR2/HPR2
+    extern_spr!(sspr_, f32, f32);
+    extern_spr!(dspr_, f64, f64);
+    extern_spr!(chpr_, f32, Complex32);
+    extern_spr!(zhpr_, f64, Complex64);
+    extern_spr2!(sspr2_, f32);
+    extern_spr2!(dspr2_, f64);
+    extern_spr2!(chpr2_, Complex32);
+    extern_spr2!(zhpr2_, Complex64);
+
+    // BLAS Level 3 - GEMM
+    extern_gemm!(sgemm_, f32);
+    extern_gemm!(dgemm_, f64);
+    extern_gemm!(cgemm_, Complex32);
+    extern_gemm!(zgemm_, Complex64);
+
+    // BLAS Level 3 - SYMM/HEMM (same argument shape for both)
+    extern_symm!(dsymm_, f64);
+    extern_symm!(ssymm_, f32);
+    extern_symm!(csymm_, Complex32);
+    extern_symm!(zsymm_, Complex64);
+    extern_symm!(chemm_, Complex32);
+    extern_symm!(zhemm_, Complex64);
+
+    // BLAS Level 3 - SYRK/HERK (alpha/beta are real for {c,z}herk, even though A/C are
+    // complex)
+    extern_syrk!(dsyrk_, f64, f64);
+    extern_syrk!(ssyrk_, f32, f32);
+    extern_syrk!(csyrk_, Complex32, Complex32);
+    extern_syrk!(zsyrk_, Complex64, Complex64);
+    extern_syrk!(cherk_, f32, Complex32);
+    extern_syrk!(zherk_, f64, Complex64);
+
+    // BLAS Level 3 - SYR2K/HER2K (alpha is complex but beta is real for {c,z}her2k)
+    extern_syr2k!(dsyr2k_, f64, f64, f64);
+    extern_syr2k!(ssyr2k_, f32, f32, f32);
+    extern_syr2k!(csyr2k_, Complex32, Complex32, Complex32);
+    extern_syr2k!(zsyr2k_, Complex64, Complex64, Complex64);
+    extern_syr2k!(cher2k_, Complex32, f32, Complex32);
+    extern_syr2k!(zher2k_, Complex64, f64, Complex64);
+
+    // BLAS Level 3 - TRMM/TRSM (same argument shape for both)
+    extern_trmm!(dtrmm_, f64);
+    extern_trmm!(strmm_, f32);
+    extern_trmm!(ctrmm_, Complex32);
+    extern_trmm!(ztrmm_, Complex64);
+    extern_trmm!(dtrsm_, f64);
+    extern_trmm!(strsm_, f32);
+    extern_trmm!(ctrsm_, Complex32);
+    extern_trmm!(ztrsm_, Complex64);
 }
 
 #[ctor::ctor]
 fn register_all_blas() {
-    // OpenBLAS uses return value convention for complex dot products
-    set_complex_return_style(ComplexReturnStyle::ReturnValue);
+    // Probe once: every complex dot routine in a given BLAS build shares the same
+    // calling convention, so cdotu_'s result tells us how to register all four. See
+    // `crate::backend::probe_complex_return_style` for how the probe itself works.
+    // Falls back to `ReturnValue` if the probe can't pin down a convention.
+    let complex_return_style =
+        unsafe { probe_complex_return_style(cdotu_ as *const ()) }.unwrap_or(ComplexReturnStyle::ReturnValue);
+    set_complex_return_style(complex_return_style);
+
+    macro_rules! reg {
+        ($name:ident, $register:ident) => {
+            $register(std::mem::transmute($name as *const ()))
+        };
+    }
 
     unsafe {
         // BLAS Level 1 - Single
-        register_srot(std::mem::transmute(srot_ as *const ()));
-        register_srotg(std::mem::transmute(srotg_ as *const ()));
-        register_srotm(std::mem::transmute(srotm_ as *const ()));
-        register_srotmg(std::mem::transmute(srotmg_ as *const ()));
-        register_sswap(std::mem::transmute(sswap_ as *const ()));
-        register_scopy(std::mem::transmute(scopy_ as *const ()));
-        register_saxpy(std::mem::transmute(saxpy_ as *const ()));
-        register_sscal(std::mem::transmute(sscal_ as *const ()));
-        register_sdot(std::mem::transmute(sdot_ as *const ()));
-        register_sdsdot(std::mem::transmute(sdsdot_ as *const ()));
-        register_snrm2(std::mem::transmute(snrm2_ as *const ()));
-        register_sasum(std::mem::transmute(sasum_ as *const ()));
-        register_isamax(std::mem::transmute(isamax_ as *const ()));
+        reg!(srot_, register_srot);
+        reg!(srotg_, register_srotg);
+        reg!(srotm_, register_srotm);
+        reg!(srotmg_, register_srotmg);
+        reg!(sswap_, register_sswap);
+        reg!(scopy_, register_scopy);
+        reg!(saxpy_, register_saxpy);
+        reg!(sscal_, register_sscal);
+        reg!(sdot_, register_sdot);
+        reg!(sdsdot_, register_sdsdot);
+        reg!(snrm2_, register_snrm2);
+        reg!(sasum_, register_sasum);
+        reg!(isamax_, register_isamax);
 
         // BLAS Level 1 - Double
-        register_drot(std::mem::transmute(drot_ as *const ()));
-        register_drotg(std::mem::transmute(drotg_ as *const ()));
-        register_drotm(std::mem::transmute(drotm_ as *const ()));
-        register_drotmg(std::mem::transmute(drotmg_ as *const ()));
-        register_dswap(std::mem::transmute(dswap_ as *const ()));
-        register_dcopy(std::mem::transmute(dcopy_ as *const ()));
-        register_daxpy(std::mem::transmute(daxpy_ as *const ()));
-        register_dscal(std::mem::transmute(dscal_ as *const ()));
-        register_ddot(std::mem::transmute(ddot_ as *const ()));
-        register_dsdot(std::mem::transmute(dsdot_ as *const ()));
-        register_dnrm2(std::mem::transmute(dnrm2_ as *const ()));
-        register_dasum(std::mem::transmute(dasum_ as *const ()));
-        register_idamax(std::mem::transmute(idamax_ as *const ()));
+        reg!(drot_, register_drot);
+        reg!(drotg_, register_drotg);
+        reg!(drotm_, register_drotm);
+        reg!(drotmg_, register_drotmg);
+        reg!(dswap_, register_dswap);
+        reg!(dcopy_, register_dcopy);
+        reg!(daxpy_, register_daxpy);
+        reg!(dscal_, register_dscal);
+        reg!(ddot_, register_ddot);
+        reg!(dsdot_, register_dsdot);
+        reg!(dnrm2_, register_dnrm2);
+        reg!(dasum_, register_dasum);
+        reg!(idamax_, register_idamax);
 
         // BLAS Level 1 - Single complex
-        register_cswap(std::mem::transmute(cswap_ as *const ()));
-        register_ccopy(std::mem::transmute(ccopy_ as *const ()));
-        register_caxpy(std::mem::transmute(caxpy_ as *const ()));
-        register_cscal(std::mem::transmute(cscal_ as *const ()));
-        register_csscal(std::mem::transmute(csscal_ as *const ()));
-        register_cdotu(std::mem::transmute(cdotu_ as *const ()));
-        register_cdotc(std::mem::transmute(cdotc_ as *const ()));
-        register_scnrm2(std::mem::transmute(scnrm2_ as *const ()));
-        register_scasum(std::mem::transmute(scasum_ as *const ()));
-        register_icamax(std::mem::transmute(icamax_ as *const ()));
+        reg!(cswap_, register_cswap);
+        reg!(ccopy_, register_ccopy);
+        reg!(caxpy_, register_caxpy);
+        reg!(cscal_, register_cscal);
+        reg!(csscal_, register_csscal);
+        reg!(cdotu_, register_cdotu);
+        reg!(cdotc_, register_cdotc);
+        reg!(scnrm2_, register_scnrm2);
+        reg!(scasum_, register_scasum);
+        reg!(icamax_, register_icamax);
+        reg!(csrot_, register_csrot);
+        reg!(crotg_, register_crotg);
+        reg!(scabs1_, register_scabs1);
 
         // BLAS Level 1 - Double complex
-        register_zswap(std::mem::transmute(zswap_ as *const ()));
-        register_zcopy(std::mem::transmute(zcopy_ as *const ()));
-        register_zaxpy(std::mem::transmute(zaxpy_ as *const ()));
-        register_zscal(std::mem::transmute(zscal_ as *const ()));
-        register_zdscal(std::mem::transmute(zdscal_ as *const ()));
-        register_zdotu(std::mem::transmute(zdotu_ as *const ()));
-        register_zdotc(std::mem::transmute(zdotc_ as *const ()));
-        register_dznrm2(std::mem::transmute(dznrm2_ as *const ()));
-        register_dzasum(std::mem::transmute(dzasum_ as *const ()));
-        register_izamax(std::mem::transmute(izamax_ as *const ()));
+        reg!(zswap_, register_zswap);
+        reg!(zcopy_, register_zcopy);
+        reg!(zaxpy_, register_zaxpy);
+        reg!(zscal_, register_zscal);
+        reg!(zdscal_, register_zdscal);
+        reg!(zdotu_, register_zdotu);
+        reg!(zdotc_, register_zdotc);
+        reg!(dznrm2_, register_dznrm2);
+        reg!(dzasum_, register_dzasum);
+        reg!(izamax_, register_izamax);
+        reg!(zdrot_, register_zdrot);
+        reg!(zrotg_, register_zrotg);
+        reg!(dcabs1_, register_dcabs1);
 
         // BLAS Level 2 - GEMV
-        register_sgemv(std::mem::transmute(sgemv_ as *const ()));
-        register_dgemv(std::mem::transmute(dgemv_ as *const ()));
-        register_cgemv(std::mem::transmute(cgemv_ as *const ()));
-        register_zgemv(std::mem::transmute(zgemv_ as *const ()));
+        reg!(sgemv_, register_sgemv);
+        reg!(dgemv_, register_dgemv);
+        reg!(cgemv_, register_cgemv);
+        reg!(zgemv_, register_zgemv);
 
         // BLAS Level 2 - GBMV
-        register_sgbmv(std::mem::transmute(sgbmv_ as *const ()));
-        register_dgbmv(std::mem::transmute(dgbmv_ as *const ()));
-        register_cgbmv(std::mem::transmute(cgbmv_ as *const ()));
-        register_zgbmv(std::mem::transmute(zgbmv_ as *const ()));
+        reg!(sgbmv_, register_sgbmv);
+        reg!(dgbmv_, register_dgbmv);
+        reg!(cgbmv_, register_cgbmv);
+        reg!(zgbmv_, register_zgbmv);
 
         // BLAS Level 2 - SYMV/HEMV
-        register_ssymv(std::mem::transmute(ssymv_ as *const ()));
-        register_dsymv(std::mem::transmute(dsymv_ as *const ()));
-        register_chemv(std::mem::transmute(chemv_ as *const ()));
-        register_zhemv(std::mem::transmute(zhemv_ as *const ()));
+        reg!(ssymv_, register_ssymv);
+        reg!(dsymv_, register_dsymv);
+        reg!(chemv_, register_chemv);
+        reg!(zhemv_, register_zhemv);
 
         // BLAS Level 2 - SBMV/HBMV (symmetric/hermitian band matrix-vector)
-        register_ssbmv(std::mem::transmute(ssbmv_ as *const ()));
-        register_dsbmv(std::mem::transmute(dsbmv_ as *const ()));
-        register_chbmv(std::mem::transmute(chbmv_ as *const ()));
-        register_zhbmv(std::mem::transmute(zhbmv_ as *const ()));
+        reg!(ssbmv_, register_ssbmv);
+        reg!(dsbmv_, register_dsbmv);
+        reg!(chbmv_, register_chbmv);
+        reg!(zhbmv_, register_zhbmv);
 
         // BLAS Level 2 - TRMV
-        register_strmv(std::mem::transmute(strmv_ as *const ()));
-        register_dtrmv(std::mem::transmute(dtrmv_ as *const ()));
-        register_ctrmv(std::mem::transmute(ctrmv_ as *const ()));
-        register_ztrmv(std::mem::transmute(ztrmv_ as *const ()));
+        reg!(strmv_, register_strmv);
+        reg!(dtrmv_, register_dtrmv);
+        reg!(ctrmv_, register_ctrmv);
+        reg!(ztrmv_, register_ztrmv);
 
         // BLAS Level 2 - TRSV
-        register_strsv(std::mem::transmute(strsv_ as *const ()));
-        register_dtrsv(std::mem::transmute(dtrsv_ as *const ()));
-        register_ctrsv(std::mem::transmute(ctrsv_ as *const ()));
-        register_ztrsv(std::mem::transmute(ztrsv_ as *const ()));
+        reg!(strsv_, register_strsv);
+        reg!(dtrsv_, register_dtrsv);
+        reg!(ctrsv_, register_ctrsv);
+        reg!(ztrsv_, register_ztrsv);
 
         // BLAS Level 2 - TBMV (triangular band matrix-vector multiply)
-        register_stbmv(std::mem::transmute(stbmv_ as *const ()));
-        register_dtbmv(std::mem::transmute(dtbmv_ as *const ()));
-        register_ctbmv(std::mem::transmute(ctbmv_ as *const ()));
-        register_ztbmv(std::mem::transmute(ztbmv_ as *const ()));
+        reg!(stbmv_, register_stbmv);
+        reg!(dtbmv_, register_dtbmv);
+        reg!(ctbmv_, register_ctbmv);
+        reg!(ztbmv_, register_ztbmv);
 
         // BLAS Level 2 - TBSV (triangular band solve)
-        register_stbsv(std::mem::transmute(stbsv_ as *const ()));
-        register_dtbsv(std::mem::transmute(dtbsv_ as *const ()));
-        register_ctbsv(std::mem::transmute(ctbsv_ as *const ()));
-        register_ztbsv(std::mem::transmute(ztbsv_ as *const ()));
+        reg!(stbsv_, register_stbsv);
+        reg!(dtbsv_, register_dtbsv);
+        reg!(ctbsv_, register_ctbsv);
+        reg!(ztbsv_, register_ztbsv);
 
         // BLAS Level 2 - GER (rank-1 update)
-        register_sger(std::mem::transmute(sger_ as *const ()));
-        register_dger(std::mem::transmute(dger_ as *const ()));
-        register_cgeru(std::mem::transmute(cgeru_ as *const ()));
-        register_cgerc(std::mem::transmute(cgerc_ as *const ()));
-        register_zgeru(std::mem::transmute(zgeru_ as *const ()));
-        register_zgerc(std::mem::transmute(zgerc_ as *const ()));
+        reg!(sger_, register_sger);
+        reg!(dger_, register_dger);
+        reg!(cgeru_, register_cgeru);
+        reg!(cgerc_, register_cgerc);
+        reg!(zgeru_, register_zgeru);
+        reg!(zgerc_, register_zgerc);
 
         // BLAS Level 2 - SYR/HER (symmetric/hermitian rank-1 update)
-        register_ssyr(std::mem::transmute(ssyr_ as *const ()));
-        register_dsyr(std::mem::transmute(dsyr_ as *const ()));
-        register_cher(std::mem::transmute(cher_ as *const ()));
-        register_zher(std::mem::transmute(zher_ as *const ()));
+        reg!(ssyr_, register_ssyr);
+        reg!(dsyr_, register_dsyr);
+        reg!(cher_, register_cher);
+        reg!(zher_, register_zher);
 
         // BLAS Level 2 - SYR2/HER2 (symmetric/hermitian rank-2 update)
-        register_ssyr2(std::mem::transmute(ssyr2_ as *const ()));
-        register_dsyr2(std::mem::transmute(dsyr2_ as *const ()));
-        register_cher2(std::mem::transmute(cher2_ as *const ()));
-        register_zher2(std::mem::transmute(zher2_ as *const ()));
+        reg!(ssyr2_, register_ssyr2);
+        reg!(dsyr2_, register_dsyr2);
+        reg!(cher2_, register_cher2);
+        reg!(zher2_, register_zher2);
 
         // BLAS Level 2 - SPMV/HPMV (symmetric/hermitian packed matrix-vector multiply)
-        register_sspmv(std::mem::transmute(sspmv_ as *const ()));
-        register_dspmv(std::mem::transmute(dspmv_ as *const ()));
-        register_chpmv(std::mem::transmute(chpmv_ as *const ()));
-        register_zhpmv(std::mem::transmute(zhpmv_ as *const ()));
+        reg!(sspmv_, register_sspmv);
+        reg!(dspmv_, register_dspmv);
+        reg!(chpmv_, register_chpmv);
+        reg!(zhpmv_, register_zhpmv);
 
         // BLAS Level 2 - TPMV (triangular packed matrix-vector multiply)
-        register_stpmv(std::mem::transmute(stpmv_ as *const ()));
-        register_dtpmv(std::mem::transmute(dtpmv_ as *const ()));
-        register_ctpmv(std::mem::transmute(ctpmv_ as *const ()));
-        register_ztpmv(std::mem::transmute(ztpmv_ as *const ()));
+        reg!(stpmv_, register_stpmv);
+        reg!(dtpmv_, register_dtpmv);
+        reg!(ctpmv_, register_ctpmv);
+        reg!(ztpmv_, register_ztpmv);
 
         // BLAS Level 2 - TPSV (triangular packed solve)
-        register_stpsv(std::mem::transmute(stpsv_ as *const ()));
-        register_dtpsv(std::mem::transmute(dtpsv_ as *const ()));
-        register_ctpsv(std::mem::transmute(ctpsv_ as *const ()));
-        register_ztpsv(std::mem::transmute(ztpsv_ as *const ()));
+        reg!(stpsv_, register_stpsv);
+        reg!(dtpsv_, register_dtpsv);
+        reg!(ctpsv_, register_ctpsv);
+        reg!(ztpsv_, register_ztpsv);
 
         // BLAS Level 2 - SPR/HPR (symmetric/hermitian packed rank-1 update)
-        register_sspr(std::mem::transmute(sspr_ as *const ()));
-        register_dspr(std::mem::transmute(dspr_ as *const ()));
-        register_chpr(std::mem::transmute(chpr_ as *const ()));
-        register_zhpr(std::mem::transmute(zhpr_ as *const ()));
+        reg!(sspr_, register_sspr);
+        reg!(dspr_, register_dspr);
+        reg!(chpr_, register_chpr);
+        reg!(zhpr_, register_zhpr);
 
         // BLAS Level 2 - SPR2/HPR2 (symmetric/hermitian packed rank-2 update)
-        register_sspr2(std::mem::transmute(sspr2_ as *const ()));
-        register_dspr2(std::mem::transmute(dspr2_ as *const ()));
-        register_chpr2(std::mem::transmute(chpr2_ as *const ()));
-        register_zhpr2(std::mem::transmute(zhpr2_ as *const ()));
-
-        // BLAS Level 3
-        register_sgemm(std::mem::transmute(sgemm_ as *const ()));
-        register_dgemm(std::mem::transmute(dgemm_ as *const ()));
-        register_cgemm(std::mem::transmute(cgemm_ as *const ()));
-        register_zgemm(std::mem::transmute(zgemm_ as *const ()));
-        register_dsymm(std::mem::transmute(dsymm_ as *const ()));
-        register_dsyrk(std::mem::transmute(dsyrk_ as *const ()));
-        register_dsyr2k(std::mem::transmute(dsyr2k_ as *const ()));
-        register_dtrmm(std::mem::transmute(dtrmm_ as *const ()));
-        register_dtrsm(std::mem::transmute(dtrsm_ as *const ()));
+        reg!(sspr2_, register_sspr2);
+        reg!(dspr2_, register_dspr2);
+        reg!(chpr2_, register_chpr2);
+        reg!(zhpr2_, register_zhpr2);
+
+        // BLAS Level 3 - GEMM
+        reg!(sgemm_, register_sgemm);
+        reg!(dgemm_, register_dgemm);
+        reg!(cgemm_, register_cgemm);
+        reg!(zgemm_, register_zgemm);
+
+        // BLAS Level 3 - SYMM/HEMM
+        reg!(dsymm_, register_dsymm);
+        reg!(ssymm_, register_ssymm);
+        reg!(csymm_, register_csymm);
+        reg!(zsymm_, register_zsymm);
+        reg!(chemm_, register_chemm);
+        reg!(zhemm_, register_zhemm);
+
+        // BLAS Level 3 - SYRK/HERK
+        reg!(dsyrk_, register_dsyrk);
+        reg!(ssyrk_, register_ssyrk);
+        reg!(csyrk_, register_csyrk);
+        reg!(zsyrk_, register_zsyrk);
+        reg!(cherk_, register_cherk);
+        reg!(zherk_, register_zherk);
+
+        // BLAS Level 3 - SYR2K/HER2K
+        reg!(dsyr2k_, register_dsyr2k);
+        reg!(ssyr2k_, register_ssyr2k);
+        reg!(csyr2k_, register_csyr2k);
+        reg!(zsyr2k_, register_zsyr2k);
+        reg!(cher2k_, register_cher2k);
+        reg!(zher2k_, register_zher2k);
+
+        // BLAS Level 3 - TRMM/TRSM
+        reg!(dtrmm_, register_dtrmm);
+        reg!(strmm_, register_strmm);
+        reg!(ctrmm_, register_ctrmm);
+        reg!(ztrmm_, register_ztrmm);
+        reg!(dtrsm_, register_dtrsm);
+        reg!(strsm_, register_strsm);
+        reg!(ctrsm_, register_ctrsm);
+        reg!(ztrsm_, register_ztrsm);
     }
 }
@@ -0,0 +1,241 @@
+//! Trait-object backend injection: a single `BlasBackend` implementation supplies every
+//! function pointer at once, instead of a caller making one `register_*` call per routine.
+//!
+//! This is the same shape as other pluggable-subsystem seams in this crate (a boxed/
+//! borrowed `dyn Trait` behind one entry point, with a trivial mock implementation for
+//! tests) rather than a new mechanism: [`register_backend`] is just [`register_by_name`]
+//! driven from a trait object instead of a symbol-name resolver, reusing the exact same
+//! [`RegistrationReport`] outcome type [`crate::registry::register_all_detailed`] and
+//! [`crate::dlopen::load_backend`] already return.
+
+use crate::backend::*;
+use crate::registry::RegistrationReport;
+
+/// Declares one [`BlasBackend`] method per routine, all defaulting to `None`, plus
+/// [`register_backend`] and [`MockBackend`], which need the exact same symbol/FnPtr list.
+macro_rules! blas_backend {
+    ($( $name:ident : $fn_ptr:ident ),* $(,)?) => {
+        /// Supplies function pointers for some or all of the Fortran BLAS routines this
+        /// crate can register. Every method defaults to `None`, so an implementation only
+        /// needs to override the routines it actually provides.
+        ///
+        /// Pass an implementation to [`register_backend`] to install every pointer it
+        /// returns in one call, instead of calling `register_*` once per routine.
+        pub trait BlasBackend {
+            $(
+                #[doc = concat!("This backend's `", stringify!($name), "` pointer, or `None` if it doesn't provide one.")]
+                fn $name(&self) -> Option<$fn_ptr> {
+                    None
+                }
+            )*
+        }
+
+        /// Installs every non-`None` pointer `backend` supplies into the matching global
+        /// `register_*` slot, via the same name-keyed dispatch
+        /// [`crate::registry::register_by_name`] uses. Slots `backend` returns `None` for
+        /// are left exactly as they were; slots that already hold a pointer from an
+        /// earlier registration are reported in [`RegistrationReport::already_registered`]
+        /// rather than panicking, the same as [`crate::registry::register_all_detailed`].
+        ///
+        /// # Safety
+        ///
+        /// Every `Some` pointer `backend` returns must be a valid Fortran BLAS function
+        /// pointer with the signature CBLAS expects for that method's routine, per the
+        /// safety requirements of the corresponding `register_*` function in
+        /// [`crate::backend`].
+        pub unsafe fn register_backend(backend: &dyn BlasBackend) -> RegistrationReport {
+            let mut report = RegistrationReport::default();
+            $(
+                if let Some(f) = backend.$name() {
+                    match unsafe { crate::backend::register_by_name_raw(stringify!($name), f as *const ()) } {
+                        Some(Ok(())) => report.registered.push(stringify!($name)),
+                        Some(Err(())) => report.already_registered.push(stringify!($name)),
+                        None => unreachable!(
+                            "blas_backend! only lists names register_by_name_raw recognizes"
+                        ),
+                    }
+                }
+            )*
+            report
+        }
+
+        impl BlasBackend for MockBackend {
+            $(
+                fn $name(&self) -> Option<$fn_ptr> {
+                    // SAFETY: `mock_stub` never reads its arguments or return slot before
+                    // diverging, so the mismatch between its real signature (none) and
+                    // `$fn_ptr`'s is harmless — see `crate::dlopen::stub_trap`, which the
+                    // same reasoning is documented on.
+                    Some(unsafe { std::mem::transmute::<*const (), $fn_ptr>(mock_stub as *const ()) })
+                }
+            )*
+        }
+    };
+}
+
+/// A [`BlasBackend`] whose every routine is a stub that panics, naming itself, when
+/// called. Lets a downstream crate test that its own injection wiring calls
+/// [`register_backend`] and ends up dispatching through the right slot, without linking a
+/// real BLAS provider to do it.
+pub struct MockBackend;
+
+/// Diverges immediately, reporting that the calling routine was never meant to be called:
+/// it only exists so [`MockBackend`] has a pointer to hand out. See
+/// [`crate::dlopen::stub_trap`] for the identical reasoning about transmuting one
+/// no-argument function to every `*FnPtr` type.
+extern "C" fn mock_stub() -> ! {
+    panic!(
+        "cblas-inject: MockBackend stub called — this backend only exists to test \
+         injection wiring, not to compute anything; register a real backend before \
+         making BLAS calls"
+    )
+}
+
+blas_backend! {
+    srot: SrotFnPtr,
+    srotg: SrotgFnPtr,
+    srotm: SrotmFnPtr,
+    srotmg: SrotmgFnPtr,
+    sswap: SswapFnPtr,
+    scopy: ScopyFnPtr,
+    saxpy: SaxpyFnPtr,
+    sscal: SscalFnPtr,
+    sdot: SdotFnPtr,
+    sdsdot: SdsdotFnPtr,
+    snrm2: Snrm2FnPtr,
+    sasum: SasumFnPtr,
+    isamax: IsamaxFnPtr,
+    drot: DrotFnPtr,
+    drotg: DrotgFnPtr,
+    drotm: DrotmFnPtr,
+    drotmg: DrotmgFnPtr,
+    dswap: DswapFnPtr,
+    dcopy: DcopyFnPtr,
+    daxpy: DaxpyFnPtr,
+    dscal: DscalFnPtr,
+    ddot: DdotFnPtr,
+    dsdot: DsdotFnPtr,
+    dnrm2: Dnrm2FnPtr,
+    dasum: DasumFnPtr,
+    idamax: IdamaxFnPtr,
+    cswap: CswapFnPtr,
+    ccopy: CcopyFnPtr,
+    caxpy: CaxpyFnPtr,
+    cscal: CscalFnPtr,
+    csscal: CsscalFnPtr,
+    cdotu: CdotuFnPtr,
+    cdotc: CdotcFnPtr,
+    scnrm2: Scnrm2FnPtr,
+    scasum: ScasumFnPtr,
+    icamax: IcamaxFnPtr,
+    csrot: CsrotFnPtr,
+    scabs1: Scabs1FnPtr,
+    zswap: ZswapFnPtr,
+    zcopy: ZcopyFnPtr,
+    zaxpy: ZaxpyFnPtr,
+    zscal: ZscalFnPtr,
+    zdscal: ZdscalFnPtr,
+    zdotu: ZdotuFnPtr,
+    zdotc: ZdotcFnPtr,
+    dznrm2: Dznrm2FnPtr,
+    dzasum: DzasumFnPtr,
+    izamax: IzamaxFnPtr,
+    zdrot: ZdrotFnPtr,
+    dcabs1: Dcabs1FnPtr,
+    sgemv: SgemvFnPtr,
+    dgemv: DgemvFnPtr,
+    cgemv: CgemvFnPtr,
+    zgemv: ZgemvFnPtr,
+    sgbmv: SgbmvFnPtr,
+    dgbmv: DgbmvFnPtr,
+    cgbmv: CgbmvFnPtr,
+    zgbmv: ZgbmvFnPtr,
+    ssymv: SsymvFnPtr,
+    dsymv: DsymvFnPtr,
+    chemv: ChemvFnPtr,
+    zhemv: ZhemvFnPtr,
+    ssbmv: SsbmvFnPtr,
+    dsbmv: DsbmvFnPtr,
+    chbmv: ChbmvFnPtr,
+    zhbmv: ZhbmvFnPtr,
+    strmv: StrmvFnPtr,
+    dtrmv: DtrmvFnPtr,
+    ctrmv: CtrmvFnPtr,
+    ztrmv: ZtrmvFnPtr,
+    strsv: StrsvFnPtr,
+    dtrsv: DtrsvFnPtr,
+    ctrsv: CtrsvFnPtr,
+    ztrsv: ZtrsvFnPtr,
+    stbmv: StbmvFnPtr,
+    dtbmv: DtbmvFnPtr,
+    ctbmv: CtbmvFnPtr,
+    ztbmv: ZtbmvFnPtr,
+    stbsv: StbsvFnPtr,
+    dtbsv: DtbsvFnPtr,
+    ctbsv: CtbsvFnPtr,
+    ztbsv: ZtbsvFnPtr,
+    sger: SgerFnPtr,
+    dger: DgerFnPtr,
+    cgeru: CgeruFnPtr,
+    cgerc: CgercFnPtr,
+    zgeru: ZgeruFnPtr,
+    zgerc: ZgercFnPtr,
+    ssyr: SsyrFnPtr,
+    dsyr: DsyrFnPtr,
+    cher: CherFnPtr,
+    zher: ZherFnPtr,
+    ssyr2: Ssyr2FnPtr,
+    dsyr2: Dsyr2FnPtr,
+    cher2: Cher2FnPtr,
+    zher2: Zher2FnPtr,
+    sspmv: SspmvFnPtr,
+    dspmv: DspmvFnPtr,
+    chpmv: ChpmvFnPtr,
+    zhpmv: ZhpmvFnPtr,
+    stpmv: StpmvFnPtr,
+    dtpmv: DtpmvFnPtr,
+    ctpmv: CtpmvFnPtr,
+    ztpmv: ZtpmvFnPtr,
+    stpsv: StpsvFnPtr,
+    dtpsv: DtpsvFnPtr,
+    ctpsv: CtpsvFnPtr,
+    ztpsv: ZtpsvFnPtr,
+    sspr: SsprFnPtr,
+    dspr: DsprFnPtr,
+    chpr: ChprFnPtr,
+    zhpr: ZhprFnPtr,
+    sspr2: Sspr2FnPtr,
+    dspr2: Dspr2FnPtr,
+    chpr2: Chpr2FnPtr,
+    zhpr2: Zhpr2FnPtr,
+    sgemm: SgemmFnPtr,
+    dgemm: DgemmFnPtr,
+    cgemm: CgemmFnPtr,
+    zgemm: ZgemmFnPtr,
+    dsymm: DsymmFnPtr,
+    ssymm: SsymmFnPtr,
+    csymm: CsymmFnPtr,
+    zsymm: ZsymmFnPtr,
+    chemm: ChemmFnPtr,
+    zhemm: ZhemmFnPtr,
+    dsyrk: DsyrkFnPtr,
+    ssyrk: SsyrkFnPtr,
+    csyrk: CsyrkFnPtr,
+    zsyrk: ZsyrkFnPtr,
+    dsyr2k: Dsyr2kFnPtr,
+    ssyr2k: Ssyr2kFnPtr,
+    csyr2k: Csyr2kFnPtr,
+    zsyr2k: Zsyr2kFnPtr,
+    cherk: CherkFnPtr,
+    zherk: ZherkFnPtr,
+    cher2k: Cher2kFnPtr,
+    zher2k: Zher2kFnPtr,
+    dtrmm: DtrmmFnPtr,
+    strmm: StrmmFnPtr,
+    ctrmm: CtrmmFnPtr,
+    ztrmm: ZtrmmFnPtr,
+    dtrsm: DtrsmFnPtr,
+    strsm: StrsmFnPtr,
+    ctrsm: CtrsmFnPtr,
+    ztrsm: ZtrsmFnPtr,
+}
@@ -6,14 +6,20 @@
 
 use num_complex::{Complex32, Complex64};
 
+use crate::accumulate::{
+    is_compensated, kbn_cdot, kbn_dasum, kbn_ddot, kbn_dnrm2, kbn_dzasum, kbn_sasum, kbn_scasum,
+    kbn_sdot, kbn_snrm2, kbn_zdot,
+};
 use crate::backend::{
-    get_cdotc_ptr, get_cdotu_ptr, get_complex_return_style, get_dasum, get_ddot, get_dnrm2,
-    get_dsdot, get_dzasum, get_dznrm2, get_icamax, get_idamax, get_isamax, get_izamax, get_sasum,
-    get_scasum, get_scnrm2, get_sdot, get_sdsdot, get_snrm2, get_zdotc_ptr, get_zdotu_ptr,
-    CdotcFnPtr, CdotcHiddenFnPtr, CdotuFnPtr, CdotuHiddenFnPtr, ZdotcFnPtr, ZdotcHiddenFnPtr,
-    ZdotuFnPtr, ZdotuHiddenFnPtr,
+    get_cdotc_ptr, get_cdotu_ptr, get_complex_return_style_for, get_dasum, get_ddot, get_dnrm2,
+    get_dsdot, get_dzasum, get_icamax, get_icamin, get_idamax, get_idamin, get_isamax,
+    get_isamin, get_izamax, get_izamin, get_sasum, get_scasum, get_sdot, get_sdsdot, get_snrm2,
+    get_zdotc_ptr, get_zdotu_ptr, CdotcFnPtr, CdotcHiddenFnPtr, CdotuFnPtr, CdotuHiddenFnPtr,
+    ZdotcFnPtr, ZdotcHiddenFnPtr, ZdotuFnPtr, ZdotuHiddenFnPtr,
 };
-use crate::types::{blasint, ComplexReturnStyle};
+use crate::counters::{count_call, CallInfo};
+use crate::nrm2::{scaled_dznrm2, scaled_scnrm2};
+use crate::types::{blasint, CBLAS_INDEX, ComplexReturnStyle};
 
 // =============================================================================
 // Dot products
@@ -28,6 +34,9 @@ use crate::types::{blasint, ComplexReturnStyle};
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
 /// - sdot must be registered via `register_sdot`
+///
+/// Computed via Kahan–Babuška–Neumaier compensated summation instead, if
+/// [`crate::set_accumulation_mode`] has been set to `Compensated`.
 #[no_mangle]
 pub unsafe extern "C" fn cblas_sdot(
     n: blasint,
@@ -36,6 +45,10 @@ pub unsafe extern "C" fn cblas_sdot(
     y: *const f32,
     incy: blasint,
 ) -> f32 {
+    count_call!("cblas_sdot", CallInfo { n: Some(n), ..Default::default() });
+    if is_compensated() {
+        return kbn_sdot(n, x, incx, y, incy);
+    }
     let sdot = get_sdot();
     sdot(&n, x, &incx, y, &incy)
 }
@@ -49,6 +62,9 @@ pub unsafe extern "C" fn cblas_sdot(
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
 /// - ddot must be registered via `register_ddot`
+///
+/// Computed via Kahan–Babuška–Neumaier compensated summation instead, if
+/// [`crate::set_accumulation_mode`] has been set to `Compensated`.
 #[no_mangle]
 pub unsafe extern "C" fn cblas_ddot(
     n: blasint,
@@ -57,6 +73,10 @@ pub unsafe extern "C" fn cblas_ddot(
     y: *const f64,
     incy: blasint,
 ) -> f64 {
+    count_call!("cblas_ddot", CallInfo { n: Some(n), ..Default::default() });
+    if is_compensated() {
+        return kbn_ddot(n, x, incx, y, incy);
+    }
     let ddot = get_ddot();
     ddot(&n, x, &incx, y, &incy)
 }
@@ -70,6 +90,9 @@ pub unsafe extern "C" fn cblas_ddot(
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
 /// - cdotu must be registered via `register_cdotu`
+///
+/// Computed via Kahan–Babuška–Neumaier compensated summation instead, if
+/// [`crate::set_accumulation_mode`] has been set to `Compensated`.
 #[no_mangle]
 pub unsafe extern "C" fn cblas_cdotu_sub(
     n: blasint,
@@ -79,8 +102,12 @@ pub unsafe extern "C" fn cblas_cdotu_sub(
     incy: blasint,
     dotu: *mut Complex32,
 ) {
+    if is_compensated() {
+        *dotu = kbn_cdot(n, x, incx, y, incy, false);
+        return;
+    }
     let ptr = get_cdotu_ptr();
-    match get_complex_return_style() {
+    match get_complex_return_style_for("cdotu") {
         ComplexReturnStyle::ReturnValue => {
             let f: CdotuFnPtr = std::mem::transmute(ptr);
             *dotu = f(&n, x, &incx, y, &incy);
@@ -101,6 +128,9 @@ pub unsafe extern "C" fn cblas_cdotu_sub(
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
 /// - zdotu must be registered via `register_zdotu`
+///
+/// Computed via Kahan–Babuška–Neumaier compensated summation instead, if
+/// [`crate::set_accumulation_mode`] has been set to `Compensated`.
 #[no_mangle]
 pub unsafe extern "C" fn cblas_zdotu_sub(
     n: blasint,
@@ -110,8 +140,12 @@ pub unsafe extern "C" fn cblas_zdotu_sub(
     incy: blasint,
     dotu: *mut Complex64,
 ) {
+    if is_compensated() {
+        *dotu = kbn_zdot(n, x, incx, y, incy, false);
+        return;
+    }
     let ptr = get_zdotu_ptr();
-    match get_complex_return_style() {
+    match get_complex_return_style_for("zdotu") {
         ComplexReturnStyle::ReturnValue => {
             let f: ZdotuFnPtr = std::mem::transmute(ptr);
             *dotu = f(&n, x, &incx, y, &incy);
@@ -132,6 +166,9 @@ pub unsafe extern "C" fn cblas_zdotu_sub(
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
 /// - cdotc must be registered via `register_cdotc`
+///
+/// Computed via Kahan–Babuška–Neumaier compensated summation instead, if
+/// [`crate::set_accumulation_mode`] has been set to `Compensated`.
 #[no_mangle]
 pub unsafe extern "C" fn cblas_cdotc_sub(
     n: blasint,
@@ -141,8 +178,12 @@ pub unsafe extern "C" fn cblas_cdotc_sub(
     incy: blasint,
     dotc: *mut Complex32,
 ) {
+    if is_compensated() {
+        *dotc = kbn_cdot(n, x, incx, y, incy, true);
+        return;
+    }
     let ptr = get_cdotc_ptr();
-    match get_complex_return_style() {
+    match get_complex_return_style_for("cdotc") {
         ComplexReturnStyle::ReturnValue => {
             let f: CdotcFnPtr = std::mem::transmute(ptr);
             *dotc = f(&n, x, &incx, y, &incy);
@@ -163,6 +204,9 @@ pub unsafe extern "C" fn cblas_cdotc_sub(
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
 /// - zdotc must be registered via `register_zdotc`
+///
+/// Computed via Kahan–Babuška–Neumaier compensated summation instead, if
+/// [`crate::set_accumulation_mode`] has been set to `Compensated`.
 #[no_mangle]
 pub unsafe extern "C" fn cblas_zdotc_sub(
     n: blasint,
@@ -172,8 +216,12 @@ pub unsafe extern "C" fn cblas_zdotc_sub(
     incy: blasint,
     dotc: *mut Complex64,
 ) {
+    if is_compensated() {
+        *dotc = kbn_zdot(n, x, incx, y, incy, true);
+        return;
+    }
     let ptr = get_zdotc_ptr();
-    match get_complex_return_style() {
+    match get_complex_return_style_for("zdotc") {
         ComplexReturnStyle::ReturnValue => {
             let f: ZdotcFnPtr = std::mem::transmute(ptr);
             *dotc = f(&n, x, &incx, y, &incy);
@@ -245,8 +293,14 @@ pub unsafe extern "C" fn cblas_dsdot(
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
 /// - snrm2 must be registered via `register_snrm2`
+///
+/// Computed via Kahan–Babuška–Neumaier compensated summation instead, if
+/// [`crate::set_accumulation_mode`] has been set to `Compensated`.
 #[no_mangle]
 pub unsafe extern "C" fn cblas_snrm2(n: blasint, x: *const f32, incx: blasint) -> f32 {
+    if is_compensated() {
+        return kbn_snrm2(n, x, incx);
+    }
     let snrm2 = get_snrm2();
     snrm2(&n, x, &incx)
 }
@@ -260,40 +314,48 @@ pub unsafe extern "C" fn cblas_snrm2(n: blasint, x: *const f32, incx: blasint) -
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
 /// - dnrm2 must be registered via `register_dnrm2`
+///
+/// Computed via Kahan–Babuška–Neumaier compensated summation instead, if
+/// [`crate::set_accumulation_mode`] has been set to `Compensated`.
 #[no_mangle]
 pub unsafe extern "C" fn cblas_dnrm2(n: blasint, x: *const f64, incx: blasint) -> f64 {
+    if is_compensated() {
+        return kbn_dnrm2(n, x, incx);
+    }
     let dnrm2 = get_dnrm2();
     dnrm2(&n, x, &incx)
 }
 
 /// Complex single precision Euclidean norm.
 ///
-/// Computes: sqrt(sum(|x[i]|^2))
+/// Computes: sqrt(sum(|x[i]|^2)). `n <= 0` or `incx <= 0` yields 0, per BLAS convention.
+/// Computed directly via the overflow-safe scaled recurrence in [`crate::nrm2`] rather
+/// than delegating to the registered backend, so the result is correct even for
+/// components near the floating-point range limits regardless of backend quality.
 ///
 /// # Safety
 ///
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
-/// - scnrm2 must be registered via `register_scnrm2`
 #[no_mangle]
 pub unsafe extern "C" fn cblas_scnrm2(n: blasint, x: *const Complex32, incx: blasint) -> f32 {
-    let scnrm2 = get_scnrm2();
-    scnrm2(&n, x, &incx)
+    scaled_scnrm2(n, x, incx)
 }
 
 /// Complex double precision Euclidean norm.
 ///
-/// Computes: sqrt(sum(|x[i]|^2))
+/// Computes: sqrt(sum(|x[i]|^2)). `n <= 0` or `incx <= 0` yields 0, per BLAS convention.
+/// Computed directly via the overflow-safe scaled recurrence in [`crate::nrm2`] rather
+/// than delegating to the registered backend, so the result is correct even for
+/// components near the floating-point range limits regardless of backend quality.
 ///
 /// # Safety
 ///
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
-/// - dznrm2 must be registered via `register_dznrm2`
 #[no_mangle]
 pub unsafe extern "C" fn cblas_dznrm2(n: blasint, x: *const Complex64, incx: blasint) -> f64 {
-    let dznrm2 = get_dznrm2();
-    dznrm2(&n, x, &incx)
+    scaled_dznrm2(n, x, incx)
 }
 
 // =============================================================================
@@ -309,8 +371,14 @@ pub unsafe extern "C" fn cblas_dznrm2(n: blasint, x: *const Complex64, incx: bla
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
 /// - sasum must be registered via `register_sasum`
+///
+/// Computed via Kahan–Babuška–Neumaier compensated summation instead, if
+/// [`crate::set_accumulation_mode`] has been set to `Compensated`.
 #[no_mangle]
 pub unsafe extern "C" fn cblas_sasum(n: blasint, x: *const f32, incx: blasint) -> f32 {
+    if is_compensated() {
+        return kbn_sasum(n, x, incx);
+    }
     let sasum = get_sasum();
     sasum(&n, x, &incx)
 }
@@ -324,38 +392,56 @@ pub unsafe extern "C" fn cblas_sasum(n: blasint, x: *const f32, incx: blasint) -
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
 /// - dasum must be registered via `register_dasum`
+///
+/// Computed via Kahan–Babuška–Neumaier compensated summation instead, if
+/// [`crate::set_accumulation_mode`] has been set to `Compensated`.
 #[no_mangle]
 pub unsafe extern "C" fn cblas_dasum(n: blasint, x: *const f64, incx: blasint) -> f64 {
+    if is_compensated() {
+        return kbn_dasum(n, x, incx);
+    }
     let dasum = get_dasum();
     dasum(&n, x, &incx)
 }
 
 /// Complex single precision sum of absolute values.
 ///
-/// Computes: sum(|Re(x[i])| + |Im(x[i])|)
+/// Computes: sum(|Re(x[i])| + |Im(x[i])|). `n <= 0` or `incx <= 0` yields 0, per BLAS convention.
 ///
 /// # Safety
 ///
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
 /// - scasum must be registered via `register_scasum`
+///
+/// Computed via Kahan–Babuška–Neumaier compensated summation instead, if
+/// [`crate::set_accumulation_mode`] has been set to `Compensated`.
 #[no_mangle]
 pub unsafe extern "C" fn cblas_scasum(n: blasint, x: *const Complex32, incx: blasint) -> f32 {
+    if is_compensated() {
+        return kbn_scasum(n, x, incx);
+    }
     let scasum = get_scasum();
     scasum(&n, x, &incx)
 }
 
 /// Complex double precision sum of absolute values.
 ///
-/// Computes: sum(|Re(x[i])| + |Im(x[i])|)
+/// Computes: sum(|Re(x[i])| + |Im(x[i])|). `n <= 0` or `incx <= 0` yields 0, per BLAS convention.
 ///
 /// # Safety
 ///
 /// - All pointers must be valid and properly aligned
 /// - Vector dimensions and increments must be consistent
 /// - dzasum must be registered via `register_dzasum`
+///
+/// Computed via Kahan–Babuška–Neumaier compensated summation instead, if
+/// [`crate::set_accumulation_mode`] has been set to `Compensated`.
 #[no_mangle]
 pub unsafe extern "C" fn cblas_dzasum(n: blasint, x: *const Complex64, incx: blasint) -> f64 {
+    if is_compensated() {
+        return kbn_dzasum(n, x, incx);
+    }
     let dzasum = get_dzasum();
     dzasum(&n, x, &incx)
 }
@@ -376,7 +462,7 @@ pub unsafe extern "C" fn cblas_dzasum(n: blasint, x: *const Complex64, incx: bla
 /// - Vector dimensions and increments must be consistent
 /// - isamax must be registered via `register_isamax`
 #[no_mangle]
-pub unsafe extern "C" fn cblas_isamax(n: blasint, x: *const f32, incx: blasint) -> blasint {
+pub unsafe extern "C" fn cblas_isamax(n: blasint, x: *const f32, incx: blasint) -> CBLAS_INDEX {
     let isamax = get_isamax();
     let idx = isamax(&n, x, &incx);
     // Fortran returns 1-based index, convert to 0-based for CBLAS
@@ -399,7 +485,7 @@ pub unsafe extern "C" fn cblas_isamax(n: blasint, x: *const f32, incx: blasint)
 /// - Vector dimensions and increments must be consistent
 /// - idamax must be registered via `register_idamax`
 #[no_mangle]
-pub unsafe extern "C" fn cblas_idamax(n: blasint, x: *const f64, incx: blasint) -> blasint {
+pub unsafe extern "C" fn cblas_idamax(n: blasint, x: *const f64, incx: blasint) -> CBLAS_INDEX {
     let idamax = get_idamax();
     let idx = idamax(&n, x, &incx);
     // Fortran returns 1-based index, convert to 0-based for CBLAS
@@ -414,7 +500,7 @@ pub unsafe extern "C" fn cblas_idamax(n: blasint, x: *const f64, incx: blasint)
 ///
 /// Returns the index of the first element with maximum |Re(x[i])| + |Im(x[i])|.
 /// Note: CBLAS uses 0-based indexing, but Fortran BLAS returns 1-based index,
-/// so we subtract 1 from the result.
+/// so we subtract 1 from the result. `n <= 0` or `incx <= 0` yields index 0.
 ///
 /// # Safety
 ///
@@ -422,7 +508,7 @@ pub unsafe extern "C" fn cblas_idamax(n: blasint, x: *const f64, incx: blasint)
 /// - Vector dimensions and increments must be consistent
 /// - icamax must be registered via `register_icamax`
 #[no_mangle]
-pub unsafe extern "C" fn cblas_icamax(n: blasint, x: *const Complex32, incx: blasint) -> blasint {
+pub unsafe extern "C" fn cblas_icamax(n: blasint, x: *const Complex32, incx: blasint) -> CBLAS_INDEX {
     let icamax = get_icamax();
     let idx = icamax(&n, x, &incx);
     // Fortran returns 1-based index, convert to 0-based for CBLAS
@@ -437,7 +523,7 @@ pub unsafe extern "C" fn cblas_icamax(n: blasint, x: *const Complex32, incx: bla
 ///
 /// Returns the index of the first element with maximum |Re(x[i])| + |Im(x[i])|.
 /// Note: CBLAS uses 0-based indexing, but Fortran BLAS returns 1-based index,
-/// so we subtract 1 from the result.
+/// so we subtract 1 from the result. `n <= 0` or `incx <= 0` yields index 0.
 ///
 /// # Safety
 ///
@@ -445,7 +531,7 @@ pub unsafe extern "C" fn cblas_icamax(n: blasint, x: *const Complex32, incx: bla
 /// - Vector dimensions and increments must be consistent
 /// - izamax must be registered via `register_izamax`
 #[no_mangle]
-pub unsafe extern "C" fn cblas_izamax(n: blasint, x: *const Complex64, incx: blasint) -> blasint {
+pub unsafe extern "C" fn cblas_izamax(n: blasint, x: *const Complex64, incx: blasint) -> CBLAS_INDEX {
     let izamax = get_izamax();
     let idx = izamax(&n, x, &incx);
     // Fortran returns 1-based index, convert to 0-based for CBLAS
@@ -455,3 +541,107 @@ pub unsafe extern "C" fn cblas_izamax(n: blasint, x: *const Complex64, incx: bla
         0
     }
 }
+
+// =============================================================================
+// Index of minimum absolute value (OpenBLAS extension)
+// =============================================================================
+
+/// Index of minimum absolute value (single precision).
+///
+/// Returns the index of the first element with minimum |x[i]|. This is an
+/// OpenBLAS extension, not part of the reference CBLAS standard, mirroring
+/// `cblas_isamax` for the minimum instead of the maximum.
+/// Note: CBLAS uses 0-based indexing, but Fortran BLAS returns 1-based index,
+/// so we subtract 1 from the result.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - Vector dimensions and increments must be consistent
+/// - isamin must be registered via `register_isamin`
+#[no_mangle]
+pub unsafe extern "C" fn cblas_isamin(n: blasint, x: *const f32, incx: blasint) -> CBLAS_INDEX {
+    let isamin = get_isamin();
+    let idx = isamin(&n, x, &incx);
+    // Fortran returns 1-based index, convert to 0-based for CBLAS
+    if idx > 0 {
+        idx - 1
+    } else {
+        0
+    }
+}
+
+/// Index of minimum absolute value (double precision).
+///
+/// Returns the index of the first element with minimum |x[i]|. This is an
+/// OpenBLAS extension, not part of the reference CBLAS standard, mirroring
+/// `cblas_idamax` for the minimum instead of the maximum.
+/// Note: CBLAS uses 0-based indexing, but Fortran BLAS returns 1-based index,
+/// so we subtract 1 from the result.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - Vector dimensions and increments must be consistent
+/// - idamin must be registered via `register_idamin`
+#[no_mangle]
+pub unsafe extern "C" fn cblas_idamin(n: blasint, x: *const f64, incx: blasint) -> CBLAS_INDEX {
+    let idamin = get_idamin();
+    let idx = idamin(&n, x, &incx);
+    // Fortran returns 1-based index, convert to 0-based for CBLAS
+    if idx > 0 {
+        idx - 1
+    } else {
+        0
+    }
+}
+
+/// Index of minimum absolute value (complex single precision).
+///
+/// Returns the index of the first element with minimum |Re(x[i])| + |Im(x[i])|.
+/// This is an OpenBLAS extension, not part of the reference CBLAS standard,
+/// mirroring `cblas_icamax` for the minimum instead of the maximum.
+/// Note: CBLAS uses 0-based indexing, but Fortran BLAS returns 1-based index,
+/// so we subtract 1 from the result. `n <= 0` or `incx <= 0` yields index 0.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - Vector dimensions and increments must be consistent
+/// - icamin must be registered via `register_icamin`
+#[no_mangle]
+pub unsafe extern "C" fn cblas_icamin(n: blasint, x: *const Complex32, incx: blasint) -> CBLAS_INDEX {
+    let icamin = get_icamin();
+    let idx = icamin(&n, x, &incx);
+    // Fortran returns 1-based index, convert to 0-based for CBLAS
+    if idx > 0 {
+        idx - 1
+    } else {
+        0
+    }
+}
+
+/// Index of minimum absolute value (complex double precision).
+///
+/// Returns the index of the first element with minimum |Re(x[i])| + |Im(x[i])|.
+/// This is an OpenBLAS extension, not part of the reference CBLAS standard,
+/// mirroring `cblas_izamax` for the minimum instead of the maximum.
+/// Note: CBLAS uses 0-based indexing, but Fortran BLAS returns 1-based index,
+/// so we subtract 1 from the result. `n <= 0` or `incx <= 0` yields index 0.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - Vector dimensions and increments must be consistent
+/// - izamin must be registered via `register_izamin`
+#[no_mangle]
+pub unsafe extern "C" fn cblas_izamin(n: blasint, x: *const Complex64, incx: blasint) -> CBLAS_INDEX {
+    let izamin = get_izamin();
+    let idx = izamin(&n, x, &incx);
+    // Fortran returns 1-based index, convert to 0-based for CBLAS
+    if idx > 0 {
+        idx - 1
+    } else {
+        0
+    }
+}
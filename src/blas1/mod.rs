@@ -0,0 +1,6 @@
+//! BLAS Level 1 operations (vector-vector).
+
+pub mod dot;
+pub mod level1f;
+pub mod rot;
+pub mod vector;
@@ -1,10 +1,16 @@
 //! BLAS Level 1: Givens rotations and auxiliary functions.
+//!
+//! [`cblas_drotmg`]/[`cblas_srotmg`] write the modified-Givens transform as a raw
+//! 5-element `param` array whose `param[0]` flag value decides which of `param[1..5]`
+//! are actually meaningful; [`cblas_drotm`]/[`cblas_srotm`] read that array back in the
+//! same raw form. [`ModifiedGivensParams`] and the `*_typed` functions below decode and
+//! re-encode that array instead, so a caller doesn't have to hand-parse the flag.
 
 use num_complex::{Complex32, Complex64};
 
 use crate::backend::{
-    get_dcabs1, get_drot, get_drotg, get_drotm, get_drotmg, get_scabs1, get_srot, get_srotg,
-    get_srotm, get_srotmg,
+    get_crotg, get_csrot, get_dcabs1, get_drot, get_drotg, get_drotm, get_drotmg, get_scabs1,
+    get_srot, get_srotg, get_srotm, get_srotmg, get_zdrot, get_zrotg,
 };
 use crate::types::blasint;
 
@@ -62,6 +68,62 @@ pub unsafe extern "C" fn cblas_srot(
     srot(&n, x, &incx, y, &incy, &c, &s);
 }
 
+/// Apply a real Givens rotation to a complex vector (single precision).
+///
+/// Applies the same rotation as `cblas_srot`, but to the real/imaginary components
+/// of `x` and `y` taken together as real vectors of length `2*n`:
+/// ```text
+/// x[i] = c*x[i] + s*y[i]
+/// y[i] = -s*x[i] + c*y[i]
+/// ```
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - Arrays must have at least `n` elements with the given stride
+/// - csrot must be registered via `register_csrot`
+#[no_mangle]
+pub unsafe extern "C" fn cblas_csrot(
+    n: blasint,
+    x: *mut Complex32,
+    incx: blasint,
+    y: *mut Complex32,
+    incy: blasint,
+    c: f32,
+    s: f32,
+) {
+    let csrot = get_csrot();
+    csrot(&n, x, &incx, y, &incy, &c, &s);
+}
+
+/// Apply a real Givens rotation to a complex vector (double precision).
+///
+/// Applies the same rotation as `cblas_drot`, but to the real/imaginary components
+/// of `x` and `y` taken together as real vectors of length `2*n`:
+/// ```text
+/// x[i] = c*x[i] + s*y[i]
+/// y[i] = -s*x[i] + c*y[i]
+/// ```
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - Arrays must have at least `n` elements with the given stride
+/// - zdrot must be registered via `register_zdrot`
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zdrot(
+    n: blasint,
+    x: *mut Complex64,
+    incx: blasint,
+    y: *mut Complex64,
+    incy: blasint,
+    c: f64,
+    s: f64,
+) {
+    let zdrot = get_zdrot();
+    zdrot(&n, x, &incx, y, &incy, &c, &s);
+}
+
 /// Generate Givens rotation (double precision).
 ///
 /// Computes `c` and `s` such that:
@@ -104,6 +166,48 @@ pub unsafe extern "C" fn cblas_srotg(a: *mut f32, b: *mut f32, c: *mut f32, s: *
     srotg(a, b, c, s);
 }
 
+/// Generate a complex Givens rotation (single precision).
+///
+/// Computes real `c` and complex `s` such that the rotation zeroes the second
+/// component of `(a, b)`. `a` is overwritten with the resulting `r`; `b` is left
+/// unchanged, matching the reference BLAS `CROTG` convention.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - crotg must be registered via `register_crotg`
+#[no_mangle]
+pub unsafe extern "C" fn cblas_crotg(
+    a: *mut Complex32,
+    b: *const Complex32,
+    c: *mut f32,
+    s: *mut Complex32,
+) {
+    let crotg = get_crotg();
+    crotg(a, b, c, s);
+}
+
+/// Generate a complex Givens rotation (double precision).
+///
+/// Computes real `c` and complex `s` such that the rotation zeroes the second
+/// component of `(a, b)`. `a` is overwritten with the resulting `r`; `b` is left
+/// unchanged, matching the reference BLAS `ZROTG` convention.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - zrotg must be registered via `register_zrotg`
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zrotg(
+    a: *mut Complex64,
+    b: *const Complex64,
+    c: *mut f64,
+    s: *mut Complex64,
+) {
+    let zrotg = get_zrotg();
+    zrotg(a, b, c, s);
+}
+
 /// Apply modified Givens rotation (double precision).
 ///
 /// Applies the modified Givens rotation specified by the 5-element parameter array `p`:
@@ -229,3 +333,182 @@ pub unsafe extern "C" fn cblas_scabs1(z: *const Complex32) -> f32 {
     let scabs1 = get_scabs1();
     scabs1(z)
 }
+
+/// Decoded form of the modified-Givens flag `?rotmg` writes to `param[0]` (and `?rotm`
+/// reads back from it), naming which of `param[1..5]` are meaningful instead of leaving
+/// callers to remember the magic flag values themselves. See the reference BLAS
+/// `DROTMG`/`SROTMG` documentation for the underlying convention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModifiedGivensFlag {
+    /// `param[0] == -2.0`: `H` is the identity matrix; no rotation is applied.
+    Identity,
+    /// `param[0] == -1.0`: all four entries of `H` are meaningful.
+    Rescaling,
+    /// `param[0] == 0.0`: the diagonal of `H` is implicitly `1.0`; only the
+    /// off-diagonal entries (`h21`, `h12`) are stored in `param`.
+    OffDiagonal,
+    /// `param[0] == 1.0`: the off-diagonal of `H` is implicitly `(-1.0, 1.0)`; only the
+    /// diagonal entries (`h11`, `h22`) are stored in `param`.
+    Diagonal,
+}
+
+impl ModifiedGivensFlag {
+    fn from_raw(flag: f64) -> Self {
+        if flag == -2.0 {
+            Self::Identity
+        } else if flag == -1.0 {
+            Self::Rescaling
+        } else if flag == 0.0 {
+            Self::OffDiagonal
+        } else {
+            // 1.0 is the only flag value `?rotmg` can still produce here.
+            Self::Diagonal
+        }
+    }
+
+    fn to_raw(self) -> f64 {
+        match self {
+            Self::Identity => -2.0,
+            Self::Rescaling => -1.0,
+            Self::OffDiagonal => 0.0,
+            Self::Diagonal => 1.0,
+        }
+    }
+}
+
+/// A modified-Givens transform `H`, decoded from (or ready to be encoded into) the flat
+/// `param` array `?rotmg`/`?rotm` use, together with the full `[h11, h21, h12, h22]`
+/// matrix in column-major order with the flag-implied entries filled in, rather than
+/// left as whatever `param[1..5]` happens to hold for entries the flag marks unused.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModifiedGivensParams<T> {
+    pub flag: ModifiedGivensFlag,
+    /// `[h11, h21, h12, h22]`, column-major.
+    pub h: [T; 4],
+}
+
+impl ModifiedGivensParams<f64> {
+    /// Decodes a raw `drotmg`/`drotm` parameter array (`[flag, h11, h21, h12, h22]`).
+    pub fn from_raw_param(p: [f64; 5]) -> Self {
+        let flag = ModifiedGivensFlag::from_raw(p[0]);
+        let h = match flag {
+            ModifiedGivensFlag::Identity => [1.0, 0.0, 0.0, 1.0],
+            ModifiedGivensFlag::Rescaling => [p[1], p[2], p[3], p[4]],
+            ModifiedGivensFlag::OffDiagonal => [1.0, p[2], p[3], 1.0],
+            ModifiedGivensFlag::Diagonal => [p[1], -1.0, 1.0, p[4]],
+        };
+        Self { flag, h }
+    }
+
+    /// Encodes back into the raw 5-element form `drotm` expects as `param`.
+    pub fn to_raw_param(self) -> [f64; 5] {
+        let [h11, h21, h12, h22] = self.h;
+        match self.flag {
+            ModifiedGivensFlag::Identity => [-2.0, 0.0, 0.0, 0.0, 0.0],
+            ModifiedGivensFlag::Rescaling => [-1.0, h11, h21, h12, h22],
+            ModifiedGivensFlag::OffDiagonal => [0.0, 0.0, h21, h12, 0.0],
+            ModifiedGivensFlag::Diagonal => [1.0, h11, 0.0, 0.0, h22],
+        }
+    }
+}
+
+impl ModifiedGivensParams<f32> {
+    /// Decodes a raw `srotmg`/`srotm` parameter array (`[flag, h11, h21, h12, h22]`).
+    pub fn from_raw_param(p: [f32; 5]) -> Self {
+        let flag = ModifiedGivensFlag::from_raw(p[0] as f64);
+        let h = match flag {
+            ModifiedGivensFlag::Identity => [1.0, 0.0, 0.0, 1.0],
+            ModifiedGivensFlag::Rescaling => [p[1], p[2], p[3], p[4]],
+            ModifiedGivensFlag::OffDiagonal => [1.0, p[2], p[3], 1.0],
+            ModifiedGivensFlag::Diagonal => [p[1], -1.0, 1.0, p[4]],
+        };
+        Self { flag, h }
+    }
+
+    /// Encodes back into the raw 5-element form `srotm` expects as `param`.
+    pub fn to_raw_param(self) -> [f32; 5] {
+        let [h11, h21, h12, h22] = self.h;
+        match self.flag {
+            ModifiedGivensFlag::Identity => [-2.0, 0.0, 0.0, 0.0, 0.0],
+            ModifiedGivensFlag::Rescaling => [-1.0, h11, h21, h12, h22],
+            ModifiedGivensFlag::OffDiagonal => [0.0, 0.0, h21, h12, 0.0],
+            ModifiedGivensFlag::Diagonal => [1.0, h11, 0.0, 0.0, h22],
+        }
+    }
+}
+
+/// Safe, typed form of [`cblas_drotmg`]: generates the modified-Givens transform that
+/// eliminates the second component of `(b1, b2)` while rescaling by `(d1, d2)`, and
+/// returns the updated `(d1, d2, b1)` together with the decoded [`ModifiedGivensParams`]
+/// instead of writing the raw flag and opaque `param` array through output pointers.
+///
+/// # Panics
+///
+/// Panics if `drotmg` hasn't been registered, same as [`cblas_drotmg`].
+pub fn drotmg_typed(
+    d1: f64,
+    d2: f64,
+    b1: f64,
+    b2: f64,
+) -> (f64, f64, f64, ModifiedGivensParams<f64>) {
+    let (mut d1, mut d2, mut b1) = (d1, d2, b1);
+    let mut p = [0.0f64; 5];
+    unsafe {
+        cblas_drotmg(&mut d1, &mut d2, &mut b1, b2, p.as_mut_ptr());
+    }
+    (d1, d2, b1, <ModifiedGivensParams<f64>>::from_raw_param(p))
+}
+
+/// Single-precision counterpart of [`drotmg_typed`]; see its documentation.
+///
+/// # Panics
+///
+/// Panics if `srotmg` hasn't been registered, same as [`cblas_srotmg`].
+pub fn srotmg_typed(
+    d1: f32,
+    d2: f32,
+    b1: f32,
+    b2: f32,
+) -> (f32, f32, f32, ModifiedGivensParams<f32>) {
+    let (mut d1, mut d2, mut b1) = (d1, d2, b1);
+    let mut p = [0.0f32; 5];
+    unsafe {
+        cblas_srotmg(&mut d1, &mut d2, &mut b1, b2, p.as_mut_ptr());
+    }
+    (d1, d2, b1, <ModifiedGivensParams<f32>>::from_raw_param(p))
+}
+
+/// Typed form of [`cblas_drotm`]: encodes `params` into the raw `param` array the
+/// Fortran routine expects, then applies the modified-Givens rotation to `x`/`y`.
+///
+/// # Safety
+///
+/// Same pointer/stride requirements as [`cblas_drotm`].
+pub unsafe fn drotm_typed(
+    n: blasint,
+    x: *mut f64,
+    incx: blasint,
+    y: *mut f64,
+    incy: blasint,
+    params: ModifiedGivensParams<f64>,
+) {
+    let p = params.to_raw_param();
+    cblas_drotm(n, x, incx, y, incy, p.as_ptr());
+}
+
+/// Single-precision counterpart of [`drotm_typed`]; see its documentation.
+///
+/// # Safety
+///
+/// Same pointer/stride requirements as [`cblas_srotm`].
+pub unsafe fn srotm_typed(
+    n: blasint,
+    x: *mut f32,
+    incx: blasint,
+    y: *mut f32,
+    incy: blasint,
+    params: ModifiedGivensParams<f32>,
+) {
+    let p = params.to_raw_param();
+    cblas_srotm(n, x, incx, y, incy, p.as_ptr());
+}
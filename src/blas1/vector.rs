@@ -310,3 +310,77 @@ pub unsafe extern "C" fn cblas_zdscal(n: blasint, alpha: f64, x: *mut Complex64,
     let zdscal = get_zdscal();
     zdscal(&n, &alpha, x, &incx);
 }
+
+// =============================================================================
+// Safe, slice-based wrappers
+// =============================================================================
+
+/// Checks that a strided vector of length `n` with increment `inc` fits in `slice`,
+/// returning a descriptive error tagged with `routine` and `arg_name` if not.
+fn check_vector_len(
+    routine: &str,
+    arg_name: &str,
+    slice_len: usize,
+    n: blasint,
+    inc: blasint,
+) -> Result<(), String> {
+    if n < 0 {
+        return Err(format!("{routine}: n ({n}) must be non-negative"));
+    }
+    let needed = 1 + (n.max(1) as usize - 1) * (inc.unsigned_abs() as usize);
+    if n > 0 && slice_len < needed {
+        return Err(format!(
+            "{routine}: `{arg_name}` has {slice_len} elements, but n={n} and inc{arg_name}={inc} need at least {needed}"
+        ));
+    }
+    Ok(())
+}
+
+/// Safe, slice-based vector swap, for callers who'd rather check a `Result` than hold
+/// up the `unsafe` contract [`cblas_dswap`] requires of its raw pointers.
+pub fn try_dswap(n: blasint, x: &mut [f64], incx: blasint, y: &mut [f64], incy: blasint) -> Result<(), String> {
+    if !crate::backend::has_dswap() {
+        return Err("dswap backend not registered: call register_dswap first".to_string());
+    }
+    check_vector_len("dswap", "x", x.len(), n, incx)?;
+    check_vector_len("dswap", "y", y.len(), n, incy)?;
+    unsafe { cblas_dswap(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy) };
+    Ok(())
+}
+
+/// Safe, slice-based vector copy, for callers who'd rather check a `Result` than hold
+/// up the `unsafe` contract [`cblas_dcopy`] requires of its raw pointers.
+pub fn try_dcopy(n: blasint, x: &[f64], incx: blasint, y: &mut [f64], incy: blasint) -> Result<(), String> {
+    if !crate::backend::has_dcopy() {
+        return Err("dcopy backend not registered: call register_dcopy first".to_string());
+    }
+    check_vector_len("dcopy", "x", x.len(), n, incx)?;
+    check_vector_len("dcopy", "y", y.len(), n, incy)?;
+    unsafe { cblas_dcopy(n, x.as_ptr(), incx, y.as_mut_ptr(), incy) };
+    Ok(())
+}
+
+/// Safe, slice-based axpy (`y = alpha*x + y`), for callers who'd rather check a
+/// `Result` than hold up the `unsafe` contract [`cblas_daxpy`] requires of its raw
+/// pointers.
+pub fn try_daxpy(n: blasint, alpha: f64, x: &[f64], incx: blasint, y: &mut [f64], incy: blasint) -> Result<(), String> {
+    if !crate::backend::has_daxpy() {
+        return Err("daxpy backend not registered: call register_daxpy first".to_string());
+    }
+    check_vector_len("daxpy", "x", x.len(), n, incx)?;
+    check_vector_len("daxpy", "y", y.len(), n, incy)?;
+    unsafe { cblas_daxpy(n, alpha, x.as_ptr(), incx, y.as_mut_ptr(), incy) };
+    Ok(())
+}
+
+/// Safe, slice-based vector scaling (`x = alpha*x`), for callers who'd rather check a
+/// `Result` than hold up the `unsafe` contract [`cblas_dscal`] requires of its raw
+/// pointers.
+pub fn try_dscal(n: blasint, alpha: f64, x: &mut [f64], incx: blasint) -> Result<(), String> {
+    if !crate::backend::has_dscal() {
+        return Err("dscal backend not registered: call register_dscal first".to_string());
+    }
+    check_vector_len("dscal", "x", x.len(), n, incx)?;
+    unsafe { cblas_dscal(n, alpha, x.as_mut_ptr(), incx) };
+    Ok(())
+}
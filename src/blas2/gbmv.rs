@@ -7,33 +7,57 @@
 //! Copyright (c) 2011-2014, The OpenBLAS Project. BSD-3-Clause License.
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/gbmv.c>
 //!
-//! For row-major layout:
-//! - Swap m and n
-//! - Swap kl and ku (sub-diagonals <-> super-diagonals)
-//! - Flip the transpose operation (NoTrans <-> Trans, ConjNoTrans <-> ConjTrans)
+//! For row-major layout, [`crate::layout::gbmv_convert`] swaps m/n, swaps kl/ku
+//! (sub-diagonals <-> super-diagonals), and flips the transpose operation
+//! (NoTrans <-> Trans).
+//!
+//! `CblasConjTrans` needs more care: the swap above reinterprets the stored band data as
+//! its own transpose, so `op(A) = conj(A)^T` on the row-major matrix becomes
+//! `conj(view)` on the reinterpreted one — conjugate-without-transpose, which Fortran has
+//! no character code for. `cblas_cgbmv`/`cblas_zgbmv` realize it the same way
+//! `cblas_cgemm`/`cblas_zgemm` realize `CblasConjNoTrans`: conjugate the band data into a
+//! scratch buffer and call the backend with plain `NoTrans` in its place.
 
 use num_complex::{Complex32, Complex64};
 
 use crate::backend::{get_cgbmv, get_dgbmv, get_sgbmv, get_zgbmv};
+use crate::conj::conjugate_matrix_copy;
+use crate::layout::gbmv_convert;
 use crate::types::{
     blasint, transpose_to_char, CblasColMajor, CblasConjTrans, CblasNoTrans, CblasRowMajor,
-    CblasTrans, CBLAS_ORDER, CBLAS_TRANSPOSE,
+    CBLAS_ORDER, CBLAS_TRANSPOSE,
 };
+use crate::validation::validate;
 
-/// Flip transpose operation for row-major conversion.
-///
-/// NoTrans <-> Trans, ConjNoTrans <-> ConjTrans
-#[inline]
-fn flip_transpose(trans: CBLAS_TRANSPOSE) -> CBLAS_TRANSPOSE {
-    match trans {
-        CblasNoTrans => CblasTrans,
-        CblasTrans => CblasNoTrans,
-        CblasConjTrans => {
-            // ConjNoTrans is not in our enum but maps to ConjTrans flip
-            // For real types, ConjTrans == Trans
-            CblasNoTrans
-        }
-    }
+/// Validates CBLAS argument positions 3 (m), 4 (n), 5 (kl), 6 (ku), 9 (lda), 11
+/// (incx), and 14 (incy) for `cblas_?gbmv`: `m >= 0`, `n >= 0`, `kl >= 0`, `ku >= 0`,
+/// `lda >= kl + ku + 1` to hold the full band plus diagonal, `incx != 0`, and
+/// `incy != 0`. Positions are fixed regardless of `order`, the same as GEMV's
+/// `check_gemv`: the internal `m`/`n`/`kl`/`ku` swap only changes what's passed to
+/// the Fortran backend, not where the caller's own arguments sit in the CBLAS call.
+#[allow(clippy::too_many_arguments)]
+unsafe fn check_gbmv(
+    routine: &str,
+    m: blasint,
+    n: blasint,
+    kl: blasint,
+    ku: blasint,
+    lda: blasint,
+    incx: blasint,
+    incy: blasint,
+) -> bool {
+    validate(
+        routine,
+        &[
+            (m >= 0, 3),
+            (n >= 0, 4),
+            (kl >= 0, 5),
+            (ku >= 0, 6),
+            (lda > kl + ku, 9),
+            (incx != 0, 11),
+            (incy != 0, 14),
+        ],
+    )
 }
 
 /// Single precision general band matrix-vector multiply.
@@ -64,49 +88,16 @@ pub unsafe extern "C" fn cblas_sgbmv(
     y: *mut f32,
     incy: blasint,
 ) {
+    if check_gbmv("cblas_sgbmv", m, n, kl, ku, lda, incx, incy) {
+        return;
+    }
     let sgbmv = get_sgbmv();
 
-    match order {
-        CblasColMajor => {
-            // Column-major: call Fortran directly
-            let trans_char = transpose_to_char(trans);
-            sgbmv(
-                &trans_char,
-                &m,
-                &n,
-                &kl,
-                &ku,
-                &alpha,
-                a,
-                &lda,
-                x,
-                &incx,
-                &beta,
-                y,
-                &incy,
-            );
-        }
-        CblasRowMajor => {
-            // Row-major: swap m/n, kl/ku and flip transpose
-            // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/gbmv.c
-            let trans_char = transpose_to_char(flip_transpose(trans));
-            sgbmv(
-                &trans_char,
-                &n,  // swapped: m -> n
-                &m,  // swapped: n -> m
-                &ku, // swapped: kl -> ku
-                &kl, // swapped: ku -> kl
-                &alpha,
-                a,
-                &lda,
-                x,
-                &incx,
-                &beta,
-                y,
-                &incy,
-            );
-        }
-    }
+    let (trans, m, n, kl, ku) = gbmv_convert(order, trans, m, n, kl, ku);
+    let trans_char = transpose_to_char(trans);
+    sgbmv(
+        &trans_char, &m, &n, &kl, &ku, &alpha, a, &lda, x, &incx, &beta, y, &incy,
+    );
 }
 
 /// Double precision general band matrix-vector multiply.
@@ -137,46 +128,16 @@ pub unsafe extern "C" fn cblas_dgbmv(
     y: *mut f64,
     incy: blasint,
 ) {
+    if check_gbmv("cblas_dgbmv", m, n, kl, ku, lda, incx, incy) {
+        return;
+    }
     let dgbmv = get_dgbmv();
 
-    match order {
-        CblasColMajor => {
-            let trans_char = transpose_to_char(trans);
-            dgbmv(
-                &trans_char,
-                &m,
-                &n,
-                &kl,
-                &ku,
-                &alpha,
-                a,
-                &lda,
-                x,
-                &incx,
-                &beta,
-                y,
-                &incy,
-            );
-        }
-        CblasRowMajor => {
-            let trans_char = transpose_to_char(flip_transpose(trans));
-            dgbmv(
-                &trans_char,
-                &n,
-                &m,
-                &ku,
-                &kl,
-                &alpha,
-                a,
-                &lda,
-                x,
-                &incx,
-                &beta,
-                y,
-                &incy,
-            );
-        }
-    }
+    let (trans, m, n, kl, ku) = gbmv_convert(order, trans, m, n, kl, ku);
+    let trans_char = transpose_to_char(trans);
+    dgbmv(
+        &trans_char, &m, &n, &kl, &ku, &alpha, a, &lda, x, &incx, &beta, y, &incy,
+    );
 }
 
 /// Single precision complex general band matrix-vector multiply.
@@ -207,6 +168,9 @@ pub unsafe extern "C" fn cblas_cgbmv(
     y: *mut Complex32,
     incy: blasint,
 ) {
+    if check_gbmv("cblas_cgbmv", m, n, kl, ku, lda, incx, incy) {
+        return;
+    }
     let cgbmv = get_cgbmv();
 
     match order {
@@ -229,34 +193,33 @@ pub unsafe extern "C" fn cblas_cgbmv(
             );
         }
         CblasRowMajor => {
-            // For complex, we need to handle ConjTrans specially
-            let flipped_trans = match trans {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                CblasConjTrans => {
-                    // ConjTrans in row-major becomes ConjNoTrans in col-major
-                    // But Fortran uses 'R' for this (conjugate, no transpose)
-                    // OpenBLAS maps CblasConjTrans -> trans=2 (R) for row-major
-                    // However, we don't have a ConjNoTrans enum value
-                    // For row-major ConjTrans: becomes column-major with conjugate and no transpose
-                    CblasNoTrans // This is approximate - complex conjugate handling differs
-                }
-            };
-            let trans_char = transpose_to_char(flipped_trans);
+            if trans == CblasConjTrans {
+                // op(A) = conj(A)^T: the m<->n/kl<->ku swap already reinterprets the
+                // band data as its own transpose, so what remains is conjugating that
+                // data and calling with plain NoTrans (see the module doc).
+                let a_conj = conjugate_matrix_copy(CblasColMajor, lda, m, a, lda);
+                let trans_char = transpose_to_char(CblasNoTrans);
+                cgbmv(
+                    &trans_char,
+                    &n,
+                    &m,
+                    &ku,
+                    &kl,
+                    alpha,
+                    a_conj.as_ptr(),
+                    &lda,
+                    x,
+                    &incx,
+                    beta,
+                    y,
+                    &incy,
+                );
+                return;
+            }
+            let (trans, m, n, kl, ku) = gbmv_convert(order, trans, m, n, kl, ku);
+            let trans_char = transpose_to_char(trans);
             cgbmv(
-                &trans_char,
-                &n,
-                &m,
-                &ku,
-                &kl,
-                alpha,
-                a,
-                &lda,
-                x,
-                &incx,
-                beta,
-                y,
-                &incy,
+                &trans_char, &m, &n, &kl, &ku, alpha, a, &lda, x, &incx, beta, y, &incy,
             );
         }
     }
@@ -290,6 +253,9 @@ pub unsafe extern "C" fn cblas_zgbmv(
     y: *mut Complex64,
     incy: blasint,
 ) {
+    if check_gbmv("cblas_zgbmv", m, n, kl, ku, lda, incx, incy) {
+        return;
+    }
     let zgbmv = get_zgbmv();
 
     match order {
@@ -312,30 +278,31 @@ pub unsafe extern "C" fn cblas_zgbmv(
             );
         }
         CblasRowMajor => {
-            // For complex, we need to handle ConjTrans specially
-            let flipped_trans = match trans {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                CblasConjTrans => {
-                    // Same handling as cgbmv
-                    CblasNoTrans
-                }
-            };
-            let trans_char = transpose_to_char(flipped_trans);
+            if trans == CblasConjTrans {
+                // Same handling as cblas_cgbmv.
+                let a_conj = conjugate_matrix_copy(CblasColMajor, lda, m, a, lda);
+                let trans_char = transpose_to_char(CblasNoTrans);
+                zgbmv(
+                    &trans_char,
+                    &n,
+                    &m,
+                    &ku,
+                    &kl,
+                    alpha,
+                    a_conj.as_ptr(),
+                    &lda,
+                    x,
+                    &incx,
+                    beta,
+                    y,
+                    &incy,
+                );
+                return;
+            }
+            let (trans, m, n, kl, ku) = gbmv_convert(order, trans, m, n, kl, ku);
+            let trans_char = transpose_to_char(trans);
             zgbmv(
-                &trans_char,
-                &n,
-                &m,
-                &ku,
-                &kl,
-                alpha,
-                a,
-                &lda,
-                x,
-                &incx,
-                beta,
-                y,
-                &incy,
+                &trans_char, &m, &n, &kl, &ku, alpha, a, &lda, x, &incx, beta, y, &incy,
             );
         }
     }
@@ -6,30 +6,138 @@
 //! Copyright (c) 2011-2014, The OpenBLAS Project. BSD-3-Clause License.
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/gemv.c>
 //!
-//! For row-major layout:
-//! - Swap m and n
-//! - Flip the transpose operation (NoTrans <-> Trans, ConjNoTrans <-> ConjTrans)
+//! For row-major layout we swap `m`/`n` and flip the transpose operation: `NoTrans`
+//! <-> `Trans`. `CblasConjNoTrans` has no Fortran character code in either layout (only
+//! `N`/`T`/`C`), so `cblas_cgemv`/`cblas_zgemv` realize it via `conj(A)*x = conj(A *
+//! conj(x))`: conjugate `x` into a scratch copy and `y` in place, call the backend with
+//! plain `NoTrans` and conjugated `alpha`/`beta`, then conjugate `y` in place again to
+//! recover the true result. Row-major `ConjTrans` needs the same trick (it would
+//! otherwise flip to a row-major `ConjNoTrans`, which hits the same missing-character
+//! problem), just with `m`/`n` swapped.
+//!
+//! All numeric work here is delegated to whatever `crate::backend::get_*gemv` returns,
+//! so there's no compute loop in this module itself to dispatch SIMD from. That
+//! accessor does fall back to a pure-Rust kernel (`crate::reference::ref_sgemv`/
+//! `ref_dgemv`) when the `reference` feature is on and no backend is registered, but
+//! that fallback is still a plain scalar loop today; `crate::simd`'s
+//! runtime-CPU-feature-detected kernel is only wired into `ref_stpsv`/`ref_dtpsv`'s
+//! substitution loop so far (see `crate::reference`'s module doc).
+//!
+//! `trans` is GEMV's only CHARACTER argument, so it's also where this crate's hidden
+//! character-length handling (see `crate::backend::CharLenConvention`) is demonstrated:
+//! each `call_?gemv` helper below transmutes the registered function pointer to the
+//! trailing- or interspersed-length variant the active convention calls for, rather than
+//! calling the plain pointer directly. The same recipe (dispatch on
+//! `get_char_len_convention()`, transmute to a parallel pointer type carrying the extra
+//! `usize` arguments) applies to every other CHARACTER-bearing routine in `blas2`/`blas3`.
+
+use std::ffi::c_char;
 
 use num_complex::{Complex32, Complex64};
 
-use crate::backend::{get_cgemv, get_dgemv, get_sgemv, get_zgemv};
+use crate::backend::{
+    get_cgemv, get_char_len_convention, get_dgemv, get_sgemv, get_zgemv, CgemvFnPtr,
+    CgemvInterspersedLenFnPtr, CgemvTrailingLenFnPtr, DgemvFnPtr, DgemvInterspersedLenFnPtr,
+    DgemvTrailingLenFnPtr, SgemvFnPtr, SgemvInterspersedLenFnPtr, SgemvTrailingLenFnPtr,
+    ZgemvFnPtr, ZgemvInterspersedLenFnPtr, ZgemvTrailingLenFnPtr,
+};
+use crate::conj::{conjugate_vector_copy, conjugate_vector_inplace, Conjugate};
 use crate::types::{
-    blasint, transpose_to_char, CblasColMajor, CblasConjTrans, CblasNoTrans, CblasRowMajor,
-    CblasTrans, CBLAS_ORDER, CBLAS_TRANSPOSE,
+    blasint, flip_transpose_real, normalize_transpose_real, transpose_to_char, CblasColMajor,
+    CblasConjNoTrans, CblasConjTrans, CblasNoTrans, CblasRowMajor, CblasTrans, CharLenConvention,
+    CBLAS_ORDER, CBLAS_TRANSPOSE,
 };
+use crate::validation::validate;
 
-/// Flip transpose operation for row-major conversion.
+/// Validates CBLAS argument positions 3 (m), 4 (n), 7 (lda), 9 (incx), and 12 (incy)
+/// for `cblas_?gemv`: `m >= 0`, `n >= 0`, `incx != 0`, `incy != 0`, and `lda` large
+/// enough to hold `A` in the layout `order` stores it in (`lda >= max(1,m)` for
+/// `CblasColMajor`, `lda >= max(1,n)` for `CblasRowMajor` — this doesn't depend on
+/// `trans`, since `trans` only changes which product is computed, not how `A` is
+/// stored). Positions are fixed regardless of `order`, unlike [`validate_layout`]'s
+/// shift: the row-major `lda` minimum already accounts for the layout directly.
 ///
-/// NoTrans <-> Trans, ConjNoTrans <-> ConjTrans
+/// [`validate_layout`]: crate::validation::validate_layout
+unsafe fn check_gemv(
+    routine: &str,
+    order: CBLAS_ORDER,
+    m: blasint,
+    n: blasint,
+    lda: blasint,
+    incx: blasint,
+    incy: blasint,
+) -> bool {
+    let lda_min = match order {
+        CblasColMajor => m.max(1),
+        CblasRowMajor => n.max(1),
+    };
+    validate(
+        routine,
+        &[
+            (m >= 0, 3),
+            (n >= 0, 4),
+            (lda >= lda_min, 7),
+            (incx != 0, 9),
+            (incy != 0, 12),
+        ],
+    )
+}
+
+/// Calls `sgemv`, routing the lone CHARACTER argument (`trans`) through whichever
+/// hidden character-length convention is currently configured.
 #[inline]
-fn flip_transpose(trans: CBLAS_TRANSPOSE) -> CBLAS_TRANSPOSE {
-    match trans {
-        CblasNoTrans => CblasTrans,
-        CblasTrans => CblasNoTrans,
-        CblasConjTrans => {
-            // ConjNoTrans is not in our enum but maps to ConjTrans flip
-            // For real types, ConjTrans == Trans
-            CblasNoTrans
+#[allow(clippy::too_many_arguments)]
+unsafe fn call_sgemv(
+    sgemv: SgemvFnPtr,
+    trans_char: c_char,
+    m: blasint,
+    n: blasint,
+    alpha: f32,
+    a: *const f32,
+    lda: blasint,
+    x: *const f32,
+    incx: blasint,
+    beta: f32,
+    y: *mut f32,
+    incy: blasint,
+) {
+    match get_char_len_convention() {
+        CharLenConvention::None => {
+            sgemv(&trans_char, &m, &n, &alpha, a, &lda, x, &incx, &beta, y, &incy)
+        }
+        CharLenConvention::Trailing => {
+            let f: SgemvTrailingLenFnPtr = std::mem::transmute(sgemv);
+            f(
+                &trans_char,
+                &m,
+                &n,
+                &alpha,
+                a,
+                &lda,
+                x,
+                &incx,
+                &beta,
+                y,
+                &incy,
+                1,
+            )
+        }
+        CharLenConvention::Interspersed => {
+            let f: SgemvInterspersedLenFnPtr = std::mem::transmute(sgemv);
+            f(
+                &trans_char,
+                1,
+                &m,
+                &n,
+                &alpha,
+                a,
+                &lda,
+                x,
+                &incx,
+                &beta,
+                y,
+                &incy,
+            )
         }
     }
 }
@@ -59,13 +167,56 @@ pub unsafe extern "C" fn cblas_sgemv(
     y: *mut f32,
     incy: blasint,
 ) {
+    if check_gemv("cblas_sgemv", order, m, n, lda, incx, incy) {
+        return;
+    }
     let sgemv = get_sgemv();
 
     match order {
         CblasColMajor => {
             // Column-major: call Fortran directly
-            let trans_char = transpose_to_char(trans);
-            sgemv(
+            let trans_char = transpose_to_char(normalize_transpose_real(trans));
+            call_sgemv(
+                sgemv, trans_char, m, n, alpha, a, lda, x, incx, beta, y, incy,
+            );
+        }
+        CblasRowMajor => {
+            // Row-major: swap m/n and flip transpose
+            // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/gemv.c
+            let flipped = flip_transpose_real(normalize_transpose_real(trans));
+            let trans_char = transpose_to_char(flipped);
+            call_sgemv(
+                sgemv, trans_char, n, m, alpha, a, lda, x, incx, beta, y, incy,
+            );
+        }
+    }
+}
+
+/// Calls `dgemv`, routing the lone CHARACTER argument (`trans`) through whichever
+/// hidden character-length convention is currently configured.
+#[inline]
+#[allow(clippy::too_many_arguments)]
+unsafe fn call_dgemv(
+    dgemv: DgemvFnPtr,
+    trans_char: c_char,
+    m: blasint,
+    n: blasint,
+    alpha: f64,
+    a: *const f64,
+    lda: blasint,
+    x: *const f64,
+    incx: blasint,
+    beta: f64,
+    y: *mut f64,
+    incy: blasint,
+) {
+    match get_char_len_convention() {
+        CharLenConvention::None => {
+            dgemv(&trans_char, &m, &n, &alpha, a, &lda, x, &incx, &beta, y, &incy)
+        }
+        CharLenConvention::Trailing => {
+            let f: DgemvTrailingLenFnPtr = std::mem::transmute(dgemv);
+            f(
                 &trans_char,
                 &m,
                 &n,
@@ -77,16 +228,16 @@ pub unsafe extern "C" fn cblas_sgemv(
                 &beta,
                 y,
                 &incy,
-            );
+                1,
+            )
         }
-        CblasRowMajor => {
-            // Row-major: swap m/n and flip transpose
-            // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/gemv.c
-            let trans_char = transpose_to_char(flip_transpose(trans));
-            sgemv(
+        CharLenConvention::Interspersed => {
+            let f: DgemvInterspersedLenFnPtr = std::mem::transmute(dgemv);
+            f(
                 &trans_char,
-                &n, // swapped: m -> n
-                &m, // swapped: n -> m
+                1,
+                &m,
+                &n,
                 &alpha,
                 a,
                 &lda,
@@ -95,7 +246,7 @@ pub unsafe extern "C" fn cblas_sgemv(
                 &beta,
                 y,
                 &incy,
-            );
+            )
         }
     }
 }
@@ -125,40 +276,83 @@ pub unsafe extern "C" fn cblas_dgemv(
     y: *mut f64,
     incy: blasint,
 ) {
+    if check_gemv("cblas_dgemv", order, m, n, lda, incx, incy) {
+        return;
+    }
     let dgemv = get_dgemv();
 
     match order {
         CblasColMajor => {
-            let trans_char = transpose_to_char(trans);
-            dgemv(
+            let trans_char = transpose_to_char(normalize_transpose_real(trans));
+            call_dgemv(
+                dgemv, trans_char, m, n, alpha, a, lda, x, incx, beta, y, incy,
+            );
+        }
+        CblasRowMajor => {
+            let flipped = flip_transpose_real(normalize_transpose_real(trans));
+            let trans_char = transpose_to_char(flipped);
+            call_dgemv(
+                dgemv, trans_char, n, m, alpha, a, lda, x, incx, beta, y, incy,
+            );
+        }
+    }
+}
+
+/// Calls `cgemv`, routing the lone CHARACTER argument (`trans`) through whichever
+/// hidden character-length convention is currently configured.
+#[inline]
+#[allow(clippy::too_many_arguments)]
+unsafe fn call_cgemv(
+    cgemv: CgemvFnPtr,
+    trans_char: c_char,
+    m: blasint,
+    n: blasint,
+    alpha: *const Complex32,
+    a: *const Complex32,
+    lda: blasint,
+    x: *const Complex32,
+    incx: blasint,
+    beta: *const Complex32,
+    y: *mut Complex32,
+    incy: blasint,
+) {
+    match get_char_len_convention() {
+        CharLenConvention::None => {
+            cgemv(&trans_char, &m, &n, alpha, a, &lda, x, &incx, beta, y, &incy)
+        }
+        CharLenConvention::Trailing => {
+            let f: CgemvTrailingLenFnPtr = std::mem::transmute(cgemv);
+            f(
                 &trans_char,
                 &m,
                 &n,
-                &alpha,
+                alpha,
                 a,
                 &lda,
                 x,
                 &incx,
-                &beta,
+                beta,
                 y,
                 &incy,
-            );
+                1,
+            )
         }
-        CblasRowMajor => {
-            let trans_char = transpose_to_char(flip_transpose(trans));
-            dgemv(
+        CharLenConvention::Interspersed => {
+            let f: CgemvInterspersedLenFnPtr = std::mem::transmute(cgemv);
+            f(
                 &trans_char,
-                &n,
+                1,
                 &m,
-                &alpha,
+                &n,
+                alpha,
                 a,
                 &lda,
                 x,
                 &incx,
-                &beta,
+                beta,
                 y,
                 &incy,
-            );
+            )
         }
     }
 }
@@ -167,6 +361,13 @@ pub unsafe extern "C" fn cblas_dgemv(
 ///
 /// Computes: y = alpha * op(A) * x + beta * y
 ///
+/// `CblasConjNoTrans` has no Fortran character code (only `N`/`T`/`C`) in either
+/// layout, so it's realized up front via `conj(A)*x = conj(A * conj(x))`: `x` is
+/// conjugated into a scratch copy and `y` is conjugated in place around a plain
+/// `NoTrans` call made with conjugated `alpha`/`beta`, then the rest of this function
+/// runs as if `CblasNoTrans` had been requested. Row-major `CblasConjTrans` is handled
+/// the same way, but with `m`/`n` swapped per the row-major conversion below.
+///
 /// # Safety
 ///
 /// - All pointers must be valid and properly aligned
@@ -188,12 +389,106 @@ pub unsafe extern "C" fn cblas_cgemv(
     y: *mut Complex32,
     incy: blasint,
 ) {
+    if check_gemv("cblas_cgemv", order, m, n, lda, incx, incy) {
+        return;
+    }
     let cgemv = get_cgemv();
 
+    let x_conj_buf;
+    let alpha_buf;
+    let beta_buf;
+    let conj_notrans = trans == CblasConjNoTrans;
+    let (trans, x, incx, alpha, beta) = if conj_notrans {
+        x_conj_buf = conjugate_vector_copy(n, x, incx);
+        conjugate_vector_inplace(m, y, incy);
+        alpha_buf = (*alpha).conjugate();
+        beta_buf = (*beta).conjugate();
+        (
+            CblasNoTrans,
+            x_conj_buf.as_ptr(),
+            1,
+            &alpha_buf as *const Complex32,
+            &beta_buf as *const Complex32,
+        )
+    } else {
+        (trans, x, incx, alpha, beta)
+    };
+
     match order {
         CblasColMajor => {
             let trans_char = transpose_to_char(trans);
-            cgemv(
+            call_cgemv(
+                cgemv, trans_char, m, n, alpha, a, lda, x, incx, beta, y, incy,
+            );
+        }
+        CblasRowMajor => match trans {
+            CblasNoTrans | CblasTrans => {
+                let flipped = if trans == CblasNoTrans {
+                    CblasTrans
+                } else {
+                    CblasNoTrans
+                };
+                let trans_char = transpose_to_char(flipped);
+                call_cgemv(
+                    cgemv, trans_char, n, m, alpha, a, lda, x, incx, beta, y, incy,
+                );
+            }
+            CblasConjTrans => {
+                let x_conj = conjugate_vector_copy(m, x, incx);
+                conjugate_vector_inplace(n, y, incy);
+                let alpha_conj = (*alpha).conjugate();
+                let beta_conj = (*beta).conjugate();
+                let trans_char = transpose_to_char(CblasNoTrans);
+                call_cgemv(
+                    cgemv,
+                    trans_char,
+                    n,
+                    m,
+                    &alpha_conj,
+                    a,
+                    lda,
+                    x_conj.as_ptr(),
+                    1,
+                    &beta_conj,
+                    y,
+                    incy,
+                );
+                conjugate_vector_inplace(n, y, incy);
+            }
+            CblasConjNoTrans => unreachable!("ConjNoTrans was normalized to NoTrans above"),
+        },
+    }
+
+    if conj_notrans {
+        conjugate_vector_inplace(m, y, incy);
+    }
+}
+
+/// Calls `zgemv`, routing the lone CHARACTER argument (`trans`) through whichever
+/// hidden character-length convention is currently configured.
+#[inline]
+#[allow(clippy::too_many_arguments)]
+unsafe fn call_zgemv(
+    zgemv: ZgemvFnPtr,
+    trans_char: c_char,
+    m: blasint,
+    n: blasint,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: blasint,
+    x: *const Complex64,
+    incx: blasint,
+    beta: *const Complex64,
+    y: *mut Complex64,
+    incy: blasint,
+) {
+    match get_char_len_convention() {
+        CharLenConvention::None => {
+            zgemv(&trans_char, &m, &n, alpha, a, &lda, x, &incx, beta, y, &incy)
+        }
+        CharLenConvention::Trailing => {
+            let f: ZgemvTrailingLenFnPtr = std::mem::transmute(zgemv);
+            f(
                 &trans_char,
                 &m,
                 &n,
@@ -205,27 +500,16 @@ pub unsafe extern "C" fn cblas_cgemv(
                 beta,
                 y,
                 &incy,
-            );
+                1,
+            )
         }
-        CblasRowMajor => {
-            // For complex, we need to handle ConjTrans specially
-            let flipped_trans = match trans {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                CblasConjTrans => {
-                    // ConjTrans in row-major becomes ConjNoTrans in col-major
-                    // But Fortran uses 'R' for this (conjugate, no transpose)
-                    // OpenBLAS maps CblasConjTrans -> trans=2 (R) for row-major
-                    // However, we don't have a ConjNoTrans enum value
-                    // For row-major ConjTrans: becomes column-major with conjugate and no transpose
-                    CblasNoTrans // This is approximate - complex conjugate handling differs
-                }
-            };
-            let trans_char = transpose_to_char(flipped_trans);
-            cgemv(
+        CharLenConvention::Interspersed => {
+            let f: ZgemvInterspersedLenFnPtr = std::mem::transmute(zgemv);
+            f(
                 &trans_char,
-                &n,
+                1,
                 &m,
+                &n,
                 alpha,
                 a,
                 &lda,
@@ -234,7 +518,7 @@ pub unsafe extern "C" fn cblas_cgemv(
                 beta,
                 y,
                 &incy,
-            );
+            )
         }
     }
 }
@@ -243,6 +527,13 @@ pub unsafe extern "C" fn cblas_cgemv(
 ///
 /// Computes: y = alpha * op(A) * x + beta * y
 ///
+/// `CblasConjNoTrans` has no Fortran character code (only `N`/`T`/`C`) in either
+/// layout, so it's realized up front via `conj(A)*x = conj(A * conj(x))`: `x` is
+/// conjugated into a scratch copy and `y` is conjugated in place around a plain
+/// `NoTrans` call made with conjugated `alpha`/`beta`, then the rest of this function
+/// runs as if `CblasNoTrans` had been requested. Row-major `CblasConjTrans` is handled
+/// the same way, but with `m`/`n` swapped per the row-major conversion below.
+///
 /// # Safety
 ///
 /// - All pointers must be valid and properly aligned
@@ -264,49 +555,156 @@ pub unsafe extern "C" fn cblas_zgemv(
     y: *mut Complex64,
     incy: blasint,
 ) {
+    if check_gemv("cblas_zgemv", order, m, n, lda, incx, incy) {
+        return;
+    }
     let zgemv = get_zgemv();
 
+    let x_conj_buf;
+    let alpha_buf;
+    let beta_buf;
+    let conj_notrans = trans == CblasConjNoTrans;
+    let (trans, x, incx, alpha, beta) = if conj_notrans {
+        x_conj_buf = conjugate_vector_copy(n, x, incx);
+        conjugate_vector_inplace(m, y, incy);
+        alpha_buf = (*alpha).conjugate();
+        beta_buf = (*beta).conjugate();
+        (
+            CblasNoTrans,
+            x_conj_buf.as_ptr(),
+            1,
+            &alpha_buf as *const Complex64,
+            &beta_buf as *const Complex64,
+        )
+    } else {
+        (trans, x, incx, alpha, beta)
+    };
+
     match order {
         CblasColMajor => {
             let trans_char = transpose_to_char(trans);
-            zgemv(
-                &trans_char,
-                &m,
-                &n,
-                alpha,
-                a,
-                &lda,
-                x,
-                &incx,
-                beta,
-                y,
-                &incy,
+            call_zgemv(
+                zgemv, trans_char, m, n, alpha, a, lda, x, incx, beta, y, incy,
             );
         }
-        CblasRowMajor => {
-            // For complex, we need to handle ConjTrans specially
-            let flipped_trans = match trans {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                CblasConjTrans => {
-                    // Same handling as cgemv
+        CblasRowMajor => match trans {
+            CblasNoTrans | CblasTrans => {
+                let flipped = if trans == CblasNoTrans {
+                    CblasTrans
+                } else {
                     CblasNoTrans
-                }
-            };
-            let trans_char = transpose_to_char(flipped_trans);
-            zgemv(
-                &trans_char,
-                &n,
-                &m,
-                alpha,
-                a,
-                &lda,
-                x,
-                &incx,
-                beta,
-                y,
-                &incy,
-            );
-        }
+                };
+                let trans_char = transpose_to_char(flipped);
+                call_zgemv(
+                    zgemv, trans_char, n, m, alpha, a, lda, x, incx, beta, y, incy,
+                );
+            }
+            CblasConjTrans => {
+                let x_conj = conjugate_vector_copy(m, x, incx);
+                conjugate_vector_inplace(n, y, incy);
+                let alpha_conj = (*alpha).conjugate();
+                let beta_conj = (*beta).conjugate();
+                let trans_char = transpose_to_char(CblasNoTrans);
+                call_zgemv(
+                    zgemv,
+                    trans_char,
+                    n,
+                    m,
+                    &alpha_conj,
+                    a,
+                    lda,
+                    x_conj.as_ptr(),
+                    1,
+                    &beta_conj,
+                    y,
+                    incy,
+                );
+                conjugate_vector_inplace(n, y, incy);
+            }
+            CblasConjNoTrans => unreachable!("ConjNoTrans was normalized to NoTrans above"),
+        },
+    }
+
+    if conj_notrans {
+        conjugate_vector_inplace(m, y, incy);
+    }
+}
+
+/// Safe, slice-based general matrix-vector multiply, for callers who'd rather check a
+/// `Result` than hold up the `unsafe` contract [`cblas_dgemv`] requires of its raw
+/// pointers.
+///
+/// Validates that `a`, `x`, and `y` are long enough for the given dimensions, leading
+/// dimension, and increments, and that dgemv has been registered, before dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_dgemv(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: blasint,
+    n: blasint,
+    alpha: f64,
+    a: &[f64],
+    lda: blasint,
+    x: &[f64],
+    incx: blasint,
+    beta: f64,
+    y: &mut [f64],
+    incy: blasint,
+) -> Result<(), String> {
+    if !crate::backend::has_dgemv() {
+        return Err("dgemv backend not registered: call register_dgemv first".to_string());
+    }
+    if m < 0 || n < 0 {
+        return Err(format!("dgemv: m ({m}) and n ({n}) must be non-negative"));
+    }
+
+    // Number of `lda`-strided lines: columns for column-major, rows for row-major.
+    let a_lines = match order {
+        CblasColMajor => n,
+        CblasRowMajor => m,
+    };
+    let a_len_needed = (lda.max(1) as usize) * (a_lines.max(1) as usize);
+    if a.len() < a_len_needed {
+        return Err(format!(
+            "dgemv: `a` has {} elements, but lda={lda} and order={order:?} need at least {a_len_needed}",
+            a.len()
+        ));
+    }
+
+    let (nx, ny) = match normalize_transpose_real(trans) {
+        CblasNoTrans => (n, m),
+        _ => (m, n),
+    };
+    let x_len_needed = 1 + (nx.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if nx > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "dgemv: `x` has {} elements, but n={nx} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+    let y_len_needed = 1 + (ny.max(1) as usize - 1) * (incy.unsigned_abs() as usize);
+    if ny > 0 && y.len() < y_len_needed {
+        return Err(format!(
+            "dgemv: `y` has {} elements, but n={ny} and incy={incy} need at least {y_len_needed}",
+            y.len()
+        ));
+    }
+
+    unsafe {
+        cblas_dgemv(
+            order,
+            trans,
+            m,
+            n,
+            alpha,
+            a.as_ptr(),
+            lda,
+            x.as_ptr(),
+            incx,
+            beta,
+            y.as_mut_ptr(),
+            incy,
+        );
     }
+    Ok(())
 }
@@ -233,3 +233,61 @@ pub unsafe extern "C" fn cblas_zgerc(
         }
     }
 }
+
+/// Safe, slice-based general rank-1 update, for callers who'd rather check a `Result`
+/// than hold up the `unsafe` contract [`cblas_dger`] requires of its raw pointers.
+///
+/// Validates that `x`, `y`, and `a` are long enough for the given dimensions, leading
+/// dimension, and increments, and that dger has been registered, before dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_dger(
+    order: CBLAS_ORDER,
+    m: blasint,
+    n: blasint,
+    alpha: f64,
+    x: &[f64],
+    incx: blasint,
+    y: &[f64],
+    incy: blasint,
+    a: &mut [f64],
+    lda: blasint,
+) -> Result<(), String> {
+    if !crate::backend::has_dger() {
+        return Err("dger backend not registered: call register_dger first".to_string());
+    }
+    if m < 0 || n < 0 {
+        return Err(format!("dger: m ({m}) and n ({n}) must be non-negative"));
+    }
+
+    let x_len_needed = 1 + (m.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if m > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "dger: `x` has {} elements, but m={m} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+    let y_len_needed = 1 + (n.max(1) as usize - 1) * (incy.unsigned_abs() as usize);
+    if n > 0 && y.len() < y_len_needed {
+        return Err(format!(
+            "dger: `y` has {} elements, but n={n} and incy={incy} need at least {y_len_needed}",
+            y.len()
+        ));
+    }
+    // Number of `lda`-strided lines: columns for column-major, rows for row-major.
+    let a_lines = match order {
+        CblasColMajor => n,
+        CblasRowMajor => m,
+    };
+    let a_len_needed = (lda.max(1) as usize) * (a_lines.max(1) as usize);
+    if a.len() < a_len_needed {
+        return Err(format!(
+            "dger: `a` has {} elements, but lda={lda} and order={order:?} need at least {a_len_needed}",
+            a.len()
+        ));
+    }
+
+    unsafe {
+        cblas_dger(order, m, n, alpha, x.as_ptr(), incx, y.as_ptr(), incy, a.as_mut_ptr(), lda);
+    }
+    Ok(())
+}
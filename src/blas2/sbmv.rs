@@ -12,9 +12,35 @@ use num_complex::{Complex32, Complex64};
 
 use crate::backend::{get_chbmv, get_dsbmv, get_ssbmv, get_zhbmv};
 use crate::types::{
-    blasint, uplo_to_char, CblasColMajor, CblasLower, CblasRowMajor, CblasUpper, CBLAS_ORDER,
-    CBLAS_UPLO,
+    blasint, flip_uplo, uplo_to_char, CblasColMajor, CblasRowMajor, CBLAS_ORDER, CBLAS_UPLO,
 };
+use crate::validation::validate_layout;
+
+/// Validates CBLAS argument positions 3 (n), 4 (k), 7 (lda), 9 (incx), and 12 (incy)
+/// for `cblas_?sbmv`/`cblas_?hbmv`: `n >= 0`, `k >= 0`, `lda >= k + 1` to hold the full
+/// band plus diagonal, `incx != 0`, and `incy != 0`. Positions are logical
+/// (`CblasColMajor`); see [`validate_layout`] for the `CblasRowMajor` renumbering.
+unsafe fn check_sbmv(
+    routine: &str,
+    order: CBLAS_ORDER,
+    n: blasint,
+    k: blasint,
+    lda: blasint,
+    incx: blasint,
+    incy: blasint,
+) -> bool {
+    validate_layout(
+        routine,
+        order,
+        &[
+            (n >= 0, 3),
+            (k >= 0, 4),
+            (lda > k, 7),
+            (incx != 0, 9),
+            (incy != 0, 12),
+        ],
+    )
+}
 
 /// Single precision symmetric band matrix-vector multiply.
 ///
@@ -42,6 +68,10 @@ pub unsafe extern "C" fn cblas_ssbmv(
     y: *mut f32,
     incy: blasint,
 ) {
+    if check_sbmv("cblas_ssbmv", order, n, k, lda, incx, incy) {
+        return;
+    }
+
     let ssbmv = get_ssbmv();
 
     match order {
@@ -54,10 +84,7 @@ pub unsafe extern "C" fn cblas_ssbmv(
         CblasRowMajor => {
             // Row-major: swap Upper/Lower
             // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/sbmv.c
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             ssbmv(
                 &uplo_char, &n, &k, &alpha, a, &lda, x, &incx, &beta, y, &incy,
@@ -92,6 +119,10 @@ pub unsafe extern "C" fn cblas_dsbmv(
     y: *mut f64,
     incy: blasint,
 ) {
+    if check_sbmv("cblas_dsbmv", order, n, k, lda, incx, incy) {
+        return;
+    }
+
     let dsbmv = get_dsbmv();
 
     match order {
@@ -103,10 +134,7 @@ pub unsafe extern "C" fn cblas_dsbmv(
         }
         CblasRowMajor => {
             // Row-major: swap Upper/Lower
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             dsbmv(
                 &uplo_char, &n, &k, &alpha, a, &lda, x, &incx, &beta, y, &incy,
@@ -148,6 +176,10 @@ pub unsafe extern "C" fn cblas_chbmv(
     y: *mut Complex32,
     incy: blasint,
 ) {
+    if check_sbmv("cblas_chbmv", order, n, k, lda, incx, incy) {
+        return;
+    }
+
     let chbmv = get_chbmv();
 
     match order {
@@ -160,10 +192,7 @@ pub unsafe extern "C" fn cblas_chbmv(
         CblasRowMajor => {
             // Row-major for Hermitian: swap Upper/Lower and conjugate scalars and vectors
             // Following the CBLAS reference implementation approach
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
 
             // Conjugate alpha and beta
@@ -175,30 +204,40 @@ pub unsafe extern "C" fn cblas_chbmv(
             // For row-major Hermitian operations, we need to conjugate input x
             // and conjugate output y before and after the operation
             if n > 0 {
-                // Conjugate y (in-place before operation)
+                let abs_incx = if incx < 0 { -incx } else { incx };
                 let abs_incy = if incy < 0 { -incy } else { incy };
+                let n_usize = n as usize;
+
+                // Avoid the heap allocation for the common small-n case: only spill
+                // conj(x) to a Vec once it overflows this fixed stack buffer.
+                const STACK_LEN: usize = 64;
+                let mut stack_buf = [Complex32::new(0.0, 0.0); STACK_LEN];
+                let mut heap_buf;
+                let x_conj: &mut [Complex32] = if n_usize <= STACK_LEN {
+                    &mut stack_buf[..n_usize]
+                } else {
+                    heap_buf = vec![Complex32::new(0.0, 0.0); n_usize];
+                    &mut heap_buf[..]
+                };
+
+                // Single traversal: conjugate y in place and build conj(x), fused.
                 for i in 0..n {
-                    let idx = if incy < 0 {
+                    let yidx = if incy < 0 {
                         ((n - 1 - i) * abs_incy) as isize
                     } else {
                         (i * abs_incy) as isize
                     };
-                    let y_ptr = y.offset(idx);
-                    let val = *y_ptr;
-                    *y_ptr = Complex32::new(val.re, -val.im);
-                }
+                    let y_ptr = y.offset(yidx);
+                    let yval = *y_ptr;
+                    *y_ptr = Complex32::new(yval.re, -yval.im);
 
-                // Create conjugated copy of x
-                let abs_incx = if incx < 0 { -incx } else { incx };
-                let mut x_conj = vec![Complex32::new(0.0, 0.0); n as usize];
-                for i in 0..n {
-                    let idx = if incx < 0 {
+                    let xidx = if incx < 0 {
                         ((n - 1 - i) * abs_incx) as isize
                     } else {
                         (i * abs_incx) as isize
                     };
-                    let val = *x.offset(idx);
-                    x_conj[i as usize] = Complex32::new(val.re, -val.im);
+                    let xval = *x.offset(xidx);
+                    x_conj[i as usize] = Complex32::new(xval.re, -xval.im);
                 }
 
                 // Call Fortran HBMV with conjugated values
@@ -216,7 +255,7 @@ pub unsafe extern "C" fn cblas_chbmv(
                     &incy,
                 );
 
-                // Conjugate y (in-place after operation)
+                // Single traversal: conjugate y back in place.
                 for i in 0..n {
                     let idx = if incy < 0 {
                         ((n - 1 - i) * abs_incy) as isize
@@ -265,6 +304,10 @@ pub unsafe extern "C" fn cblas_zhbmv(
     y: *mut Complex64,
     incy: blasint,
 ) {
+    if check_sbmv("cblas_zhbmv", order, n, k, lda, incx, incy) {
+        return;
+    }
+
     let zhbmv = get_zhbmv();
 
     match order {
@@ -277,10 +320,7 @@ pub unsafe extern "C" fn cblas_zhbmv(
         CblasRowMajor => {
             // Row-major for Hermitian: swap Upper/Lower and conjugate scalars and vectors
             // Following the CBLAS reference implementation approach
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
 
             // Conjugate alpha and beta
@@ -292,30 +332,40 @@ pub unsafe extern "C" fn cblas_zhbmv(
             // For row-major Hermitian operations, we need to conjugate input x
             // and conjugate output y before and after the operation
             if n > 0 {
-                // Conjugate y (in-place before operation)
+                let abs_incx = if incx < 0 { -incx } else { incx };
                 let abs_incy = if incy < 0 { -incy } else { incy };
+                let n_usize = n as usize;
+
+                // Avoid the heap allocation for the common small-n case: only spill
+                // conj(x) to a Vec once it overflows this fixed stack buffer.
+                const STACK_LEN: usize = 64;
+                let mut stack_buf = [Complex64::new(0.0, 0.0); STACK_LEN];
+                let mut heap_buf;
+                let x_conj: &mut [Complex64] = if n_usize <= STACK_LEN {
+                    &mut stack_buf[..n_usize]
+                } else {
+                    heap_buf = vec![Complex64::new(0.0, 0.0); n_usize];
+                    &mut heap_buf[..]
+                };
+
+                // Single traversal: conjugate y in place and build conj(x), fused.
                 for i in 0..n {
-                    let idx = if incy < 0 {
+                    let yidx = if incy < 0 {
                         ((n - 1 - i) * abs_incy) as isize
                     } else {
                         (i * abs_incy) as isize
                     };
-                    let y_ptr = y.offset(idx);
-                    let val = *y_ptr;
-                    *y_ptr = Complex64::new(val.re, -val.im);
-                }
+                    let y_ptr = y.offset(yidx);
+                    let yval = *y_ptr;
+                    *y_ptr = Complex64::new(yval.re, -yval.im);
 
-                // Create conjugated copy of x
-                let abs_incx = if incx < 0 { -incx } else { incx };
-                let mut x_conj = vec![Complex64::new(0.0, 0.0); n as usize];
-                for i in 0..n {
-                    let idx = if incx < 0 {
+                    let xidx = if incx < 0 {
                         ((n - 1 - i) * abs_incx) as isize
                     } else {
                         (i * abs_incx) as isize
                     };
-                    let val = *x.offset(idx);
-                    x_conj[i as usize] = Complex64::new(val.re, -val.im);
+                    let xval = *x.offset(xidx);
+                    x_conj[i as usize] = Complex64::new(xval.re, -xval.im);
                 }
 
                 // Call Fortran HBMV with conjugated values
@@ -333,7 +383,7 @@ pub unsafe extern "C" fn cblas_zhbmv(
                     &incy,
                 );
 
-                // Conjugate y (in-place after operation)
+                // Single traversal: conjugate y back in place.
                 for i in 0..n {
                     let idx = if incy < 0 {
                         ((n - 1 - i) * abs_incy) as isize
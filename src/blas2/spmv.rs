@@ -12,9 +12,23 @@ use num_complex::{Complex32, Complex64};
 
 use crate::backend::{get_chpmv, get_dspmv, get_sspmv, get_zhpmv};
 use crate::types::{
-    blasint, uplo_to_char, CblasColMajor, CblasLower, CblasRowMajor, CblasUpper, CBLAS_ORDER,
-    CBLAS_UPLO,
+    blasint, flip_uplo, uplo_to_char, CblasColMajor, CblasRowMajor, CBLAS_ORDER, CBLAS_UPLO,
 };
+use crate::validation::validate_layout;
+
+/// Validates CBLAS argument positions 3 (n), 7 (incx), and 10 (incy) for
+/// `cblas_?spmv`/`cblas_?hpmv`: `n >= 0`, `incx != 0`, and `incy != 0`. There is no
+/// `lda` to check here since `ap` is packed. Positions are logical (`CblasColMajor`);
+/// see [`validate_layout`] for the `CblasRowMajor` renumbering.
+unsafe fn check_spmv(
+    routine: &str,
+    order: CBLAS_ORDER,
+    n: blasint,
+    incx: blasint,
+    incy: blasint,
+) -> bool {
+    validate_layout(routine, order, &[(n >= 0, 3), (incx != 0, 7), (incy != 0, 10)])
+}
 
 /// Single precision symmetric packed matrix-vector multiply.
 ///
@@ -40,6 +54,10 @@ pub unsafe extern "C" fn cblas_sspmv(
     y: *mut f32,
     incy: blasint,
 ) {
+    if check_spmv("cblas_sspmv", order, n, incx, incy) {
+        return;
+    }
+
     let sspmv = get_sspmv();
 
     match order {
@@ -49,10 +67,7 @@ pub unsafe extern "C" fn cblas_sspmv(
         }
         CblasRowMajor => {
             // Row-major: swap Upper/Lower
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             sspmv(&uplo_char, &n, &alpha, ap, x, &incx, &beta, y, &incy);
         }
@@ -83,6 +98,10 @@ pub unsafe extern "C" fn cblas_dspmv(
     y: *mut f64,
     incy: blasint,
 ) {
+    if check_spmv("cblas_dspmv", order, n, incx, incy) {
+        return;
+    }
+
     let dspmv = get_dspmv();
 
     match order {
@@ -92,10 +111,7 @@ pub unsafe extern "C" fn cblas_dspmv(
         }
         CblasRowMajor => {
             // Row-major: swap Upper/Lower
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             dspmv(&uplo_char, &n, &alpha, ap, x, &incx, &beta, y, &incy);
         }
@@ -126,6 +142,10 @@ pub unsafe extern "C" fn cblas_chpmv(
     y: *mut Complex32,
     incy: blasint,
 ) {
+    if check_spmv("cblas_chpmv", order, n, incx, incy) {
+        return;
+    }
+
     let chpmv = get_chpmv();
 
     match order {
@@ -135,10 +155,7 @@ pub unsafe extern "C" fn cblas_chpmv(
         }
         CblasRowMajor => {
             // Row-major for Hermitian: swap Upper/Lower and conjugate scalars and vectors
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
 
             // Conjugate alpha and beta
@@ -148,30 +165,40 @@ pub unsafe extern "C" fn cblas_chpmv(
             let conj_beta = Complex32::new(beta_val.re, -beta_val.im);
 
             if n > 0 {
-                // Conjugate y (in-place before operation)
+                let abs_incx = if incx < 0 { -incx } else { incx };
                 let abs_incy = if incy < 0 { -incy } else { incy };
+                let n_usize = n as usize;
+
+                // Avoid the heap allocation for the common small-n case: only spill
+                // conj(x) to a Vec once it overflows this fixed stack buffer.
+                const STACK_LEN: usize = 64;
+                let mut stack_buf = [Complex32::new(0.0, 0.0); STACK_LEN];
+                let mut heap_buf;
+                let x_conj: &mut [Complex32] = if n_usize <= STACK_LEN {
+                    &mut stack_buf[..n_usize]
+                } else {
+                    heap_buf = vec![Complex32::new(0.0, 0.0); n_usize];
+                    &mut heap_buf[..]
+                };
+
+                // Single traversal: conjugate y in place and build conj(x), fused.
                 for i in 0..n {
-                    let idx = if incy < 0 {
+                    let yidx = if incy < 0 {
                         ((n - 1 - i) * abs_incy) as isize
                     } else {
                         (i * abs_incy) as isize
                     };
-                    let y_ptr = y.offset(idx);
-                    let val = *y_ptr;
-                    *y_ptr = Complex32::new(val.re, -val.im);
-                }
+                    let y_ptr = y.offset(yidx);
+                    let yval = *y_ptr;
+                    *y_ptr = Complex32::new(yval.re, -yval.im);
 
-                // Create conjugated copy of x
-                let abs_incx = if incx < 0 { -incx } else { incx };
-                let mut x_conj = vec![Complex32::new(0.0, 0.0); n as usize];
-                for i in 0..n {
-                    let idx = if incx < 0 {
+                    let xidx = if incx < 0 {
                         ((n - 1 - i) * abs_incx) as isize
                     } else {
                         (i * abs_incx) as isize
                     };
-                    let val = *x.offset(idx);
-                    x_conj[i as usize] = Complex32::new(val.re, -val.im);
+                    let xval = *x.offset(xidx);
+                    x_conj[i as usize] = Complex32::new(xval.re, -xval.im);
                 }
 
                 // Call Fortran HPMV with conjugated values
@@ -187,7 +214,7 @@ pub unsafe extern "C" fn cblas_chpmv(
                     &incy,
                 );
 
-                // Conjugate y (in-place after operation)
+                // Single traversal: conjugate y back in place.
                 for i in 0..n {
                     let idx = if incy < 0 {
                         ((n - 1 - i) * abs_incy) as isize
@@ -227,6 +254,10 @@ pub unsafe extern "C" fn cblas_zhpmv(
     y: *mut Complex64,
     incy: blasint,
 ) {
+    if check_spmv("cblas_zhpmv", order, n, incx, incy) {
+        return;
+    }
+
     let zhpmv = get_zhpmv();
 
     match order {
@@ -236,10 +267,7 @@ pub unsafe extern "C" fn cblas_zhpmv(
         }
         CblasRowMajor => {
             // Row-major for Hermitian: swap Upper/Lower and conjugate scalars and vectors
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
 
             // Conjugate alpha and beta
@@ -249,30 +277,40 @@ pub unsafe extern "C" fn cblas_zhpmv(
             let conj_beta = Complex64::new(beta_val.re, -beta_val.im);
 
             if n > 0 {
-                // Conjugate y (in-place before operation)
+                let abs_incx = if incx < 0 { -incx } else { incx };
                 let abs_incy = if incy < 0 { -incy } else { incy };
+                let n_usize = n as usize;
+
+                // Avoid the heap allocation for the common small-n case: only spill
+                // conj(x) to a Vec once it overflows this fixed stack buffer.
+                const STACK_LEN: usize = 64;
+                let mut stack_buf = [Complex64::new(0.0, 0.0); STACK_LEN];
+                let mut heap_buf;
+                let x_conj: &mut [Complex64] = if n_usize <= STACK_LEN {
+                    &mut stack_buf[..n_usize]
+                } else {
+                    heap_buf = vec![Complex64::new(0.0, 0.0); n_usize];
+                    &mut heap_buf[..]
+                };
+
+                // Single traversal: conjugate y in place and build conj(x), fused.
                 for i in 0..n {
-                    let idx = if incy < 0 {
+                    let yidx = if incy < 0 {
                         ((n - 1 - i) * abs_incy) as isize
                     } else {
                         (i * abs_incy) as isize
                     };
-                    let y_ptr = y.offset(idx);
-                    let val = *y_ptr;
-                    *y_ptr = Complex64::new(val.re, -val.im);
-                }
+                    let y_ptr = y.offset(yidx);
+                    let yval = *y_ptr;
+                    *y_ptr = Complex64::new(yval.re, -yval.im);
 
-                // Create conjugated copy of x
-                let abs_incx = if incx < 0 { -incx } else { incx };
-                let mut x_conj = vec![Complex64::new(0.0, 0.0); n as usize];
-                for i in 0..n {
-                    let idx = if incx < 0 {
+                    let xidx = if incx < 0 {
                         ((n - 1 - i) * abs_incx) as isize
                     } else {
                         (i * abs_incx) as isize
                     };
-                    let val = *x.offset(idx);
-                    x_conj[i as usize] = Complex64::new(val.re, -val.im);
+                    let xval = *x.offset(xidx);
+                    x_conj[i as usize] = Complex64::new(xval.re, -xval.im);
                 }
 
                 // Call Fortran HPMV with conjugated values
@@ -288,7 +326,7 @@ pub unsafe extern "C" fn cblas_zhpmv(
                     &incy,
                 );
 
-                // Conjugate y (in-place after operation)
+                // Single traversal: conjugate y back in place.
                 for i in 0..n {
                     let idx = if incy < 0 {
                         ((n - 1 - i) * abs_incy) as isize
@@ -303,3 +341,7 @@ pub unsafe extern "C" fn cblas_zhpmv(
         }
     }
 }
+
+// Note: cblas_sspmv/dspmv/chpmv/zhpmv, their backend getters, and their extern/registration
+// wiring already existed in full before this request landed (see autoregister.rs, backend.rs,
+// registry.rs) — no further change was needed here.
@@ -11,16 +11,44 @@
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/zhpr.c>
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/spr2.c>
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/zhpr2.c>
+//!
+//! Real SPR/SPR2 are symmetric, so `A_row^T == A_row` and the uplo flip alone realizes
+//! the row-major conversion (the view is the matrix itself). Complex HPR/HPR2 are
+//! Hermitian instead (`A_row^T == conj(A_row)`), so the view is `conj(A_row)`, not
+//! `A_row`: reproducing the update on `A_row` requires conjugating the vector operands
+//! (and, for HPR2, `alpha`) before calling the backend, the same conjugation
+//! `cblas_chemv`/`cblas_zhemv` apply to keep the Hermitian row-major transpose correct.
 
 use num_complex::{Complex32, Complex64};
 
 use crate::backend::{
     get_chpr, get_chpr2, get_dspr, get_dspr2, get_sspr, get_sspr2, get_zhpr, get_zhpr2,
 };
+use crate::conj::conjugate_vector_copy;
 use crate::types::{
-    blasint, uplo_to_char, CblasColMajor, CblasLower, CblasRowMajor, CblasUpper, CBLAS_ORDER,
-    CBLAS_UPLO,
+    blasint, flip_uplo, uplo_to_char, CblasColMajor, CblasRowMajor, CBLAS_ORDER, CBLAS_UPLO,
 };
+use crate::validation::validate_layout;
+
+/// Validates CBLAS argument positions 3 (n) and 6 (incx) for `cblas_?spr`/`cblas_?hpr`:
+/// `n >= 0` and `incx != 0`. There is no `lda` to check here since `ap` is packed.
+/// Positions are logical (`CblasColMajor`); see [`validate_layout`] for the
+/// `CblasRowMajor` renumbering.
+unsafe fn check_pr(routine: &str, order: CBLAS_ORDER, n: blasint, incx: blasint) -> bool {
+    validate_layout(routine, order, &[(n >= 0, 3), (incx != 0, 6)])
+}
+
+/// Validates CBLAS argument positions 3 (n), 6 (incx), and 8 (incy) for
+/// `cblas_?spr2`/`cblas_?hpr2`: `n >= 0`, `incx != 0`, and `incy != 0`.
+unsafe fn check_pr2(
+    routine: &str,
+    order: CBLAS_ORDER,
+    n: blasint,
+    incx: blasint,
+    incy: blasint,
+) -> bool {
+    validate_layout(routine, order, &[(n >= 0, 3), (incx != 0, 6), (incy != 0, 8)])
+}
 
 // =============================================================================
 // Real SPR: A = alpha * x * x^T + A
@@ -43,6 +71,10 @@ pub unsafe extern "C" fn cblas_sspr(
     incx: blasint,
     ap: *mut f32,
 ) {
+    if check_pr("cblas_sspr", order, n, incx) {
+        return;
+    }
+
     let sspr = get_sspr();
 
     match order {
@@ -52,10 +84,7 @@ pub unsafe extern "C" fn cblas_sspr(
         }
         CblasRowMajor => {
             // Row-major: invert uplo (Upper <-> Lower)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             sspr(&uplo_char, &n, &alpha, x, &incx, ap);
         }
@@ -79,6 +108,10 @@ pub unsafe extern "C" fn cblas_dspr(
     incx: blasint,
     ap: *mut f64,
 ) {
+    if check_pr("cblas_dspr", order, n, incx) {
+        return;
+    }
+
     let dspr = get_dspr();
 
     match order {
@@ -88,10 +121,7 @@ pub unsafe extern "C" fn cblas_dspr(
         }
         CblasRowMajor => {
             // Row-major: invert uplo (Upper <-> Lower)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             dspr(&uplo_char, &n, &alpha, x, &incx, ap);
         }
@@ -121,6 +151,10 @@ pub unsafe extern "C" fn cblas_chpr(
     incx: blasint,
     ap: *mut Complex32,
 ) {
+    if check_pr("cblas_chpr", order, n, incx) {
+        return;
+    }
+
     let chpr = get_chpr();
 
     match order {
@@ -129,13 +163,11 @@ pub unsafe extern "C" fn cblas_chpr(
             chpr(&uplo_char, &n, &alpha, x, &incx, ap);
         }
         CblasRowMajor => {
-            // Row-major for Hermitian: invert uplo
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            // Row-major for Hermitian: invert uplo and conjugate x (see the module doc).
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
-            chpr(&uplo_char, &n, &alpha, x, &incx, ap);
+            let x_conj = conjugate_vector_copy(n, x, incx);
+            chpr(&uplo_char, &n, &alpha, x_conj.as_ptr(), &1, ap);
         }
     }
 }
@@ -159,6 +191,10 @@ pub unsafe extern "C" fn cblas_zhpr(
     incx: blasint,
     ap: *mut Complex64,
 ) {
+    if check_pr("cblas_zhpr", order, n, incx) {
+        return;
+    }
+
     let zhpr = get_zhpr();
 
     match order {
@@ -167,13 +203,11 @@ pub unsafe extern "C" fn cblas_zhpr(
             zhpr(&uplo_char, &n, &alpha, x, &incx, ap);
         }
         CblasRowMajor => {
-            // Row-major for Hermitian: invert uplo
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            // Row-major for Hermitian: invert uplo and conjugate x (see the module doc).
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
-            zhpr(&uplo_char, &n, &alpha, x, &incx, ap);
+            let x_conj = conjugate_vector_copy(n, x, incx);
+            zhpr(&uplo_char, &n, &alpha, x_conj.as_ptr(), &1, ap);
         }
     }
 }
@@ -202,6 +236,10 @@ pub unsafe extern "C" fn cblas_sspr2(
     incy: blasint,
     ap: *mut f32,
 ) {
+    if check_pr2("cblas_sspr2", order, n, incx, incy) {
+        return;
+    }
+
     let sspr2 = get_sspr2();
 
     match order {
@@ -211,10 +249,7 @@ pub unsafe extern "C" fn cblas_sspr2(
         }
         CblasRowMajor => {
             // Row-major: invert uplo (Upper <-> Lower)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             sspr2(&uplo_char, &n, &alpha, x, &incx, y, &incy, ap);
         }
@@ -241,6 +276,10 @@ pub unsafe extern "C" fn cblas_dspr2(
     incy: blasint,
     ap: *mut f64,
 ) {
+    if check_pr2("cblas_dspr2", order, n, incx, incy) {
+        return;
+    }
+
     let dspr2 = get_dspr2();
 
     match order {
@@ -250,10 +289,7 @@ pub unsafe extern "C" fn cblas_dspr2(
         }
         CblasRowMajor => {
             // Row-major: invert uplo (Upper <-> Lower)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             dspr2(&uplo_char, &n, &alpha, x, &incx, y, &incy, ap);
         }
@@ -286,6 +322,10 @@ pub unsafe extern "C" fn cblas_chpr2(
     incy: blasint,
     ap: *mut Complex32,
 ) {
+    if check_pr2("cblas_chpr2", order, n, incx, incy) {
+        return;
+    }
+
     let chpr2 = get_chpr2();
 
     match order {
@@ -294,14 +334,23 @@ pub unsafe extern "C" fn cblas_chpr2(
             chpr2(&uplo_char, &n, alpha, x, &incx, y, &incy, ap);
         }
         CblasRowMajor => {
-            // Row-major for Hermitian: invert uplo and swap x<->y
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            // Row-major for Hermitian: invert uplo, conjugate alpha, x, and y (see the
+            // module doc).
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
-            // For HPR2 in row-major, we swap x and y
-            chpr2(&uplo_char, &n, alpha, y, &incy, x, &incx, ap);
+            let alpha_conj = (*alpha).conj();
+            let x_conj = conjugate_vector_copy(n, x, incx);
+            let y_conj = conjugate_vector_copy(n, y, incy);
+            chpr2(
+                &uplo_char,
+                &n,
+                &alpha_conj,
+                x_conj.as_ptr(),
+                &1,
+                y_conj.as_ptr(),
+                &1,
+                ap,
+            );
         }
     }
 }
@@ -328,6 +377,10 @@ pub unsafe extern "C" fn cblas_zhpr2(
     incy: blasint,
     ap: *mut Complex64,
 ) {
+    if check_pr2("cblas_zhpr2", order, n, incx, incy) {
+        return;
+    }
+
     let zhpr2 = get_zhpr2();
 
     match order {
@@ -336,14 +389,386 @@ pub unsafe extern "C" fn cblas_zhpr2(
             zhpr2(&uplo_char, &n, alpha, x, &incx, y, &incy, ap);
         }
         CblasRowMajor => {
-            // Row-major for Hermitian: invert uplo and swap x<->y
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            // Row-major for Hermitian: invert uplo, conjugate alpha, x, and y (see the
+            // module doc).
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
-            // For HPR2 in row-major, we swap x and y
-            zhpr2(&uplo_char, &n, alpha, y, &incy, x, &incx, ap);
+            let alpha_conj = (*alpha).conj();
+            let x_conj = conjugate_vector_copy(n, x, incx);
+            let y_conj = conjugate_vector_copy(n, y, incy);
+            zhpr2(
+                &uplo_char,
+                &n,
+                &alpha_conj,
+                x_conj.as_ptr(),
+                &1,
+                y_conj.as_ptr(),
+                &1,
+                ap,
+            );
         }
     }
 }
+
+// =============================================================================
+// Safe, slice-based wrappers
+// =============================================================================
+
+/// The packed-storage invariant every SPR/HPR/SPR2/HPR2 wrapper below checks: `ap` must
+/// hold at least `n*(n+1)/2` elements to store one triangle of an `n x n` matrix.
+fn packed_len_needed(n: blasint) -> usize {
+    let n = n.max(0) as usize;
+    n * (n + 1) / 2
+}
+
+/// Safe, slice-based single precision symmetric packed rank-1 update, for callers
+/// who'd rather check a `Result` than hold up the `unsafe` contract [`cblas_sspr`]
+/// requires of its raw pointers.
+///
+/// Validates that `x` is long enough for `n`/`incx`, that `ap` holds at least
+/// `n*(n+1)/2` elements, and that sspr has been registered, before dispatching.
+pub fn try_sspr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blasint,
+    alpha: f32,
+    x: &[f32],
+    incx: blasint,
+    ap: &mut [f32],
+) -> Result<(), String> {
+    crate::backend::try_get_sspr().map_err(|e| e.to_string())?;
+    if n < 0 {
+        return Err(format!("sspr: n ({n}) must be non-negative"));
+    }
+    let x_len_needed = 1 + (n.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if n > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "sspr: `x` has {} elements, but n={n} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+    let ap_len_needed = packed_len_needed(n);
+    if ap.len() < ap_len_needed {
+        return Err(format!(
+            "sspr: `ap` has {} elements, but n={n} needs at least {ap_len_needed}",
+            ap.len()
+        ));
+    }
+
+    unsafe { cblas_sspr(order, uplo, n, alpha, x.as_ptr(), incx, ap.as_mut_ptr()) };
+    Ok(())
+}
+
+/// Safe, slice-based double precision symmetric packed rank-1 update, for callers
+/// who'd rather check a `Result` than hold up the `unsafe` contract [`cblas_dspr`]
+/// requires of its raw pointers.
+///
+/// Validates that `x` is long enough for `n`/`incx`, that `ap` holds at least
+/// `n*(n+1)/2` elements, and that dspr has been registered, before dispatching.
+pub fn try_dspr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blasint,
+    alpha: f64,
+    x: &[f64],
+    incx: blasint,
+    ap: &mut [f64],
+) -> Result<(), String> {
+    crate::backend::try_get_dspr().map_err(|e| e.to_string())?;
+    if n < 0 {
+        return Err(format!("dspr: n ({n}) must be non-negative"));
+    }
+    let x_len_needed = 1 + (n.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if n > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "dspr: `x` has {} elements, but n={n} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+    let ap_len_needed = packed_len_needed(n);
+    if ap.len() < ap_len_needed {
+        return Err(format!(
+            "dspr: `ap` has {} elements, but n={n} needs at least {ap_len_needed}",
+            ap.len()
+        ));
+    }
+
+    unsafe { cblas_dspr(order, uplo, n, alpha, x.as_ptr(), incx, ap.as_mut_ptr()) };
+    Ok(())
+}
+
+/// Safe, slice-based single precision complex hermitian packed rank-1 update, for
+/// callers who'd rather check a `Result` than hold up the `unsafe` contract
+/// [`cblas_chpr`] requires of its raw pointers.
+///
+/// Validates that `x` is long enough for `n`/`incx`, that `ap` holds at least
+/// `n*(n+1)/2` elements, and that chpr has been registered, before dispatching.
+pub fn try_chpr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blasint,
+    alpha: f32,
+    x: &[Complex32],
+    incx: blasint,
+    ap: &mut [Complex32],
+) -> Result<(), String> {
+    crate::backend::try_get_chpr().map_err(|e| e.to_string())?;
+    if n < 0 {
+        return Err(format!("chpr: n ({n}) must be non-negative"));
+    }
+    let x_len_needed = 1 + (n.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if n > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "chpr: `x` has {} elements, but n={n} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+    let ap_len_needed = packed_len_needed(n);
+    if ap.len() < ap_len_needed {
+        return Err(format!(
+            "chpr: `ap` has {} elements, but n={n} needs at least {ap_len_needed}",
+            ap.len()
+        ));
+    }
+
+    unsafe { cblas_chpr(order, uplo, n, alpha, x.as_ptr(), incx, ap.as_mut_ptr()) };
+    Ok(())
+}
+
+/// Safe, slice-based double precision complex hermitian packed rank-1 update, for
+/// callers who'd rather check a `Result` than hold up the `unsafe` contract
+/// [`cblas_zhpr`] requires of its raw pointers.
+///
+/// Validates that `x` is long enough for `n`/`incx`, that `ap` holds at least
+/// `n*(n+1)/2` elements, and that zhpr has been registered, before dispatching.
+pub fn try_zhpr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blasint,
+    alpha: f64,
+    x: &[Complex64],
+    incx: blasint,
+    ap: &mut [Complex64],
+) -> Result<(), String> {
+    crate::backend::try_get_zhpr().map_err(|e| e.to_string())?;
+    if n < 0 {
+        return Err(format!("zhpr: n ({n}) must be non-negative"));
+    }
+    let x_len_needed = 1 + (n.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if n > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "zhpr: `x` has {} elements, but n={n} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+    let ap_len_needed = packed_len_needed(n);
+    if ap.len() < ap_len_needed {
+        return Err(format!(
+            "zhpr: `ap` has {} elements, but n={n} needs at least {ap_len_needed}",
+            ap.len()
+        ));
+    }
+
+    unsafe { cblas_zhpr(order, uplo, n, alpha, x.as_ptr(), incx, ap.as_mut_ptr()) };
+    Ok(())
+}
+
+/// Safe, slice-based single precision symmetric packed rank-2 update, for callers
+/// who'd rather check a `Result` than hold up the `unsafe` contract [`cblas_sspr2`]
+/// requires of its raw pointers.
+///
+/// Validates that `x`/`y` are long enough for `n`/`incx`/`incy`, that `ap` holds at
+/// least `n*(n+1)/2` elements, and that sspr2 has been registered, before dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_sspr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blasint,
+    alpha: f32,
+    x: &[f32],
+    incx: blasint,
+    y: &[f32],
+    incy: blasint,
+    ap: &mut [f32],
+) -> Result<(), String> {
+    crate::backend::try_get_sspr2().map_err(|e| e.to_string())?;
+    if n < 0 {
+        return Err(format!("sspr2: n ({n}) must be non-negative"));
+    }
+    let x_len_needed = 1 + (n.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if n > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "sspr2: `x` has {} elements, but n={n} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+    let y_len_needed = 1 + (n.max(1) as usize - 1) * (incy.unsigned_abs() as usize);
+    if n > 0 && y.len() < y_len_needed {
+        return Err(format!(
+            "sspr2: `y` has {} elements, but n={n} and incy={incy} need at least {y_len_needed}",
+            y.len()
+        ));
+    }
+    let ap_len_needed = packed_len_needed(n);
+    if ap.len() < ap_len_needed {
+        return Err(format!(
+            "sspr2: `ap` has {} elements, but n={n} needs at least {ap_len_needed}",
+            ap.len()
+        ));
+    }
+
+    unsafe {
+        cblas_sspr2(order, uplo, n, alpha, x.as_ptr(), incx, y.as_ptr(), incy, ap.as_mut_ptr())
+    };
+    Ok(())
+}
+
+/// Safe, slice-based double precision symmetric packed rank-2 update, for callers
+/// who'd rather check a `Result` than hold up the `unsafe` contract [`cblas_dspr2`]
+/// requires of its raw pointers.
+///
+/// Validates that `x`/`y` are long enough for `n`/`incx`/`incy`, that `ap` holds at
+/// least `n*(n+1)/2` elements, and that dspr2 has been registered, before dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_dspr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blasint,
+    alpha: f64,
+    x: &[f64],
+    incx: blasint,
+    y: &[f64],
+    incy: blasint,
+    ap: &mut [f64],
+) -> Result<(), String> {
+    crate::backend::try_get_dspr2().map_err(|e| e.to_string())?;
+    if n < 0 {
+        return Err(format!("dspr2: n ({n}) must be non-negative"));
+    }
+    let x_len_needed = 1 + (n.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if n > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "dspr2: `x` has {} elements, but n={n} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+    let y_len_needed = 1 + (n.max(1) as usize - 1) * (incy.unsigned_abs() as usize);
+    if n > 0 && y.len() < y_len_needed {
+        return Err(format!(
+            "dspr2: `y` has {} elements, but n={n} and incy={incy} need at least {y_len_needed}",
+            y.len()
+        ));
+    }
+    let ap_len_needed = packed_len_needed(n);
+    if ap.len() < ap_len_needed {
+        return Err(format!(
+            "dspr2: `ap` has {} elements, but n={n} needs at least {ap_len_needed}",
+            ap.len()
+        ));
+    }
+
+    unsafe {
+        cblas_dspr2(order, uplo, n, alpha, x.as_ptr(), incx, y.as_ptr(), incy, ap.as_mut_ptr())
+    };
+    Ok(())
+}
+
+/// Safe, slice-based single precision complex hermitian packed rank-2 update, for
+/// callers who'd rather check a `Result` than hold up the `unsafe` contract
+/// [`cblas_chpr2`] requires of its raw pointers.
+///
+/// Validates that `x`/`y` are long enough for `n`/`incx`/`incy`, that `ap` holds at
+/// least `n*(n+1)/2` elements, and that chpr2 has been registered, before dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_chpr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blasint,
+    alpha: Complex32,
+    x: &[Complex32],
+    incx: blasint,
+    y: &[Complex32],
+    incy: blasint,
+    ap: &mut [Complex32],
+) -> Result<(), String> {
+    crate::backend::try_get_chpr2().map_err(|e| e.to_string())?;
+    if n < 0 {
+        return Err(format!("chpr2: n ({n}) must be non-negative"));
+    }
+    let x_len_needed = 1 + (n.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if n > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "chpr2: `x` has {} elements, but n={n} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+    let y_len_needed = 1 + (n.max(1) as usize - 1) * (incy.unsigned_abs() as usize);
+    if n > 0 && y.len() < y_len_needed {
+        return Err(format!(
+            "chpr2: `y` has {} elements, but n={n} and incy={incy} need at least {y_len_needed}",
+            y.len()
+        ));
+    }
+    let ap_len_needed = packed_len_needed(n);
+    if ap.len() < ap_len_needed {
+        return Err(format!(
+            "chpr2: `ap` has {} elements, but n={n} needs at least {ap_len_needed}",
+            ap.len()
+        ));
+    }
+
+    unsafe {
+        cblas_chpr2(order, uplo, n, &alpha, x.as_ptr(), incx, y.as_ptr(), incy, ap.as_mut_ptr())
+    };
+    Ok(())
+}
+
+/// Safe, slice-based double precision complex hermitian packed rank-2 update, for
+/// callers who'd rather check a `Result` than hold up the `unsafe` contract
+/// [`cblas_zhpr2`] requires of its raw pointers.
+///
+/// Validates that `x`/`y` are long enough for `n`/`incx`/`incy`, that `ap` holds at
+/// least `n*(n+1)/2` elements, and that zhpr2 has been registered, before dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_zhpr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blasint,
+    alpha: Complex64,
+    x: &[Complex64],
+    incx: blasint,
+    y: &[Complex64],
+    incy: blasint,
+    ap: &mut [Complex64],
+) -> Result<(), String> {
+    crate::backend::try_get_zhpr2().map_err(|e| e.to_string())?;
+    if n < 0 {
+        return Err(format!("zhpr2: n ({n}) must be non-negative"));
+    }
+    let x_len_needed = 1 + (n.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if n > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "zhpr2: `x` has {} elements, but n={n} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+    let y_len_needed = 1 + (n.max(1) as usize - 1) * (incy.unsigned_abs() as usize);
+    if n > 0 && y.len() < y_len_needed {
+        return Err(format!(
+            "zhpr2: `y` has {} elements, but n={n} and incy={incy} need at least {y_len_needed}",
+            y.len()
+        ));
+    }
+    let ap_len_needed = packed_len_needed(n);
+    if ap.len() < ap_len_needed {
+        return Err(format!(
+            "zhpr2: `ap` has {} elements, but n={n} needs at least {ap_len_needed}",
+            ap.len()
+        ));
+    }
+
+    unsafe {
+        cblas_zhpr2(order, uplo, n, &alpha, x.as_ptr(), incx, y.as_ptr(), incy, ap.as_mut_ptr())
+    };
+    Ok(())
+}
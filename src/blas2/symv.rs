@@ -12,8 +12,7 @@ use num_complex::{Complex32, Complex64};
 
 use crate::backend::{get_chemv, get_dsymv, get_ssymv, get_zhemv};
 use crate::types::{
-    blasint, uplo_to_char, CblasColMajor, CblasLower, CblasRowMajor, CblasUpper, CBLAS_ORDER,
-    CBLAS_UPLO,
+    blasint, flip_uplo, uplo_to_char, CblasColMajor, CblasRowMajor, CBLAS_ORDER, CBLAS_UPLO,
 };
 
 /// Single precision symmetric matrix-vector multiply.
@@ -51,10 +50,7 @@ pub unsafe extern "C" fn cblas_ssymv(
         CblasRowMajor => {
             // Row-major: swap Upper/Lower
             // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/symv.c
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             ssymv(&uplo_char, &n, &alpha, a, &lda, x, &incx, &beta, y, &incy);
         }
@@ -95,10 +91,7 @@ pub unsafe extern "C" fn cblas_dsymv(
         }
         CblasRowMajor => {
             // Row-major: swap Upper/Lower
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             dsymv(&uplo_char, &n, &alpha, a, &lda, x, &incx, &beta, y, &incy);
         }
@@ -147,10 +140,7 @@ pub unsafe extern "C" fn cblas_chemv(
         CblasRowMajor => {
             // Row-major for Hermitian: swap Upper/Lower and conjugate scalars and vectors
             // Following the CBLAS reference implementation approach
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
 
             // Conjugate alpha and beta
@@ -260,10 +250,7 @@ pub unsafe extern "C" fn cblas_zhemv(
         CblasRowMajor => {
             // Row-major for Hermitian: swap Upper/Lower and conjugate scalars and vectors
             // Following the CBLAS reference implementation approach
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
 
             // Conjugate alpha and beta
@@ -11,15 +11,22 @@
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/zher.c>
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/syr2.c>
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/zher2.c>
+//!
+//! Real SYR/SYR2 are symmetric, so `A_row^T == A_row` and the uplo flip alone realizes
+//! the row-major conversion (the view is the matrix itself). Complex HER/HER2 are
+//! Hermitian instead (`A_row^T == conj(A_row)`), so the view is `conj(A_row)`, not
+//! `A_row`: reproducing the update on `A_row` requires conjugating the vector operands
+//! (and, for HER2, `alpha`) before calling the backend, the same conjugation
+//! `cblas_chemv`/`cblas_zhemv` apply to keep the Hermitian row-major transpose correct.
 
 use num_complex::{Complex32, Complex64};
 
 use crate::backend::{
     get_cher, get_cher2, get_dsyr, get_dsyr2, get_ssyr, get_ssyr2, get_zher, get_zher2,
 };
+use crate::conj::conjugate_vector_copy;
 use crate::types::{
-    blasint, uplo_to_char, CblasColMajor, CblasLower, CblasRowMajor, CblasUpper, CBLAS_ORDER,
-    CBLAS_UPLO,
+    blasint, flip_uplo, uplo_to_char, CblasColMajor, CblasRowMajor, CBLAS_ORDER, CBLAS_UPLO,
 };
 
 // =============================================================================
@@ -53,10 +60,7 @@ pub unsafe extern "C" fn cblas_ssyr(
         }
         CblasRowMajor => {
             // Row-major: invert uplo (Upper <-> Lower)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             ssyr(&uplo_char, &n, &alpha, x, &incx, a, &lda);
         }
@@ -90,10 +94,7 @@ pub unsafe extern "C" fn cblas_dsyr(
         }
         CblasRowMajor => {
             // Row-major: invert uplo (Upper <-> Lower)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             dsyr(&uplo_char, &n, &alpha, x, &incx, a, &lda);
         }
@@ -132,15 +133,11 @@ pub unsafe extern "C" fn cblas_cher(
             cher(&uplo_char, &n, &alpha, x, &incx, a, &lda);
         }
         CblasRowMajor => {
-            // Row-major for Hermitian: invert uplo
-            // The Hermitian property A = A^H means A^T = conj(A)
-            // So row-major upper triangle = col-major lower triangle (conjugated)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            // Row-major for Hermitian: invert uplo and conjugate x (see the module doc).
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
-            cher(&uplo_char, &n, &alpha, x, &incx, a, &lda);
+            let x_conj = conjugate_vector_copy(n, x, incx);
+            cher(&uplo_char, &n, &alpha, x_conj.as_ptr(), &1, a, &lda);
         }
     }
 }
@@ -173,13 +170,11 @@ pub unsafe extern "C" fn cblas_zher(
             zher(&uplo_char, &n, &alpha, x, &incx, a, &lda);
         }
         CblasRowMajor => {
-            // Row-major for Hermitian: invert uplo
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            // Row-major for Hermitian: invert uplo and conjugate x (see the module doc).
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
-            zher(&uplo_char, &n, &alpha, x, &incx, a, &lda);
+            let x_conj = conjugate_vector_copy(n, x, incx);
+            zher(&uplo_char, &n, &alpha, x_conj.as_ptr(), &1, a, &lda);
         }
     }
 }
@@ -218,10 +213,7 @@ pub unsafe extern "C" fn cblas_ssyr2(
         }
         CblasRowMajor => {
             // Row-major: invert uplo (Upper <-> Lower)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             ssyr2(&uplo_char, &n, &alpha, x, &incx, y, &incy, a, &lda);
         }
@@ -258,10 +250,7 @@ pub unsafe extern "C" fn cblas_dsyr2(
         }
         CblasRowMajor => {
             // Row-major: invert uplo (Upper <-> Lower)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
             dsyr2(&uplo_char, &n, &alpha, x, &incx, y, &incy, a, &lda);
         }
@@ -303,15 +292,24 @@ pub unsafe extern "C" fn cblas_cher2(
             cher2(&uplo_char, &n, alpha, x, &incx, y, &incy, a, &lda);
         }
         CblasRowMajor => {
-            // Row-major for Hermitian: invert uplo
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            // Row-major for Hermitian: invert uplo, conjugate alpha, x, and y (see the
+            // module doc).
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
-            // For HER2 in row-major, we also need to swap x and y
-            // and use conjugate of alpha (handled by the property of HER2)
-            cher2(&uplo_char, &n, alpha, y, &incy, x, &incx, a, &lda);
+            let alpha_conj = (*alpha).conj();
+            let x_conj = conjugate_vector_copy(n, x, incx);
+            let y_conj = conjugate_vector_copy(n, y, incy);
+            cher2(
+                &uplo_char,
+                &n,
+                &alpha_conj,
+                x_conj.as_ptr(),
+                &1,
+                y_conj.as_ptr(),
+                &1,
+                a,
+                &lda,
+            );
         }
     }
 }
@@ -347,13 +345,24 @@ pub unsafe extern "C" fn cblas_zher2(
             zher2(&uplo_char, &n, alpha, x, &incx, y, &incy, a, &lda);
         }
         CblasRowMajor => {
-            // Row-major for Hermitian: invert uplo and swap x<->y
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            // Row-major for Hermitian: invert uplo, conjugate alpha, x, and y (see the
+            // module doc).
+            let new_uplo = flip_uplo(uplo);
             let uplo_char = uplo_to_char(new_uplo);
-            zher2(&uplo_char, &n, alpha, y, &incy, x, &incx, a, &lda);
+            let alpha_conj = (*alpha).conj();
+            let x_conj = conjugate_vector_copy(n, x, incx);
+            let y_conj = conjugate_vector_copy(n, y, incy);
+            zher2(
+                &uplo_char,
+                &n,
+                &alpha_conj,
+                x_conj.as_ptr(),
+                &1,
+                y_conj.as_ptr(),
+                &1,
+                a,
+                &lda,
+            );
         }
     }
 }
@@ -9,12 +9,35 @@
 
 use num_complex::{Complex32, Complex64};
 
-use crate::backend::{get_ctbmv, get_dtbmv, get_stbmv, get_ztbmv};
+use crate::backend::{
+    get_ctbmv, get_ctbmv_cblas, get_dtbmv, get_dtbmv_cblas, get_stbmv, get_stbmv_cblas, get_ztbmv,
+    get_ztbmv_cblas,
+};
 use crate::types::{
-    blasint, diag_to_char, normalize_transpose_real, transpose_to_char, uplo_to_char, CblasColMajor,
-    CblasConjNoTrans, CblasConjTrans, CblasLower, CblasNoTrans, CblasRowMajor, CblasTrans,
-    CblasUpper, CBLAS_DIAG, CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
+    blasint, diag_to_char, flip_transpose_real, flip_uplo, normalize_transpose_real,
+    transpose_to_char, uplo_to_char, CblasColMajor, CblasConjNoTrans, CblasConjTrans,
+    CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_DIAG, CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
 };
+use crate::validation::validate_layout;
+
+/// Validates CBLAS argument positions 5 (n), 6 (k), 8 (lda), and 10 (incx) for
+/// `cblas_?tbmv`: `n >= 0`, `k >= 0`, `lda >= k + 1` to hold the full band plus
+/// diagonal, and `incx != 0`. Positions are logical (`CblasColMajor`); see
+/// [`validate_layout`] for the `CblasRowMajor` renumbering.
+unsafe fn check_tbmv(
+    routine: &str,
+    order: CBLAS_ORDER,
+    n: blasint,
+    k: blasint,
+    lda: blasint,
+    incx: blasint,
+) -> bool {
+    validate_layout(
+        routine,
+        order,
+        &[(n >= 0, 5), (k >= 0, 6), (lda > k, 8), (incx != 0, 10)],
+    )
+}
 
 /// Single precision triangular band matrix-vector multiply.
 ///
@@ -38,6 +61,15 @@ pub unsafe extern "C" fn cblas_stbmv(
     x: *mut f32,
     incx: blasint,
 ) {
+    if check_tbmv("cblas_stbmv", order, n, k, lda, incx) {
+        return;
+    }
+
+    if let Some(stbmv_cblas) = get_stbmv_cblas() {
+        stbmv_cblas(order, uplo, trans, diag, n, k, a, lda, x, incx);
+        return;
+    }
+
     let stbmv = get_stbmv();
 
     match order {
@@ -50,15 +82,8 @@ pub unsafe extern "C" fn cblas_stbmv(
         CblasRowMajor => {
             // Row-major: invert uplo and trans
             // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/tbmv.c
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match normalize_transpose_real(trans) {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                _ => unreachable!(),
-            };
+            let new_uplo = flip_uplo(uplo);
+            let new_trans = flip_transpose_real(normalize_transpose_real(trans));
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
             let diag_char = diag_to_char(diag);
@@ -89,6 +114,15 @@ pub unsafe extern "C" fn cblas_dtbmv(
     x: *mut f64,
     incx: blasint,
 ) {
+    if check_tbmv("cblas_dtbmv", order, n, k, lda, incx) {
+        return;
+    }
+
+    if let Some(dtbmv_cblas) = get_dtbmv_cblas() {
+        dtbmv_cblas(order, uplo, trans, diag, n, k, a, lda, x, incx);
+        return;
+    }
+
     let dtbmv = get_dtbmv();
 
     match order {
@@ -100,15 +134,8 @@ pub unsafe extern "C" fn cblas_dtbmv(
         }
         CblasRowMajor => {
             // Row-major: invert uplo and trans
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match normalize_transpose_real(trans) {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                _ => unreachable!(),
-            };
+            let new_uplo = flip_uplo(uplo);
+            let new_trans = flip_transpose_real(normalize_transpose_real(trans));
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
             let diag_char = diag_to_char(diag);
@@ -139,6 +166,15 @@ pub unsafe extern "C" fn cblas_ctbmv(
     x: *mut Complex32,
     incx: blasint,
 ) {
+    if check_tbmv("cblas_ctbmv", order, n, k, lda, incx) {
+        return;
+    }
+
+    if let Some(ctbmv_cblas) = get_ctbmv_cblas() {
+        ctbmv_cblas(order, uplo, trans, diag, n, k, a, lda, x, incx);
+        return;
+    }
+
     let ctbmv = get_ctbmv();
 
     match order {
@@ -151,10 +187,7 @@ pub unsafe extern "C" fn cblas_ctbmv(
         CblasRowMajor => {
             // Row-major: invert uplo and trans
             // For complex: flip transpose with conjugation preserved (OpenBLAS)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
@@ -191,6 +224,15 @@ pub unsafe extern "C" fn cblas_ztbmv(
     x: *mut Complex64,
     incx: blasint,
 ) {
+    if check_tbmv("cblas_ztbmv", order, n, k, lda, incx) {
+        return;
+    }
+
+    if let Some(ztbmv_cblas) = get_ztbmv_cblas() {
+        ztbmv_cblas(order, uplo, trans, diag, n, k, a, lda, x, incx);
+        return;
+    }
+
     let ztbmv = get_ztbmv();
 
     match order {
@@ -203,10 +245,7 @@ pub unsafe extern "C" fn cblas_ztbmv(
         CblasRowMajor => {
             // Row-major: invert uplo and trans
             // For complex: flip transpose with conjugation preserved (OpenBLAS)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
@@ -220,3 +259,211 @@ pub unsafe extern "C" fn cblas_ztbmv(
         }
     }
 }
+
+/// Safe, slice-based single precision triangular band matrix-vector multiply, for
+/// callers who'd rather check a `Result` than hold up the `unsafe` contract
+/// [`cblas_stbmv`] requires of its raw pointers.
+///
+/// Validates that `a` and `x` are long enough for the given dimension, band width,
+/// leading dimension, and increment, and that stbmv has been registered, before
+/// dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_stbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blasint,
+    k: blasint,
+    a: &[f32],
+    lda: blasint,
+    x: &mut [f32],
+    incx: blasint,
+) -> Result<(), String> {
+    crate::backend::try_get_stbmv().map_err(|e| e.to_string())?;
+    if n < 0 {
+        return Err(format!("stbmv: n ({n}) must be non-negative"));
+    }
+    if k < 0 {
+        return Err(format!("stbmv: k ({k}) must be non-negative"));
+    }
+    if lda < k + 1 {
+        return Err(format!("stbmv: lda ({lda}) must be at least k+1 ({})", k + 1));
+    }
+
+    let a_len_needed = (lda.max(1) as usize) * (n.max(1) as usize);
+    if a.len() < a_len_needed {
+        return Err(format!(
+            "stbmv: `a` has {} elements, but lda={lda} and n={n} need at least {a_len_needed}",
+            a.len()
+        ));
+    }
+    let x_len_needed = 1 + (n.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if n > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "stbmv: `x` has {} elements, but n={n} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+
+    unsafe {
+        cblas_stbmv(order, uplo, trans, diag, n, k, a.as_ptr(), lda, x.as_mut_ptr(), incx);
+    }
+    Ok(())
+}
+
+/// Safe, slice-based double precision triangular band matrix-vector multiply, for
+/// callers who'd rather check a `Result` than hold up the `unsafe` contract
+/// [`cblas_dtbmv`] requires of its raw pointers.
+///
+/// Validates that `a` and `x` are long enough for the given dimension, band width,
+/// leading dimension, and increment, and that dtbmv has been registered, before
+/// dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_dtbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blasint,
+    k: blasint,
+    a: &[f64],
+    lda: blasint,
+    x: &mut [f64],
+    incx: blasint,
+) -> Result<(), String> {
+    crate::backend::try_get_dtbmv().map_err(|e| e.to_string())?;
+    if n < 0 {
+        return Err(format!("dtbmv: n ({n}) must be non-negative"));
+    }
+    if k < 0 {
+        return Err(format!("dtbmv: k ({k}) must be non-negative"));
+    }
+    if lda < k + 1 {
+        return Err(format!("dtbmv: lda ({lda}) must be at least k+1 ({})", k + 1));
+    }
+
+    let a_len_needed = (lda.max(1) as usize) * (n.max(1) as usize);
+    if a.len() < a_len_needed {
+        return Err(format!(
+            "dtbmv: `a` has {} elements, but lda={lda} and n={n} need at least {a_len_needed}",
+            a.len()
+        ));
+    }
+    let x_len_needed = 1 + (n.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if n > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "dtbmv: `x` has {} elements, but n={n} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+
+    unsafe {
+        cblas_dtbmv(order, uplo, trans, diag, n, k, a.as_ptr(), lda, x.as_mut_ptr(), incx);
+    }
+    Ok(())
+}
+
+/// Safe, slice-based single precision complex triangular band matrix-vector multiply,
+/// for callers who'd rather check a `Result` than hold up the `unsafe` contract
+/// [`cblas_ctbmv`] requires of its raw pointers.
+///
+/// Validates that `a` and `x` are long enough for the given dimension, band width,
+/// leading dimension, and increment, and that ctbmv has been registered, before
+/// dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_ctbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blasint,
+    k: blasint,
+    a: &[Complex32],
+    lda: blasint,
+    x: &mut [Complex32],
+    incx: blasint,
+) -> Result<(), String> {
+    crate::backend::try_get_ctbmv().map_err(|e| e.to_string())?;
+    if n < 0 {
+        return Err(format!("ctbmv: n ({n}) must be non-negative"));
+    }
+    if k < 0 {
+        return Err(format!("ctbmv: k ({k}) must be non-negative"));
+    }
+    if lda < k + 1 {
+        return Err(format!("ctbmv: lda ({lda}) must be at least k+1 ({})", k + 1));
+    }
+
+    let a_len_needed = (lda.max(1) as usize) * (n.max(1) as usize);
+    if a.len() < a_len_needed {
+        return Err(format!(
+            "ctbmv: `a` has {} elements, but lda={lda} and n={n} need at least {a_len_needed}",
+            a.len()
+        ));
+    }
+    let x_len_needed = 1 + (n.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if n > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "ctbmv: `x` has {} elements, but n={n} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+
+    unsafe {
+        cblas_ctbmv(order, uplo, trans, diag, n, k, a.as_ptr(), lda, x.as_mut_ptr(), incx);
+    }
+    Ok(())
+}
+
+/// Safe, slice-based double precision complex triangular band matrix-vector multiply,
+/// for callers who'd rather check a `Result` than hold up the `unsafe` contract
+/// [`cblas_ztbmv`] requires of its raw pointers.
+///
+/// Validates that `a` and `x` are long enough for the given dimension, band width,
+/// leading dimension, and increment, and that ztbmv has been registered, before
+/// dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_ztbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blasint,
+    k: blasint,
+    a: &[Complex64],
+    lda: blasint,
+    x: &mut [Complex64],
+    incx: blasint,
+) -> Result<(), String> {
+    crate::backend::try_get_ztbmv().map_err(|e| e.to_string())?;
+    if n < 0 {
+        return Err(format!("ztbmv: n ({n}) must be non-negative"));
+    }
+    if k < 0 {
+        return Err(format!("ztbmv: k ({k}) must be non-negative"));
+    }
+    if lda < k + 1 {
+        return Err(format!("ztbmv: lda ({lda}) must be at least k+1 ({})", k + 1));
+    }
+
+    let a_len_needed = (lda.max(1) as usize) * (n.max(1) as usize);
+    if a.len() < a_len_needed {
+        return Err(format!(
+            "ztbmv: `a` has {} elements, but lda={lda} and n={n} need at least {a_len_needed}",
+            a.len()
+        ));
+    }
+    let x_len_needed = 1 + (n.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if n > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "ztbmv: `x` has {} elements, but n={n} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+
+    unsafe {
+        cblas_ztbmv(order, uplo, trans, diag, n, k, a.as_ptr(), lda, x.as_mut_ptr(), incx);
+    }
+    Ok(())
+}
@@ -7,15 +7,29 @@
 //! Row-major conversion logic derived from OpenBLAS.
 //! Copyright (c) 2011-2014, The OpenBLAS Project. BSD-3-Clause License.
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/tbsv.c>
+//!
+//! All numeric work here is delegated to the registered Fortran backend (see
+//! `crate::backend`). Unlike `crate::blas2::tpmv`'s TPSV, `crate::backend::get_*tbsv`
+//! has no `reference`-feature fallback at all (no `ref_*tbsv` exists), so there is
+//! genuinely no pure-Rust compute kernel anywhere in the crate yet to apply a
+//! CPU-feature-detected SIMD dispatch layer to for this routine.
 
 use num_complex::{Complex32, Complex64};
 
 use crate::backend::{get_ctbsv, get_dtbsv, get_stbsv, get_ztbsv};
+use crate::conj::conjugate_vector_inplace;
 use crate::types::{
-    blasint, diag_to_char, transpose_to_char, uplo_to_char, CblasColMajor, CblasConjTrans,
-    CblasLower, CblasNoTrans, CblasRowMajor, CblasTrans, CblasUpper, CBLAS_DIAG, CBLAS_ORDER,
-    CBLAS_TRANSPOSE, CBLAS_UPLO,
+    blasint, diag_to_char, flip_uplo, transpose_to_char, uplo_to_char, CblasColMajor,
+    CblasConjNoTrans, CblasConjTrans, CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_DIAG,
+    CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
 };
+use crate::validation::validate;
+
+/// Validates CBLAS argument positions 5 (n) and 6 (k)/7 (lda) for `cblas_?tbsv`:
+/// `n >= 0`, `k >= 0`, and `lda >= k + 1` to hold the full band plus diagonal.
+unsafe fn check_tbsv(routine: &str, n: blasint, k: blasint, lda: blasint) -> bool {
+    validate(routine, &[(n >= 0, 5), (k >= 0, 6), (lda >= k + 1, 8)])
+}
 
 /// Single precision triangular band solve.
 ///
@@ -39,6 +53,10 @@ pub unsafe extern "C" fn cblas_stbsv(
     x: *mut f32,
     incx: blasint,
 ) {
+    if check_tbsv("cblas_stbsv", n, k, lda) {
+        return;
+    }
+
     let stbsv = get_stbsv();
 
     match order {
@@ -51,14 +69,12 @@ pub unsafe extern "C" fn cblas_stbsv(
         CblasRowMajor => {
             // Row-major: invert uplo and trans
             // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/tbsv.c
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
                 CblasConjTrans => CblasNoTrans, // For real types, ConjTrans = Trans
+                CblasConjNoTrans => CblasTrans, // For real types, ConjNoTrans = NoTrans
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -90,6 +106,10 @@ pub unsafe extern "C" fn cblas_dtbsv(
     x: *mut f64,
     incx: blasint,
 ) {
+    if check_tbsv("cblas_dtbsv", n, k, lda) {
+        return;
+    }
+
     let dtbsv = get_dtbsv();
 
     match order {
@@ -101,14 +121,12 @@ pub unsafe extern "C" fn cblas_dtbsv(
         }
         CblasRowMajor => {
             // Row-major: invert uplo and trans
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
                 CblasConjTrans => CblasNoTrans, // For real types, ConjTrans = Trans
+                CblasConjNoTrans => CblasTrans, // For real types, ConjNoTrans = NoTrans
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -140,6 +158,19 @@ pub unsafe extern "C" fn cblas_ctbsv(
     x: *mut Complex32,
     incx: blasint,
 ) {
+    if check_tbsv("cblas_ctbsv", n, k, lda) {
+        return;
+    }
+
+    if trans == CblasConjNoTrans {
+        // op(A) = conj(A): conjugate x, solve with the unconjugated A via plain
+        // NoTrans, then conjugate the result back. See `crate::conj`.
+        conjugate_vector_inplace(n, x, incx);
+        cblas_ctbsv(order, uplo, CblasNoTrans, diag, n, k, a, lda, x, incx);
+        conjugate_vector_inplace(n, x, incx);
+        return;
+    }
+
     let ctbsv = get_ctbsv();
 
     match order {
@@ -152,14 +183,12 @@ pub unsafe extern "C" fn cblas_ctbsv(
         CblasRowMajor => {
             // Row-major: invert uplo and trans
             // For complex: ConjTrans stays ConjTrans (conjugate is preserved)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
                 CblasConjTrans => CblasConjTrans, // Conjugate transpose stays as conj trans for complex
+                CblasConjNoTrans => unreachable!("handled above"),
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -191,6 +220,19 @@ pub unsafe extern "C" fn cblas_ztbsv(
     x: *mut Complex64,
     incx: blasint,
 ) {
+    if check_tbsv("cblas_ztbsv", n, k, lda) {
+        return;
+    }
+
+    if trans == CblasConjNoTrans {
+        // op(A) = conj(A): conjugate x, solve with the unconjugated A via plain
+        // NoTrans, then conjugate the result back. See `crate::conj`.
+        conjugate_vector_inplace(n, x, incx);
+        cblas_ztbsv(order, uplo, CblasNoTrans, diag, n, k, a, lda, x, incx);
+        conjugate_vector_inplace(n, x, incx);
+        return;
+    }
+
     let ztbsv = get_ztbsv();
 
     match order {
@@ -203,14 +245,12 @@ pub unsafe extern "C" fn cblas_ztbsv(
         CblasRowMajor => {
             // Row-major: invert uplo and trans
             // For complex: ConjTrans stays ConjTrans (conjugate is preserved)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
                 CblasConjTrans => CblasConjTrans, // Conjugate transpose stays as conj trans for complex
+                CblasConjNoTrans => unreachable!("handled above"),
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -8,118 +8,365 @@
 //! Copyright (c) 2011-2014, The OpenBLAS Project. BSD-3-Clause License.
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/tpmv.c>
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/tpsv.c>
+//!
+//! All numeric work here is delegated to whatever `crate::backend::get_*tpmv`/
+//! `get_*tpsv` returns, so there's no compute loop in this module itself to dispatch
+//! SIMD from. Those accessors do fall back to a pure-Rust kernel
+//! (`crate::reference::ref_stpmv`/`ref_dtpmv`/`ref_stpsv`/`ref_dtpsv`) when the
+//! `reference` feature is on and no backend is registered; of those, TPSV's
+//! substitution loop is the one that dispatches to `crate::simd`'s
+//! runtime-CPU-feature-detected kernel when `incx == 1` (see `crate::reference`'s
+//! module doc).
+//!
+//! `CblasConjNoTrans` (`op(A) = conj(A)`) has no native Fortran character. Both TPMV
+//! and TPSV realize it the same way `cblas_ctrmv`/`cblas_ctrsv` do: conjugate `x` in
+//! place, recurse with plain `CblasNoTrans`, then conjugate the result back — valid for
+//! TPMV via `conj(x_new) = A*conj(x_old)` and for TPSV via `conj(A)*x=b ⟺ A*conj(x)=conj(b)`.
+//!
+//! The row/column-major conversion logic is shared across precisions via the sealed
+//! `BlasFloat` trait and the generic [`tpmv`]/[`tpsv`] functions, following the same
+//! pattern `crate::blas3::trsm` uses for `TrsmScalar`/`trsm`.
+//!
+//! `cblas_?tpsv_refine` layers iterative refinement on top of the plain TPSV/TPMV
+//! kernels for nearly-singular triangular systems, accumulating the residual in the
+//! wider precision (`f32`→`f64`, `Complex32`→`Complex64`) when one is available.
+
+use std::ffi::c_char;
 
 use num_complex::{Complex32, Complex64};
 
 use crate::backend::{
     get_ctpmv, get_ctpsv, get_dtpmv, get_dtpsv, get_stpmv, get_stpsv, get_ztpmv, get_ztpsv,
 };
+use crate::conj::conjugate_vector_inplace;
 use crate::types::{
-    blasint, diag_to_char, transpose_to_char, uplo_to_char, CblasColMajor, CblasConjTrans,
-    CblasLower, CblasNoTrans, CblasRowMajor, CblasTrans, CblasUpper, CBLAS_DIAG, CBLAS_ORDER,
-    CBLAS_TRANSPOSE, CBLAS_UPLO,
+    blasint, diag_to_char, flip_transpose_real, flip_uplo, normalize_transpose_real,
+    transpose_to_char, uplo_to_char, CblasColMajor, CblasConjNoTrans, CblasConjTrans,
+    CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_DIAG, CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
 };
+use crate::validation::validate;
 
-// =============================================================================
-// TPMV: Triangular Packed Matrix-Vector Multiply
-// =============================================================================
+/// Validates CBLAS argument positions 5 (n) and 8 (incx) for `cblas_?tpmv`/`cblas_?tpsv`:
+/// `n >= 0` and `incx != 0`. There is no `lda` to check here since `ap` is packed.
+unsafe fn check_tpmv(routine: &str, n: blasint, incx: blasint) -> bool {
+    validate(routine, &[(n >= 0, 5), (incx != 0, 8)])
+}
 
-/// Single precision triangular packed matrix-vector multiply.
-///
-/// Computes x = op(A) * x where A is triangular in packed format.
+/// Validates CBLAS argument positions 5 (n) and 9 (incx) for `cblas_?tpsv_refine`.
+unsafe fn check_tpsv_refine(routine: &str, n: blasint, incx: blasint) -> bool {
+    validate(routine, &[(n >= 0, 5), (incx != 0, 9)])
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+    impl Sealed for num_complex::Complex32 {}
+    impl Sealed for num_complex::Complex64 {}
+}
+
+/// Scalar types usable with the generic [`tpmv`]/[`tpsv`] dispatch core, hiding which
+/// precision's Fortran backend is called and how row-major storage inverts `trans`.
+pub trait BlasFloat: sealed::Sealed + Copy {
+    /// Calls the registered packed triangular matrix-vector multiply backend.
+    ///
+    /// # Safety
+    ///
+    /// Same pointer/alignment requirements as the underlying Fortran `?tpmv` routine.
+    unsafe fn tpmv_call(
+        uplo_char: &c_char,
+        trans_char: &c_char,
+        diag_char: &c_char,
+        n: &blasint,
+        ap: *const Self,
+        x: *mut Self,
+        incx: &blasint,
+    );
+
+    /// Calls the registered packed triangular solve backend.
+    ///
+    /// # Safety
+    ///
+    /// Same pointer/alignment requirements as the underlying Fortran `?tpsv` routine.
+    unsafe fn tpsv_call(
+        uplo_char: &c_char,
+        trans_char: &c_char,
+        diag_char: &c_char,
+        n: &blasint,
+        ap: *const Self,
+        x: *mut Self,
+        incx: &blasint,
+    );
+
+    /// Inverts `trans` for row-major packed storage, after `uplo` has already been
+    /// flipped. Real types collapse any conjugate flag (conjugation is a no-op on reals);
+    /// complex types keep `ConjTrans` distinct and never see `ConjNoTrans` here, since
+    /// callers resolve it via `conjugate_vector_inplace` before reaching row-major dispatch.
+    fn invert_trans_row_major(trans: CBLAS_TRANSPOSE) -> CBLAS_TRANSPOSE;
+}
+
+impl BlasFloat for f32 {
+    unsafe fn tpmv_call(
+        uplo_char: &c_char,
+        trans_char: &c_char,
+        diag_char: &c_char,
+        n: &blasint,
+        ap: *const Self,
+        x: *mut Self,
+        incx: &blasint,
+    ) {
+        get_stpmv()(uplo_char, trans_char, diag_char, n, ap, x, incx);
+    }
+
+    unsafe fn tpsv_call(
+        uplo_char: &c_char,
+        trans_char: &c_char,
+        diag_char: &c_char,
+        n: &blasint,
+        ap: *const Self,
+        x: *mut Self,
+        incx: &blasint,
+    ) {
+        get_stpsv()(uplo_char, trans_char, diag_char, n, ap, x, incx);
+    }
+
+    fn invert_trans_row_major(trans: CBLAS_TRANSPOSE) -> CBLAS_TRANSPOSE {
+        flip_transpose_real(normalize_transpose_real(trans))
+    }
+}
+
+impl BlasFloat for f64 {
+    unsafe fn tpmv_call(
+        uplo_char: &c_char,
+        trans_char: &c_char,
+        diag_char: &c_char,
+        n: &blasint,
+        ap: *const Self,
+        x: *mut Self,
+        incx: &blasint,
+    ) {
+        get_dtpmv()(uplo_char, trans_char, diag_char, n, ap, x, incx);
+    }
+
+    unsafe fn tpsv_call(
+        uplo_char: &c_char,
+        trans_char: &c_char,
+        diag_char: &c_char,
+        n: &blasint,
+        ap: *const Self,
+        x: *mut Self,
+        incx: &blasint,
+    ) {
+        get_dtpsv()(uplo_char, trans_char, diag_char, n, ap, x, incx);
+    }
+
+    fn invert_trans_row_major(trans: CBLAS_TRANSPOSE) -> CBLAS_TRANSPOSE {
+        flip_transpose_real(normalize_transpose_real(trans))
+    }
+}
+
+impl BlasFloat for Complex32 {
+    unsafe fn tpmv_call(
+        uplo_char: &c_char,
+        trans_char: &c_char,
+        diag_char: &c_char,
+        n: &blasint,
+        ap: *const Self,
+        x: *mut Self,
+        incx: &blasint,
+    ) {
+        get_ctpmv()(uplo_char, trans_char, diag_char, n, ap, x, incx);
+    }
+
+    unsafe fn tpsv_call(
+        uplo_char: &c_char,
+        trans_char: &c_char,
+        diag_char: &c_char,
+        n: &blasint,
+        ap: *const Self,
+        x: *mut Self,
+        incx: &blasint,
+    ) {
+        get_ctpsv()(uplo_char, trans_char, diag_char, n, ap, x, incx);
+    }
+
+    fn invert_trans_row_major(trans: CBLAS_TRANSPOSE) -> CBLAS_TRANSPOSE {
+        match trans {
+            CblasNoTrans => CblasTrans,
+            CblasTrans => CblasNoTrans,
+            CblasConjTrans => CblasConjTrans,
+            CblasConjNoTrans => {
+                unreachable!("ConjNoTrans is resolved by the caller before row-major dispatch")
+            }
+        }
+    }
+}
+
+impl BlasFloat for Complex64 {
+    unsafe fn tpmv_call(
+        uplo_char: &c_char,
+        trans_char: &c_char,
+        diag_char: &c_char,
+        n: &blasint,
+        ap: *const Self,
+        x: *mut Self,
+        incx: &blasint,
+    ) {
+        get_ztpmv()(uplo_char, trans_char, diag_char, n, ap, x, incx);
+    }
+
+    unsafe fn tpsv_call(
+        uplo_char: &c_char,
+        trans_char: &c_char,
+        diag_char: &c_char,
+        n: &blasint,
+        ap: *const Self,
+        x: *mut Self,
+        incx: &blasint,
+    ) {
+        get_ztpsv()(uplo_char, trans_char, diag_char, n, ap, x, incx);
+    }
+
+    fn invert_trans_row_major(trans: CBLAS_TRANSPOSE) -> CBLAS_TRANSPOSE {
+        match trans {
+            CblasNoTrans => CblasTrans,
+            CblasTrans => CblasNoTrans,
+            CblasConjTrans => CblasConjTrans,
+            CblasConjNoTrans => {
+                unreachable!("ConjNoTrans is resolved by the caller before row-major dispatch")
+            }
+        }
+    }
+}
+
+/// Generic packed triangular matrix-vector multiply, shared by all four precisions.
 ///
 /// # Safety
 ///
 /// - All pointers must be valid and properly aligned
 /// - The packed array `ap` must contain n*(n+1)/2 elements
-/// - stpmv must be registered via `register_stpmv`
-#[no_mangle]
-pub unsafe extern "C" fn cblas_stpmv(
+/// - The relevant backend (`T`'s `tpmv_call`) must be registered
+pub unsafe fn tpmv<T: BlasFloat>(
     order: CBLAS_ORDER,
     uplo: CBLAS_UPLO,
     trans: CBLAS_TRANSPOSE,
     diag: CBLAS_DIAG,
     n: blasint,
-    ap: *const f32,
-    x: *mut f32,
+    ap: *const T,
+    x: *mut T,
     incx: blasint,
 ) {
-    let stpmv = get_stpmv();
-
     match order {
         CblasColMajor => {
             let uplo_char = uplo_to_char(uplo);
             let trans_char = transpose_to_char(trans);
             let diag_char = diag_to_char(diag);
-            stpmv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
+            T::tpmv_call(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
         }
         CblasRowMajor => {
-            // Row-major: invert uplo and trans
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match trans {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                CblasConjTrans => CblasNoTrans, // For real types, ConjTrans = Trans
-            };
+            let new_uplo = flip_uplo(uplo);
+            let new_trans = T::invert_trans_row_major(trans);
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
             let diag_char = diag_to_char(diag);
-            stpmv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
+            T::tpmv_call(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
         }
     }
 }
 
-/// Double precision triangular packed matrix-vector multiply.
-///
-/// Computes x = op(A) * x where A is triangular in packed format.
+/// Generic packed triangular solve, shared by all four precisions.
 ///
 /// # Safety
 ///
 /// - All pointers must be valid and properly aligned
 /// - The packed array `ap` must contain n*(n+1)/2 elements
-/// - dtpmv must be registered via `register_dtpmv`
-#[no_mangle]
-pub unsafe extern "C" fn cblas_dtpmv(
+/// - The relevant backend (`T`'s `tpsv_call`) must be registered
+pub unsafe fn tpsv<T: BlasFloat>(
     order: CBLAS_ORDER,
     uplo: CBLAS_UPLO,
     trans: CBLAS_TRANSPOSE,
     diag: CBLAS_DIAG,
     n: blasint,
-    ap: *const f64,
-    x: *mut f64,
+    ap: *const T,
+    x: *mut T,
     incx: blasint,
 ) {
-    let dtpmv = get_dtpmv();
-
     match order {
         CblasColMajor => {
             let uplo_char = uplo_to_char(uplo);
             let trans_char = transpose_to_char(trans);
             let diag_char = diag_to_char(diag);
-            dtpmv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
+            T::tpsv_call(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
         }
         CblasRowMajor => {
-            // Row-major: invert uplo and trans
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match trans {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                CblasConjTrans => CblasNoTrans, // For real types, ConjTrans = Trans
-            };
+            let new_uplo = flip_uplo(uplo);
+            let new_trans = T::invert_trans_row_major(trans);
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
             let diag_char = diag_to_char(diag);
-            dtpmv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
+            T::tpsv_call(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
         }
     }
 }
 
+// =============================================================================
+// TPMV: Triangular Packed Matrix-Vector Multiply
+// =============================================================================
+
+/// Single precision triangular packed matrix-vector multiply.
+///
+/// Computes x = op(A) * x where A is triangular in packed format.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - The packed array `ap` must contain n*(n+1)/2 elements
+/// - stpmv must be registered via `register_stpmv`
+#[no_mangle]
+pub unsafe extern "C" fn cblas_stpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blasint,
+    ap: *const f32,
+    x: *mut f32,
+    incx: blasint,
+) {
+    if check_tpmv("cblas_stpmv", n, incx) {
+        return;
+    }
+
+    tpmv(order, uplo, trans, diag, n, ap, x, incx);
+}
+
+/// Double precision triangular packed matrix-vector multiply.
+///
+/// Computes x = op(A) * x where A is triangular in packed format.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - The packed array `ap` must contain n*(n+1)/2 elements
+/// - dtpmv must be registered via `register_dtpmv`
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blasint,
+    ap: *const f64,
+    x: *mut f64,
+    incx: blasint,
+) {
+    if check_tpmv("cblas_dtpmv", n, incx) {
+        return;
+    }
+
+    tpmv(order, uplo, trans, diag, n, ap, x, incx);
+}
+
 /// Single precision complex triangular packed matrix-vector multiply.
 ///
 /// Computes x = op(A) * x where A is triangular in packed format.
@@ -140,33 +387,20 @@ pub unsafe extern "C" fn cblas_ctpmv(
     x: *mut Complex32,
     incx: blasint,
 ) {
-    let ctpmv = get_ctpmv();
+    if check_tpmv("cblas_ctpmv", n, incx) {
+        return;
+    }
 
-    match order {
-        CblasColMajor => {
-            let uplo_char = uplo_to_char(uplo);
-            let trans_char = transpose_to_char(trans);
-            let diag_char = diag_to_char(diag);
-            ctpmv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
-        }
-        CblasRowMajor => {
-            // Row-major: invert uplo and trans
-            // For complex: ConjTrans stays ConjTrans
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match trans {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                CblasConjTrans => CblasConjTrans,
-            };
-            let uplo_char = uplo_to_char(new_uplo);
-            let trans_char = transpose_to_char(new_trans);
-            let diag_char = diag_to_char(diag);
-            ctpmv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
-        }
+    if trans == CblasConjNoTrans {
+        // conj(x_new) = A * conj(x_old): conjugate x, multiply with plain NoTrans, then
+        // conjugate the result back.
+        conjugate_vector_inplace(n, x, incx);
+        cblas_ctpmv(order, uplo, CblasNoTrans, diag, n, ap, x, incx);
+        conjugate_vector_inplace(n, x, incx);
+        return;
     }
+
+    tpmv(order, uplo, trans, diag, n, ap, x, incx);
 }
 
 /// Double precision complex triangular packed matrix-vector multiply.
@@ -189,33 +423,20 @@ pub unsafe extern "C" fn cblas_ztpmv(
     x: *mut Complex64,
     incx: blasint,
 ) {
-    let ztpmv = get_ztpmv();
+    if check_tpmv("cblas_ztpmv", n, incx) {
+        return;
+    }
 
-    match order {
-        CblasColMajor => {
-            let uplo_char = uplo_to_char(uplo);
-            let trans_char = transpose_to_char(trans);
-            let diag_char = diag_to_char(diag);
-            ztpmv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
-        }
-        CblasRowMajor => {
-            // Row-major: invert uplo and trans
-            // For complex: ConjTrans stays ConjTrans
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match trans {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                CblasConjTrans => CblasConjTrans,
-            };
-            let uplo_char = uplo_to_char(new_uplo);
-            let trans_char = transpose_to_char(new_trans);
-            let diag_char = diag_to_char(diag);
-            ztpmv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
-        }
+    if trans == CblasConjNoTrans {
+        // conj(x_new) = A * conj(x_old): conjugate x, multiply with plain NoTrans, then
+        // conjugate the result back.
+        conjugate_vector_inplace(n, x, incx);
+        cblas_ztpmv(order, uplo, CblasNoTrans, diag, n, ap, x, incx);
+        conjugate_vector_inplace(n, x, incx);
+        return;
     }
+
+    tpmv(order, uplo, trans, diag, n, ap, x, incx);
 }
 
 // =============================================================================
@@ -242,32 +463,11 @@ pub unsafe extern "C" fn cblas_stpsv(
     x: *mut f32,
     incx: blasint,
 ) {
-    let stpsv = get_stpsv();
-
-    match order {
-        CblasColMajor => {
-            let uplo_char = uplo_to_char(uplo);
-            let trans_char = transpose_to_char(trans);
-            let diag_char = diag_to_char(diag);
-            stpsv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
-        }
-        CblasRowMajor => {
-            // Row-major: invert uplo and trans
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match trans {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                CblasConjTrans => CblasNoTrans, // For real types, ConjTrans = Trans
-            };
-            let uplo_char = uplo_to_char(new_uplo);
-            let trans_char = transpose_to_char(new_trans);
-            let diag_char = diag_to_char(diag);
-            stpsv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
-        }
+    if check_tpmv("cblas_stpsv", n, incx) {
+        return;
     }
+
+    tpsv(order, uplo, trans, diag, n, ap, x, incx);
 }
 
 /// Double precision triangular packed solve.
@@ -290,32 +490,11 @@ pub unsafe extern "C" fn cblas_dtpsv(
     x: *mut f64,
     incx: blasint,
 ) {
-    let dtpsv = get_dtpsv();
-
-    match order {
-        CblasColMajor => {
-            let uplo_char = uplo_to_char(uplo);
-            let trans_char = transpose_to_char(trans);
-            let diag_char = diag_to_char(diag);
-            dtpsv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
-        }
-        CblasRowMajor => {
-            // Row-major: invert uplo and trans
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match trans {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                CblasConjTrans => CblasNoTrans, // For real types, ConjTrans = Trans
-            };
-            let uplo_char = uplo_to_char(new_uplo);
-            let trans_char = transpose_to_char(new_trans);
-            let diag_char = diag_to_char(diag);
-            dtpsv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
-        }
+    if check_tpmv("cblas_dtpsv", n, incx) {
+        return;
     }
+
+    tpsv(order, uplo, trans, diag, n, ap, x, incx);
 }
 
 /// Single precision complex triangular packed solve.
@@ -338,33 +517,20 @@ pub unsafe extern "C" fn cblas_ctpsv(
     x: *mut Complex32,
     incx: blasint,
 ) {
-    let ctpsv = get_ctpsv();
+    if check_tpmv("cblas_ctpsv", n, incx) {
+        return;
+    }
 
-    match order {
-        CblasColMajor => {
-            let uplo_char = uplo_to_char(uplo);
-            let trans_char = transpose_to_char(trans);
-            let diag_char = diag_to_char(diag);
-            ctpsv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
-        }
-        CblasRowMajor => {
-            // Row-major: invert uplo and trans
-            // For complex: ConjTrans stays ConjTrans
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match trans {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                CblasConjTrans => CblasConjTrans,
-            };
-            let uplo_char = uplo_to_char(new_uplo);
-            let trans_char = transpose_to_char(new_trans);
-            let diag_char = diag_to_char(diag);
-            ctpsv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
-        }
+    if trans == CblasConjNoTrans {
+        // op(A) = conj(A): conjugate x, solve with the unconjugated A via plain
+        // NoTrans, then conjugate the result back. See `crate::conj`.
+        conjugate_vector_inplace(n, x, incx);
+        cblas_ctpsv(order, uplo, CblasNoTrans, diag, n, ap, x, incx);
+        conjugate_vector_inplace(n, x, incx);
+        return;
     }
+
+    tpsv(order, uplo, trans, diag, n, ap, x, incx);
 }
 
 /// Double precision complex triangular packed solve.
@@ -387,31 +553,352 @@ pub unsafe extern "C" fn cblas_ztpsv(
     x: *mut Complex64,
     incx: blasint,
 ) {
-    let ztpsv = get_ztpsv();
+    if check_tpmv("cblas_ztpsv", n, incx) {
+        return;
+    }
 
-    match order {
-        CblasColMajor => {
-            let uplo_char = uplo_to_char(uplo);
-            let trans_char = transpose_to_char(trans);
-            let diag_char = diag_to_char(diag);
-            ztpsv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
+    if trans == CblasConjNoTrans {
+        // op(A) = conj(A): conjugate x, solve with the unconjugated A via plain
+        // NoTrans, then conjugate the result back. See `crate::conj`.
+        conjugate_vector_inplace(n, x, incx);
+        cblas_ztpsv(order, uplo, CblasNoTrans, diag, n, ap, x, incx);
+        conjugate_vector_inplace(n, x, incx);
+        return;
+    }
+
+    tpsv(order, uplo, trans, diag, n, ap, x, incx);
+}
+
+// =============================================================================
+// TPSV iterative refinement
+// =============================================================================
+
+/// Single precision triangular packed solve with iterative refinement.
+///
+/// Solves op(A) * x = b where A is triangular in packed format, refining the plain
+/// TPSV solution against residuals accumulated in `f64` to recover digits lost to
+/// rounding when `A` is nearly singular. Unlike `cblas_stpsv`, `b` is left intact and
+/// the solution is written to `x`.
+///
+/// Iterates at most `max_iter` times, stopping early once `||delta|| / ||x||` (or
+/// `||delta||` when `x` is exactly zero) drops below `tol`. Returns the number of
+/// refinement iterations actually taken, so a caller can detect non-convergence.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - The packed array `ap` must contain n*(n+1)/2 elements
+/// - `b` and `x` must not overlap
+/// - stpsv, stpmv, dtpsv and dtpmv must be registered
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn cblas_stpsv_refine(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blasint,
+    ap: *const f32,
+    b: *const f32,
+    x: *mut f32,
+    incx: blasint,
+    max_iter: blasint,
+    tol: f32,
+) -> blasint {
+    if check_tpsv_refine("cblas_stpsv_refine", n, incx) {
+        return 0;
+    }
+
+    let n = n as usize;
+    let stride = incx as isize;
+
+    for i in 0..n {
+        *x.offset(i as isize * stride) = *b.offset(i as isize * stride);
+    }
+    cblas_stpsv(order, uplo, trans, diag, n as blasint, ap, x, incx);
+
+    // Promote `ap` and the residual accumulation to f64 so the extra digits
+    // refinement is chasing aren't immediately lost back to f32 rounding.
+    let ap_len = n * (n + 1) / 2;
+    let ap64: Vec<f64> = (0..ap_len).map(|i| *ap.add(i) as f64).collect();
+    let b64: Vec<f64> = (0..n).map(|i| *b.offset(i as isize * stride) as f64).collect();
+
+    let mut iterations = 0;
+    for _ in 0..max_iter.max(0) {
+        let mut ax64: Vec<f64> = (0..n).map(|i| *x.offset(i as isize * stride) as f64).collect();
+        cblas_dtpmv(order, uplo, trans, diag, n as blasint, ap64.as_ptr(), ax64.as_mut_ptr(), 1);
+
+        let mut x_norm = 0.0f64;
+        let mut delta32 = vec![0.0f32; n];
+        for i in 0..n {
+            delta32[i] = (b64[i] - ax64[i]) as f32;
+            x_norm += (*x.offset(i as isize * stride) as f64).powi(2);
         }
-        CblasRowMajor => {
-            // Row-major: invert uplo and trans
-            // For complex: ConjTrans stays ConjTrans
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match trans {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                CblasConjTrans => CblasConjTrans,
-            };
-            let uplo_char = uplo_to_char(new_uplo);
-            let trans_char = transpose_to_char(new_trans);
-            let diag_char = diag_to_char(diag);
-            ztpsv(&uplo_char, &trans_char, &diag_char, &n, ap, x, &incx);
+        x_norm = x_norm.sqrt();
+
+        cblas_stpsv(order, uplo, trans, diag, n as blasint, ap, delta32.as_mut_ptr(), 1);
+
+        let mut delta_norm = 0.0f64;
+        for i in 0..n {
+            delta_norm += (delta32[i] as f64).powi(2);
+            *x.offset(i as isize * stride) += delta32[i];
+        }
+        delta_norm = delta_norm.sqrt();
+        iterations += 1;
+
+        let converged = if x_norm > 0.0 {
+            delta_norm / x_norm < tol as f64
+        } else {
+            delta_norm < tol as f64
+        };
+        if converged {
+            break;
         }
     }
+
+    iterations
+}
+
+/// Double precision triangular packed solve with iterative refinement.
+///
+/// See [`cblas_stpsv_refine`]; `f64` has no wider precision available in this crate,
+/// so the residual is accumulated natively.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - The packed array `ap` must contain n*(n+1)/2 elements
+/// - `b` and `x` must not overlap
+/// - dtpsv and dtpmv must be registered
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn cblas_dtpsv_refine(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blasint,
+    ap: *const f64,
+    b: *const f64,
+    x: *mut f64,
+    incx: blasint,
+    max_iter: blasint,
+    tol: f64,
+) -> blasint {
+    if check_tpsv_refine("cblas_dtpsv_refine", n, incx) {
+        return 0;
+    }
+
+    let n_usize = n as usize;
+    let stride = incx as isize;
+
+    for i in 0..n_usize {
+        *x.offset(i as isize * stride) = *b.offset(i as isize * stride);
+    }
+    cblas_dtpsv(order, uplo, trans, diag, n, ap, x, incx);
+
+    let mut iterations = 0;
+    for _ in 0..max_iter.max(0) {
+        let mut ax = vec![0.0f64; n_usize];
+        for i in 0..n_usize {
+            ax[i] = *x.offset(i as isize * stride);
+        }
+        cblas_dtpmv(order, uplo, trans, diag, n, ap, ax.as_mut_ptr(), 1);
+
+        let mut x_norm = 0.0f64;
+        let mut delta = vec![0.0f64; n_usize];
+        for i in 0..n_usize {
+            delta[i] = *b.offset(i as isize * stride) - ax[i];
+            x_norm += (*x.offset(i as isize * stride)).powi(2);
+        }
+        x_norm = x_norm.sqrt();
+
+        cblas_dtpsv(order, uplo, trans, diag, n, ap, delta.as_mut_ptr(), 1);
+
+        let mut delta_norm = 0.0f64;
+        for i in 0..n_usize {
+            delta_norm += delta[i].powi(2);
+            *x.offset(i as isize * stride) += delta[i];
+        }
+        delta_norm = delta_norm.sqrt();
+        iterations += 1;
+
+        let converged = if x_norm > 0.0 {
+            delta_norm / x_norm < tol
+        } else {
+            delta_norm < tol
+        };
+        if converged {
+            break;
+        }
+    }
+
+    iterations
+}
+
+/// Single precision complex triangular packed solve with iterative refinement.
+///
+/// See [`cblas_stpsv_refine`]; the residual is accumulated in `Complex64`.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - The packed array `ap` must contain n*(n+1)/2 elements
+/// - `b` and `x` must not overlap
+/// - ctpsv, ctpmv, ztpsv and ztpmv must be registered
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn cblas_ctpsv_refine(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blasint,
+    ap: *const Complex32,
+    b: *const Complex32,
+    x: *mut Complex32,
+    incx: blasint,
+    max_iter: blasint,
+    tol: f32,
+) -> blasint {
+    if check_tpsv_refine("cblas_ctpsv_refine", n, incx) {
+        return 0;
+    }
+
+    let n = n as usize;
+    let stride = incx as isize;
+
+    for i in 0..n {
+        *x.offset(i as isize * stride) = *b.offset(i as isize * stride);
+    }
+    cblas_ctpsv(order, uplo, trans, diag, n as blasint, ap, x, incx);
+
+    let ap_len = n * (n + 1) / 2;
+    let ap64: Vec<Complex64> = (0..ap_len)
+        .map(|i| Complex64::new((*ap.add(i)).re as f64, (*ap.add(i)).im as f64))
+        .collect();
+    let b64: Vec<Complex64> = (0..n)
+        .map(|i| {
+            let v = *b.offset(i as isize * stride);
+            Complex64::new(v.re as f64, v.im as f64)
+        })
+        .collect();
+
+    let mut iterations = 0;
+    for _ in 0..max_iter.max(0) {
+        let mut ax64: Vec<Complex64> = (0..n)
+            .map(|i| {
+                let v = *x.offset(i as isize * stride);
+                Complex64::new(v.re as f64, v.im as f64)
+            })
+            .collect();
+        cblas_ztpmv(order, uplo, trans, diag, n as blasint, ap64.as_ptr(), ax64.as_mut_ptr(), 1);
+
+        let mut x_norm = 0.0f64;
+        let mut delta32 = vec![Complex32::new(0.0, 0.0); n];
+        for i in 0..n {
+            let r = b64[i] - ax64[i];
+            delta32[i] = Complex32::new(r.re as f32, r.im as f32);
+            x_norm += (*x.offset(i as isize * stride)).norm_sqr() as f64;
+        }
+        x_norm = x_norm.sqrt();
+
+        cblas_ctpsv(order, uplo, trans, diag, n as blasint, ap, delta32.as_mut_ptr(), 1);
+
+        let mut delta_norm = 0.0f64;
+        for i in 0..n {
+            delta_norm += delta32[i].norm_sqr() as f64;
+            *x.offset(i as isize * stride) += delta32[i];
+        }
+        delta_norm = delta_norm.sqrt();
+        iterations += 1;
+
+        let converged = if x_norm > 0.0 {
+            delta_norm / x_norm < tol as f64
+        } else {
+            delta_norm < tol as f64
+        };
+        if converged {
+            break;
+        }
+    }
+
+    iterations
+}
+
+/// Double precision complex triangular packed solve with iterative refinement.
+///
+/// See [`cblas_stpsv_refine`]; `Complex64` has no wider precision available in this
+/// crate, so the residual is accumulated natively.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - The packed array `ap` must contain n*(n+1)/2 elements
+/// - `b` and `x` must not overlap
+/// - ztpsv and ztpmv must be registered
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn cblas_ztpsv_refine(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blasint,
+    ap: *const Complex64,
+    b: *const Complex64,
+    x: *mut Complex64,
+    incx: blasint,
+    max_iter: blasint,
+    tol: f64,
+) -> blasint {
+    if check_tpsv_refine("cblas_ztpsv_refine", n, incx) {
+        return 0;
+    }
+
+    let n_usize = n as usize;
+    let stride = incx as isize;
+
+    for i in 0..n_usize {
+        *x.offset(i as isize * stride) = *b.offset(i as isize * stride);
+    }
+    cblas_ztpsv(order, uplo, trans, diag, n, ap, x, incx);
+
+    let mut iterations = 0;
+    for _ in 0..max_iter.max(0) {
+        let mut ax = vec![Complex64::new(0.0, 0.0); n_usize];
+        for i in 0..n_usize {
+            ax[i] = *x.offset(i as isize * stride);
+        }
+        cblas_ztpmv(order, uplo, trans, diag, n, ap, ax.as_mut_ptr(), 1);
+
+        let mut x_norm = 0.0f64;
+        let mut delta = vec![Complex64::new(0.0, 0.0); n_usize];
+        for i in 0..n_usize {
+            delta[i] = *b.offset(i as isize * stride) - ax[i];
+            x_norm += (*x.offset(i as isize * stride)).norm_sqr();
+        }
+        x_norm = x_norm.sqrt();
+
+        cblas_ztpsv(order, uplo, trans, diag, n, ap, delta.as_mut_ptr(), 1);
+
+        let mut delta_norm = 0.0f64;
+        for i in 0..n_usize {
+            delta_norm += delta[i].norm_sqr();
+            *x.offset(i as isize * stride) += delta[i];
+        }
+        delta_norm = delta_norm.sqrt();
+        iterations += 1;
+
+        let converged = if x_norm > 0.0 {
+            delta_norm / x_norm < tol
+        } else {
+            delta_norm < tol
+        };
+        if converged {
+            break;
+        }
+    }
+
+    iterations
 }
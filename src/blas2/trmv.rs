@@ -6,14 +6,28 @@
 //! Row-major conversion logic derived from OpenBLAS.
 //! Copyright (c) 2011-2014, The OpenBLAS Project. BSD-3-Clause License.
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/trmv.c>
+//!
+//! `CblasConjNoTrans` (`op(A) = conj(A)`) has no native Fortran character. Unlike a
+//! solve, TRMV is a plain multiply, but the same conjugate-the-vector trick still
+//! applies: conjugating both sides of `x_new = conj(A) * x_old` gives
+//! `conj(x_new) = A * conj(x_old)`, so we conjugate `x`, multiply with plain `NoTrans`,
+//! and conjugate the result back (mirroring `cblas_ctbsv`/`cblas_ztbsv`).
+//!
+//! Row-major `CblasConjTrans` needs its own care: the uplo flip already reinterprets the
+//! stored triangle as its own transpose, so `op(A) = conj(A)^T` becomes `conj(view)` on
+//! that reinterpretation — conjugate-without-transpose, which again has no native
+//! character. `cblas_ctrmv`/`cblas_ztrmv` realize it by conjugating `A` into a scratch
+//! buffer and calling the backend with plain `NoTrans`, the same approach
+//! `cblas_cgemm`/`cblas_zgemm` use for `CblasConjNoTrans`.
 
 use num_complex::{Complex32, Complex64};
 
 use crate::backend::{get_ctrmv, get_dtrmv, get_strmv, get_ztrmv};
+use crate::conj::{conjugate_matrix_copy, conjugate_vector_inplace};
 use crate::types::{
-    blasint, diag_to_char, normalize_transpose_real, transpose_to_char, uplo_to_char,
-    CblasColMajor, CblasConjNoTrans, CblasConjTrans, CblasLower, CblasNoTrans, CblasRowMajor,
-    CblasTrans, CblasUpper, CBLAS_DIAG, CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
+    blasint, diag_to_char, flip_transpose_real, flip_uplo, normalize_transpose_real,
+    transpose_to_char, uplo_to_char, CblasColMajor, CblasConjNoTrans, CblasConjTrans,
+    CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_DIAG, CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
 };
 
 /// Single precision triangular matrix-vector multiply.
@@ -49,15 +63,8 @@ pub unsafe extern "C" fn cblas_strmv(
         CblasRowMajor => {
             // Row-major: invert uplo and trans
             // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/trmv.c
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match normalize_transpose_real(trans) {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                _ => unreachable!(),
-            };
+            let new_uplo = flip_uplo(uplo);
+            let new_trans = flip_transpose_real(normalize_transpose_real(trans));
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
             let diag_char = diag_to_char(diag);
@@ -98,15 +105,8 @@ pub unsafe extern "C" fn cblas_dtrmv(
         }
         CblasRowMajor => {
             // Row-major: invert uplo and trans
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match normalize_transpose_real(trans) {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                _ => unreachable!(),
-            };
+            let new_uplo = flip_uplo(uplo);
+            let new_trans = flip_transpose_real(normalize_transpose_real(trans));
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
             let diag_char = diag_to_char(diag);
@@ -136,6 +136,15 @@ pub unsafe extern "C" fn cblas_ctrmv(
     x: *mut Complex32,
     incx: blasint,
 ) {
+    if trans == CblasConjNoTrans {
+        // conj(x_new) = A * conj(x_old): conjugate x, multiply with plain NoTrans, then
+        // conjugate the result back.
+        conjugate_vector_inplace(n, x, incx);
+        cblas_ctrmv(order, uplo, CblasNoTrans, diag, n, a, lda, x, incx);
+        conjugate_vector_inplace(n, x, incx);
+        return;
+    }
+
     let ctrmv = get_ctrmv();
 
     match order {
@@ -146,17 +155,34 @@ pub unsafe extern "C" fn cblas_ctrmv(
             ctrmv(&uplo_char, &trans_char, &diag_char, &n, a, &lda, x, &incx);
         }
         CblasRowMajor => {
+            if trans == CblasConjTrans {
+                // op(A) = conj(A)^T: the uplo flip already reinterprets the stored
+                // triangle as its own transpose, so what remains is conjugating that
+                // data and calling with plain NoTrans (see the module doc).
+                let new_uplo = flip_uplo(uplo);
+                let a_conj = conjugate_matrix_copy(CblasColMajor, n, n, a, lda);
+                let uplo_char = uplo_to_char(new_uplo);
+                let trans_char = transpose_to_char(CblasNoTrans);
+                let diag_char = diag_to_char(diag);
+                ctrmv(
+                    &uplo_char,
+                    &trans_char,
+                    &diag_char,
+                    &n,
+                    a_conj.as_ptr(),
+                    &lda,
+                    x,
+                    &incx,
+                );
+                return;
+            }
             // Row-major: invert uplo and trans
-            // For complex: ConjTrans stays ConjTrans (conjugate is preserved)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
-                CblasConjNoTrans => CblasConjTrans,
-                CblasConjTrans => CblasConjNoTrans,
+                CblasConjTrans => unreachable!("handled above"),
+                CblasConjNoTrans => unreachable!("handled above"),
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -187,6 +213,15 @@ pub unsafe extern "C" fn cblas_ztrmv(
     x: *mut Complex64,
     incx: blasint,
 ) {
+    if trans == CblasConjNoTrans {
+        // conj(x_new) = A * conj(x_old): conjugate x, multiply with plain NoTrans, then
+        // conjugate the result back.
+        conjugate_vector_inplace(n, x, incx);
+        cblas_ztrmv(order, uplo, CblasNoTrans, diag, n, a, lda, x, incx);
+        conjugate_vector_inplace(n, x, incx);
+        return;
+    }
+
     let ztrmv = get_ztrmv();
 
     match order {
@@ -197,17 +232,34 @@ pub unsafe extern "C" fn cblas_ztrmv(
             ztrmv(&uplo_char, &trans_char, &diag_char, &n, a, &lda, x, &incx);
         }
         CblasRowMajor => {
+            if trans == CblasConjTrans {
+                // op(A) = conj(A)^T: the uplo flip already reinterprets the stored
+                // triangle as its own transpose, so what remains is conjugating that
+                // data and calling with plain NoTrans (see the module doc).
+                let new_uplo = flip_uplo(uplo);
+                let a_conj = conjugate_matrix_copy(CblasColMajor, n, n, a, lda);
+                let uplo_char = uplo_to_char(new_uplo);
+                let trans_char = transpose_to_char(CblasNoTrans);
+                let diag_char = diag_to_char(diag);
+                ztrmv(
+                    &uplo_char,
+                    &trans_char,
+                    &diag_char,
+                    &n,
+                    a_conj.as_ptr(),
+                    &lda,
+                    x,
+                    &incx,
+                );
+                return;
+            }
             // Row-major: invert uplo and trans
-            // For complex: flip transpose with conjugation preserved (OpenBLAS)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
-                CblasConjNoTrans => CblasConjTrans,
-                CblasConjTrans => CblasConjNoTrans,
+                CblasConjTrans => unreachable!("handled above"),
+                CblasConjNoTrans => unreachable!("handled above"),
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -216,3 +268,49 @@ pub unsafe extern "C" fn cblas_ztrmv(
         }
     }
 }
+
+/// Safe, slice-based triangular matrix-vector multiply, for callers who'd rather check
+/// a `Result` than hold up the `unsafe` contract [`cblas_dtrmv`] requires of its raw
+/// pointers.
+///
+/// Validates that `a` and `x` are long enough for the given dimension, leading
+/// dimension, and increment, and that dtrmv has been registered, before dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_dtrmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blasint,
+    a: &[f64],
+    lda: blasint,
+    x: &mut [f64],
+    incx: blasint,
+) -> Result<(), String> {
+    if !crate::backend::has_dtrmv() {
+        return Err("dtrmv backend not registered: call register_dtrmv first".to_string());
+    }
+    if n < 0 {
+        return Err(format!("dtrmv: n ({n}) must be non-negative"));
+    }
+
+    let a_len_needed = (lda.max(1) as usize) * (n.max(1) as usize);
+    if a.len() < a_len_needed {
+        return Err(format!(
+            "dtrmv: `a` has {} elements, but lda={lda} and n={n} need at least {a_len_needed}",
+            a.len()
+        ));
+    }
+    let x_len_needed = 1 + (n.max(1) as usize - 1) * (incx.unsigned_abs() as usize);
+    if n > 0 && x.len() < x_len_needed {
+        return Err(format!(
+            "dtrmv: `x` has {} elements, but n={n} and incx={incx} need at least {x_len_needed}",
+            x.len()
+        ));
+    }
+
+    unsafe {
+        cblas_dtrmv(order, uplo, trans, diag, n, a.as_ptr(), lda, x.as_mut_ptr(), incx);
+    }
+    Ok(())
+}
@@ -7,15 +7,48 @@
 //! Row-major conversion logic derived from OpenBLAS.
 //! Copyright (c) 2011-2014, The OpenBLAS Project. BSD-3-Clause License.
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/trsv.c>
+//!
+//! `cblas_strsv`/`cblas_dtrsv` delegate their row-major conversion to
+//! [`crate::layout::trsv_convert`]. The complex routines below keep their own
+//! inline handling since they also need the `Conj*` special-casing described next.
+//!
+//! `CblasConjNoTrans` (`op(A) = conj(A)`) has no native Fortran character, so it's
+//! realized the same way `cblas_ctbsv`/`cblas_ztbsv` realize it: solving `conj(A)*x = b`
+//! is the same as solving `A*conj(x) = conj(b)` for `conj(x)`, so we conjugate `x` in
+//! place, solve with plain `NoTrans`, and conjugate the result back.
+//!
+//! Row-major `CblasConjTrans` needs its own care: the uplo flip already reinterprets the
+//! stored triangle as its own transpose, so `op(A) = conj(A)^T` becomes `conj(view)` on
+//! that reinterpretation — conjugate-without-transpose, which again has no native
+//! character. `cblas_ctrsv`/`cblas_ztrsv` realize it by conjugating `A` into a scratch
+//! buffer and calling the backend with plain `NoTrans`, the same approach
+//! `cblas_cgemm`/`cblas_zgemm` use for `CblasConjNoTrans`.
 
 use num_complex::{Complex32, Complex64};
 
 use crate::backend::{get_ctrsv, get_dtrsv, get_strsv, get_ztrsv};
+use crate::conj::{conjugate_matrix_copy, conjugate_vector_inplace};
+use crate::layout::trsv_convert;
 use crate::types::{
-    blasint, diag_to_char, normalize_transpose_real, transpose_to_char, uplo_to_char,
-    CblasColMajor, CblasConjNoTrans, CblasConjTrans, CblasLower, CblasNoTrans, CblasRowMajor,
-    CblasTrans, CblasUpper, CBLAS_DIAG, CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
+    blasint, diag_to_char, flip_uplo, transpose_to_char, uplo_to_char, CblasColMajor,
+    CblasConjNoTrans, CblasConjTrans, CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_DIAG,
+    CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
 };
+use crate::validation::validate_layout;
+
+/// Validates CBLAS argument positions 5 (n), 7 (lda), and 9 (incx) for `cblas_?trsv`:
+/// `n >= 0`, `lda >= max(1,n)` (A is n x n regardless of layout), and `incx != 0`.
+/// Positions are logical (`CblasColMajor`); see [`validate_layout`] for the
+/// `CblasRowMajor` renumbering.
+unsafe fn check_trsv(
+    routine: &str,
+    order: CBLAS_ORDER,
+    n: blasint,
+    lda: blasint,
+    incx: blasint,
+) -> bool {
+    validate_layout(routine, order, &[(n >= 0, 5), (lda >= n.max(1), 7), (incx != 0, 9)])
+}
 
 /// Single precision triangular solve.
 ///
@@ -38,33 +71,16 @@ pub unsafe extern "C" fn cblas_strsv(
     x: *mut f32,
     incx: blasint,
 ) {
+    if check_trsv("cblas_strsv", order, n, lda, incx) {
+        return;
+    }
     let strsv = get_strsv();
 
-    match order {
-        CblasColMajor => {
-            let uplo_char = uplo_to_char(uplo);
-            let trans_char = transpose_to_char(normalize_transpose_real(trans));
-            let diag_char = diag_to_char(diag);
-            strsv(&uplo_char, &trans_char, &diag_char, &n, a, &lda, x, &incx);
-        }
-        CblasRowMajor => {
-            // Row-major: invert uplo and trans
-            // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/trsv.c
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match normalize_transpose_real(trans) {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                _ => unreachable!(),
-            };
-            let uplo_char = uplo_to_char(new_uplo);
-            let trans_char = transpose_to_char(new_trans);
-            let diag_char = diag_to_char(diag);
-            strsv(&uplo_char, &trans_char, &diag_char, &n, a, &lda, x, &incx);
-        }
-    }
+    let (uplo, trans, n) = trsv_convert(order, uplo, trans, n);
+    let uplo_char = uplo_to_char(uplo);
+    let trans_char = transpose_to_char(trans);
+    let diag_char = diag_to_char(diag);
+    strsv(&uplo_char, &trans_char, &diag_char, &n, a, &lda, x, &incx);
 }
 
 /// Double precision triangular solve.
@@ -88,32 +104,16 @@ pub unsafe extern "C" fn cblas_dtrsv(
     x: *mut f64,
     incx: blasint,
 ) {
+    if check_trsv("cblas_dtrsv", order, n, lda, incx) {
+        return;
+    }
     let dtrsv = get_dtrsv();
 
-    match order {
-        CblasColMajor => {
-            let uplo_char = uplo_to_char(uplo);
-            let trans_char = transpose_to_char(normalize_transpose_real(trans));
-            let diag_char = diag_to_char(diag);
-            dtrsv(&uplo_char, &trans_char, &diag_char, &n, a, &lda, x, &incx);
-        }
-        CblasRowMajor => {
-            // Row-major: invert uplo and trans
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let new_trans = match normalize_transpose_real(trans) {
-                CblasNoTrans => CblasTrans,
-                CblasTrans => CblasNoTrans,
-                _ => unreachable!(),
-            };
-            let uplo_char = uplo_to_char(new_uplo);
-            let trans_char = transpose_to_char(new_trans);
-            let diag_char = diag_to_char(diag);
-            dtrsv(&uplo_char, &trans_char, &diag_char, &n, a, &lda, x, &incx);
-        }
-    }
+    let (uplo, trans, n) = trsv_convert(order, uplo, trans, n);
+    let uplo_char = uplo_to_char(uplo);
+    let trans_char = transpose_to_char(trans);
+    let diag_char = diag_to_char(diag);
+    dtrsv(&uplo_char, &trans_char, &diag_char, &n, a, &lda, x, &incx);
 }
 
 /// Single precision complex triangular solve.
@@ -137,6 +137,18 @@ pub unsafe extern "C" fn cblas_ctrsv(
     x: *mut Complex32,
     incx: blasint,
 ) {
+    if check_trsv("cblas_ctrsv", order, n, lda, incx) {
+        return;
+    }
+    if trans == CblasConjNoTrans {
+        // Solving conj(A)*x = b is the same as solving A*conj(x) = conj(b): conjugate x,
+        // solve with plain NoTrans, then conjugate the result back.
+        conjugate_vector_inplace(n, x, incx);
+        cblas_ctrsv(order, uplo, CblasNoTrans, diag, n, a, lda, x, incx);
+        conjugate_vector_inplace(n, x, incx);
+        return;
+    }
+
     let ctrsv = get_ctrsv();
 
     match order {
@@ -147,17 +159,34 @@ pub unsafe extern "C" fn cblas_ctrsv(
             ctrsv(&uplo_char, &trans_char, &diag_char, &n, a, &lda, x, &incx);
         }
         CblasRowMajor => {
+            if trans == CblasConjTrans {
+                // op(A) = conj(A)^T: the uplo flip already reinterprets the stored
+                // triangle as its own transpose, so what remains is conjugating that
+                // data and calling with plain NoTrans (see the module doc).
+                let new_uplo = flip_uplo(uplo);
+                let a_conj = conjugate_matrix_copy(CblasColMajor, n, n, a, lda);
+                let uplo_char = uplo_to_char(new_uplo);
+                let trans_char = transpose_to_char(CblasNoTrans);
+                let diag_char = diag_to_char(diag);
+                ctrsv(
+                    &uplo_char,
+                    &trans_char,
+                    &diag_char,
+                    &n,
+                    a_conj.as_ptr(),
+                    &lda,
+                    x,
+                    &incx,
+                );
+                return;
+            }
             // Row-major: invert uplo and trans
-            // For complex: ConjTrans stays ConjTrans (conjugate is preserved)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
-                CblasConjNoTrans => CblasConjTrans,
-                CblasConjTrans => CblasConjNoTrans,
+                CblasConjTrans => unreachable!("handled above"),
+                CblasConjNoTrans => unreachable!("handled above"),
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -188,6 +217,18 @@ pub unsafe extern "C" fn cblas_ztrsv(
     x: *mut Complex64,
     incx: blasint,
 ) {
+    if check_trsv("cblas_ztrsv", order, n, lda, incx) {
+        return;
+    }
+    if trans == CblasConjNoTrans {
+        // Solving conj(A)*x = b is the same as solving A*conj(x) = conj(b): conjugate x,
+        // solve with plain NoTrans, then conjugate the result back.
+        conjugate_vector_inplace(n, x, incx);
+        cblas_ztrsv(order, uplo, CblasNoTrans, diag, n, a, lda, x, incx);
+        conjugate_vector_inplace(n, x, incx);
+        return;
+    }
+
     let ztrsv = get_ztrsv();
 
     match order {
@@ -198,17 +239,34 @@ pub unsafe extern "C" fn cblas_ztrsv(
             ztrsv(&uplo_char, &trans_char, &diag_char, &n, a, &lda, x, &incx);
         }
         CblasRowMajor => {
+            if trans == CblasConjTrans {
+                // op(A) = conj(A)^T: the uplo flip already reinterprets the stored
+                // triangle as its own transpose, so what remains is conjugating that
+                // data and calling with plain NoTrans (see the module doc).
+                let new_uplo = flip_uplo(uplo);
+                let a_conj = conjugate_matrix_copy(CblasColMajor, n, n, a, lda);
+                let uplo_char = uplo_to_char(new_uplo);
+                let trans_char = transpose_to_char(CblasNoTrans);
+                let diag_char = diag_to_char(diag);
+                ztrsv(
+                    &uplo_char,
+                    &trans_char,
+                    &diag_char,
+                    &n,
+                    a_conj.as_ptr(),
+                    &lda,
+                    x,
+                    &incx,
+                );
+                return;
+            }
             // Row-major: invert uplo and trans
-            // For complex: ConjTrans stays ConjTrans (conjugate is preserved)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
-                CblasConjNoTrans => CblasConjTrans,
-                CblasConjTrans => CblasConjNoTrans,
+                CblasConjTrans => unreachable!("handled above"),
+                CblasConjNoTrans => unreachable!("handled above"),
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
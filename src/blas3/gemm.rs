@@ -7,14 +7,80 @@
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/gemm.c>
 //!
 //! For row-major layout, we swap A↔B, m↔n, lda↔ldb, TransA↔TransB.
-//! The transpose flags are NOT inverted, just swapped.
+//! The transpose flags are NOT inverted, just swapped. This swap-without-inversion
+//! is valid for `NoTrans`/`Trans`/`ConjTrans` since it's a pure memory-layout identity
+//! that conjugation commutes with, but `CblasConjNoTrans` has no Fortran character
+//! code at all, so `cblas_cgemm`/`cblas_zgemm` realize it by conjugating the affected
+//! operand into a scratch buffer before the row/col-major dispatch below.
+//!
+//! Large calls (`m*n*k` at or above `crate::set_gpu_offload_threshold`'s threshold) are
+//! forwarded to a registered device backend instead of the CPU Fortran one; see
+//! `crate::backend::dispatch_sgemm` and its siblings.
+//!
+//! When `crate::enable_call_tracing` is on, each call records its shape and an
+//! estimated `2*m*n*k` FLOP count; see `crate::trace`. Separately, every call always
+//! bumps its routine's entry in `crate::call_counts` and, if one is registered,
+//! invokes `crate::set_call_hook`'s hook; see `crate::counters`.
 
 use num_complex::{Complex32, Complex64};
 
-use crate::backend::{get_cgemm, get_dgemm, get_sgemm, get_zgemm};
+use crate::backend::{dispatch_cgemm, dispatch_dgemm, dispatch_sgemm, dispatch_zgemm};
+use crate::conj::conjugate_matrix_copy;
+use crate::counters::{count_call, CallInfo};
+use crate::trace::{trace_call, CallShape};
 use crate::types::{
-    blasint, transpose_to_char, CblasColMajor, CblasRowMajor, CBLAS_ORDER, CBLAS_TRANSPOSE,
+    blasint, transpose_to_char, CblasColMajor, CblasConjNoTrans, CblasNoTrans, CblasRowMajor,
+    CBLAS_ORDER, CBLAS_TRANSPOSE,
 };
+use crate::validation::validate;
+
+/// Validates CBLAS argument positions 4 (m), 5 (n), 6 (k), 9 (lda), 11 (ldb), and 14
+/// (ldc) for `cblas_?gemm`: `m,n,k >= 0`, and `lda`/`ldb`/`ldc` large enough to hold
+/// `A`/`B`/`C` in the layout `order` and transpose `transa`/`transb` imply (`op(A)` is
+/// `m x k` so untransposed `A` is stored `m x k` and transposed `k x m`, and
+/// symmetrically for `B`'s `k x n`/`n x k`; `ConjNoTrans`/`ConjTrans` share the same
+/// dimensions as `NoTrans`/`Trans` since conjugation doesn't transpose). Positions are
+/// fixed regardless of `order`, matching [`crate::blas2::gemv::check_gemv`]'s reasoning:
+/// the row-major leading-dimension minimums already account for the layout directly.
+#[allow(clippy::too_many_arguments)]
+unsafe fn check_gemm(
+    routine: &str,
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    transb: CBLAS_TRANSPOSE,
+    m: blasint,
+    n: blasint,
+    k: blasint,
+    lda: blasint,
+    ldb: blasint,
+    ldc: blasint,
+) -> bool {
+    let a_is_notrans = transa == CblasNoTrans || transa == CblasConjNoTrans;
+    let b_is_notrans = transb == CblasNoTrans || transb == CblasConjNoTrans;
+    let lda_min = match order {
+        CblasColMajor => if a_is_notrans { m } else { k }.max(1),
+        CblasRowMajor => if a_is_notrans { k } else { m }.max(1),
+    };
+    let ldb_min = match order {
+        CblasColMajor => if b_is_notrans { k } else { n }.max(1),
+        CblasRowMajor => if b_is_notrans { n } else { k }.max(1),
+    };
+    let ldc_min = match order {
+        CblasColMajor => m.max(1),
+        CblasRowMajor => n.max(1),
+    };
+    validate(
+        routine,
+        &[
+            (m >= 0, 4),
+            (n >= 0, 5),
+            (k >= 0, 6),
+            (lda >= lda_min, 9),
+            (ldb >= ldb_min, 11),
+            (ldc >= ldc_min, 14),
+        ],
+    )
+}
 
 /// Double precision general matrix multiply.
 ///
@@ -42,51 +108,66 @@ pub unsafe fn cblas_dgemm(
     c: *mut f64,
     ldc: blasint,
 ) {
-    let dgemm = get_dgemm();
+    if check_gemm("cblas_dgemm", order, transa, transb, m, n, k, lda, ldb, ldc) {
+        return;
+    }
+    let dgemm = dispatch_dgemm(m, n, k);
 
-    match order {
-        CblasColMajor => {
-            // Column-major: call Fortran directly
-            let transa_char = transpose_to_char(transa);
-            let transb_char = transpose_to_char(transb);
-            dgemm(
-                &transa_char,
-                &transb_char,
-                &m,
-                &n,
-                &k,
-                &alpha,
-                a,
-                &lda,
-                b,
-                &ldb,
-                &beta,
-                c,
-                &ldc,
-            );
-        }
-        CblasRowMajor => {
-            // Row-major: swap A↔B, m↔n, lda↔ldb, TransA↔TransB
-            // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/gemm.c#L489-L537
-            let transa_char = transpose_to_char(transb); // TransB becomes transa
-            let transb_char = transpose_to_char(transa); // TransA becomes transb
-            dgemm(
-                &transa_char,
-                &transb_char,
-                &n, // swapped: m -> n
-                &m, // swapped: n -> m
-                &k,
-                &alpha,
-                b,    // swapped: a -> b
-                &ldb, // swapped: lda -> ldb
-                a,    // swapped: b -> a
-                &lda, // swapped: ldb -> lda
-                &beta,
-                c,
-                &ldc,
-            );
+    let shape = CallShape {
+        m: Some(m),
+        n: Some(n),
+        k: Some(k),
+        lda: Some(lda),
+        ldb: Some(ldb),
+        ldc: Some(ldc),
+        ..Default::default()
+    };
+    count_call!("cblas_dgemm", CallInfo { m: Some(m), n: Some(n), k: Some(k) });
+    trace_call!("cblas_dgemm", shape, 2 * m as u64 * n as u64 * k as u64, {
+        match order {
+            CblasColMajor => {
+                // Column-major: call Fortran directly
+                let transa_char = transpose_to_char(transa);
+                let transb_char = transpose_to_char(transb);
+                dgemm(
+                    &transa_char,
+                    &transb_char,
+                    &m,
+                    &n,
+                    &k,
+                    &alpha,
+                    a,
+                    &lda,
+                    b,
+                    &ldb,
+                    &beta,
+                    c,
+                    &ldc,
+                );
+            }
+            CblasRowMajor => {
+                // Row-major: swap A↔B, m↔n, lda↔ldb, TransA↔TransB
+                // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/gemm.c#L489-L537
+                let transa_char = transpose_to_char(transb); // TransB becomes transa
+                let transb_char = transpose_to_char(transa); // TransA becomes transb
+                dgemm(
+                    &transa_char,
+                    &transb_char,
+                    &n, // swapped: m -> n
+                    &m, // swapped: n -> m
+                    &k,
+                    &alpha,
+                    b,    // swapped: a -> b
+                    &ldb, // swapped: lda -> ldb
+                    a,    // swapped: b -> a
+                    &lda, // swapped: ldb -> lda
+                    &beta,
+                    c,
+                    &ldc,
+                );
+            }
         }
-    }
+    })
 }
 
 /// Single precision general matrix multiply.
@@ -115,54 +196,73 @@ pub unsafe fn cblas_sgemm(
     c: *mut f32,
     ldc: blasint,
 ) {
-    let sgemm = get_sgemm();
+    if check_gemm("cblas_sgemm", order, transa, transb, m, n, k, lda, ldb, ldc) {
+        return;
+    }
+    let sgemm = dispatch_sgemm(m, n, k);
 
-    match order {
-        CblasColMajor => {
-            let transa_char = transpose_to_char(transa);
-            let transb_char = transpose_to_char(transb);
-            sgemm(
-                &transa_char,
-                &transb_char,
-                &m,
-                &n,
-                &k,
-                &alpha,
-                a,
-                &lda,
-                b,
-                &ldb,
-                &beta,
-                c,
-                &ldc,
-            );
+    let shape = CallShape {
+        m: Some(m),
+        n: Some(n),
+        k: Some(k),
+        lda: Some(lda),
+        ldb: Some(ldb),
+        ldc: Some(ldc),
+        ..Default::default()
+    };
+    count_call!("cblas_sgemm", CallInfo { m: Some(m), n: Some(n), k: Some(k) });
+    trace_call!("cblas_sgemm", shape, 2 * m as u64 * n as u64 * k as u64, {
+        match order {
+            CblasColMajor => {
+                let transa_char = transpose_to_char(transa);
+                let transb_char = transpose_to_char(transb);
+                sgemm(
+                    &transa_char,
+                    &transb_char,
+                    &m,
+                    &n,
+                    &k,
+                    &alpha,
+                    a,
+                    &lda,
+                    b,
+                    &ldb,
+                    &beta,
+                    c,
+                    &ldc,
+                );
+            }
+            CblasRowMajor => {
+                let transa_char = transpose_to_char(transb);
+                let transb_char = transpose_to_char(transa);
+                sgemm(
+                    &transa_char,
+                    &transb_char,
+                    &n,
+                    &m,
+                    &k,
+                    &alpha,
+                    b,
+                    &ldb,
+                    a,
+                    &lda,
+                    &beta,
+                    c,
+                    &ldc,
+                );
+            }
         }
-        CblasRowMajor => {
-            let transa_char = transpose_to_char(transb);
-            let transb_char = transpose_to_char(transa);
-            sgemm(
-                &transa_char,
-                &transb_char,
-                &n,
-                &m,
-                &k,
-                &alpha,
-                b,
-                &ldb,
-                a,
-                &lda,
-                &beta,
-                c,
-                &ldc,
-            );
-        }
-    }
+    })
 }
 
 /// Double precision complex general matrix multiply.
 ///
 /// Computes: C = alpha * op(A) * op(B) + beta * C
 ///
+/// `CblasConjNoTrans` on either operand is realized by conjugating that operand into a
+/// scratch buffer and calling the backend with plain `CblasNoTrans` in its place, since
+/// Fortran GEMM has no character code for conjugate-without-transpose.
+///
 /// # Safety
 ///
 /// - All pointers must be valid and properly aligned
@@ -185,54 +285,88 @@ pub unsafe fn cblas_zgemm(
     c: *mut Complex64,
     ldc: blasint,
 ) {
-    let zgemm = get_zgemm();
+    if check_gemm("cblas_zgemm", order, transa, transb, m, n, k, lda, ldb, ldc) {
+        return;
+    }
+    let zgemm = dispatch_zgemm(m, n, k);
 
-    match order {
-        CblasColMajor => {
-            let transa_char = transpose_to_char(transa);
-            let transb_char = transpose_to_char(transb);
-            zgemm(
-                &transa_char,
-                &transb_char,
-                &m,
-                &n,
-                &k,
-                &alpha,
-                a,
-                &lda,
-                b,
-                &ldb,
-                &beta,
-                c,
-                &ldc,
-            );
-        }
-        CblasRowMajor => {
-            let transa_char = transpose_to_char(transb);
-            let transb_char = transpose_to_char(transa);
-            zgemm(
-                &transa_char,
-                &transb_char,
-                &n,
-                &m,
-                &k,
-                &alpha,
-                b,
-                &ldb,
-                a,
-                &lda,
-                &beta,
-                c,
-                &ldc,
-            );
+    let a_conj;
+    let (transa, a, lda) = if transa == CblasConjNoTrans {
+        a_conj = conjugate_matrix_copy(order, m, k, a, lda);
+        (CblasNoTrans, a_conj.as_ptr(), lda)
+    } else {
+        (transa, a, lda)
+    };
+    let b_conj;
+    let (transb, b, ldb) = if transb == CblasConjNoTrans {
+        b_conj = conjugate_matrix_copy(order, k, n, b, ldb);
+        (CblasNoTrans, b_conj.as_ptr(), ldb)
+    } else {
+        (transb, b, ldb)
+    };
+
+    let shape = CallShape {
+        m: Some(m),
+        n: Some(n),
+        k: Some(k),
+        lda: Some(lda),
+        ldb: Some(ldb),
+        ldc: Some(ldc),
+        ..Default::default()
+    };
+    count_call!("cblas_zgemm", CallInfo { m: Some(m), n: Some(n), k: Some(k) });
+    trace_call!("cblas_zgemm", shape, 2 * m as u64 * n as u64 * k as u64, {
+        match order {
+            CblasColMajor => {
+                let transa_char = transpose_to_char(transa);
+                let transb_char = transpose_to_char(transb);
+                zgemm(
+                    &transa_char,
+                    &transb_char,
+                    &m,
+                    &n,
+                    &k,
+                    &alpha,
+                    a,
+                    &lda,
+                    b,
+                    &ldb,
+                    &beta,
+                    c,
+                    &ldc,
+                );
+            }
+            CblasRowMajor => {
+                let transa_char = transpose_to_char(transb);
+                let transb_char = transpose_to_char(transa);
+                zgemm(
+                    &transa_char,
+                    &transb_char,
+                    &n,
+                    &m,
+                    &k,
+                    &alpha,
+                    b,
+                    &ldb,
+                    a,
+                    &lda,
+                    &beta,
+                    c,
+                    &ldc,
+                );
+            }
         }
-    }
+    })
 }
 
 /// Single precision complex general matrix multiply.
 ///
 /// Computes: C = alpha * op(A) * op(B) + beta * C
 ///
+/// `CblasConjNoTrans` on either operand is realized by conjugating that operand into a
+/// scratch buffer and calling the backend with plain `CblasNoTrans` in its place, since
+/// Fortran GEMM has no character code for conjugate-without-transpose.
+///
 /// # Safety
 ///
 /// - All pointers must be valid and properly aligned
@@ -255,46 +389,148 @@ pub unsafe fn cblas_cgemm(
     c: *mut Complex32,
     ldc: blasint,
 ) {
-    let cgemm = get_cgemm();
+    if check_gemm("cblas_cgemm", order, transa, transb, m, n, k, lda, ldb, ldc) {
+        return;
+    }
+    let cgemm = dispatch_cgemm(m, n, k);
 
-    match order {
-        CblasColMajor => {
-            let transa_char = transpose_to_char(transa);
-            let transb_char = transpose_to_char(transb);
-            cgemm(
-                &transa_char,
-                &transb_char,
-                &m,
-                &n,
-                &k,
-                &alpha,
-                a,
-                &lda,
-                b,
-                &ldb,
-                &beta,
-                c,
-                &ldc,
-            );
-        }
-        CblasRowMajor => {
-            let transa_char = transpose_to_char(transb);
-            let transb_char = transpose_to_char(transa);
-            cgemm(
-                &transa_char,
-                &transb_char,
-                &n,
-                &m,
-                &k,
-                &alpha,
-                b,
-                &ldb,
-                a,
-                &lda,
-                &beta,
-                c,
-                &ldc,
-            );
+    let a_conj;
+    let (transa, a, lda) = if transa == CblasConjNoTrans {
+        a_conj = conjugate_matrix_copy(order, m, k, a, lda);
+        (CblasNoTrans, a_conj.as_ptr(), lda)
+    } else {
+        (transa, a, lda)
+    };
+    let b_conj;
+    let (transb, b, ldb) = if transb == CblasConjNoTrans {
+        b_conj = conjugate_matrix_copy(order, k, n, b, ldb);
+        (CblasNoTrans, b_conj.as_ptr(), ldb)
+    } else {
+        (transb, b, ldb)
+    };
+
+    let shape = CallShape {
+        m: Some(m),
+        n: Some(n),
+        k: Some(k),
+        lda: Some(lda),
+        ldb: Some(ldb),
+        ldc: Some(ldc),
+        ..Default::default()
+    };
+    count_call!("cblas_cgemm", CallInfo { m: Some(m), n: Some(n), k: Some(k) });
+    trace_call!("cblas_cgemm", shape, 2 * m as u64 * n as u64 * k as u64, {
+        match order {
+            CblasColMajor => {
+                let transa_char = transpose_to_char(transa);
+                let transb_char = transpose_to_char(transb);
+                cgemm(
+                    &transa_char,
+                    &transb_char,
+                    &m,
+                    &n,
+                    &k,
+                    &alpha,
+                    a,
+                    &lda,
+                    b,
+                    &ldb,
+                    &beta,
+                    c,
+                    &ldc,
+                );
+            }
+            CblasRowMajor => {
+                let transa_char = transpose_to_char(transb);
+                let transb_char = transpose_to_char(transa);
+                cgemm(
+                    &transa_char,
+                    &transb_char,
+                    &n,
+                    &m,
+                    &k,
+                    &alpha,
+                    b,
+                    &ldb,
+                    a,
+                    &lda,
+                    &beta,
+                    c,
+                    &ldc,
+                );
+            }
         }
+    })
+}
+
+/// Safe, slice-based general matrix multiply, for callers who'd rather check a
+/// `Result` than hold up the `unsafe` contract [`cblas_dgemm`] requires of its raw
+/// pointers.
+///
+/// Validates that `a`, `b`, and `c` are long enough for the given dimensions and
+/// leading dimensions, and that dgemm has been registered, before dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_dgemm(
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    transb: CBLAS_TRANSPOSE,
+    m: blasint,
+    n: blasint,
+    k: blasint,
+    alpha: f64,
+    a: &[f64],
+    lda: blasint,
+    b: &[f64],
+    ldb: blasint,
+    beta: f64,
+    c: &mut [f64],
+    ldc: blasint,
+) -> Result<(), String> {
+    if !crate::backend::has_dgemm() {
+        return Err("dgemm backend not registered: call register_dgemm first".to_string());
+    }
+    if m < 0 || n < 0 || k < 0 {
+        return Err(format!("dgemm: m ({m}), n ({n}), and k ({k}) must be non-negative"));
+    }
+
+    let op_a_is_notrans = transa == CblasNoTrans;
+    let op_b_is_notrans = transb == CblasNoTrans;
+    let (a_rows, a_cols) = if op_a_is_notrans { (m, k) } else { (k, m) };
+    let (b_rows, b_cols) = if op_b_is_notrans { (k, n) } else { (n, k) };
+
+    let (a_phys_rows, a_phys_cols, b_phys_rows, b_phys_cols, c_phys_rows, c_phys_cols) = match order
+    {
+        CblasColMajor => (a_rows, a_cols, b_rows, b_cols, m, n),
+        CblasRowMajor => (a_cols, a_rows, b_cols, b_rows, n, m),
+    };
+
+    let a_len_needed = (lda.max(1) as usize) * (a_phys_cols.max(1) as usize);
+    if a.len() < a_len_needed || lda < a_phys_rows.max(1) {
+        return Err(format!(
+            "dgemm: `a` has {} elements, but lda={lda} needs at least {a_len_needed} (and lda >= {a_phys_rows})",
+            a.len()
+        ));
+    }
+    let b_len_needed = (ldb.max(1) as usize) * (b_phys_cols.max(1) as usize);
+    if b.len() < b_len_needed || ldb < b_phys_rows.max(1) {
+        return Err(format!(
+            "dgemm: `b` has {} elements, but ldb={ldb} needs at least {b_len_needed} (and ldb >= {b_phys_rows})",
+            b.len()
+        ));
+    }
+    let c_len_needed = (ldc.max(1) as usize) * (c_phys_cols.max(1) as usize);
+    if c.len() < c_len_needed || ldc < c_phys_rows.max(1) {
+        return Err(format!(
+            "dgemm: `c` has {} elements, but ldc={ldc} needs at least {c_len_needed} (and ldc >= {c_phys_rows})",
+            c.len()
+        ));
+    }
+
+    unsafe {
+        cblas_dgemm(
+            order, transa, transb, m, n, k, alpha, a.as_ptr(), lda, b.as_ptr(), ldb, beta,
+            c.as_mut_ptr(), ldc,
+        );
     }
+    Ok(())
 }
@@ -0,0 +1,169 @@
+//! Quantized low-precision GEMM (int8 x int8 -> int32, gemmlowp-style requantization).
+//!
+//! Unlike the rest of `blas3`, there is no Fortran routine being wrapped here: int8
+//! GEMM isn't part of the BLAS standard, so [`reference_quant_gemm`] below *is* the
+//! implementation, not a trampoline to one registered at link time.
+//! [`crate::register_quant_gemm`] still lets a caller swap in a faster kernel (e.g. a
+//! hand-vectorized one) without touching [`gemmlowp_gemm_i8`], the same way every other
+//! `register_*` hook in this crate substitutes a backend for its routine.
+//!
+//! The requantization step follows gemmlowp's fixed-point convention: see
+//! <https://github.com/google/gemmlowp/blob/master/doc/quantization.md>.
+
+use crate::backend::{get_quant_gemm, QuantGemmFnPtr};
+use crate::types::blasint;
+use std::sync::OnceLock;
+
+/// Fixed-point requantization parameters for [`gemmlowp_requantize`].
+///
+/// `mult` is the effective rescaling factor normalized into a `[2^30, 2^31)`
+/// fixed-point mantissa, and `shift` is its power-of-two exponent: the scaled value is
+/// divided by `2^(-shift)`, so a right shift by `s` bits is `shift == -s`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QuantGemmParams {
+    pub a_zero: i8,
+    pub b_zero: i8,
+    pub output_zero: u8,
+    pub mult: i32,
+    pub shift: i32,
+}
+
+static QUANT_PARAMS: OnceLock<QuantGemmParams> = OnceLock::new();
+
+/// Set the requantization parameters used by [`gemmlowp_gemm_i8`].
+///
+/// # Panics
+///
+/// Panics if parameters have already been set.
+pub fn set_quant_gemm_params(params: QuantGemmParams) {
+    QUANT_PARAMS
+        .set(params)
+        .expect("quant gemm params already set (can only be set once)");
+}
+
+fn quant_gemm_params() -> QuantGemmParams {
+    *QUANT_PARAMS
+        .get()
+        .expect("quant gemm params not set: call set_quant_gemm_params() first")
+}
+
+/// `SaturatingRoundingDoublingHighMul(a, b)`: the high 32 bits of the rounded, doubled
+/// 64-bit product `a * b`, saturating the one case (`a == b == i32::MIN`) that would
+/// otherwise wrap back around to `i32::MIN` instead of the mathematically correct
+/// `i32::MAX`.
+fn saturating_rounding_doubling_high_mul(a: i32, b: i32) -> i32 {
+    if a == i32::MIN && b == i32::MIN {
+        return i32::MAX;
+    }
+    let product = (a as i64) * (b as i64);
+    ((product + (1i64 << 30)) >> 31) as i32
+}
+
+/// `RoundingDivideByPOT(v, e)`: divide `v` by `2^e`, rounding half away from zero.
+fn rounding_divide_by_pot(v: i32, e: i32) -> i32 {
+    let mask = (1i32 << e) - 1;
+    let remainder = v & mask;
+    let threshold = (mask >> 1) + i32::from(v < 0);
+    (v >> e) + i32::from(remainder > threshold)
+}
+
+/// Requantizes one int32 GEMM accumulator to a uint8 output value using `params`.
+pub fn gemmlowp_requantize(x: i32, params: &QuantGemmParams) -> u8 {
+    let scaled = saturating_rounding_doubling_high_mul(x, params.mult);
+    let shifted = rounding_divide_by_pot(scaled, -params.shift);
+    let result = i32::from(params.output_zero) + shifted;
+    result.clamp(0, 255) as u8
+}
+
+/// The crate's own int8 GEMM kernel, used as the default [`QuantGemmFnPtr`] when no
+/// faster one has been registered via [`crate::register_quant_gemm`].
+///
+/// # Safety
+///
+/// See [`QuantGemmFnPtr`]'s contract.
+#[allow(clippy::too_many_arguments)]
+pub(crate) unsafe extern "C" fn reference_quant_gemm(
+    m: blasint,
+    n: blasint,
+    k: blasint,
+    a: *const i8,
+    lda: blasint,
+    b: *const i8,
+    ldb: blasint,
+    c: *mut i32,
+    ldc: blasint,
+    a_zero: i8,
+    b_zero: i8,
+) {
+    let (m, n, k, lda, ldb, ldc) = (
+        m as isize,
+        n as isize,
+        k as isize,
+        lda as isize,
+        ldb as isize,
+        ldc as isize,
+    );
+    for j in 0..n {
+        for i in 0..m {
+            let mut acc: i32 = 0;
+            for p in 0..k {
+                let av = *a.offset(i + p * lda) as i32 - a_zero as i32;
+                let bv = *b.offset(p + j * ldb) as i32 - b_zero as i32;
+                acc += av * bv;
+            }
+            *c.offset(i + j * ldc) = acc;
+        }
+    }
+}
+
+/// Computes an int8 x int8 GEMM with int32 accumulation via the registered (or default
+/// reference) kernel, then requantizes every accumulator to uint8 using the parameters
+/// set by [`set_quant_gemm_params`].
+///
+/// `a` is `m x k`, `b` is `k x n`, `c` is `m x n`, all column-major with the given
+/// leading dimensions.
+///
+/// # Safety
+///
+/// - All pointers must be valid, properly aligned, and sized for m/n/k/lda/ldb/ldc
+/// - `c` must hold at least `ldc * n` uint8 elements
+/// - `set_quant_gemm_params` must have been called first
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub unsafe extern "C" fn gemmlowp_gemm_i8(
+    m: blasint,
+    n: blasint,
+    k: blasint,
+    a: *const i8,
+    lda: blasint,
+    b: *const i8,
+    ldb: blasint,
+    c: *mut u8,
+    ldc: blasint,
+) {
+    let params = quant_gemm_params();
+    let quant_gemm: QuantGemmFnPtr = get_quant_gemm();
+
+    let (m_usize, n_usize, ldc_usize) = (m as usize, n as usize, ldc.max(1) as usize);
+    let mut acc = vec![0i32; ldc_usize * n_usize.max(1)];
+    quant_gemm(
+        m,
+        n,
+        k,
+        a,
+        lda,
+        b,
+        ldb,
+        acc.as_mut_ptr(),
+        ldc,
+        params.a_zero,
+        params.b_zero,
+    );
+
+    for j in 0..n_usize {
+        for i in 0..m_usize {
+            let idx = i + j * ldc_usize;
+            *c.add(idx) = gemmlowp_requantize(acc[idx], &params);
+        }
+    }
+}
@@ -10,11 +10,52 @@
 
 use crate::backend::{get_chemm, get_zhemm};
 use crate::types::{
-    blasint, side_to_char, uplo_to_char, CblasColMajor, CblasLeft, CblasLower, CblasRight,
-    CblasRowMajor, CblasUpper, CBLAS_ORDER, CBLAS_SIDE, CBLAS_UPLO,
+    blasint, flip_side, flip_uplo, side_to_char, uplo_to_char, CblasColMajor, CblasLeft,
+    CblasRight, CblasRowMajor, CBLAS_ORDER, CBLAS_SIDE, CBLAS_UPLO,
 };
+use crate::validation::validate_if_enabled;
 use num_complex::{Complex32, Complex64};
 
+/// Validates CBLAS argument positions 4 (m), 5 (n), 8 (lda), 10 (ldb), and 13 (ldc) for
+/// `cblas_?hemm`, mirroring `trsm.rs`'s `check_trsm`: the Hermitian matrix `A` is m x m
+/// for `Side=Left` or n x n for `Side=Right` (`lda`'s minimum, like `check_trsm`'s,
+/// depends only on `side` — the row-major `m`/`n` swap and `side` flip cancel out, so
+/// the same formula applies regardless of `order`); `B`/`C` are both m x n, so `ldb`/
+/// `ldc`'s minimums depend on `order` directly.
+///
+/// Opt-in, like `syrk.rs`'s `check_syrk`: a no-op until [`crate::enable_validation`] has
+/// been called.
+#[allow(clippy::too_many_arguments)]
+unsafe fn check_hemm(
+    routine: &str,
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    m: blasint,
+    n: blasint,
+    lda: blasint,
+    ldb: blasint,
+    ldc: blasint,
+) -> bool {
+    let lda_min = match side {
+        CblasLeft => m.max(1),
+        CblasRight => n.max(1),
+    };
+    let (ldb_min, ldc_min) = match order {
+        CblasColMajor => (m.max(1), m.max(1)),
+        CblasRowMajor => (n.max(1), n.max(1)),
+    };
+    validate_if_enabled(
+        routine,
+        &[
+            (m >= 0, 4),
+            (n >= 0, 5),
+            (lda >= lda_min, 8),
+            (ldb >= ldb_min, 10),
+            (ldc >= ldc_min, 13),
+        ],
+    )
+}
+
 /// Single precision complex Hermitian matrix multiply.
 ///
 /// # Safety
@@ -39,6 +80,9 @@ pub unsafe extern "C" fn cblas_chemm(
     c: *mut Complex32,
     ldc: blasint,
 ) {
+    if check_hemm("cblas_chemm", order, side, m, n, lda, ldb, ldc) {
+        return;
+    }
     let chemm = get_chemm();
 
     match order {
@@ -51,14 +95,8 @@ pub unsafe extern "C" fn cblas_chemm(
         }
         CblasRowMajor => {
             // Row-major: swap m↔n, invert side, invert uplo
-            let new_side = match side {
-                CblasLeft => CblasRight,
-                CblasRight => CblasLeft,
-            };
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_side = flip_side(side);
+            let new_uplo = flip_uplo(uplo);
             let side_char = side_to_char(new_side);
             let uplo_char = uplo_to_char(new_uplo);
             chemm(
@@ -94,6 +132,9 @@ pub unsafe extern "C" fn cblas_zhemm(
     c: *mut Complex64,
     ldc: blasint,
 ) {
+    if check_hemm("cblas_zhemm", order, side, m, n, lda, ldb, ldc) {
+        return;
+    }
     let zhemm = get_zhemm();
 
     match order {
@@ -106,14 +147,8 @@ pub unsafe extern "C" fn cblas_zhemm(
         }
         CblasRowMajor => {
             // Row-major: swap m↔n, invert side, invert uplo
-            let new_side = match side {
-                CblasLeft => CblasRight,
-                CblasRight => CblasLeft,
-            };
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_side = flip_side(side);
+            let new_uplo = flip_uplo(uplo);
             let side_char = side_to_char(new_side);
             let uplo_char = uplo_to_char(new_uplo);
             zhemm(
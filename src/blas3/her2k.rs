@@ -12,8 +12,9 @@
 
 use crate::backend::{get_cher2k, get_zher2k};
 use crate::types::{
-    blasint, transpose_to_char, uplo_to_char, CblasColMajor, CblasConjTrans, CblasLower,
-    CblasNoTrans, CblasRowMajor, CblasUpper, CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
+    blasint, flip_uplo, transpose_to_char, uplo_to_char, CblasColMajor, CblasConjNoTrans,
+    CblasConjTrans, CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_ORDER, CBLAS_TRANSPOSE,
+    CBLAS_UPLO,
 };
 use num_complex::{Complex32, Complex64};
 
@@ -67,14 +68,14 @@ pub unsafe extern "C" fn cblas_cher2k(
         CblasRowMajor => {
             // Row-major: invert uplo, invert trans (NoTrans<->ConjTrans), and conjugate alpha
             // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/her2k.c
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
-                CblasNoTrans => CblasConjTrans,
+                // Only NoTrans/ConjTrans are meaningful for HER2K; ConjNoTrans has no
+                // valid Hermitian interpretation (matching herk.rs), so it's treated
+                // like NoTrans rather than silently collapsed by a wildcard arm.
+                CblasNoTrans | CblasConjNoTrans => CblasConjTrans,
                 CblasConjTrans => CblasNoTrans,
-                _ => CblasNoTrans,
+                CblasTrans => CblasConjTrans,
             };
             // Conjugate alpha for row-major
             let alpha_val = *alpha;
@@ -149,14 +150,14 @@ pub unsafe extern "C" fn cblas_zher2k(
         CblasRowMajor => {
             // Row-major: invert uplo, invert trans (NoTrans<->ConjTrans), and conjugate alpha
             // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/her2k.c
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
-                CblasNoTrans => CblasConjTrans,
+                // Only NoTrans/ConjTrans are meaningful for HER2K; ConjNoTrans has no
+                // valid Hermitian interpretation (matching herk.rs), so it's treated
+                // like NoTrans rather than silently collapsed by a wildcard arm.
+                CblasNoTrans | CblasConjNoTrans => CblasConjTrans,
                 CblasConjTrans => CblasNoTrans,
-                _ => CblasNoTrans,
+                CblasTrans => CblasConjTrans,
             };
             // Conjugate alpha for row-major
             let alpha_val = *alpha;
@@ -12,11 +12,44 @@
 
 use crate::backend::{get_cherk, get_zherk};
 use crate::types::{
-    blasint, transpose_to_char, uplo_to_char, CblasColMajor, CblasConjTrans, CblasLower,
-    CblasNoTrans, CblasRowMajor, CblasUpper, CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
+    blasint, flip_uplo, transpose_to_char, uplo_to_char, CblasColMajor, CblasConjTrans,
+    CblasNoTrans, CblasRowMajor, CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
 };
+use crate::validation::validate;
 use num_complex::{Complex32, Complex64};
 
+/// Validates CBLAS argument positions 3 (trans), 4 (n), 5 (k), 8 (lda), and 11 (ldc)
+/// for `cblas_?herk`: `trans` is `NoTrans` or `ConjTrans` (unlike SYRK, `Trans` isn't
+/// meaningful for a Hermitian update and isn't silently accepted here), `n >= 0`,
+/// `k >= 0`, `ldc >= max(1,n)` (`C` is n x n regardless of layout), and `lda` large
+/// enough to hold `A` in the shape `trans` and `order` together imply. Column-major
+/// `NoTrans` stores `A` as n x k (`lda >= max(1,n)`); `ConjTrans` stores it as k x n
+/// (`lda >= max(1,k)`); row-major swaps which of those applies, since the caller's
+/// `lda` already describes the row-major layout directly.
+unsafe fn check_herk(
+    routine: &str,
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    n: blasint,
+    k: blasint,
+    lda: blasint,
+    ldc: blasint,
+) -> bool {
+    let row_major = order == CblasRowMajor;
+    let conj_trans = trans == CblasConjTrans;
+    let lda_min = if row_major == conj_trans { n.max(1) } else { k.max(1) };
+    validate(
+        routine,
+        &[
+            (trans == CblasNoTrans || conj_trans, 3),
+            (n >= 0, 4),
+            (k >= 0, 5),
+            (lda >= lda_min, 8),
+            (ldc >= n.max(1), 11),
+        ],
+    )
+}
+
 /// Single precision complex Hermitian rank-k update.
 ///
 /// Computes: C = alpha * A * A^H + beta * C  (Trans=NoTrans)
@@ -44,6 +77,9 @@ pub unsafe extern "C" fn cblas_cherk(
     c: *mut Complex32,
     ldc: blasint,
 ) {
+    if check_herk("cblas_cherk", order, trans, n, k, lda, ldc) {
+        return;
+    }
     let cherk = get_cherk();
 
     match order {
@@ -66,10 +102,7 @@ pub unsafe extern "C" fn cblas_cherk(
         CblasRowMajor => {
             // Row-major: invert trans (NoTrans<->ConjTrans), invert uplo
             // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/herk.c
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasConjTrans,
                 CblasConjTrans => CblasNoTrans,
@@ -121,6 +154,9 @@ pub unsafe extern "C" fn cblas_zherk(
     c: *mut Complex64,
     ldc: blasint,
 ) {
+    if check_herk("cblas_zherk", order, trans, n, k, lda, ldc) {
+        return;
+    }
     let zherk = get_zherk();
 
     match order {
@@ -142,10 +178,7 @@ pub unsafe extern "C" fn cblas_zherk(
         }
         CblasRowMajor => {
             // Row-major: invert trans (NoTrans<->ConjTrans), invert uplo
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasConjTrans,
                 CblasConjTrans => CblasNoTrans,
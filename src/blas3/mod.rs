@@ -1,6 +1,7 @@
 //! BLAS Level 3 operations (matrix-matrix).
 
 pub mod gemm;
+pub mod gemm_lowp;
 pub mod hemm;
 pub mod her2k;
 pub mod herk;
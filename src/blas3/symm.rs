@@ -10,8 +10,8 @@
 
 use crate::backend::{get_csymm, get_dsymm, get_ssymm, get_zsymm};
 use crate::types::{
-    blasint, side_to_char, uplo_to_char, CblasColMajor, CblasLeft, CblasLower, CblasRight,
-    CblasRowMajor, CblasUpper, CBLAS_ORDER, CBLAS_SIDE, CBLAS_UPLO,
+    blasint, flip_side, flip_uplo, side_to_char, uplo_to_char, CblasColMajor, CblasRowMajor,
+    CBLAS_ORDER, CBLAS_SIDE, CBLAS_UPLO,
 };
 use num_complex::{Complex32, Complex64};
 
@@ -52,14 +52,8 @@ pub unsafe extern "C" fn cblas_dsymm(
         CblasRowMajor => {
             // Row-major: swap m↔n, invert side, invert uplo
             // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/symm.c
-            let new_side = match side {
-                CblasLeft => CblasRight,
-                CblasRight => CblasLeft,
-            };
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_side = flip_side(side);
+            let new_uplo = flip_uplo(uplo);
             let side_char = side_to_char(new_side);
             let uplo_char = uplo_to_char(new_uplo);
             dsymm(
@@ -107,14 +101,8 @@ pub unsafe extern "C" fn cblas_ssymm(
         }
         CblasRowMajor => {
             // Row-major: swap m↔n, invert side, invert uplo
-            let new_side = match side {
-                CblasLeft => CblasRight,
-                CblasRight => CblasLeft,
-            };
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_side = flip_side(side);
+            let new_uplo = flip_uplo(uplo);
             let side_char = side_to_char(new_side);
             let uplo_char = uplo_to_char(new_uplo);
             ssymm(
@@ -162,14 +150,8 @@ pub unsafe extern "C" fn cblas_csymm(
         }
         CblasRowMajor => {
             // Row-major: swap m↔n, invert side, invert uplo
-            let new_side = match side {
-                CblasLeft => CblasRight,
-                CblasRight => CblasLeft,
-            };
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_side = flip_side(side);
+            let new_uplo = flip_uplo(uplo);
             let side_char = side_to_char(new_side);
             let uplo_char = uplo_to_char(new_uplo);
             csymm(
@@ -217,14 +199,8 @@ pub unsafe extern "C" fn cblas_zsymm(
         }
         CblasRowMajor => {
             // Row-major: swap m↔n, invert side, invert uplo
-            let new_side = match side {
-                CblasLeft => CblasRight,
-                CblasRight => CblasLeft,
-            };
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_side = flip_side(side);
+            let new_uplo = flip_uplo(uplo);
             let side_char = side_to_char(new_side);
             let uplo_char = uplo_to_char(new_uplo);
             zsymm(
@@ -235,3 +211,72 @@ pub unsafe extern "C" fn cblas_zsymm(
         }
     }
 }
+
+/// Safe, slice-based symmetric matrix multiply, for callers who'd rather check a
+/// `Result` than hold up the `unsafe` contract [`cblas_dsymm`] requires of its raw
+/// pointers.
+///
+/// Validates that `a`, `b`, and `c` are long enough for the given dimensions and
+/// leading dimensions, and that dsymm has been registered, before dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_dsymm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: blasint,
+    n: blasint,
+    alpha: f64,
+    a: &[f64],
+    lda: blasint,
+    b: &[f64],
+    ldb: blasint,
+    beta: f64,
+    c: &mut [f64],
+    ldc: blasint,
+) -> Result<(), String> {
+    if !crate::backend::has_dsymm() {
+        return Err("dsymm backend not registered: call register_dsymm first".to_string());
+    }
+    if m < 0 || n < 0 {
+        return Err(format!("dsymm: m ({m}) and n ({n}) must be non-negative"));
+    }
+
+    let k = match side {
+        crate::types::CBLAS_SIDE::CblasLeft => m,
+        crate::types::CBLAS_SIDE::CblasRight => n,
+    };
+    let a_len_needed = (lda.max(1) as usize) * (k.max(1) as usize);
+    if a.len() < a_len_needed {
+        return Err(format!(
+            "dsymm: `a` has {} elements, but lda={lda}, side={side:?}, order={order:?} need at least {a_len_needed}",
+            a.len()
+        ));
+    }
+    // Column-major B/C are m rows x n cols (len = ld*n); row-major are n rows x m cols (len = ld*m).
+    let (b_cols, c_cols) = match order {
+        CblasColMajor => (n, n),
+        CblasRowMajor => (m, m),
+    };
+    let b_len_needed = (ldb.max(1) as usize) * (b_cols.max(1) as usize);
+    if b.len() < b_len_needed {
+        return Err(format!(
+            "dsymm: `b` has {} elements, but ldb={ldb} and order={order:?} need at least {b_len_needed}",
+            b.len()
+        ));
+    }
+    let c_len_needed = (ldc.max(1) as usize) * (c_cols.max(1) as usize);
+    if c.len() < c_len_needed {
+        return Err(format!(
+            "dsymm: `c` has {} elements, but ldc={ldc} and order={order:?} need at least {c_len_needed}",
+            c.len()
+        ));
+    }
+
+    unsafe {
+        cblas_dsymm(
+            order, side, uplo, m, n, alpha, a.as_ptr(), lda, b.as_ptr(), ldb, beta, c.as_mut_ptr(),
+            ldc,
+        );
+    }
+    Ok(())
+}
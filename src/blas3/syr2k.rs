@@ -10,11 +10,48 @@
 
 use crate::backend::{get_csyr2k, get_dsyr2k, get_ssyr2k, get_zsyr2k};
 use crate::types::{
-    blasint, transpose_to_char, uplo_to_char, CblasColMajor, CblasLower, CblasNoTrans,
-    CblasRowMajor, CblasTrans, CblasUpper, CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
+    blasint, flip_uplo, transpose_to_char, uplo_to_char, CblasColMajor, CblasConjNoTrans,
+    CblasConjTrans, CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_ORDER, CBLAS_TRANSPOSE,
+    CBLAS_UPLO,
 };
+use crate::validation::validate_if_enabled;
 use num_complex::{Complex32, Complex64};
 
+/// Validates CBLAS argument positions 4 (n), 5 (k), 8 (lda), 10 (ldb), and 13 (ldc) for
+/// `cblas_?syr2k`, mirroring `syrk.rs`'s `check_syrk`: `A` and `B` share the same
+/// logical shape (n x k or k x n, per `trans`/`order`), so `lda` and `ldb` share the
+/// same minimum; `ldc >= max(1,n)` always (`C` is n x n).
+///
+/// Like `check_syrk`, this is opt-in: it only reports through `cblas_xerbla` once
+/// [`crate::enable_validation`] has been called, since SYR2K predates that
+/// infrastructure and enabling it unconditionally here would be a behavior change for
+/// existing callers.
+#[allow(clippy::too_many_arguments)]
+unsafe fn check_syr2k(
+    routine: &str,
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    n: blasint,
+    k: blasint,
+    lda: blasint,
+    ldb: blasint,
+    ldc: blasint,
+) -> bool {
+    let row_major = order == CblasRowMajor;
+    let transposed = trans == CblasTrans || trans == CblasConjTrans;
+    let ab_lda_min = if row_major == transposed { n.max(1) } else { k.max(1) };
+    validate_if_enabled(
+        routine,
+        &[
+            (n >= 0, 4),
+            (k >= 0, 5),
+            (lda >= ab_lda_min, 8),
+            (ldb >= ab_lda_min, 10),
+            (ldc >= n.max(1), 13),
+        ],
+    )
+}
+
 /// Double precision symmetric rank-2k update.
 ///
 /// # Safety
@@ -39,6 +76,9 @@ pub unsafe extern "C" fn cblas_dsyr2k(
     c: *mut f64,
     ldc: blasint,
 ) {
+    if check_syr2k("cblas_dsyr2k", order, trans, n, k, lda, ldb, ldc) {
+        return;
+    }
     let dsyr2k = get_dsyr2k();
 
     match order {
@@ -63,14 +103,14 @@ pub unsafe extern "C" fn cblas_dsyr2k(
         CblasRowMajor => {
             // Row-major: invert trans, invert uplo (same as syrk)
             // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/syr2k.c
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
-                _ => CblasNoTrans,
+                // ConjTrans behaves like Trans, ConjNoTrans like NoTrans, for
+                // symmetric (non-Hermitian) data
+                CblasConjTrans => CblasNoTrans,
+                CblasConjNoTrans => CblasTrans,
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -116,6 +156,9 @@ pub unsafe extern "C" fn cblas_ssyr2k(
     c: *mut f32,
     ldc: blasint,
 ) {
+    if check_syr2k("cblas_ssyr2k", order, trans, n, k, lda, ldb, ldc) {
+        return;
+    }
     let ssyr2k = get_ssyr2k();
 
     match order {
@@ -140,14 +183,14 @@ pub unsafe extern "C" fn cblas_ssyr2k(
         CblasRowMajor => {
             // Row-major: invert trans, invert uplo (same as syrk)
             // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/syr2k.c
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
-                _ => CblasNoTrans,
+                // ConjTrans behaves like Trans, ConjNoTrans like NoTrans, for
+                // symmetric (non-Hermitian) data
+                CblasConjTrans => CblasNoTrans,
+                CblasConjNoTrans => CblasTrans,
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -193,6 +236,9 @@ pub unsafe extern "C" fn cblas_csyr2k(
     c: *mut Complex32,
     ldc: blasint,
 ) {
+    if check_syr2k("cblas_csyr2k", order, trans, n, k, lda, ldb, ldc) {
+        return;
+    }
     let csyr2k = get_csyr2k();
 
     match order {
@@ -217,14 +263,14 @@ pub unsafe extern "C" fn cblas_csyr2k(
         CblasRowMajor => {
             // Row-major: invert trans, invert uplo
             // For complex symmetric, Trans stays Trans (not ConjTrans)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
-                _ => CblasNoTrans,
+                // ConjTrans behaves like Trans, ConjNoTrans like NoTrans, for
+                // symmetric (non-Hermitian) data
+                CblasConjTrans => CblasNoTrans,
+                CblasConjNoTrans => CblasTrans,
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -270,6 +316,9 @@ pub unsafe extern "C" fn cblas_zsyr2k(
     c: *mut Complex64,
     ldc: blasint,
 ) {
+    if check_syr2k("cblas_zsyr2k", order, trans, n, k, lda, ldb, ldc) {
+        return;
+    }
     let zsyr2k = get_zsyr2k();
 
     match order {
@@ -294,14 +343,14 @@ pub unsafe extern "C" fn cblas_zsyr2k(
         CblasRowMajor => {
             // Row-major: invert trans, invert uplo
             // For complex symmetric, Trans stays Trans (not ConjTrans)
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
-                _ => CblasNoTrans,
+                // ConjTrans behaves like Trans, ConjNoTrans like NoTrans, for
+                // symmetric (non-Hermitian) data
+                CblasConjTrans => CblasNoTrans,
+                CblasConjNoTrans => CblasTrans,
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -7,14 +7,50 @@
 //! Row-major conversion logic derived from OpenBLAS.
 //! Copyright (c) 2011-2014, The OpenBLAS Project. BSD-3-Clause License.
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/syrk.c>
+//!
+//! Large `cblas_dsyrk` calls (`n*n*k` at or above `crate::set_gpu_offload_threshold`'s
+//! threshold) are forwarded to a registered device backend instead of the CPU Fortran
+//! one; see `crate::backend::dispatch_dsyrk`.
 
-use crate::backend::{get_csyrk, get_dsyrk, get_ssyrk, get_zsyrk};
+use crate::backend::{dispatch_dsyrk, get_csyrk, get_ssyrk, get_zsyrk};
 use crate::types::{
-    blasint, transpose_to_char, uplo_to_char, CblasColMajor, CblasLower, CblasNoTrans,
-    CblasRowMajor, CblasTrans, CblasUpper, CBLAS_ORDER, CBLAS_TRANSPOSE, CBLAS_UPLO,
+    blasint, flip_uplo, transpose_to_char, uplo_to_char, CblasColMajor, CblasConjNoTrans,
+    CblasConjTrans, CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_ORDER, CBLAS_TRANSPOSE,
+    CBLAS_UPLO,
 };
+use crate::validation::validate_if_enabled;
 use num_complex::{Complex32, Complex64};
 
+/// Validates CBLAS argument positions 4 (n), 5 (k), 8 (lda), and 11 (ldc) for
+/// `cblas_?syrk`, mirroring `herk.rs`'s `check_herk` but without a `trans`-legality
+/// check (SYRK silently accepts all four `CBLAS_TRANSPOSE` values; see the
+/// `CblasConjTrans`/`CblasConjNoTrans` row-major handling above). `ldc >= max(1,n)`
+/// always (`C` is n x n); `lda`'s minimum follows which of `A`'s two logical shapes
+/// (n x k or k x n) `trans` and `order` together imply, by the same reasoning as
+/// `check_herk`.
+///
+/// Unlike GEMV/TRSV/HERK's validation, this is opt-in: it only reports through
+/// `cblas_xerbla` once [`crate::enable_validation`] has been called, since SYRK
+/// predates that infrastructure and enabling it unconditionally here would be a
+/// behavior change for existing callers.
+unsafe fn check_syrk(
+    routine: &str,
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    n: blasint,
+    k: blasint,
+    lda: blasint,
+    ldc: blasint,
+) -> bool {
+    let row_major = order == CblasRowMajor;
+    let transposed = trans == CblasTrans || trans == CblasConjTrans;
+    let lda_min = if row_major == transposed { n.max(1) } else { k.max(1) };
+    validate_if_enabled(
+        routine,
+        &[(n >= 0, 4), (k >= 0, 5), (lda >= lda_min, 8), (ldc >= n.max(1), 11)],
+    )
+}
+
 /// Double precision symmetric rank-k update.
 ///
 /// # Safety
@@ -37,7 +73,10 @@ pub unsafe extern "C" fn cblas_dsyrk(
     c: *mut f64,
     ldc: blasint,
 ) {
-    let dsyrk = get_dsyrk();
+    if check_syrk("cblas_dsyrk", order, trans, n, k, lda, ldc) {
+        return;
+    }
+    let dsyrk = dispatch_dsyrk(n, k);
 
     match order {
         CblasColMajor => {
@@ -59,15 +98,14 @@ pub unsafe extern "C" fn cblas_dsyrk(
         CblasRowMajor => {
             // Row-major: invert trans, invert uplo
             // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/syrk.c
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
-                // ConjTrans is handled same as Trans for real symmetric
-                _ => CblasNoTrans,
+                // ConjTrans behaves like Trans, ConjNoTrans like NoTrans, for real
+                // symmetric data (conjugation is a no-op)
+                CblasConjTrans => CblasNoTrans,
+                CblasConjNoTrans => CblasTrans,
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -109,6 +147,9 @@ pub unsafe extern "C" fn cblas_ssyrk(
     c: *mut f32,
     ldc: blasint,
 ) {
+    if check_syrk("cblas_ssyrk", order, trans, n, k, lda, ldc) {
+        return;
+    }
     let ssyrk = get_ssyrk();
 
     match order {
@@ -130,15 +171,14 @@ pub unsafe extern "C" fn cblas_ssyrk(
         }
         CblasRowMajor => {
             // Row-major: invert trans, invert uplo
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
-                // ConjTrans is handled same as Trans for real symmetric
-                _ => CblasNoTrans,
+                // ConjTrans behaves like Trans, ConjNoTrans like NoTrans, for real
+                // symmetric data (conjugation is a no-op)
+                CblasConjTrans => CblasNoTrans,
+                CblasConjNoTrans => CblasTrans,
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -180,6 +220,9 @@ pub unsafe extern "C" fn cblas_csyrk(
     c: *mut Complex32,
     ldc: blasint,
 ) {
+    if check_syrk("cblas_csyrk", order, trans, n, k, lda, ldc) {
+        return;
+    }
     let csyrk = get_csyrk();
 
     match order {
@@ -201,15 +244,15 @@ pub unsafe extern "C" fn cblas_csyrk(
         }
         CblasRowMajor => {
             // Row-major: invert trans, invert uplo
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
-                // ConjTrans is handled same as Trans for complex symmetric
-                _ => CblasNoTrans,
+                // ConjTrans behaves like Trans, ConjNoTrans like NoTrans: SYRK is
+                // symmetric, not Hermitian, so these flip the same as their
+                // unconjugated counterparts
+                CblasConjTrans => CblasNoTrans,
+                CblasConjNoTrans => CblasTrans,
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -251,6 +294,9 @@ pub unsafe extern "C" fn cblas_zsyrk(
     c: *mut Complex64,
     ldc: blasint,
 ) {
+    if check_syrk("cblas_zsyrk", order, trans, n, k, lda, ldc) {
+        return;
+    }
     let zsyrk = get_zsyrk();
 
     match order {
@@ -272,15 +318,15 @@ pub unsafe extern "C" fn cblas_zsyrk(
         }
         CblasRowMajor => {
             // Row-major: invert trans, invert uplo
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_uplo = flip_uplo(uplo);
             let new_trans = match trans {
                 CblasNoTrans => CblasTrans,
                 CblasTrans => CblasNoTrans,
-                // ConjTrans is handled same as Trans for complex symmetric
-                _ => CblasNoTrans,
+                // ConjTrans behaves like Trans, ConjNoTrans like NoTrans: SYRK is
+                // symmetric, not Hermitian, so these flip the same as their
+                // unconjugated counterparts
+                CblasConjTrans => CblasNoTrans,
+                CblasConjNoTrans => CblasTrans,
             };
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(new_trans);
@@ -299,3 +345,57 @@ pub unsafe extern "C" fn cblas_zsyrk(
         }
     }
 }
+
+/// Safe, slice-based symmetric rank-k update, for callers who'd rather check a
+/// `Result` than hold up the `unsafe` contract [`cblas_dsyrk`] requires of its raw
+/// pointers.
+///
+/// Validates that `a` and `c` are long enough for the given dimensions and leading
+/// dimensions, and that dsyrk has been registered, before dispatching.
+#[allow(clippy::too_many_arguments)]
+pub fn try_dsyrk(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blasint,
+    k: blasint,
+    alpha: f64,
+    a: &[f64],
+    lda: blasint,
+    beta: f64,
+    c: &mut [f64],
+    ldc: blasint,
+) -> Result<(), String> {
+    if !crate::backend::has_dsyrk() {
+        return Err("dsyrk backend not registered: call register_dsyrk first".to_string());
+    }
+    if n < 0 || k < 0 {
+        return Err(format!("dsyrk: n ({n}) and k ({k}) must be non-negative"));
+    }
+
+    // A is n x k for NoTrans, k x n for Trans (logical shape before storage order).
+    let (a_rows, a_cols) = if trans == CblasNoTrans { (n, k) } else { (k, n) };
+    // Column-major storage needs ld >= rows, len = ld*cols; row-major needs ld >= cols, len = ld*rows.
+    let a_len_needed = match order {
+        CblasColMajor => (lda.max(1) as usize) * (a_cols.max(1) as usize),
+        CblasRowMajor => (lda.max(1) as usize) * (a_rows.max(1) as usize),
+    };
+    if a.len() < a_len_needed {
+        return Err(format!(
+            "dsyrk: `a` has {} elements, but lda={lda}, trans={trans:?}, order={order:?} need at least {a_len_needed}",
+            a.len()
+        ));
+    }
+    let c_len_needed = (ldc.max(1) as usize) * (n.max(1) as usize);
+    if c.len() < c_len_needed {
+        return Err(format!(
+            "dsyrk: `c` has {} elements, but ldc={ldc} and n={n} need at least {c_len_needed}",
+            c.len()
+        ));
+    }
+
+    unsafe {
+        cblas_dsyrk(order, uplo, trans, n, k, alpha, a.as_ptr(), lda, beta, c.as_mut_ptr(), ldc);
+    }
+    Ok(())
+}
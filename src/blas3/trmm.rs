@@ -8,12 +8,52 @@
 //! Copyright (c) 2011-2014, The OpenBLAS Project. BSD-3-Clause License.
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/trmm.c>
 
-use crate::backend::get_dtrmm;
+use crate::backend::{get_ctrmm, get_dtrmm, get_strmm, get_ztrmm};
+use crate::conj::conjugate_matrix_inplace;
+use crate::layout::trmm_convert;
 use crate::types::{
     blasint, diag_to_char, side_to_char, transpose_to_char, uplo_to_char, CblasColMajor,
-    CblasLeft, CblasLower, CblasRight, CblasRowMajor, CblasUpper, CBLAS_DIAG, CBLAS_ORDER,
+    CblasConjNoTrans, CblasLeft, CblasNoTrans, CblasRight, CblasRowMajor, CBLAS_DIAG, CBLAS_ORDER,
     CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO,
 };
+use crate::validation::validate;
+use num_complex::{Complex32, Complex64};
+
+/// Validates the common CBLAS argument positions 6 (m), 7 (n), 10 (lda), 12 (ldb)
+/// for `cblas_?trmm`, returning `true` (after reporting via xerbla) if the caller
+/// should bail out. `lda`/`ldb` minimums follow the CBLAS convention: the
+/// triangular matrix `A` is `m x m` for `Side=Left` or `n x n` for `Side=Right`, and
+/// `ldb`'s minimum depends on `order`, not on any row-major remapping already
+/// applied — mirrors `trsm`'s `check_trsm` exactly, since TRMM and TRSM share the
+/// same argument shape.
+#[allow(clippy::too_many_arguments)]
+unsafe fn check_trmm(
+    routine: &str,
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    m: blasint,
+    n: blasint,
+    lda: blasint,
+    ldb: blasint,
+) -> bool {
+    let lda_min = match side {
+        CblasLeft => m.max(1),
+        CblasRight => n.max(1),
+    };
+    let ldb_min = match order {
+        CblasColMajor => m.max(1),
+        CblasRowMajor => n.max(1),
+    };
+    validate(
+        routine,
+        &[
+            (m >= 0, 6),
+            (n >= 0, 7),
+            (lda >= lda_min, 10),
+            (ldb >= ldb_min, 12),
+        ],
+    )
+}
 
 /// Double precision triangular matrix multiply.
 ///
@@ -22,8 +62,9 @@ use crate::types::{
 /// - All pointers must be valid and properly aligned
 /// - Matrix dimensions and leading dimensions must be consistent
 /// - dtrmm must be registered via `register_dtrmm`
+#[no_mangle]
 #[allow(clippy::too_many_arguments)]
-pub unsafe fn cblas_dtrmm(
+pub unsafe extern "C" fn cblas_dtrmm(
     order: CBLAS_ORDER,
     side: CBLAS_SIDE,
     uplo: CBLAS_UPLO,
@@ -37,47 +78,151 @@ pub unsafe fn cblas_dtrmm(
     b: *mut f64,
     ldb: blasint,
 ) {
+    if check_trmm("cblas_dtrmm", order, side, m, n, lda, ldb) {
+        return;
+    }
     let dtrmm = get_dtrmm();
 
-    match order {
-        CblasColMajor => {
-            let side_char = side_to_char(side);
-            let uplo_char = uplo_to_char(uplo);
-            let trans_char = transpose_to_char(trans);
-            let diag_char = diag_to_char(diag);
-            dtrmm(
-                &side_char, &uplo_char, &trans_char, &diag_char, &m, &n, &alpha, a, &lda, b, &ldb,
-            );
-        }
-        CblasRowMajor => {
-            // Row-major: swap m↔n, invert side, invert uplo
-            // Trans is NOT inverted
-            // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/trmm.c
-            let new_side = match side {
-                CblasLeft => CblasRight,
-                CblasRight => CblasLeft,
-            };
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let side_char = side_to_char(new_side);
-            let uplo_char = uplo_to_char(new_uplo);
-            let trans_char = transpose_to_char(trans); // NOT inverted
-            let diag_char = diag_to_char(diag);
-            dtrmm(
-                &side_char,
-                &uplo_char,
-                &trans_char,
-                &diag_char,
-                &n, // swapped
-                &m, // swapped
-                &alpha,
-                a,
-                &lda,
-                b,
-                &ldb,
-            );
-        }
+    let (side, uplo, trans, diag, m, n) = trmm_convert(order, side, uplo, trans, diag, m, n);
+    let side_char = side_to_char(side);
+    let uplo_char = uplo_to_char(uplo);
+    let trans_char = transpose_to_char(trans);
+    let diag_char = diag_to_char(diag);
+    dtrmm(
+        &side_char, &uplo_char, &trans_char, &diag_char, &m, &n, &alpha, a, &lda, b, &ldb,
+    );
+}
+
+/// Single precision triangular matrix multiply.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - Matrix dimensions and leading dimensions must be consistent
+/// - strmm must be registered via `register_strmm`
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn cblas_strmm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blasint,
+    n: blasint,
+    alpha: f32,
+    a: *const f32,
+    lda: blasint,
+    b: *mut f32,
+    ldb: blasint,
+) {
+    if check_trmm("cblas_strmm", order, side, m, n, lda, ldb) {
+        return;
     }
+    let strmm = get_strmm();
+
+    let (side, uplo, trans, diag, m, n) = trmm_convert(order, side, uplo, trans, diag, m, n);
+    let side_char = side_to_char(side);
+    let uplo_char = uplo_to_char(uplo);
+    let trans_char = transpose_to_char(trans);
+    let diag_char = diag_to_char(diag);
+    strmm(
+        &side_char, &uplo_char, &trans_char, &diag_char, &m, &n, &alpha, a, &lda, b, &ldb,
+    );
+}
+
+/// Single precision complex triangular matrix multiply.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - Matrix dimensions and leading dimensions must be consistent
+/// - ctrmm must be registered via `register_ctrmm`
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn cblas_ctrmm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blasint,
+    n: blasint,
+    alpha: *const Complex32,
+    a: *const Complex32,
+    lda: blasint,
+    b: *mut Complex32,
+    ldb: blasint,
+) {
+    if check_trmm("cblas_ctrmm", order, side, m, n, lda, ldb) {
+        return;
+    }
+    if trans == CblasConjNoTrans {
+        // op(A) = conj(A) has no Fortran character code. Conjugating both sides of
+        // B := alpha*conj(A)*B (Side=Left) or B := alpha*B*conj(A) (Side=Right) turns
+        // the conjugated A into a plain, un-conjugated one on the conjugated B, so
+        // conjugate B, recurse with NoTrans and conj(alpha), then conjugate back —
+        // the same trick `cblas_ctrsm` uses for TRSM's `ConjNoTrans`.
+        let conj_alpha = alpha.read().conj();
+        conjugate_matrix_inplace(order, m, n, b, ldb);
+        cblas_ctrmm(order, side, uplo, CblasNoTrans, diag, m, n, &conj_alpha, a, lda, b, ldb);
+        conjugate_matrix_inplace(order, m, n, b, ldb);
+        return;
+    }
+    let ctrmm = get_ctrmm();
+
+    let (side, uplo, trans, diag, m, n) = trmm_convert(order, side, uplo, trans, diag, m, n);
+    let side_char = side_to_char(side);
+    let uplo_char = uplo_to_char(uplo);
+    let trans_char = transpose_to_char(trans);
+    let diag_char = diag_to_char(diag);
+    ctrmm(
+        &side_char, &uplo_char, &trans_char, &diag_char, &m, &n, alpha, a, &lda, b, &ldb,
+    );
+}
+
+/// Double precision complex triangular matrix multiply.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - Matrix dimensions and leading dimensions must be consistent
+/// - ztrmm must be registered via `register_ztrmm`
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn cblas_ztrmm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blasint,
+    n: blasint,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: blasint,
+    b: *mut Complex64,
+    ldb: blasint,
+) {
+    if check_trmm("cblas_ztrmm", order, side, m, n, lda, ldb) {
+        return;
+    }
+    if trans == CblasConjNoTrans {
+        // Same handling as cblas_ctrmm.
+        let conj_alpha = alpha.read().conj();
+        conjugate_matrix_inplace(order, m, n, b, ldb);
+        cblas_ztrmm(order, side, uplo, CblasNoTrans, diag, m, n, &conj_alpha, a, lda, b, ldb);
+        conjugate_matrix_inplace(order, m, n, b, ldb);
+        return;
+    }
+    let ztrmm = get_ztrmm();
+
+    let (side, uplo, trans, diag, m, n) = trmm_convert(order, side, uplo, trans, diag, m, n);
+    let side_char = side_to_char(side);
+    let uplo_char = uplo_to_char(uplo);
+    let trans_char = transpose_to_char(trans);
+    let diag_char = diag_to_char(diag);
+    ztrmm(
+        &side_char, &uplo_char, &trans_char, &diag_char, &m, &n, alpha, a, &lda, b, &ldb,
+    );
 }
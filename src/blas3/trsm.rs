@@ -7,26 +7,220 @@
 //! Row-major conversion logic derived from OpenBLAS.
 //! Copyright (c) 2011-2014, The OpenBLAS Project. BSD-3-Clause License.
 //! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/trsm.c>
+//!
+//! Large `cblas_dtrsm` calls (`m*n` at or above `crate::set_gpu_offload_threshold`'s
+//! threshold) are forwarded to a registered device backend instead of the CPU Fortran
+//! one; see `crate::backend::dispatch_dtrsm`.
 
 use num_complex::{Complex32, Complex64};
 
-use crate::backend::{get_ctrsm, get_dtrsm, get_strsm, get_ztrsm};
+use crate::backend::{dispatch_dtrsm, get_ctrsm, get_strsm, get_ztrsm};
+use crate::conj::conjugate_matrix_inplace;
 use crate::types::{
-    blasint, diag_to_char, side_to_char, transpose_to_char, uplo_to_char, CblasColMajor, CblasLeft,
-    CblasLower, CblasRight, CblasRowMajor, CblasUpper, CBLAS_DIAG, CBLAS_ORDER, CBLAS_SIDE,
-    CBLAS_TRANSPOSE, CBLAS_UPLO,
+    blasint, diag_to_char, flip_side, flip_uplo, side_to_char, transpose_to_char, uplo_to_char,
+    CblasColMajor, CblasConjNoTrans, CblasLeft, CblasNoTrans, CblasRight, CblasRowMajor,
+    CBLAS_DIAG, CBLAS_ORDER, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO,
 };
+use crate::validation::validate;
 
-/// Double precision triangular solve.
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+    impl Sealed for num_complex::Complex32 {}
+    impl Sealed for num_complex::Complex64 {}
+}
+
+/// A scalar type with a registered `?trsm` backend, letting [`trsm`] collapse the four
+/// near-identical `cblas_{s,d,c,z}trsm` bodies into one generic code path. Sealed since
+/// the row-major conversion logic assumes the CBLAS Fortran `?trsm` ABI exactly.
+///
+/// `alpha` is passed by value for the real types and by pointer for the complex ones,
+/// matching how the underlying Fortran routines receive it; each implementation hides
+/// that difference behind `call`.
+pub trait TrsmScalar: sealed::Sealed + Copy {
+    /// The `register_*trsm` function name to mention in a "not registered" error,
+    /// e.g. `"register_dtrsm"`.
+    const REGISTER_FN: &'static str;
+
+    /// Whether this scalar's `?trsm` backend has been registered, without panicking.
+    /// Backs [`try_trsm`]'s `Result` return.
+    fn is_registered() -> bool;
+
+    /// Fetches the registered backend and invokes it with Fortran-convention arguments
+    /// (`side`/`uplo`/`trans`/`diag` already converted to `c_char`).
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn call(
+        side_char: &std::ffi::c_char,
+        uplo_char: &std::ffi::c_char,
+        trans_char: &std::ffi::c_char,
+        diag_char: &std::ffi::c_char,
+        m: &blasint,
+        n: &blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: &blasint,
+        b: *mut Self,
+        ldb: &blasint,
+    );
+}
+
+impl TrsmScalar for f64 {
+    const REGISTER_FN: &'static str = "register_dtrsm";
+
+    fn is_registered() -> bool {
+        crate::backend::has_dtrsm()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn call(
+        side_char: &std::ffi::c_char,
+        uplo_char: &std::ffi::c_char,
+        trans_char: &std::ffi::c_char,
+        diag_char: &std::ffi::c_char,
+        m: &blasint,
+        n: &blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: &blasint,
+        b: *mut Self,
+        ldb: &blasint,
+    ) {
+        dispatch_dtrsm(*m, *n)(
+            side_char, uplo_char, trans_char, diag_char, m, n, &alpha, a, lda, b, ldb,
+        );
+    }
+}
+
+impl TrsmScalar for f32 {
+    const REGISTER_FN: &'static str = "register_strsm";
+
+    fn is_registered() -> bool {
+        crate::backend::has_strsm()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn call(
+        side_char: &std::ffi::c_char,
+        uplo_char: &std::ffi::c_char,
+        trans_char: &std::ffi::c_char,
+        diag_char: &std::ffi::c_char,
+        m: &blasint,
+        n: &blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: &blasint,
+        b: *mut Self,
+        ldb: &blasint,
+    ) {
+        get_strsm()(
+            side_char, uplo_char, trans_char, diag_char, m, n, &alpha, a, lda, b, ldb,
+        );
+    }
+}
+
+impl TrsmScalar for Complex64 {
+    const REGISTER_FN: &'static str = "register_ztrsm";
+
+    fn is_registered() -> bool {
+        crate::backend::has_ztrsm()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn call(
+        side_char: &std::ffi::c_char,
+        uplo_char: &std::ffi::c_char,
+        trans_char: &std::ffi::c_char,
+        diag_char: &std::ffi::c_char,
+        m: &blasint,
+        n: &blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: &blasint,
+        b: *mut Self,
+        ldb: &blasint,
+    ) {
+        get_ztrsm()(
+            side_char, uplo_char, trans_char, diag_char, m, n, &alpha, a, lda, b, ldb,
+        );
+    }
+}
+
+impl TrsmScalar for Complex32 {
+    const REGISTER_FN: &'static str = "register_ctrsm";
+
+    fn is_registered() -> bool {
+        crate::backend::has_ctrsm()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn call(
+        side_char: &std::ffi::c_char,
+        uplo_char: &std::ffi::c_char,
+        trans_char: &std::ffi::c_char,
+        diag_char: &std::ffi::c_char,
+        m: &blasint,
+        n: &blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: &blasint,
+        b: *mut Self,
+        ldb: &blasint,
+    ) {
+        get_ctrsm()(
+            side_char, uplo_char, trans_char, diag_char, m, n, &alpha, a, lda, b, ldb,
+        );
+    }
+}
+
+/// Validates the common CBLAS argument positions 6 (m), 7 (n), 10 (lda), 12 (ldb)
+/// for `cblas_?trsm`, returning `true` (after reporting via xerbla) if the caller
+/// should bail out. `lda`/`ldb` minimums follow the CBLAS convention: the triangular
+/// matrix `A` is `m x m` for `Side=Left` or `n x n` for `Side=Right`, and `ldb`'s
+/// minimum depends on `order`, not on any row-major remapping already applied.
+#[allow(clippy::too_many_arguments)]
+unsafe fn check_trsm(
+    routine: &str,
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    m: blasint,
+    n: blasint,
+    lda: blasint,
+    ldb: blasint,
+) -> bool {
+    let lda_min = match side {
+        CblasLeft => m.max(1),
+        CblasRight => n.max(1),
+    };
+    let ldb_min = match order {
+        CblasColMajor => m.max(1),
+        CblasRowMajor => n.max(1),
+    };
+    validate(
+        routine,
+        &[
+            (m >= 0, 6),
+            (n >= 0, 7),
+            (lda >= lda_min, 10),
+            (ldb >= ldb_min, 12),
+        ],
+    )
+}
+
+/// Generic triangular solve collapsing the four `cblas_{s,d,c,z}trsm` bodies.
+///
+/// Solves `op(A) * X = alpha * B` (Side=Left) or `X * op(A) = alpha * B` (Side=Right),
+/// overwriting `B` with `X`. Handles both `CblasColMajor` (direct forward) and
+/// `CblasRowMajor` (swap `m`<->`n`, invert `side`, invert `uplo`; `trans` is not
+/// inverted) before dispatching to the registered Fortran backend for `T`.
 ///
 /// # Safety
 ///
 /// - All pointers must be valid and properly aligned
 /// - Matrix dimensions and leading dimensions must be consistent
-/// - dtrsm must be registered via `register_dtrsm`
+/// - The backend for `T` must be registered (`register_dtrsm`/`register_strsm`/…)
 #[allow(clippy::too_many_arguments)]
-#[no_mangle]
-pub unsafe extern "C" fn cblas_dtrsm(
+pub unsafe fn trsm<T: TrsmScalar>(
     order: CBLAS_ORDER,
     side: CBLAS_SIDE,
     uplo: CBLAS_UPLO,
@@ -34,28 +228,26 @@ pub unsafe extern "C" fn cblas_dtrsm(
     diag: CBLAS_DIAG,
     m: blasint,
     n: blasint,
-    alpha: f64,
-    a: *const f64,
+    alpha: T,
+    a: *const T,
     lda: blasint,
-    b: *mut f64,
+    b: *mut T,
     ldb: blasint,
 ) {
-    let dtrsm = get_dtrsm();
-
     match order {
         CblasColMajor => {
             let side_char = side_to_char(side);
             let uplo_char = uplo_to_char(uplo);
             let trans_char = transpose_to_char(trans);
             let diag_char = diag_to_char(diag);
-            dtrsm(
+            T::call(
                 &side_char,
                 &uplo_char,
                 &trans_char,
                 &diag_char,
                 &m,
                 &n,
-                &alpha,
+                alpha,
                 a,
                 &lda,
                 b,
@@ -66,26 +258,20 @@ pub unsafe extern "C" fn cblas_dtrsm(
             // Row-major: swap m↔n, invert side, invert uplo
             // Trans is NOT inverted
             // Following OpenBLAS: https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/trsm.c
-            let new_side = match side {
-                CblasLeft => CblasRight,
-                CblasRight => CblasLeft,
-            };
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
+            let new_side = flip_side(side);
+            let new_uplo = flip_uplo(uplo);
             let side_char = side_to_char(new_side);
             let uplo_char = uplo_to_char(new_uplo);
             let trans_char = transpose_to_char(trans); // NOT inverted
             let diag_char = diag_to_char(diag);
-            dtrsm(
+            T::call(
                 &side_char,
                 &uplo_char,
                 &trans_char,
                 &diag_char,
                 &n, // swapped
                 &m, // swapped
-                &alpha,
+                alpha,
                 a,
                 &lda,
                 b,
@@ -95,6 +281,115 @@ pub unsafe extern "C" fn cblas_dtrsm(
     }
 }
 
+/// Safe, slice-based triangular solve, for callers who'd rather check a `Result` than
+/// hold up the `unsafe` contract [`trsm`] requires of its raw pointers.
+///
+/// Solves `op(A) * X = alpha * B` (Side=Left) or `X * op(A) = alpha * B` (Side=Right),
+/// overwriting `B` with `X`; see [`trsm`] for the row-major handling. `side`/`uplo`/
+/// `trans`/`diag` are the ordinary typed CBLAS enums — there's no separate "safe" enum
+/// family, since [`CBLAS_SIDE`]/[`CBLAS_UPLO`]/[`CBLAS_TRANSPOSE`]/[`CBLAS_DIAG`] are
+/// already safe, `Copy` Rust types with no unsafe construction.
+///
+/// Returns `Err` instead of panicking if `T`'s backend hasn't been registered yet, or if
+/// `a`/`b` are too short for the claimed `lda`/`ldb`/`m`/`n`; otherwise forwards to
+/// [`trsm`].
+#[allow(clippy::too_many_arguments)]
+pub fn try_trsm<T: TrsmScalar>(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blasint,
+    n: blasint,
+    alpha: T,
+    a: &[T],
+    lda: blasint,
+    b: &mut [T],
+    ldb: blasint,
+) -> Result<(), String> {
+    if !T::is_registered() {
+        return Err(format!(
+            "trsm backend not registered: call {} first",
+            T::REGISTER_FN
+        ));
+    }
+    if m < 0 || n < 0 {
+        return Err(format!("trsm: m ({m}) and n ({n}) must be non-negative"));
+    }
+
+    let a_rows = match side {
+        CblasLeft => m,
+        CblasRight => n,
+    };
+    let a_len_needed = (lda.max(1) as usize) * (a_rows.max(1) as usize);
+    if a.len() < a_len_needed {
+        return Err(format!(
+            "trsm: `a` has {} elements, but lda={lda} and side={side:?} need at least {a_len_needed}",
+            a.len()
+        ));
+    }
+
+    let b_cols = match order {
+        CblasColMajor => n,
+        CblasRowMajor => m,
+    };
+    let b_len_needed = (ldb.max(1) as usize) * (b_cols.max(1) as usize);
+    if b.len() < b_len_needed {
+        return Err(format!(
+            "trsm: `b` has {} elements, but ldb={ldb} and order={order:?} need at least {b_len_needed}",
+            b.len()
+        ));
+    }
+
+    unsafe {
+        trsm(
+            order,
+            side,
+            uplo,
+            trans,
+            diag,
+            m,
+            n,
+            alpha,
+            a.as_ptr(),
+            lda,
+            b.as_mut_ptr(),
+            ldb,
+        );
+    }
+    Ok(())
+}
+
+/// Double precision triangular solve.
+///
+/// # Safety
+///
+/// - All pointers must be valid and properly aligned
+/// - Matrix dimensions and leading dimensions must be consistent
+/// - dtrsm must be registered via `register_dtrsm`
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtrsm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blasint,
+    n: blasint,
+    alpha: f64,
+    a: *const f64,
+    lda: blasint,
+    b: *mut f64,
+    ldb: blasint,
+) {
+    if check_trsm("cblas_dtrsm", order, side, m, n, lda, ldb) {
+        return;
+    }
+    trsm(order, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb);
+}
+
 /// Single precision triangular solve.
 ///
 /// # Safety
@@ -118,58 +413,10 @@ pub unsafe extern "C" fn cblas_strsm(
     b: *mut f32,
     ldb: blasint,
 ) {
-    let strsm = get_strsm();
-
-    match order {
-        CblasColMajor => {
-            let side_char = side_to_char(side);
-            let uplo_char = uplo_to_char(uplo);
-            let trans_char = transpose_to_char(trans);
-            let diag_char = diag_to_char(diag);
-            strsm(
-                &side_char,
-                &uplo_char,
-                &trans_char,
-                &diag_char,
-                &m,
-                &n,
-                &alpha,
-                a,
-                &lda,
-                b,
-                &ldb,
-            );
-        }
-        CblasRowMajor => {
-            // Row-major: swap m↔n, invert side, invert uplo
-            // Trans is NOT inverted
-            let new_side = match side {
-                CblasLeft => CblasRight,
-                CblasRight => CblasLeft,
-            };
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let side_char = side_to_char(new_side);
-            let uplo_char = uplo_to_char(new_uplo);
-            let trans_char = transpose_to_char(trans); // NOT inverted
-            let diag_char = diag_to_char(diag);
-            strsm(
-                &side_char,
-                &uplo_char,
-                &trans_char,
-                &diag_char,
-                &n, // swapped
-                &m, // swapped
-                &alpha,
-                a,
-                &lda,
-                b,
-                &ldb,
-            );
-        }
+    if check_trsm("cblas_strsm", order, side, m, n, lda, ldb) {
+        return;
     }
+    trsm(order, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb);
 }
 
 /// Single precision complex triangular solve.
@@ -195,58 +442,18 @@ pub unsafe extern "C" fn cblas_ctrsm(
     b: *mut Complex32,
     ldb: blasint,
 ) {
-    let ctrsm = get_ctrsm();
-
-    match order {
-        CblasColMajor => {
-            let side_char = side_to_char(side);
-            let uplo_char = uplo_to_char(uplo);
-            let trans_char = transpose_to_char(trans);
-            let diag_char = diag_to_char(diag);
-            ctrsm(
-                &side_char,
-                &uplo_char,
-                &trans_char,
-                &diag_char,
-                &m,
-                &n,
-                alpha,
-                a,
-                &lda,
-                b,
-                &ldb,
-            );
-        }
-        CblasRowMajor => {
-            // Row-major: swap m↔n, invert side, invert uplo
-            // Trans is NOT inverted
-            let new_side = match side {
-                CblasLeft => CblasRight,
-                CblasRight => CblasLeft,
-            };
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let side_char = side_to_char(new_side);
-            let uplo_char = uplo_to_char(new_uplo);
-            let trans_char = transpose_to_char(trans); // NOT inverted
-            let diag_char = diag_to_char(diag);
-            ctrsm(
-                &side_char,
-                &uplo_char,
-                &trans_char,
-                &diag_char,
-                &n, // swapped
-                &m, // swapped
-                alpha,
-                a,
-                &lda,
-                b,
-                &ldb,
-            );
-        }
+    if check_trsm("cblas_ctrsm", order, side, m, n, lda, ldb) {
+        return;
+    }
+    if trans == CblasConjNoTrans {
+        // op(A) = conj(A): solve A * conj(X) = conj(alpha) * conj(B) for conj(X)
+        // against the unconjugated A with plain NoTrans, then conjugate B back to X.
+        conjugate_matrix_inplace(order, m, n, b, ldb);
+        trsm(order, side, uplo, CblasNoTrans, diag, m, n, alpha.read().conj(), a, lda, b, ldb);
+        conjugate_matrix_inplace(order, m, n, b, ldb);
+        return;
     }
+    trsm(order, side, uplo, trans, diag, m, n, *alpha, a, lda, b, ldb);
 }
 
 /// Double precision complex triangular solve.
@@ -272,56 +479,16 @@ pub unsafe extern "C" fn cblas_ztrsm(
     b: *mut Complex64,
     ldb: blasint,
 ) {
-    let ztrsm = get_ztrsm();
-
-    match order {
-        CblasColMajor => {
-            let side_char = side_to_char(side);
-            let uplo_char = uplo_to_char(uplo);
-            let trans_char = transpose_to_char(trans);
-            let diag_char = diag_to_char(diag);
-            ztrsm(
-                &side_char,
-                &uplo_char,
-                &trans_char,
-                &diag_char,
-                &m,
-                &n,
-                alpha,
-                a,
-                &lda,
-                b,
-                &ldb,
-            );
-        }
-        CblasRowMajor => {
-            // Row-major: swap m↔n, invert side, invert uplo
-            // Trans is NOT inverted
-            let new_side = match side {
-                CblasLeft => CblasRight,
-                CblasRight => CblasLeft,
-            };
-            let new_uplo = match uplo {
-                CblasUpper => CblasLower,
-                CblasLower => CblasUpper,
-            };
-            let side_char = side_to_char(new_side);
-            let uplo_char = uplo_to_char(new_uplo);
-            let trans_char = transpose_to_char(trans); // NOT inverted
-            let diag_char = diag_to_char(diag);
-            ztrsm(
-                &side_char,
-                &uplo_char,
-                &trans_char,
-                &diag_char,
-                &n, // swapped
-                &m, // swapped
-                alpha,
-                a,
-                &lda,
-                b,
-                &ldb,
-            );
-        }
+    if check_trsm("cblas_ztrsm", order, side, m, n, lda, ldb) {
+        return;
+    }
+    if trans == CblasConjNoTrans {
+        // op(A) = conj(A): solve A * conj(X) = conj(alpha) * conj(B) for conj(X)
+        // against the unconjugated A with plain NoTrans, then conjugate B back to X.
+        conjugate_matrix_inplace(order, m, n, b, ldb);
+        trsm(order, side, uplo, CblasNoTrans, diag, m, n, alpha.read().conj(), a, lda, b, ldb);
+        conjugate_matrix_inplace(order, m, n, b, ldb);
+        return;
     }
+    trsm(order, side, uplo, trans, diag, m, n, *alpha, a, lda, b, ldb);
 }
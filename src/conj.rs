@@ -0,0 +1,140 @@
+//! Explicit conjugation helpers for the `CblasConjNoTrans` decomposition.
+//!
+//! Fortran BLAS has no character code for "conjugate, no transpose" (only `N`/`T`/`C`).
+//! Wrappers that accept `CblasConjNoTrans` realize it algebraically instead: solving
+//! `conj(A) * X = alpha * B` is the same as solving `A * Y = conj(alpha) * conj(B)` for
+//! `Y = conj(X)`, so they conjugate their complex operands in place around a plain
+//! `NoTrans` call into the registered backend. See `cblas_ctrsm`/`cblas_ztrsm` and
+//! `cblas_ctbsv`/`cblas_ztbsv` for the call sites.
+
+use num_complex::{Complex32, Complex64};
+
+use crate::types::{blasint, CBLAS_ORDER, CblasColMajor, CblasRowMajor};
+
+/// A complex scalar that knows how to conjugate itself.
+pub(crate) trait Conjugate: Copy {
+    fn conjugate(self) -> Self;
+}
+
+impl Conjugate for Complex32 {
+    fn conjugate(self) -> Self {
+        self.conj()
+    }
+}
+
+impl Conjugate for Complex64 {
+    fn conjugate(self) -> Self {
+        self.conj()
+    }
+}
+
+/// Conjugates every element of the `m x n` matrix stored at `ptr` with leading
+/// dimension `ld`, in place, honoring `order`.
+pub(crate) unsafe fn conjugate_matrix_inplace<T: Conjugate>(
+    order: CBLAS_ORDER,
+    m: blasint,
+    n: blasint,
+    ptr: *mut T,
+    ld: blasint,
+) {
+    let (rows, cols, ld) = (m as isize, n as isize, ld as isize);
+    match order {
+        CblasColMajor => {
+            for j in 0..cols {
+                for i in 0..rows {
+                    let p = ptr.offset(i + j * ld);
+                    *p = (*p).conjugate();
+                }
+            }
+        }
+        CblasRowMajor => {
+            for i in 0..rows {
+                for j in 0..cols {
+                    let p = ptr.offset(i * ld + j);
+                    *p = (*p).conjugate();
+                }
+            }
+        }
+    }
+}
+
+/// Builds a conjugated copy of the `m x n` matrix stored at `ptr` with leading dimension
+/// `ld`, honoring `order`. Used where the source operand is `*const` (e.g. GEMM's `A`/`B`),
+/// so conjugating in place isn't an option — the caller passes the returned buffer's
+/// pointer (with the same `ld`) to the backend instead.
+pub(crate) unsafe fn conjugate_matrix_copy<T: Conjugate + Default>(
+    order: CBLAS_ORDER,
+    m: blasint,
+    n: blasint,
+    ptr: *const T,
+    ld: blasint,
+) -> Vec<T> {
+    let (rows, cols, ld_i) = (m as isize, n as isize, ld as isize);
+    let len = match order {
+        CblasColMajor => (ld_i * cols.max(1)).max(0) as usize,
+        CblasRowMajor => (rows.max(1) * ld_i).max(0) as usize,
+    };
+    let mut buf = Vec::with_capacity(len);
+    buf.resize_with(len, T::default);
+    match order {
+        CblasColMajor => {
+            for j in 0..cols {
+                for i in 0..rows {
+                    let off = i + j * ld_i;
+                    buf[off as usize] = (*ptr.offset(off)).conjugate();
+                }
+            }
+        }
+        CblasRowMajor => {
+            for i in 0..rows {
+                for j in 0..cols {
+                    let off = i * ld_i + j;
+                    buf[off as usize] = (*ptr.offset(off)).conjugate();
+                }
+            }
+        }
+    }
+    buf
+}
+
+/// Conjugates the length-`n` vector at `ptr` with stride `incx`, in place. `incx` may be
+/// negative, per the usual BLAS convention that `ptr` points at the lowest-addressed
+/// element, so a negative-`incx` traversal starts at `ptr + (n - 1) * |incx|`.
+pub(crate) unsafe fn conjugate_vector_inplace<T: Conjugate>(
+    n: blasint,
+    ptr: *mut T,
+    incx: blasint,
+) {
+    let mut p = if incx < 0 && n > 0 {
+        ptr.offset(-(incx as isize) * (n as isize - 1))
+    } else {
+        ptr
+    };
+    for _ in 0..n {
+        *p = (*p).conjugate();
+        p = p.offset(incx as isize);
+    }
+}
+
+/// Builds a conjugated copy of the length-`n` vector at `ptr` with stride `incx`. Used
+/// where the source is `*const` (e.g. GEMV's `x`), so conjugating in place isn't an
+/// option; the returned buffer is contiguous (stride 1) regardless of `incx`, in the
+/// vector's logical order (a negative `incx` is read as in
+/// [`conjugate_vector_inplace`]).
+pub(crate) unsafe fn conjugate_vector_copy<T: Conjugate>(
+    n: blasint,
+    ptr: *const T,
+    incx: blasint,
+) -> Vec<T> {
+    let mut buf = Vec::with_capacity(n as usize);
+    let mut p = if incx < 0 && n > 0 {
+        ptr.offset(-(incx as isize) * (n as isize - 1))
+    } else {
+        ptr
+    };
+    for _ in 0..n {
+        buf.push((*p).conjugate());
+        p = p.offset(incx as isize);
+    }
+    buf
+}
@@ -0,0 +1,110 @@
+//! Opt-in per-routine call counters and dispatch hook.
+//!
+//! Unlike [`crate::trace`], which records full per-call timing into a ring buffer,
+//! this module is a much lighter-weight always-on tally: every instrumented entry
+//! point bumps an atomic counter keyed by routine name, and — if [`set_call_hook`] has
+//! been called — invokes the hook with the routine's [`SymbolId`] and its key
+//! dimension arguments ([`CallInfo`]). This is cheap enough to leave on permanently
+//! (a lock + hash-map lookup per call), so a caller can bracket a region with
+//! [`reset_counts`] and [`call_counts`] to see which kernels dominated it without
+//! needing to turn tracing on first.
+//!
+//! Routines opt in with the [`count_call!`] macro at their call site, same as
+//! [`crate::trace::trace_call`]. Only `cblas_{s,d,c,z}gemm` and `cblas_{s,d}dot` are
+//! wired up today, so [`call_counts`] is not yet a crate-wide profile — it will report
+//! zero traffic for every other routine (trsm, syrk, gemv, axpy, scal, ...) no matter
+//! how often they're called. Instrumenting the rest is the same one-line
+//! [`count_call!`] call at each entry point's top, same as the two routines above.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use crate::types::blasint;
+
+/// Identifies one registered BLAS routine for instrumentation purposes, e.g.
+/// `SymbolId("cblas_dgemm")`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SymbolId(pub &'static str);
+
+/// Key dimension arguments for one instrumented call. Fields that don't apply to a
+/// given routine (e.g. `k` for a Level 1 call) are left `None`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CallInfo {
+    pub m: Option<blasint>,
+    pub n: Option<blasint>,
+    pub k: Option<blasint>,
+}
+
+/// A hook registered via [`set_call_hook`], invoked with the routine's identity and
+/// call shape every time an instrumented entry point runs.
+pub type CallHook = fn(SymbolId, &CallInfo);
+
+fn counts() -> &'static Mutex<HashMap<&'static str, u64>> {
+    static COUNTS: OnceLock<Mutex<HashMap<&'static str, u64>>> = OnceLock::new();
+    COUNTS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn call_hook() -> &'static Mutex<Option<CallHook>> {
+    static HOOK: OnceLock<Mutex<Option<CallHook>>> = OnceLock::new();
+    HOOK.get_or_init(|| Mutex::new(None))
+}
+
+/// Registers `hook` to be invoked on every subsequent instrumented call, replacing
+/// any hook registered previously.
+pub fn set_call_hook(hook: CallHook) {
+    *call_hook().lock().unwrap_or_else(|e| e.into_inner()) = Some(hook);
+}
+
+/// Removes the currently registered call hook, if any. Counting itself is unaffected.
+pub fn clear_call_hook() {
+    *call_hook().lock().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Bumps `symbol`'s counter and, if one is registered, invokes the call hook.
+///
+/// Called by the [`count_call!`] macro; not meant to be called directly.
+pub(crate) fn record_call(symbol: SymbolId, info: CallInfo) {
+    *counts()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry(symbol.0)
+        .or_insert(0) += 1;
+    if let Some(hook) = *call_hook().lock().unwrap_or_else(|e| e.into_inner()) {
+        hook(symbol, &info);
+    }
+}
+
+/// Returns a snapshot of the current per-routine call counts. Order is unspecified.
+///
+/// Only covers routines instrumented with [`count_call!`] — see this module's doc for
+/// the current (partial) list — so an uninstrumented routine reads as zero calls here
+/// regardless of how often it actually ran.
+pub fn call_counts() -> impl Iterator<Item = (&'static str, u64)> {
+    counts()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .map(|(&name, &count)| (name, count))
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+/// Resets every routine's call count to zero so a caller can measure a fresh region.
+/// Does not affect the registered call hook.
+pub fn reset_counts() {
+    counts().lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// Records one instrumented call under `$symbol` with shape `$info`. Expands to a
+/// statement-like `()` expression; it does not wrap or time the call itself.
+///
+/// ```ignore
+/// count_call!("cblas_dgemm", CallInfo { m: Some(m), n: Some(n), k: Some(k) });
+/// ```
+macro_rules! count_call {
+    ($symbol:expr, $info:expr) => {
+        $crate::counters::record_call($crate::counters::SymbolId($symbol), $info)
+    };
+}
+
+pub(crate) use count_call;
@@ -0,0 +1,397 @@
+//! Runtime-selectable backend loaded via `libloading` instead of static linking.
+//!
+//! [`crate::autoregister`] binds this crate to OpenBLAS at link time through
+//! `#[link(name = "openblas")]`; that's convenient for the common case but means the
+//! provider is baked into the compiled artifact. This module offers the opposite
+//! tradeoff: if the `CBLAS_INJECT_BACKEND` environment variable is set to a shared
+//! library path (e.g. `/opt/intel/mkl/lib/libmkl_rt.so`, a BLIS build, or an Accelerate
+//! shim) when the process starts, a `ctor` resolves every Fortran symbol this crate
+//! knows about from that library and registers it — no recompilation needed to switch
+//! providers, and a single injected cdylib can be `LD_PRELOAD`ed against whichever
+//! backend is available on a given machine.
+//!
+//! Symbols the library doesn't export are registered as panicking stubs (see
+//! [`register_all_or_stub`]; the few routines the stub table has no entry for are left
+//! unregistered, see [`register_stub_by_name`]) rather than left unregistered, so the failure a caller
+//! sees names the specific missing routine instead of crashing the whole load or
+//! falling through to the generic "not registered" message for an unrelated reason.
+//!
+//! If `CBLAS_INJECT_BACKEND` is unset, this module does nothing, leaving every backend
+//! slot to be registered some other way (the static OpenBLAS ctor, a manual
+//! `register_*` call, or [`crate::registry::register_all`]).
+
+use std::env;
+use std::ffi::c_void;
+use std::sync::{Mutex, OnceLock};
+
+use libloading::Library;
+
+use crate::backend::*;
+use crate::registry::{
+    register_all_detailed, register_all_or_stub, register_by_name, RegisterError,
+    RegistrationReport, ALL_SYMBOLS,
+};
+use crate::types::ComplexReturnStyle;
+
+/// Keeps the dynamically loaded library mapped for the rest of the process's life.
+/// Dropping it would unmap the code behind every pointer `register_all_report_missing`
+/// just stored, turning every future call through this backend into a use-after-free.
+static LOADED_BACKEND: OnceLock<Library> = OnceLock::new();
+
+/// Keeps every library opened by [`load_backend`] mapped for the rest of the process's
+/// life, for the same reason as [`LOADED_BACKEND`] above. Unlike that single-slot ctor
+/// path, [`load_backend`] can be called more than once (e.g. to layer a vendor BLAS over
+/// the reference fallback for the routines it's missing), so this needs to hold an
+/// unbounded number of handles rather than just one.
+static LOADED_BACKENDS: Mutex<Vec<Library>> = Mutex::new(Vec::new());
+
+#[ctor::ctor]
+fn load_backend_from_env() {
+    let Ok(path) = env::var("CBLAS_INJECT_BACKEND") else {
+        return;
+    };
+
+    let lib = match unsafe { Library::new(&path) } {
+        Ok(lib) => lib,
+        Err(e) => {
+            eprintln!("cblas-inject: failed to load CBLAS_INJECT_BACKEND={path}: {e}");
+            return;
+        }
+    };
+    let lib = LOADED_BACKEND.get_or_init(|| lib);
+
+    let resolver = |name: &str| -> Option<*const c_void> {
+        unsafe {
+            lib.get::<*const c_void>(name.as_bytes())
+                .ok()
+                .map(|sym| *sym)
+        }
+    };
+
+    if let Some(cdotu) = resolver("cdotu_") {
+        // Falls back to `ReturnValue` if the probe can't pin down a convention (e.g.
+        // `cdotu_` isn't actually cdotu-shaped); unlike the `register_cdotu` auto-probe
+        // path, there's no `cblas_xerbla` routine name to report this against here.
+        set_complex_return_style(
+            unsafe { probe_complex_return_style(cdotu as *const ()) }
+                .unwrap_or(ComplexReturnStyle::ReturnValue),
+        );
+    }
+
+    unsafe {
+        register_all_or_stub(resolver);
+    }
+}
+
+/// Resolves and registers every backend slot this crate knows about directly from an
+/// already-opened shared library handle, using the default gfortran/OpenBLAS symbol
+/// mangling (lowercase routine name plus a trailing underscore, e.g. `dgemm_`).
+///
+/// Unlike the `CBLAS_INJECT_BACKEND` ctor above — which only runs if that environment
+/// variable is set and otherwise falls back to stubs for missing symbols — this is a
+/// plain function a caller can invoke directly against a `libloading::Library` it opened
+/// itself, and returns a [`RegistrationReport`] instead of silently leaving gaps, so the
+/// caller can decide whether a partial match is acceptable.
+///
+/// # Safety
+///
+/// `handle` must stay loaded for as long as any registered pointer might be called (the
+/// backend statics this stores into are effectively `'static`), and every symbol
+/// `handle` exports under the default mangling must be a valid Fortran BLAS function
+/// pointer with the signature CBLAS expects for that routine.
+pub unsafe fn register_from_library(handle: &Library) -> RegistrationReport {
+    register_from_library_with_mangle(handle, |name| format!("{name}_"))
+}
+
+/// Like [`register_from_library`], but with a caller-supplied name-mangling scheme
+/// instead of the default trailing-underscore convention — e.g. `|name| name.to_string()`
+/// for a library that exports unmangled names, or `|name| format!("{name}__")` for a
+/// gfortran build with `-fsecond-underscore`.
+///
+/// `mangle` receives the routine's bare name without any trailing underscore (`"dgemm"`,
+/// `"zher2"`, ...) and must return the exact symbol `handle` exports for it.
+///
+/// # Safety
+///
+/// Same requirement as [`register_from_library`].
+pub unsafe fn register_from_library_with_mangle(
+    handle: &Library,
+    mangle: impl Fn(&str) -> String,
+) -> RegistrationReport {
+    let resolver = |canonical_name: &str| -> Option<*const c_void> {
+        // `register_all_detailed`'s table calls `resolver` with the trailing-underscore
+        // convention (`"dgemm_"`); strip that back off before handing the bare routine
+        // name to `mangle`.
+        let bare = canonical_name.strip_suffix('_').unwrap_or(canonical_name);
+        let symbol = mangle(bare);
+        unsafe {
+            handle
+                .get::<*const c_void>(symbol.as_bytes())
+                .ok()
+                .map(|sym| *sym)
+        }
+    };
+    register_all_detailed(resolver)
+}
+
+/// What happened to each symbol this crate knows about during a [`load_backend`] call.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LoadReport {
+    /// Bare routine names (`"dgemm"`, not `"dgemm_"`) whose symbol was found in the
+    /// library and registered.
+    pub found: Vec<String>,
+    /// Bare routine names the library doesn't export under either the bare or
+    /// trailing-underscore name.
+    pub missing: Vec<String>,
+    /// Bare routine names whose symbol was found, but whose backend slot was already
+    /// registered (by an earlier `load_backend` call, a static ctor, or a manual
+    /// `register_*`), so this symbol was left in place instead of overwriting it.
+    pub already_registered: Vec<String>,
+}
+
+/// Why [`load_backend`] couldn't load a library.
+#[derive(Debug)]
+pub enum LoadError {
+    /// `libloading` itself failed to open the path (missing file, unresolved `NEEDED`
+    /// entries, wrong ELF class, ...).
+    Open(libloading::Error),
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Open(e) => write!(f, "failed to open backend library: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Opens the shared library at `path` (e.g. `libopenblas.so`, `libmkl_rt.so`,
+/// `libblas.so`) and registers every symbol in [`crate::registry::ALL_SYMBOLS`] it
+/// exports, trying both the bare name (`"dgemm"`) and the trailing-underscore Fortran
+/// mangling (`"dgemm_"`) for each.
+///
+/// Unlike [`register_from_library`], which registers every slot under a single mangling
+/// convention and hands back an error-free [`RegistrationReport`], this is meant as the
+/// one-call "point at a BLAS `.so` and go" entry point: it owns opening the library,
+/// tries both manglings itself, and keeps the library mapped for the rest of the
+/// process's life so the pointers it registers stay valid.
+///
+/// # Safety
+///
+/// Every symbol `path` exports under a name in [`crate::registry::ALL_SYMBOLS`] (bare or
+/// underscore-mangled) must be a valid Fortran BLAS function pointer with the signature
+/// CBLAS expects for that routine.
+pub unsafe fn load_backend(path: &str) -> Result<LoadReport, LoadError> {
+    let lib = unsafe { Library::new(path) }.map_err(LoadError::Open)?;
+
+    let mut report = LoadReport::default();
+    for &name in ALL_SYMBOLS {
+        let mangled = format!("{name}_");
+        let resolved = unsafe {
+            lib.get::<*const c_void>(name.as_bytes())
+                .or_else(|_| lib.get::<*const c_void>(mangled.as_bytes()))
+                .ok()
+                .map(|sym| *sym)
+        };
+
+        let Some(ptr) = resolved else {
+            report.missing.push(name.to_string());
+            continue;
+        };
+
+        match unsafe { register_by_name(name, ptr) } {
+            Ok(()) => report.found.push(name.to_string()),
+            Err(RegisterError::AlreadyRegistered(_)) => {
+                report.already_registered.push(name.to_string())
+            }
+            Err(RegisterError::UnknownSymbol(_)) => {
+                unreachable!("ALL_SYMBOLS only contains names register_by_name recognizes")
+            }
+        }
+    }
+
+    LOADED_BACKENDS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(lib);
+    Ok(report)
+}
+
+/// Registers the panicking stub for `name` (a Fortran symbol name like `"dgemm_"`), or
+/// does nothing if `name` has no entry in the table below. The table covers a subset of
+/// [`ALL_SYMBOLS`]: `isamin_`/`idamin_`/`icamin_`/`izamin_`, `scabs1_`/`dcabs1_`,
+/// `ssyrk_`/`csyrk_`/`zsyrk_`, `ssyr2k_`/`csyr2k_`/`zsyr2k_`, `cherk_`/`zherk_` and
+/// `cher2k_`/`zher2k_` have no stub and are left unregistered.
+pub(crate) fn register_stub_by_name(name: &str) {
+    macro_rules! stub {
+        ($name:literal, $register:ident, $stub:ident) => {
+            if name == $name {
+                unsafe { $register(std::mem::transmute($stub as *const ())) };
+                return;
+            }
+        };
+    }
+
+    stub!("srot_", register_srot, stub_trap);
+    stub!("srotg_", register_srotg, stub_trap);
+    stub!("srotm_", register_srotm, stub_trap);
+    stub!("srotmg_", register_srotmg, stub_trap);
+    stub!("sswap_", register_sswap, stub_trap);
+    stub!("scopy_", register_scopy, stub_trap);
+    stub!("saxpy_", register_saxpy, stub_trap);
+    stub!("sscal_", register_sscal, stub_trap);
+    stub!("sdot_", register_sdot, stub_trap);
+    stub!("sdsdot_", register_sdsdot, stub_trap);
+    stub!("snrm2_", register_snrm2, stub_trap);
+    stub!("sasum_", register_sasum, stub_trap);
+    stub!("isamax_", register_isamax, stub_trap);
+
+    stub!("drot_", register_drot, stub_trap);
+    stub!("drotg_", register_drotg, stub_trap);
+    stub!("drotm_", register_drotm, stub_trap);
+    stub!("drotmg_", register_drotmg, stub_trap);
+    stub!("dswap_", register_dswap, stub_trap);
+    stub!("dcopy_", register_dcopy, stub_trap);
+    stub!("daxpy_", register_daxpy, stub_trap);
+    stub!("dscal_", register_dscal, stub_trap);
+    stub!("ddot_", register_ddot, stub_trap);
+    stub!("dsdot_", register_dsdot, stub_trap);
+    stub!("dnrm2_", register_dnrm2, stub_trap);
+    stub!("dasum_", register_dasum, stub_trap);
+    stub!("idamax_", register_idamax, stub_trap);
+
+    stub!("cswap_", register_cswap, stub_trap);
+    stub!("ccopy_", register_ccopy, stub_trap);
+    stub!("caxpy_", register_caxpy, stub_trap);
+    stub!("cscal_", register_cscal, stub_trap);
+    stub!("csscal_", register_csscal, stub_trap);
+    stub!("cdotu_", register_cdotu, stub_trap);
+    stub!("cdotc_", register_cdotc, stub_trap);
+    stub!("scnrm2_", register_scnrm2, stub_trap);
+    stub!("scasum_", register_scasum, stub_trap);
+    stub!("icamax_", register_icamax, stub_trap);
+    stub!("csrot_", register_csrot, stub_trap);
+
+    stub!("zswap_", register_zswap, stub_trap);
+    stub!("zcopy_", register_zcopy, stub_trap);
+    stub!("zaxpy_", register_zaxpy, stub_trap);
+    stub!("zscal_", register_zscal, stub_trap);
+    stub!("zdscal_", register_zdscal, stub_trap);
+    stub!("zdotu_", register_zdotu, stub_trap);
+    stub!("zdotc_", register_zdotc, stub_trap);
+    stub!("dznrm2_", register_dznrm2, stub_trap);
+    stub!("dzasum_", register_dzasum, stub_trap);
+    stub!("izamax_", register_izamax, stub_trap);
+    stub!("zdrot_", register_zdrot, stub_trap);
+
+    stub!("sgemv_", register_sgemv, stub_trap);
+    stub!("dgemv_", register_dgemv, stub_trap);
+    stub!("cgemv_", register_cgemv, stub_trap);
+    stub!("zgemv_", register_zgemv, stub_trap);
+    stub!("sgbmv_", register_sgbmv, stub_trap);
+    stub!("dgbmv_", register_dgbmv, stub_trap);
+    stub!("cgbmv_", register_cgbmv, stub_trap);
+    stub!("zgbmv_", register_zgbmv, stub_trap);
+
+    stub!("ssymv_", register_ssymv, stub_trap);
+    stub!("dsymv_", register_dsymv, stub_trap);
+    stub!("chemv_", register_chemv, stub_trap);
+    stub!("zhemv_", register_zhemv, stub_trap);
+    stub!("ssbmv_", register_ssbmv, stub_trap);
+    stub!("dsbmv_", register_dsbmv, stub_trap);
+    stub!("chbmv_", register_chbmv, stub_trap);
+    stub!("zhbmv_", register_zhbmv, stub_trap);
+
+    stub!("strmv_", register_strmv, stub_trap);
+    stub!("dtrmv_", register_dtrmv, stub_trap);
+    stub!("ctrmv_", register_ctrmv, stub_trap);
+    stub!("ztrmv_", register_ztrmv, stub_trap);
+    stub!("strsv_", register_strsv, stub_trap);
+    stub!("dtrsv_", register_dtrsv, stub_trap);
+    stub!("ctrsv_", register_ctrsv, stub_trap);
+    stub!("ztrsv_", register_ztrsv, stub_trap);
+    stub!("stbmv_", register_stbmv, stub_trap);
+    stub!("dtbmv_", register_dtbmv, stub_trap);
+    stub!("ctbmv_", register_ctbmv, stub_trap);
+    stub!("ztbmv_", register_ztbmv, stub_trap);
+    stub!("stbsv_", register_stbsv, stub_trap);
+    stub!("dtbsv_", register_dtbsv, stub_trap);
+    stub!("ctbsv_", register_ctbsv, stub_trap);
+    stub!("ztbsv_", register_ztbsv, stub_trap);
+
+    stub!("sger_", register_sger, stub_trap);
+    stub!("dger_", register_dger, stub_trap);
+    stub!("cgeru_", register_cgeru, stub_trap);
+    stub!("cgerc_", register_cgerc, stub_trap);
+    stub!("zgeru_", register_zgeru, stub_trap);
+    stub!("zgerc_", register_zgerc, stub_trap);
+    stub!("ssyr_", register_ssyr, stub_trap);
+    stub!("dsyr_", register_dsyr, stub_trap);
+    stub!("cher_", register_cher, stub_trap);
+    stub!("zher_", register_zher, stub_trap);
+    stub!("ssyr2_", register_ssyr2, stub_trap);
+    stub!("dsyr2_", register_dsyr2, stub_trap);
+    stub!("cher2_", register_cher2, stub_trap);
+    stub!("zher2_", register_zher2, stub_trap);
+
+    stub!("sspmv_", register_sspmv, stub_trap);
+    stub!("dspmv_", register_dspmv, stub_trap);
+    stub!("chpmv_", register_chpmv, stub_trap);
+    stub!("zhpmv_", register_zhpmv, stub_trap);
+    stub!("stpmv_", register_stpmv, stub_trap);
+    stub!("dtpmv_", register_dtpmv, stub_trap);
+    stub!("ctpmv_", register_ctpmv, stub_trap);
+    stub!("ztpmv_", register_ztpmv, stub_trap);
+    stub!("stpsv_", register_stpsv, stub_trap);
+    stub!("dtpsv_", register_dtpsv, stub_trap);
+    stub!("ctpsv_", register_ctpsv, stub_trap);
+    stub!("ztpsv_", register_ztpsv, stub_trap);
+
+    stub!("sspr_", register_sspr, stub_trap);
+    stub!("dspr_", register_dspr, stub_trap);
+    stub!("chpr_", register_chpr, stub_trap);
+    stub!("zhpr_", register_zhpr, stub_trap);
+    stub!("sspr2_", register_sspr2, stub_trap);
+    stub!("dspr2_", register_dspr2, stub_trap);
+    stub!("chpr2_", register_chpr2, stub_trap);
+    stub!("zhpr2_", register_zhpr2, stub_trap);
+
+    stub!("sgemm_", register_sgemm, stub_trap);
+    stub!("dgemm_", register_dgemm, stub_trap);
+    stub!("cgemm_", register_cgemm, stub_trap);
+    stub!("zgemm_", register_zgemm, stub_trap);
+    stub!("dsymm_", register_dsymm, stub_trap);
+    stub!("ssymm_", register_ssymm, stub_trap);
+    stub!("csymm_", register_csymm, stub_trap);
+    stub!("zsymm_", register_zsymm, stub_trap);
+    stub!("chemm_", register_chemm, stub_trap);
+    stub!("zhemm_", register_zhemm, stub_trap);
+    stub!("dsyrk_", register_dsyrk, stub_trap);
+    stub!("dsyr2k_", register_dsyr2k, stub_trap);
+    stub!("dtrmm_", register_dtrmm, stub_trap);
+    stub!("strmm_", register_strmm, stub_trap);
+    stub!("ctrmm_", register_ctrmm, stub_trap);
+    stub!("ztrmm_", register_ztrmm, stub_trap);
+    stub!("dtrsm_", register_dtrsm, stub_trap);
+    stub!("strsm_", register_strsm, stub_trap);
+    stub!("ctrsm_", register_ctrsm, stub_trap);
+    stub!("ztrsm_", register_ztrsm, stub_trap);
+}
+
+/// Diverges immediately, reporting that the calling routine isn't implemented by the
+/// dynamically loaded backend.
+///
+/// Transmuted to every `*FnPtr` type [`register_stub_by_name`] needs, regardless of
+/// that type's real arity, argument types, or return type: since this function
+/// diverges without ever reading an argument, the mismatched calling convention is
+/// harmless — nothing here inspects the registers or stack slots the caller set up for
+/// arguments it never receives.
+extern "C" fn stub_trap() -> ! {
+    panic!(
+        "cblas-inject: routine not implemented by the dynamically loaded \
+         CBLAS_INJECT_BACKEND (the symbol was absent, so this call was registered as a \
+         stub instead of being left unregistered)"
+    );
+}
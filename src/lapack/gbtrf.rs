@@ -0,0 +1,67 @@
+//! Banded LU factorization (GBTRF) — LAPACKE-style interface.
+//!
+//! `ab` uses the usual LAPACK band storage: column `j` of the original `m x n` matrix
+//! is stored in column `j` of `ab`, in rows `max(1, j - ku)..=min(m, j + kl)` mapped to
+//! physical rows `kl + ku + 1 + i - j`. The first `kl` rows of `ab` are left for the
+//! factorization's fill-in, so `ldab` must be at least `2 * kl + ku + 1`; for row/column-major
+//! conversion purposes `ab` is just a dense `(2 * kl + ku + 1) x n` array like any
+//! other, since the band layout itself doesn't depend on the major order — only which
+//! index (row-major or column-major) walks contiguously through memory does.
+
+use num_complex::{Complex32, Complex64};
+
+use super::util::{from_col_major, to_col_major};
+use crate::lapack_backend::{get_cgbtrf, get_dgbtrf, get_sgbtrf, get_zgbtrf};
+use crate::types::{blasint, CblasColMajor, CblasRowMajor, CBLAS_ORDER};
+
+macro_rules! lapacke_gbtrf {
+    ($name:ident, $get:ident, $ty:ty) => {
+        /// Computes the LU factorization of a general banded `m x n` matrix (`kl`
+        /// sub-diagonals, `ku` super-diagonals) with partial pivoting, stored in `ab`
+        /// per the band layout documented on this module. `ipiv` (length
+        /// `min(m, n)`) records the pivot applied to row `i`.
+        ///
+        /// Returns the Fortran `info` value: `0` on success, `-i` if argument `i` was
+        /// illegal, `i > 0` if `U(i, i)` is exactly zero (the factorization completed,
+        /// but `U` is singular).
+        ///
+        /// # Safety
+        ///
+        /// - `ab` must point to a valid `ldab x n` band-storage array, `ldab >= 2 * kl
+        ///   + ku + 1`
+        /// - `ipiv` must point to a buffer of at least `min(m, n)` elements
+        /// - the backend gbtrf routine must already be registered
+        #[allow(non_snake_case, clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            order: CBLAS_ORDER,
+            m: blasint,
+            n: blasint,
+            kl: blasint,
+            ku: blasint,
+            ab: *mut $ty,
+            ldab: blasint,
+            ipiv: *mut blasint,
+        ) -> blasint {
+            let gbtrf = $get();
+            let mut info: blasint = 0;
+            match order {
+                CblasColMajor => {
+                    gbtrf(&m, &n, &kl, &ku, ab, &ldab, ipiv, &mut info);
+                }
+                CblasRowMajor => {
+                    let rows = 2 * kl + ku + 1;
+                    let mut buf = to_col_major(order, rows, n, ab, ldab);
+                    let col_ld = rows.max(1);
+                    gbtrf(&m, &n, &kl, &ku, buf.as_mut_ptr(), &col_ld, ipiv, &mut info);
+                    from_col_major(order, rows, n, &buf, ab, ldab);
+                }
+            }
+            info
+        }
+    };
+}
+
+lapacke_gbtrf!(LAPACKE_sgbtrf, get_sgbtrf, f32);
+lapacke_gbtrf!(LAPACKE_dgbtrf, get_dgbtrf, f64);
+lapacke_gbtrf!(LAPACKE_cgbtrf, get_cgbtrf, Complex32);
+lapacke_gbtrf!(LAPACKE_zgbtrf, get_zgbtrf, Complex64);
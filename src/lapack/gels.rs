@@ -0,0 +1,197 @@
+//! Linear least squares (GELS) — LAPACKE-style interface.
+
+use std::ffi::c_char;
+
+use num_complex::{Complex32, Complex64};
+
+use super::util::{from_col_major, to_col_major};
+use crate::lapack_backend::{get_cgels, get_dgels, get_sgels, get_zgels};
+use crate::types::{blasint, CblasColMajor, CblasRowMajor, CBLAS_ORDER};
+
+macro_rules! lapacke_gels_real {
+    ($name:ident, $get:ident, $ty:ty) => {
+        /// Solves the full-rank linear least-squares problem `min ||B - op(A)*X||` (or
+        /// the minimum-norm problem, when `op(A)*X = B` is underdetermined) via a QR
+        /// (`m >= n`) or LQ (`m < n`) factorization of the `m x n` matrix `a`, which is
+        /// overwritten with factorization details. `b` (leading
+        /// dimension `ldb >= max(m, n)`) holds the right-hand side on input and the
+        /// solution (in its leading `n` or `m` rows, per `trans`) on output.
+        ///
+        /// Runs the usual LAPACK workspace-query convention (`lwork == -1`) internally.
+        ///
+        /// Returns the Fortran `info` value: `0` on success, `-i` if argument `i` was
+        /// illegal.
+        ///
+        /// # Safety
+        ///
+        /// - `a` must point to a valid `m x n` matrix with leading dimension `lda`
+        /// - `b` must point to a valid `max(m, n) x nrhs` matrix with leading
+        ///   dimension `ldb`
+        /// - the backend gels routine must already be registered
+        #[allow(non_snake_case, clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            order: CBLAS_ORDER,
+            trans: c_char,
+            m: blasint,
+            n: blasint,
+            nrhs: blasint,
+            a: *mut $ty,
+            lda: blasint,
+            b: *mut $ty,
+            ldb: blasint,
+        ) -> blasint {
+            let gels = $get();
+            let mut info: blasint = 0;
+            let rows = m.max(n);
+
+            let query_lwork: blasint = -1;
+            let mut work_size: $ty = 0.0;
+
+            macro_rules! run {
+                ($a_ptr:expr, $a_ld:expr, $b_ptr:expr, $b_ld:expr) => {{
+                    gels(
+                        &trans,
+                        &m,
+                        &n,
+                        &nrhs,
+                        $a_ptr,
+                        $a_ld,
+                        $b_ptr,
+                        $b_ld,
+                        &mut work_size,
+                        &query_lwork,
+                        &mut info,
+                    );
+                    let lwork = (work_size as blasint).max(1);
+                    let mut work = vec![0.0 as $ty; lwork as usize];
+                    let lwork = work.len() as blasint;
+                    gels(
+                        &trans,
+                        &m,
+                        &n,
+                        &nrhs,
+                        $a_ptr,
+                        $a_ld,
+                        $b_ptr,
+                        $b_ld,
+                        work.as_mut_ptr(),
+                        &lwork,
+                        &mut info,
+                    );
+                }};
+            }
+
+            match order {
+                CblasColMajor => run!(a, &lda, b, &ldb),
+                CblasRowMajor => {
+                    let mut a_buf = to_col_major(order, m, n, a, lda);
+                    let mut b_buf = to_col_major(order, rows, nrhs, b, ldb);
+                    let a_col_ld = m.max(1);
+                    let b_col_ld = rows.max(1);
+                    run!(
+                        a_buf.as_mut_ptr(),
+                        &a_col_ld,
+                        b_buf.as_mut_ptr(),
+                        &b_col_ld
+                    );
+                    from_col_major(order, m, n, &a_buf, a, lda);
+                    from_col_major(order, rows, nrhs, &b_buf, b, ldb);
+                }
+            }
+            info
+        }
+    };
+}
+
+lapacke_gels_real!(LAPACKE_sgels, get_sgels, f32);
+lapacke_gels_real!(LAPACKE_dgels, get_dgels, f64);
+
+macro_rules! lapacke_gels_complex {
+    ($name:ident, $get:ident, $ty:ty) => {
+        /// See [`LAPACKE_sgels`]; complex variant, so `trans` must be `'N'` or `'C'`
+        /// (no plain transpose, only conjugate transpose, per the Fortran routine),
+        /// and `work`'s query size is read from the real part of the query result.
+        ///
+        /// # Safety
+        ///
+        /// - `a` must point to a valid `m x n` matrix with leading dimension `lda`
+        /// - `b` must point to a valid `max(m, n) x nrhs` matrix with leading
+        ///   dimension `ldb`
+        /// - the backend gels routine must already be registered
+        #[allow(non_snake_case, clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            order: CBLAS_ORDER,
+            trans: c_char,
+            m: blasint,
+            n: blasint,
+            nrhs: blasint,
+            a: *mut $ty,
+            lda: blasint,
+            b: *mut $ty,
+            ldb: blasint,
+        ) -> blasint {
+            let gels = $get();
+            let mut info: blasint = 0;
+            let rows = m.max(n);
+
+            let query_lwork: blasint = -1;
+            let mut work_size: $ty = Default::default();
+
+            macro_rules! run {
+                ($a_ptr:expr, $a_ld:expr, $b_ptr:expr, $b_ld:expr) => {{
+                    gels(
+                        &trans,
+                        &m,
+                        &n,
+                        &nrhs,
+                        $a_ptr,
+                        $a_ld,
+                        $b_ptr,
+                        $b_ld,
+                        &mut work_size,
+                        &query_lwork,
+                        &mut info,
+                    );
+                    let lwork = (work_size.re as blasint).max(1);
+                    let mut work = vec![<$ty>::default(); lwork as usize];
+                    let lwork = work.len() as blasint;
+                    gels(
+                        &trans,
+                        &m,
+                        &n,
+                        &nrhs,
+                        $a_ptr,
+                        $a_ld,
+                        $b_ptr,
+                        $b_ld,
+                        work.as_mut_ptr(),
+                        &lwork,
+                        &mut info,
+                    );
+                }};
+            }
+
+            match order {
+                CblasColMajor => run!(a, &lda, b, &ldb),
+                CblasRowMajor => {
+                    let mut a_buf = to_col_major(order, m, n, a, lda);
+                    let mut b_buf = to_col_major(order, rows, nrhs, b, ldb);
+                    let a_col_ld = m.max(1);
+                    let b_col_ld = rows.max(1);
+                    run!(
+                        a_buf.as_mut_ptr(),
+                        &a_col_ld,
+                        b_buf.as_mut_ptr(),
+                        &b_col_ld
+                    );
+                    from_col_major(order, m, n, &a_buf, a, lda);
+                    from_col_major(order, rows, nrhs, &b_buf, b, ldb);
+                }
+            }
+            info
+        }
+    };
+}
+
+lapacke_gels_complex!(LAPACKE_cgels, get_cgels, Complex32);
+lapacke_gels_complex!(LAPACKE_zgels, get_zgels, Complex64);
@@ -0,0 +1,119 @@
+//! QR factorization (GEQRF) — LAPACKE-style interface.
+
+use num_complex::{Complex32, Complex64};
+
+use super::util::{from_col_major, to_col_major};
+use crate::lapack_backend::{get_cgeqrf, get_dgeqrf, get_sgeqrf, get_zgeqrf};
+use crate::types::{blasint, CblasColMajor, CblasRowMajor, CBLAS_ORDER};
+
+macro_rules! lapacke_geqrf {
+    ($name:ident, $get:ident, $ty:ty) => {
+        /// Computes the QR factorization of a general `m x n` matrix: `A = Q * R`. `a`
+        /// is overwritten with `R` (upper triangular, in the upper triangle) and the
+        /// Householder reflectors that implicitly represent `Q` (below the diagonal);
+        /// `tau` (length `min(m, n)`) holds the scalar factors of those reflectors.
+        ///
+        /// Unlike the Fortran routine, this wrapper doesn't take `work`/`lwork`: it
+        /// runs the usual LAPACK workspace-query convention internally (`lwork == -1`)
+        /// to size a scratch buffer, then calls again to do the real factorization.
+        ///
+        /// Returns the Fortran `info` value: `0` on success, `-i` if argument `i` was
+        /// illegal.
+        ///
+        /// # Safety
+        ///
+        /// - `a` must point to a valid `m x n` matrix with leading dimension `lda`
+        /// - `tau` must point to a buffer of at least `min(m, n)` elements
+        /// - the backend geqrf routine must already be registered
+        #[allow(non_snake_case)]
+        pub unsafe fn $name(
+            order: CBLAS_ORDER,
+            m: blasint,
+            n: blasint,
+            a: *mut $ty,
+            lda: blasint,
+            tau: *mut $ty,
+        ) -> blasint {
+            let geqrf = $get();
+            let mut info: blasint = 0;
+
+            let query_lwork: blasint = -1;
+            let mut work_size: $ty = Default::default();
+
+            match order {
+                CblasColMajor => {
+                    geqrf(&m, &n, a, &lda, tau, &mut work_size, &query_lwork, &mut info);
+                    let lwork = work_size.re_as_blasint();
+                    let mut work = vec![<$ty>::default(); lwork.max(1) as usize];
+                    let lwork = work.len() as blasint;
+                    geqrf(&m, &n, a, &lda, tau, work.as_mut_ptr(), &lwork, &mut info);
+                }
+                CblasRowMajor => {
+                    let mut buf = to_col_major(order, m, n, a, lda);
+                    let col_ld = m.max(1);
+                    geqrf(
+                        &m,
+                        &n,
+                        buf.as_mut_ptr(),
+                        &col_ld,
+                        tau,
+                        &mut work_size,
+                        &query_lwork,
+                        &mut info,
+                    );
+                    let lwork = work_size.re_as_blasint();
+                    let mut work = vec![<$ty>::default(); lwork.max(1) as usize];
+                    let lwork = work.len() as blasint;
+                    geqrf(
+                        &m,
+                        &n,
+                        buf.as_mut_ptr(),
+                        &col_ld,
+                        tau,
+                        work.as_mut_ptr(),
+                        &lwork,
+                        &mut info,
+                    );
+                    from_col_major(order, m, n, &buf, a, lda);
+                }
+            }
+            info
+        }
+    };
+}
+
+/// Extracts the optimal `lwork` a workspace query wrote into `work[0]`: the real part,
+/// rounded down, for both real and complex scalar types (LAPACK always reports the
+/// query result in the real part, even for the complex routines).
+trait WorkspaceQueryResult {
+    fn re_as_blasint(self) -> blasint;
+}
+
+impl WorkspaceQueryResult for f32 {
+    fn re_as_blasint(self) -> blasint {
+        self as blasint
+    }
+}
+
+impl WorkspaceQueryResult for f64 {
+    fn re_as_blasint(self) -> blasint {
+        self as blasint
+    }
+}
+
+impl WorkspaceQueryResult for Complex32 {
+    fn re_as_blasint(self) -> blasint {
+        self.re as blasint
+    }
+}
+
+impl WorkspaceQueryResult for Complex64 {
+    fn re_as_blasint(self) -> blasint {
+        self.re as blasint
+    }
+}
+
+lapacke_geqrf!(LAPACKE_sgeqrf, get_sgeqrf, f32);
+lapacke_geqrf!(LAPACKE_dgeqrf, get_dgeqrf, f64);
+lapacke_geqrf!(LAPACKE_cgeqrf, get_cgeqrf, Complex32);
+lapacke_geqrf!(LAPACKE_zgeqrf, get_zgeqrf, Complex64);
@@ -0,0 +1,69 @@
+//! General linear system solve (GESV) — LAPACKE-style interface.
+
+use num_complex::{Complex32, Complex64};
+
+use super::util::{from_col_major, to_col_major};
+use crate::lapack_backend::{get_cgesv, get_dgesv, get_sgesv, get_zgesv};
+use crate::types::{blasint, CblasColMajor, CblasRowMajor, CBLAS_ORDER};
+
+macro_rules! lapacke_gesv {
+    ($name:ident, $get:ident, $ty:ty) => {
+        /// Solves `A * X = B` for a general `n x n` matrix `A` via LU factorization
+        /// with partial pivoting. `a` is overwritten with the factors (as a GETRF call
+        /// would leave them), `ipiv` (length `n`) with the pivot indices, and `b`
+        /// (`n x nrhs`) with the solution `X`.
+        ///
+        /// Returns the Fortran `info` value: `0` on success, `-i` if argument `i` was
+        /// illegal, `i > 0` if `U(i, i)` is exactly zero (no solution was computed).
+        ///
+        /// # Safety
+        ///
+        /// - `a` must point to a valid `n x n` matrix with leading dimension `lda`
+        /// - `b` must point to a valid `n x nrhs` matrix with leading dimension `ldb`
+        /// - `ipiv` must point to a buffer of at least `n` elements
+        /// - the backend gesv routine must already be registered
+        #[allow(non_snake_case, clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            order: CBLAS_ORDER,
+            n: blasint,
+            nrhs: blasint,
+            a: *mut $ty,
+            lda: blasint,
+            ipiv: *mut blasint,
+            b: *mut $ty,
+            ldb: blasint,
+        ) -> blasint {
+            let gesv = $get();
+            let mut info: blasint = 0;
+            match order {
+                CblasColMajor => {
+                    gesv(&n, &nrhs, a, &lda, ipiv, b, &ldb, &mut info);
+                }
+                CblasRowMajor => {
+                    let mut a_buf = to_col_major(order, n, n, a, lda);
+                    let mut b_buf = to_col_major(order, n, nrhs, b, ldb);
+                    let a_col_ld = n.max(1);
+                    let b_col_ld = n.max(1);
+                    gesv(
+                        &n,
+                        &nrhs,
+                        a_buf.as_mut_ptr(),
+                        &a_col_ld,
+                        ipiv,
+                        b_buf.as_mut_ptr(),
+                        &b_col_ld,
+                        &mut info,
+                    );
+                    from_col_major(order, n, n, &a_buf, a, lda);
+                    from_col_major(order, n, nrhs, &b_buf, b, ldb);
+                }
+            }
+            info
+        }
+    };
+}
+
+lapacke_gesv!(LAPACKE_sgesv, get_sgesv, f32);
+lapacke_gesv!(LAPACKE_dgesv, get_dgesv, f64);
+lapacke_gesv!(LAPACKE_cgesv, get_cgesv, Complex32);
+lapacke_gesv!(LAPACKE_zgesv, get_zgesv, Complex64);
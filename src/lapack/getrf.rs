@@ -0,0 +1,55 @@
+//! LU factorization (GETRF) — LAPACKE-style interface.
+
+use num_complex::{Complex32, Complex64};
+
+use super::util::{from_col_major, to_col_major};
+use crate::lapack_backend::{get_cgetrf, get_dgetrf, get_sgetrf, get_zgetrf};
+use crate::types::{blasint, CblasColMajor, CblasRowMajor, CBLAS_ORDER};
+
+macro_rules! lapacke_getrf {
+    ($name:ident, $get:ident, $ty:ty) => {
+        /// Computes the LU factorization of a general `m x n` matrix with partial
+        /// pivoting: `A = P * L * U`. `a` is overwritten with `L` (unit lower
+        /// triangular, diagonal implicit) and `U` (upper triangular); `ipiv` (length
+        /// `min(m, n)`) records the pivot that was applied to row `i`.
+        ///
+        /// Returns the Fortran `info` value: `0` on success, `-i` if argument `i` was
+        /// illegal, `i > 0` if `U(i, i)` is exactly zero (the factorization completed,
+        /// but `U` is singular).
+        ///
+        /// # Safety
+        ///
+        /// - `a` must point to a valid `m x n` matrix with leading dimension `lda`
+        /// - `ipiv` must point to a buffer of at least `min(m, n)` elements
+        /// - the backend getrf routine must already be registered
+        #[allow(non_snake_case)]
+        pub unsafe fn $name(
+            order: CBLAS_ORDER,
+            m: blasint,
+            n: blasint,
+            a: *mut $ty,
+            lda: blasint,
+            ipiv: *mut blasint,
+        ) -> blasint {
+            let getrf = $get();
+            let mut info: blasint = 0;
+            match order {
+                CblasColMajor => {
+                    getrf(&m, &n, a, &lda, ipiv, &mut info);
+                }
+                CblasRowMajor => {
+                    let mut buf = to_col_major(order, m, n, a, lda);
+                    let col_ld = m.max(1);
+                    getrf(&m, &n, buf.as_mut_ptr(), &col_ld, ipiv, &mut info);
+                    from_col_major(order, m, n, &buf, a, lda);
+                }
+            }
+            info
+        }
+    };
+}
+
+lapacke_getrf!(LAPACKE_sgetrf, get_sgetrf, f32);
+lapacke_getrf!(LAPACKE_dgetrf, get_dgetrf, f64);
+lapacke_getrf!(LAPACKE_cgetrf, get_cgetrf, Complex32);
+lapacke_getrf!(LAPACKE_zgetrf, get_zgetrf, Complex64);
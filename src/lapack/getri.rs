@@ -0,0 +1,119 @@
+//! Matrix inverse from LU factors (GETRI) — LAPACKE-style interface.
+
+use num_complex::{Complex32, Complex64};
+
+use super::util::{from_col_major, to_col_major};
+use crate::lapack_backend::{get_cgetri, get_dgetri, get_sgetri, get_zgetri};
+use crate::types::{blasint, CblasColMajor, CblasRowMajor, CBLAS_ORDER};
+
+macro_rules! lapacke_getri_real {
+    ($name:ident, $get:ident, $ty:ty) => {
+        /// Computes the inverse of a matrix given the `L`/`U` factors and pivots a
+        /// prior `LAPACKE_?getrf` call left in `a`/`ipiv`, overwriting `a` with the
+        /// inverse.
+        ///
+        /// Runs the usual LAPACK workspace-query convention (`lwork == -1`) internally
+        /// to size a scratch buffer, so callers don't manage `work`/`lwork` themselves.
+        ///
+        /// Returns the Fortran `info` value: `0` on success, `-i` if argument `i` was
+        /// illegal, `i > 0` if `U(i, i)` is exactly zero (the matrix is singular and
+        /// the inverse could not be computed).
+        ///
+        /// # Safety
+        ///
+        /// - `a` must point to a valid `n x n` matrix of `getrf` factors with leading
+        ///   dimension `lda`
+        /// - `ipiv` must point to a buffer of at least `n` elements
+        /// - the backend getri routine must already be registered
+        #[allow(non_snake_case)]
+        pub unsafe fn $name(
+            order: CBLAS_ORDER,
+            n: blasint,
+            a: *mut $ty,
+            lda: blasint,
+            ipiv: *const blasint,
+        ) -> blasint {
+            let getri = $get();
+            let mut info: blasint = 0;
+
+            let query_lwork: blasint = -1;
+            let mut work_size: $ty = 0.0;
+
+            macro_rules! run {
+                ($a_ptr:expr, $a_ld:expr) => {{
+                    getri(&n, $a_ptr, $a_ld, ipiv, &mut work_size, &query_lwork, &mut info);
+                    let lwork = (work_size as blasint).max(1);
+                    let mut work = vec![0.0 as $ty; lwork as usize];
+                    let lwork = work.len() as blasint;
+                    getri(&n, $a_ptr, $a_ld, ipiv, work.as_mut_ptr(), &lwork, &mut info);
+                }};
+            }
+
+            match order {
+                CblasColMajor => run!(a, &lda),
+                CblasRowMajor => {
+                    let mut buf = to_col_major(order, n, n, a, lda);
+                    let col_ld = n.max(1);
+                    run!(buf.as_mut_ptr(), &col_ld);
+                    from_col_major(order, n, n, &buf, a, lda);
+                }
+            }
+            info
+        }
+    };
+}
+
+lapacke_getri_real!(LAPACKE_sgetri, get_sgetri, f32);
+lapacke_getri_real!(LAPACKE_dgetri, get_dgetri, f64);
+
+macro_rules! lapacke_getri_complex {
+    ($name:ident, $get:ident, $ty:ty) => {
+        /// See [`LAPACKE_sgetri`]; complex variant, so `work`'s query size is read
+        /// from the real part of the query result (the only part LAPACK fills in).
+        ///
+        /// # Safety
+        ///
+        /// - `a` must point to a valid `n x n` matrix of `getrf` factors with leading
+        ///   dimension `lda`
+        /// - `ipiv` must point to a buffer of at least `n` elements
+        /// - the backend getri routine must already be registered
+        #[allow(non_snake_case)]
+        pub unsafe fn $name(
+            order: CBLAS_ORDER,
+            n: blasint,
+            a: *mut $ty,
+            lda: blasint,
+            ipiv: *const blasint,
+        ) -> blasint {
+            let getri = $get();
+            let mut info: blasint = 0;
+
+            let query_lwork: blasint = -1;
+            let mut work_size: $ty = Default::default();
+
+            macro_rules! run {
+                ($a_ptr:expr, $a_ld:expr) => {{
+                    getri(&n, $a_ptr, $a_ld, ipiv, &mut work_size, &query_lwork, &mut info);
+                    let lwork = (work_size.re as blasint).max(1);
+                    let mut work = vec![<$ty>::default(); lwork as usize];
+                    let lwork = work.len() as blasint;
+                    getri(&n, $a_ptr, $a_ld, ipiv, work.as_mut_ptr(), &lwork, &mut info);
+                }};
+            }
+
+            match order {
+                CblasColMajor => run!(a, &lda),
+                CblasRowMajor => {
+                    let mut buf = to_col_major(order, n, n, a, lda);
+                    let col_ld = n.max(1);
+                    run!(buf.as_mut_ptr(), &col_ld);
+                    from_col_major(order, n, n, &buf, a, lda);
+                }
+            }
+            info
+        }
+    };
+}
+
+lapacke_getri_complex!(LAPACKE_cgetri, get_cgetri, Complex32);
+lapacke_getri_complex!(LAPACKE_zgetri, get_zgetri, Complex64);
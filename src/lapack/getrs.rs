@@ -0,0 +1,72 @@
+//! Solve using LU factors (GETRS) — LAPACKE-style interface.
+
+use std::ffi::c_char;
+
+use num_complex::{Complex32, Complex64};
+
+use super::util::{from_col_major, to_col_major};
+use crate::lapack_backend::{get_cgetrs, get_dgetrs, get_sgetrs, get_zgetrs};
+use crate::types::{blasint, CblasColMajor, CblasRowMajor, CBLAS_ORDER};
+
+macro_rules! lapacke_getrs {
+    ($name:ident, $get:ident, $ty:ty) => {
+        /// Solves `op(A) * X = B` using the `L`/`U` factors and pivots a prior
+        /// `LAPACKE_?getrf` call left in `a`/`ipiv`. `trans` is the raw Fortran
+        /// character: `'N'` no-op, `'T'` transpose, `'C'` conjugate transpose.
+        ///
+        /// Returns the Fortran `info` value: `0` on success, `-i` if argument `i` was
+        /// illegal.
+        ///
+        /// # Safety
+        ///
+        /// - `a` must point to a valid `n x n` matrix of `getrf` factors with leading
+        ///   dimension `lda`
+        /// - `ipiv` must point to a buffer of at least `n` elements
+        /// - `b` must point to a valid `n x nrhs` matrix with leading dimension `ldb`
+        /// - the backend getrs routine must already be registered
+        #[allow(non_snake_case, clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            order: CBLAS_ORDER,
+            trans: c_char,
+            n: blasint,
+            nrhs: blasint,
+            a: *const $ty,
+            lda: blasint,
+            ipiv: *const blasint,
+            b: *mut $ty,
+            ldb: blasint,
+        ) -> blasint {
+            let getrs = $get();
+            let mut info: blasint = 0;
+            match order {
+                CblasColMajor => {
+                    getrs(&trans, &n, &nrhs, a, &lda, ipiv, b, &ldb, &mut info);
+                }
+                CblasRowMajor => {
+                    let a_buf = to_col_major(order, n, n, a, lda);
+                    let mut b_buf = to_col_major(order, n, nrhs, b, ldb);
+                    let a_col_ld = n.max(1);
+                    let b_col_ld = n.max(1);
+                    getrs(
+                        &trans,
+                        &n,
+                        &nrhs,
+                        a_buf.as_ptr(),
+                        &a_col_ld,
+                        ipiv,
+                        b_buf.as_mut_ptr(),
+                        &b_col_ld,
+                        &mut info,
+                    );
+                    from_col_major(order, n, nrhs, &b_buf, b, ldb);
+                }
+            }
+            info
+        }
+    };
+}
+
+lapacke_getrs!(LAPACKE_sgetrs, get_sgetrs, f32);
+lapacke_getrs!(LAPACKE_dgetrs, get_dgetrs, f64);
+lapacke_getrs!(LAPACKE_cgetrs, get_cgetrs, Complex32);
+lapacke_getrs!(LAPACKE_zgetrs, get_zgetrs, Complex64);
@@ -0,0 +1,49 @@
+//! General tridiagonal LU factorization (GTTRF) — LAPACKE-style interface.
+
+use num_complex::{Complex32, Complex64};
+
+use crate::lapack_backend::{get_cgttrf, get_dgttrf, get_sgttrf, get_zgttrf};
+use crate::types::blasint;
+
+macro_rules! lapacke_gttrf {
+    ($name:ident, $get:ident, $ty:ty) => {
+        /// Computes the LU factorization of a general tridiagonal `n x n` matrix
+        /// given as three diagonal vectors, using partial pivoting with row
+        /// interchanges.
+        ///
+        /// Tridiagonal storage is a flat set of vectors rather than a leading-dimension
+        /// matrix, so unlike the other LAPACK wrappers in this module there is no
+        /// row-major/column-major distinction to translate.
+        ///
+        /// Returns the Fortran `info` value: `0` on success, `-i` if argument `i` was
+        /// illegal, `i > 0` if `U(i, i)` is exactly zero (the factorization completed
+        /// but `U` is singular).
+        ///
+        /// # Safety
+        ///
+        /// - `dl` and `du` must point to buffers of at least `n - 1` elements
+        /// - `d` must point to a buffer of at least `n` elements
+        /// - `du2` must point to a buffer of at least `n - 2` elements
+        /// - `ipiv` must point to a buffer of at least `n` elements
+        /// - the backend gttrf routine must already be registered
+        #[allow(non_snake_case)]
+        pub unsafe fn $name(
+            n: blasint,
+            dl: *mut $ty,
+            d: *mut $ty,
+            du: *mut $ty,
+            du2: *mut $ty,
+            ipiv: *mut blasint,
+        ) -> blasint {
+            let gttrf = $get();
+            let mut info: blasint = 0;
+            gttrf(&n, dl, d, du, du2, ipiv, &mut info);
+            info
+        }
+    };
+}
+
+lapacke_gttrf!(LAPACKE_sgttrf, get_sgttrf, f32);
+lapacke_gttrf!(LAPACKE_dgttrf, get_dgttrf, f64);
+lapacke_gttrf!(LAPACKE_cgttrf, get_cgttrf, Complex32);
+lapacke_gttrf!(LAPACKE_zgttrf, get_zgttrf, Complex64);
@@ -0,0 +1,45 @@
+//! LAPACKE-style C interface backed by Fortran LAPACK function pointers.
+//!
+//! Mirrors the rest of this crate's CBLAS trampoline: each routine here converts
+//! row/column-major layout and dispatches to a runtime-registered Fortran LAPACK
+//! function pointer (see [`crate::lapack_backend`]), the same way [`crate::blas1`]/
+//! [`crate::blas2`]/[`crate::blas3`] do for BLAS. Only a representative subset of
+//! LAPACK is covered — the routines most commonly needed alongside BLAS (LU, Cholesky,
+//! QR, a linear solve, a banded LU, a tridiagonal LU, solving/inverting from existing
+//! LU factors, least squares, and a symmetric/Hermitian eigendecomposition) — rather
+//! than the whole library; follow the same pattern in [`crate::lapack_backend`]/this
+//! module to add more.
+//!
+//! Row-major handling differs from CBLAS here: GEMM and friends convert a row-major
+//! call into an algebraically equivalent column-major one (swap operands, swap
+//! dimensions, no data movement). LAPACK's factorizations and eigensolvers have no such
+//! identity, so row-major calls genuinely transpose the matrix into a column-major
+//! scratch buffer before the Fortran call and transpose the result back afterward; see
+//! [`util`].
+
+mod util;
+
+pub mod gbtrf;
+pub mod gels;
+pub mod gesv;
+pub mod getrf;
+pub mod getri;
+pub mod getrs;
+pub mod geqrf;
+pub mod gttrf;
+pub mod potrf;
+pub mod syev;
+
+pub use gbtrf::{LAPACKE_cgbtrf, LAPACKE_dgbtrf, LAPACKE_sgbtrf, LAPACKE_zgbtrf};
+pub use gels::{LAPACKE_cgels, LAPACKE_dgels, LAPACKE_sgels, LAPACKE_zgels};
+pub use gesv::{LAPACKE_cgesv, LAPACKE_dgesv, LAPACKE_sgesv, LAPACKE_zgesv};
+pub use getrf::{LAPACKE_cgetrf, LAPACKE_dgetrf, LAPACKE_sgetrf, LAPACKE_zgetrf};
+pub use getri::{LAPACKE_cgetri, LAPACKE_dgetri, LAPACKE_sgetri, LAPACKE_zgetri};
+pub use getrs::{LAPACKE_cgetrs, LAPACKE_dgetrs, LAPACKE_sgetrs, LAPACKE_zgetrs};
+pub use geqrf::{LAPACKE_cgeqrf, LAPACKE_dgeqrf, LAPACKE_sgeqrf, LAPACKE_zgeqrf};
+pub use gttrf::{LAPACKE_cgttrf, LAPACKE_dgttrf, LAPACKE_sgttrf, LAPACKE_zgttrf};
+pub use potrf::{LAPACKE_cpotrf, LAPACKE_dpotrf, LAPACKE_spotrf, LAPACKE_zpotrf};
+pub use syev::{
+    LAPACKE_cheev, LAPACKE_dsyev, LAPACKE_ssyev, LAPACKE_zheev, LAPACK_EIG_VALUES_ONLY,
+    LAPACK_EIG_VECTORS,
+};
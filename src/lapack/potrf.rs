@@ -0,0 +1,57 @@
+//! Cholesky factorization (POTRF) — LAPACKE-style interface.
+
+use num_complex::{Complex32, Complex64};
+
+use super::util::{from_col_major, to_col_major};
+use crate::lapack_backend::{get_cpotrf, get_dpotrf, get_spotrf, get_zpotrf};
+use crate::types::{
+    blasint, uplo_to_char, CblasColMajor, CblasRowMajor, CBLAS_ORDER, CBLAS_UPLO,
+};
+
+macro_rules! lapacke_potrf {
+    ($name:ident, $get:ident, $ty:ty) => {
+        /// Computes the Cholesky factorization of a symmetric/Hermitian
+        /// positive-definite `n x n` matrix: `A = U^T * U` (or `A = U^H * U` for the
+        /// complex routines) if `uplo` is `CblasUpper`, `A = L * L^T` (`L * L^H`) if
+        /// `CblasLower`. `a` is overwritten with the factor in the triangle `uplo`
+        /// selects; the other triangle is left untouched.
+        ///
+        /// Returns the Fortran `info` value: `0` on success, `-i` if argument `i` was
+        /// illegal, `i > 0` if the leading minor of order `i` is not positive-definite
+        /// (the factorization could not be completed).
+        ///
+        /// # Safety
+        ///
+        /// - `a` must point to a valid `n x n` matrix with leading dimension `lda`
+        /// - the backend potrf routine must already be registered
+        #[allow(non_snake_case)]
+        pub unsafe fn $name(
+            order: CBLAS_ORDER,
+            uplo: CBLAS_UPLO,
+            n: blasint,
+            a: *mut $ty,
+            lda: blasint,
+        ) -> blasint {
+            let potrf = $get();
+            let mut info: blasint = 0;
+            let uplo_char = uplo_to_char(uplo);
+            match order {
+                CblasColMajor => {
+                    potrf(&uplo_char, &n, a, &lda, &mut info);
+                }
+                CblasRowMajor => {
+                    let mut buf = to_col_major(order, n, n, a, lda);
+                    let col_ld = n.max(1);
+                    potrf(&uplo_char, &n, buf.as_mut_ptr(), &col_ld, &mut info);
+                    from_col_major(order, n, n, &buf, a, lda);
+                }
+            }
+            info
+        }
+    };
+}
+
+lapacke_potrf!(LAPACKE_spotrf, get_spotrf, f32);
+lapacke_potrf!(LAPACKE_dpotrf, get_dpotrf, f64);
+lapacke_potrf!(LAPACKE_cpotrf, get_cpotrf, Complex32);
+lapacke_potrf!(LAPACKE_zpotrf, get_zpotrf, Complex64);
@@ -0,0 +1,184 @@
+//! Symmetric/Hermitian eigendecomposition (SYEV/HEEV) — LAPACKE-style interface.
+
+use std::ffi::c_char;
+
+use num_complex::{Complex32, Complex64};
+
+use super::util::{from_col_major, to_col_major};
+use crate::lapack_backend::{get_cheev, get_dsyev, get_ssyev, get_zheev};
+use crate::types::{
+    blasint, uplo_to_char, CblasColMajor, CblasRowMajor, CBLAS_ORDER, CBLAS_UPLO,
+};
+
+/// `jobz` value requesting eigenvalues only (`'N'`).
+pub const LAPACK_EIG_VALUES_ONLY: c_char = b'N' as c_char;
+/// `jobz` value requesting eigenvalues and eigenvectors (`'V'`).
+pub const LAPACK_EIG_VECTORS: c_char = b'V' as c_char;
+
+macro_rules! lapacke_syev {
+    ($name:ident, $get:ident, $ty:ty) => {
+        /// Computes the eigenvalues (into `w`, ascending order) and, if `jobz` is
+        /// [`LAPACK_EIG_VECTORS`], the orthonormal eigenvectors (overwriting `a`) of a
+        /// real symmetric `n x n` matrix. Only the triangle `uplo` selects is read.
+        ///
+        /// Runs the usual LAPACK workspace-query convention (`lwork == -1`) internally
+        /// to size a scratch buffer, so callers don't manage `work`/`lwork` themselves.
+        ///
+        /// Returns the Fortran `info` value: `0` on success, `-i` if argument `i` was
+        /// illegal, `i > 0` if the algorithm failed to converge (`i` off-diagonal
+        /// elements did not converge to zero).
+        ///
+        /// # Safety
+        ///
+        /// - `a` must point to a valid `n x n` matrix with leading dimension `lda`
+        /// - `w` must point to a buffer of at least `n` elements
+        /// - the backend syev routine must already be registered
+        #[allow(non_snake_case)]
+        pub unsafe fn $name(
+            order: CBLAS_ORDER,
+            jobz: c_char,
+            uplo: CBLAS_UPLO,
+            n: blasint,
+            a: *mut $ty,
+            lda: blasint,
+            w: *mut $ty,
+        ) -> blasint {
+            let syev = $get();
+            let mut info: blasint = 0;
+            let uplo_char = uplo_to_char(uplo);
+
+            let query_lwork: blasint = -1;
+            let mut work_size: $ty = 0.0;
+
+            macro_rules! run {
+                ($a_ptr:expr, $a_ld:expr) => {{
+                    syev(
+                        &jobz,
+                        &uplo_char,
+                        &n,
+                        $a_ptr,
+                        $a_ld,
+                        w,
+                        &mut work_size,
+                        &query_lwork,
+                        &mut info,
+                    );
+                    let lwork = (work_size as blasint).max(1);
+                    let mut work = vec![0.0 as $ty; lwork as usize];
+                    let lwork = work.len() as blasint;
+                    syev(
+                        &jobz,
+                        &uplo_char,
+                        &n,
+                        $a_ptr,
+                        $a_ld,
+                        w,
+                        work.as_mut_ptr(),
+                        &lwork,
+                        &mut info,
+                    );
+                }};
+            }
+
+            match order {
+                CblasColMajor => run!(a, &lda),
+                CblasRowMajor => {
+                    let mut buf = to_col_major(order, n, n, a, lda);
+                    let col_ld = n.max(1);
+                    run!(buf.as_mut_ptr(), &col_ld);
+                    from_col_major(order, n, n, &buf, a, lda);
+                }
+            }
+            info
+        }
+    };
+}
+
+lapacke_syev!(LAPACKE_ssyev, get_ssyev, f32);
+lapacke_syev!(LAPACKE_dsyev, get_dsyev, f64);
+
+macro_rules! lapacke_heev {
+    ($name:ident, $get:ident, $ty:ty, $real_ty:ty) => {
+        /// Computes the eigenvalues (into `w`, ascending order, always real-valued even
+        /// though `a` is complex) and, if `jobz` is [`LAPACK_EIG_VECTORS`], the
+        /// orthonormal eigenvectors (overwriting `a`) of a complex Hermitian `n x n`
+        /// matrix. Only the triangle `uplo` selects is read.
+        ///
+        /// Runs the usual LAPACK workspace-query convention internally, same as the
+        /// real `syev` wrappers; `rwork` (length `max(1, 3n - 2)`) is sized directly
+        /// since only `work`'s size depends on a query.
+        ///
+        /// Returns the Fortran `info` value: `0` on success, `-i` if argument `i` was
+        /// illegal, `i > 0` if the algorithm failed to converge.
+        ///
+        /// # Safety
+        ///
+        /// - `a` must point to a valid `n x n` matrix with leading dimension `lda`
+        /// - `w` must point to a buffer of at least `n` elements
+        /// - the backend heev routine must already be registered
+        #[allow(non_snake_case)]
+        pub unsafe fn $name(
+            order: CBLAS_ORDER,
+            jobz: c_char,
+            uplo: CBLAS_UPLO,
+            n: blasint,
+            a: *mut $ty,
+            lda: blasint,
+            w: *mut $real_ty,
+        ) -> blasint {
+            let heev = $get();
+            let mut info: blasint = 0;
+            let uplo_char = uplo_to_char(uplo);
+            let mut rwork = vec![0.0 as $real_ty; (3 * n as isize - 2).max(1) as usize];
+
+            let query_lwork: blasint = -1;
+            let mut work_size: $ty = Default::default();
+
+            macro_rules! run {
+                ($a_ptr:expr, $a_ld:expr) => {{
+                    heev(
+                        &jobz,
+                        &uplo_char,
+                        &n,
+                        $a_ptr,
+                        $a_ld,
+                        w,
+                        &mut work_size,
+                        &query_lwork,
+                        rwork.as_mut_ptr(),
+                        &mut info,
+                    );
+                    let lwork = (work_size.re as blasint).max(1);
+                    let mut work = vec![<$ty>::default(); lwork as usize];
+                    let lwork = work.len() as blasint;
+                    heev(
+                        &jobz,
+                        &uplo_char,
+                        &n,
+                        $a_ptr,
+                        $a_ld,
+                        w,
+                        work.as_mut_ptr(),
+                        &lwork,
+                        rwork.as_mut_ptr(),
+                        &mut info,
+                    );
+                }};
+            }
+
+            match order {
+                CblasColMajor => run!(a, &lda),
+                CblasRowMajor => {
+                    let mut buf = to_col_major(order, n, n, a, lda);
+                    let col_ld = n.max(1);
+                    run!(buf.as_mut_ptr(), &col_ld);
+                    from_col_major(order, n, n, &buf, a, lda);
+                }
+            }
+            info
+        }
+    };
+}
+
+lapacke_heev!(LAPACKE_cheev, get_cheev, Complex32, f32);
+lapacke_heev!(LAPACKE_zheev, get_zheev, Complex64, f64);
@@ -0,0 +1,72 @@
+//! Row/column-major conversion for LAPACK routines.
+//!
+//! CBLAS's bilinear routines (GEMM, etc.) convert a row-major call into a column-major
+//! one algebraically, by swapping operands and transpose flags — no data movement
+//! needed. LAPACK's factorizations and eigensolvers have no such identity: the matrix
+//! genuinely has to be transposed in memory before and after the Fortran call. These
+//! helpers do that via a scratch buffer; every wrapper in [`crate::lapack`] uses them
+//! for `CblasRowMajor` and calls straight through for `CblasColMajor`.
+
+use crate::types::{blasint, CBLAS_ORDER, CblasColMajor, CblasRowMajor};
+
+/// Copies the `rows x cols` matrix at `ptr` (leading dimension `ld`, laid out per
+/// `order`) into a freshly allocated column-major buffer with leading dimension
+/// `rows`. The buffer's length is always `rows * cols`.
+pub(crate) unsafe fn to_col_major<T: Copy + Default>(
+    order: CBLAS_ORDER,
+    rows: blasint,
+    cols: blasint,
+    ptr: *const T,
+    ld: blasint,
+) -> Vec<T> {
+    let (rows_i, cols_i, ld_i) = (rows as isize, cols as isize, ld as isize);
+    let len = (rows_i * cols_i).max(0) as usize;
+    let mut buf = Vec::with_capacity(len);
+    buf.resize_with(len, T::default);
+    match order {
+        CblasColMajor => {
+            for j in 0..cols_i {
+                for i in 0..rows_i {
+                    buf[(i + j * rows_i) as usize] = *ptr.offset(i + j * ld_i);
+                }
+            }
+        }
+        CblasRowMajor => {
+            for i in 0..rows_i {
+                for j in 0..cols_i {
+                    buf[(i + j * rows_i) as usize] = *ptr.offset(i * ld_i + j);
+                }
+            }
+        }
+    }
+    buf
+}
+
+/// Writes a column-major `rows x cols` buffer (leading dimension `rows`, as produced by
+/// [`to_col_major`]) back into `ptr` (leading dimension `ld`, laid out per `order`).
+pub(crate) unsafe fn from_col_major<T: Copy>(
+    order: CBLAS_ORDER,
+    rows: blasint,
+    cols: blasint,
+    buf: &[T],
+    ptr: *mut T,
+    ld: blasint,
+) {
+    let (rows_i, cols_i, ld_i) = (rows as isize, cols as isize, ld as isize);
+    match order {
+        CblasColMajor => {
+            for j in 0..cols_i {
+                for i in 0..rows_i {
+                    *ptr.offset(i + j * ld_i) = buf[(i + j * rows_i) as usize];
+                }
+            }
+        }
+        CblasRowMajor => {
+            for i in 0..rows_i {
+                for j in 0..cols_i {
+                    *ptr.offset(i * ld_i + j) = buf[(i + j * rows_i) as usize];
+                }
+            }
+        }
+    }
+}
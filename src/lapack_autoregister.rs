@@ -0,0 +1,469 @@
+//! Auto-registration of Fortran LAPACK functions from OpenBLAS.
+//!
+//! OpenBLAS bundles a LAPACK implementation (historically a vendored, optimized
+//! netlib LAPACK) alongside its BLAS, exporting the same `name_` symbol convention.
+//! This mirrors [`crate::autoregister`]'s `ctor`-based registration for the handful of
+//! LAPACK routines [`crate::lapack`] wraps, linking against the same `openblas`
+//! library rather than a separate `liblapack`.
+//!
+//! As in [`crate::autoregister`], complex-typed arguments are declared as opaque
+//! `*mut ()`/`*const ()` rather than `Complex32`/`Complex64` to sidestep the ABI
+//! ambiguity around passing `extern "C"` complex structs by value across the
+//! Rust/Fortran boundary; the real typed function pointers live in
+//! [`crate::lapack_backend`], and each symbol is transmuted to its typed `*FnPtr` only
+//! at the registration call below.
+
+use std::ffi::c_char;
+
+use crate::lapack_backend::*;
+use crate::types::blasint;
+
+#[link(name = "openblas")]
+extern "C" {
+    // LU factorization (GETRF)
+    fn sgetrf_(
+        m: *const blasint,
+        n: *const blasint,
+        a: *mut f32,
+        lda: *const blasint,
+        ipiv: *mut blasint,
+        info: *mut blasint,
+    );
+    fn dgetrf_(
+        m: *const blasint,
+        n: *const blasint,
+        a: *mut f64,
+        lda: *const blasint,
+        ipiv: *mut blasint,
+        info: *mut blasint,
+    );
+    fn cgetrf_(
+        m: *const blasint,
+        n: *const blasint,
+        a: *mut (),
+        lda: *const blasint,
+        ipiv: *mut blasint,
+        info: *mut blasint,
+    );
+    fn zgetrf_(
+        m: *const blasint,
+        n: *const blasint,
+        a: *mut (),
+        lda: *const blasint,
+        ipiv: *mut blasint,
+        info: *mut blasint,
+    );
+
+    // Cholesky factorization (POTRF)
+    fn spotrf_(uplo: *const c_char, n: *const blasint, a: *mut f32, lda: *const blasint, info: *mut blasint);
+    fn dpotrf_(uplo: *const c_char, n: *const blasint, a: *mut f64, lda: *const blasint, info: *mut blasint);
+    fn cpotrf_(uplo: *const c_char, n: *const blasint, a: *mut (), lda: *const blasint, info: *mut blasint);
+    fn zpotrf_(uplo: *const c_char, n: *const blasint, a: *mut (), lda: *const blasint, info: *mut blasint);
+
+    // General linear system solve (GESV)
+    fn sgesv_(
+        n: *const blasint,
+        nrhs: *const blasint,
+        a: *mut f32,
+        lda: *const blasint,
+        ipiv: *mut blasint,
+        b: *mut f32,
+        ldb: *const blasint,
+        info: *mut blasint,
+    );
+    fn dgesv_(
+        n: *const blasint,
+        nrhs: *const blasint,
+        a: *mut f64,
+        lda: *const blasint,
+        ipiv: *mut blasint,
+        b: *mut f64,
+        ldb: *const blasint,
+        info: *mut blasint,
+    );
+    fn cgesv_(
+        n: *const blasint,
+        nrhs: *const blasint,
+        a: *mut (),
+        lda: *const blasint,
+        ipiv: *mut blasint,
+        b: *mut (),
+        ldb: *const blasint,
+        info: *mut blasint,
+    );
+    fn zgesv_(
+        n: *const blasint,
+        nrhs: *const blasint,
+        a: *mut (),
+        lda: *const blasint,
+        ipiv: *mut blasint,
+        b: *mut (),
+        ldb: *const blasint,
+        info: *mut blasint,
+    );
+
+    // QR factorization (GEQRF)
+    fn sgeqrf_(
+        m: *const blasint,
+        n: *const blasint,
+        a: *mut f32,
+        lda: *const blasint,
+        tau: *mut f32,
+        work: *mut f32,
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+    fn dgeqrf_(
+        m: *const blasint,
+        n: *const blasint,
+        a: *mut f64,
+        lda: *const blasint,
+        tau: *mut f64,
+        work: *mut f64,
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+    fn cgeqrf_(
+        m: *const blasint,
+        n: *const blasint,
+        a: *mut (),
+        lda: *const blasint,
+        tau: *mut (),
+        work: *mut (),
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+    fn zgeqrf_(
+        m: *const blasint,
+        n: *const blasint,
+        a: *mut (),
+        lda: *const blasint,
+        tau: *mut (),
+        work: *mut (),
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+
+    // Symmetric eigendecomposition (SYEV)
+    fn ssyev_(
+        jobz: *const c_char,
+        uplo: *const c_char,
+        n: *const blasint,
+        a: *mut f32,
+        lda: *const blasint,
+        w: *mut f32,
+        work: *mut f32,
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+    fn dsyev_(
+        jobz: *const c_char,
+        uplo: *const c_char,
+        n: *const blasint,
+        a: *mut f64,
+        lda: *const blasint,
+        w: *mut f64,
+        work: *mut f64,
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+
+    // Hermitian eigendecomposition (HEEV)
+    fn cheev_(
+        jobz: *const c_char,
+        uplo: *const c_char,
+        n: *const blasint,
+        a: *mut (),
+        lda: *const blasint,
+        w: *mut f32,
+        work: *mut (),
+        lwork: *const blasint,
+        rwork: *mut f32,
+        info: *mut blasint,
+    );
+    fn zheev_(
+        jobz: *const c_char,
+        uplo: *const c_char,
+        n: *const blasint,
+        a: *mut (),
+        lda: *const blasint,
+        w: *mut f64,
+        work: *mut (),
+        lwork: *const blasint,
+        rwork: *mut f64,
+        info: *mut blasint,
+    );
+
+    // Banded LU factorization (GBTRF)
+    fn sgbtrf_(
+        m: *const blasint,
+        n: *const blasint,
+        kl: *const blasint,
+        ku: *const blasint,
+        ab: *mut f32,
+        ldab: *const blasint,
+        ipiv: *mut blasint,
+        info: *mut blasint,
+    );
+    fn dgbtrf_(
+        m: *const blasint,
+        n: *const blasint,
+        kl: *const blasint,
+        ku: *const blasint,
+        ab: *mut f64,
+        ldab: *const blasint,
+        ipiv: *mut blasint,
+        info: *mut blasint,
+    );
+    fn cgbtrf_(
+        m: *const blasint,
+        n: *const blasint,
+        kl: *const blasint,
+        ku: *const blasint,
+        ab: *mut (),
+        ldab: *const blasint,
+        ipiv: *mut blasint,
+        info: *mut blasint,
+    );
+    fn zgbtrf_(
+        m: *const blasint,
+        n: *const blasint,
+        kl: *const blasint,
+        ku: *const blasint,
+        ab: *mut (),
+        ldab: *const blasint,
+        ipiv: *mut blasint,
+        info: *mut blasint,
+    );
+
+    // Tridiagonal LU factorization (GTTRF)
+    fn sgttrf_(
+        n: *const blasint,
+        dl: *mut f32,
+        d: *mut f32,
+        du: *mut f32,
+        du2: *mut f32,
+        ipiv: *mut blasint,
+        info: *mut blasint,
+    );
+    fn dgttrf_(
+        n: *const blasint,
+        dl: *mut f64,
+        d: *mut f64,
+        du: *mut f64,
+        du2: *mut f64,
+        ipiv: *mut blasint,
+        info: *mut blasint,
+    );
+    fn cgttrf_(
+        n: *const blasint,
+        dl: *mut (),
+        d: *mut (),
+        du: *mut (),
+        du2: *mut (),
+        ipiv: *mut blasint,
+        info: *mut blasint,
+    );
+    fn zgttrf_(
+        n: *const blasint,
+        dl: *mut (),
+        d: *mut (),
+        du: *mut (),
+        du2: *mut (),
+        ipiv: *mut blasint,
+        info: *mut blasint,
+    );
+
+    // Solve using GETRF's factors (GETRS)
+    fn sgetrs_(
+        trans: *const c_char,
+        n: *const blasint,
+        nrhs: *const blasint,
+        a: *const f32,
+        lda: *const blasint,
+        ipiv: *const blasint,
+        b: *mut f32,
+        ldb: *const blasint,
+        info: *mut blasint,
+    );
+    fn dgetrs_(
+        trans: *const c_char,
+        n: *const blasint,
+        nrhs: *const blasint,
+        a: *const f64,
+        lda: *const blasint,
+        ipiv: *const blasint,
+        b: *mut f64,
+        ldb: *const blasint,
+        info: *mut blasint,
+    );
+    fn cgetrs_(
+        trans: *const c_char,
+        n: *const blasint,
+        nrhs: *const blasint,
+        a: *const (),
+        lda: *const blasint,
+        ipiv: *const blasint,
+        b: *mut (),
+        ldb: *const blasint,
+        info: *mut blasint,
+    );
+    fn zgetrs_(
+        trans: *const c_char,
+        n: *const blasint,
+        nrhs: *const blasint,
+        a: *const (),
+        lda: *const blasint,
+        ipiv: *const blasint,
+        b: *mut (),
+        ldb: *const blasint,
+        info: *mut blasint,
+    );
+
+    // Inverse from GETRF's factors (GETRI)
+    fn sgetri_(
+        n: *const blasint,
+        a: *mut f32,
+        lda: *const blasint,
+        ipiv: *const blasint,
+        work: *mut f32,
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+    fn dgetri_(
+        n: *const blasint,
+        a: *mut f64,
+        lda: *const blasint,
+        ipiv: *const blasint,
+        work: *mut f64,
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+    fn cgetri_(
+        n: *const blasint,
+        a: *mut (),
+        lda: *const blasint,
+        ipiv: *const blasint,
+        work: *mut (),
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+    fn zgetri_(
+        n: *const blasint,
+        a: *mut (),
+        lda: *const blasint,
+        ipiv: *const blasint,
+        work: *mut (),
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+
+    // Linear least squares (GELS)
+    fn sgels_(
+        trans: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        nrhs: *const blasint,
+        a: *mut f32,
+        lda: *const blasint,
+        b: *mut f32,
+        ldb: *const blasint,
+        work: *mut f32,
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+    fn dgels_(
+        trans: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        nrhs: *const blasint,
+        a: *mut f64,
+        lda: *const blasint,
+        b: *mut f64,
+        ldb: *const blasint,
+        work: *mut f64,
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+    fn cgels_(
+        trans: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        nrhs: *const blasint,
+        a: *mut (),
+        lda: *const blasint,
+        b: *mut (),
+        ldb: *const blasint,
+        work: *mut (),
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+    fn zgels_(
+        trans: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        nrhs: *const blasint,
+        a: *mut (),
+        lda: *const blasint,
+        b: *mut (),
+        ldb: *const blasint,
+        work: *mut (),
+        lwork: *const blasint,
+        info: *mut blasint,
+    );
+}
+
+#[ctor::ctor]
+fn register_all_lapack() {
+    unsafe {
+        register_sgetrf(std::mem::transmute(sgetrf_ as *const ()));
+        register_dgetrf(std::mem::transmute(dgetrf_ as *const ()));
+        register_cgetrf(std::mem::transmute(cgetrf_ as *const ()));
+        register_zgetrf(std::mem::transmute(zgetrf_ as *const ()));
+
+        register_spotrf(std::mem::transmute(spotrf_ as *const ()));
+        register_dpotrf(std::mem::transmute(dpotrf_ as *const ()));
+        register_cpotrf(std::mem::transmute(cpotrf_ as *const ()));
+        register_zpotrf(std::mem::transmute(zpotrf_ as *const ()));
+
+        register_sgesv(std::mem::transmute(sgesv_ as *const ()));
+        register_dgesv(std::mem::transmute(dgesv_ as *const ()));
+        register_cgesv(std::mem::transmute(cgesv_ as *const ()));
+        register_zgesv(std::mem::transmute(zgesv_ as *const ()));
+
+        register_sgeqrf(std::mem::transmute(sgeqrf_ as *const ()));
+        register_dgeqrf(std::mem::transmute(dgeqrf_ as *const ()));
+        register_cgeqrf(std::mem::transmute(cgeqrf_ as *const ()));
+        register_zgeqrf(std::mem::transmute(zgeqrf_ as *const ()));
+
+        register_ssyev(std::mem::transmute(ssyev_ as *const ()));
+        register_dsyev(std::mem::transmute(dsyev_ as *const ()));
+        register_cheev(std::mem::transmute(cheev_ as *const ()));
+        register_zheev(std::mem::transmute(zheev_ as *const ()));
+
+        register_sgbtrf(std::mem::transmute(sgbtrf_ as *const ()));
+        register_dgbtrf(std::mem::transmute(dgbtrf_ as *const ()));
+        register_cgbtrf(std::mem::transmute(cgbtrf_ as *const ()));
+        register_zgbtrf(std::mem::transmute(zgbtrf_ as *const ()));
+
+        register_sgttrf(std::mem::transmute(sgttrf_ as *const ()));
+        register_dgttrf(std::mem::transmute(dgttrf_ as *const ()));
+        register_cgttrf(std::mem::transmute(cgttrf_ as *const ()));
+        register_zgttrf(std::mem::transmute(zgttrf_ as *const ()));
+
+        register_sgetrs(std::mem::transmute(sgetrs_ as *const ()));
+        register_dgetrs(std::mem::transmute(dgetrs_ as *const ()));
+        register_cgetrs(std::mem::transmute(cgetrs_ as *const ()));
+        register_zgetrs(std::mem::transmute(zgetrs_ as *const ()));
+
+        register_sgetri(std::mem::transmute(sgetri_ as *const ()));
+        register_dgetri(std::mem::transmute(dgetri_ as *const ()));
+        register_cgetri(std::mem::transmute(cgetri_ as *const ()));
+        register_zgetri(std::mem::transmute(zgetri_ as *const ()));
+
+        register_sgels(std::mem::transmute(sgels_ as *const ()));
+        register_dgels(std::mem::transmute(dgels_ as *const ()));
+        register_cgels(std::mem::transmute(cgels_ as *const ()));
+        register_zgels(std::mem::transmute(zgels_ as *const ()));
+    }
+}
@@ -0,0 +1,1263 @@
+//! Fortran LAPACK function pointer registry.
+//!
+//! Mirrors [`crate::backend`]'s registration scheme (typed `FnPtr` aliases, one
+//! `OnceLock` slot per routine, a `register_*`/`get_*` pair per slot) for the handful
+//! of LAPACK routines [`crate::lapack`] wraps with a LAPACKE-style C interface. See
+//! that module's docs for the row/column-major handling these routines need that
+//! CBLAS's bilinear routines don't.
+//!
+//! There is no `reference` fallback here: [`crate::reference`] only covers BLAS: a
+//! `get_*` call on an unregistered LAPACK slot always panics, the same way a `get_*`
+//! call in [`crate::backend`] does when the `reference` feature is off.
+
+use std::ffi::c_char;
+use std::sync::OnceLock;
+
+use num_complex::{Complex32, Complex64};
+
+use crate::types::blasint;
+
+// =============================================================================
+// Fortran LAPACK function pointer types
+// =============================================================================
+
+/// Fortran `sgetrf`/`dgetrf`/`cgetrf`/`zgetrf` function pointer type: LU factorization
+/// of a general `m x n` matrix with partial pivoting. `ipiv` has length `min(m, n)`.
+pub type SgetrfFnPtr = unsafe extern "C" fn(
+    m: *const blasint,
+    n: *const blasint,
+    a: *mut f32,
+    lda: *const blasint,
+    ipiv: *mut blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgetrfFnPtr`].
+pub type DgetrfFnPtr = unsafe extern "C" fn(
+    m: *const blasint,
+    n: *const blasint,
+    a: *mut f64,
+    lda: *const blasint,
+    ipiv: *mut blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgetrfFnPtr`].
+pub type CgetrfFnPtr = unsafe extern "C" fn(
+    m: *const blasint,
+    n: *const blasint,
+    a: *mut Complex32,
+    lda: *const blasint,
+    ipiv: *mut blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgetrfFnPtr`].
+pub type ZgetrfFnPtr = unsafe extern "C" fn(
+    m: *const blasint,
+    n: *const blasint,
+    a: *mut Complex64,
+    lda: *const blasint,
+    ipiv: *mut blasint,
+    info: *mut blasint,
+);
+
+/// Fortran `spotrf`/`dpotrf`/`cpotrf`/`zpotrf` function pointer type: Cholesky
+/// factorization of a symmetric/Hermitian positive-definite `n x n` matrix.
+pub type SpotrfFnPtr = unsafe extern "C" fn(
+    uplo: *const c_char,
+    n: *const blasint,
+    a: *mut f32,
+    lda: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SpotrfFnPtr`].
+pub type DpotrfFnPtr = unsafe extern "C" fn(
+    uplo: *const c_char,
+    n: *const blasint,
+    a: *mut f64,
+    lda: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SpotrfFnPtr`].
+pub type CpotrfFnPtr = unsafe extern "C" fn(
+    uplo: *const c_char,
+    n: *const blasint,
+    a: *mut Complex32,
+    lda: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SpotrfFnPtr`].
+pub type ZpotrfFnPtr = unsafe extern "C" fn(
+    uplo: *const c_char,
+    n: *const blasint,
+    a: *mut Complex64,
+    lda: *const blasint,
+    info: *mut blasint,
+);
+
+/// Fortran `sgesv`/`dgesv`/`cgesv`/`zgesv` function pointer type: solves `A*X = B` via
+/// LU factorization with partial pivoting, overwriting `a` with the factors and `b`
+/// with the solution `X`.
+pub type SgesvFnPtr = unsafe extern "C" fn(
+    n: *const blasint,
+    nrhs: *const blasint,
+    a: *mut f32,
+    lda: *const blasint,
+    ipiv: *mut blasint,
+    b: *mut f32,
+    ldb: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgesvFnPtr`].
+pub type DgesvFnPtr = unsafe extern "C" fn(
+    n: *const blasint,
+    nrhs: *const blasint,
+    a: *mut f64,
+    lda: *const blasint,
+    ipiv: *mut blasint,
+    b: *mut f64,
+    ldb: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgesvFnPtr`].
+pub type CgesvFnPtr = unsafe extern "C" fn(
+    n: *const blasint,
+    nrhs: *const blasint,
+    a: *mut Complex32,
+    lda: *const blasint,
+    ipiv: *mut blasint,
+    b: *mut Complex32,
+    ldb: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgesvFnPtr`].
+pub type ZgesvFnPtr = unsafe extern "C" fn(
+    n: *const blasint,
+    nrhs: *const blasint,
+    a: *mut Complex64,
+    lda: *const blasint,
+    ipiv: *mut blasint,
+    b: *mut Complex64,
+    ldb: *const blasint,
+    info: *mut blasint,
+);
+
+/// Fortran `sgeqrf`/`dgeqrf`/`cgeqrf`/`zgeqrf` function pointer type: QR factorization
+/// of a general `m x n` matrix. Supports the usual LAPACK workspace query convention:
+/// a call with `lwork == -1` doesn't factorize anything, it writes the optimal `lwork`
+/// into `work[0]` and returns.
+pub type SgeqrfFnPtr = unsafe extern "C" fn(
+    m: *const blasint,
+    n: *const blasint,
+    a: *mut f32,
+    lda: *const blasint,
+    tau: *mut f32,
+    work: *mut f32,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgeqrfFnPtr`].
+pub type DgeqrfFnPtr = unsafe extern "C" fn(
+    m: *const blasint,
+    n: *const blasint,
+    a: *mut f64,
+    lda: *const blasint,
+    tau: *mut f64,
+    work: *mut f64,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgeqrfFnPtr`].
+pub type CgeqrfFnPtr = unsafe extern "C" fn(
+    m: *const blasint,
+    n: *const blasint,
+    a: *mut Complex32,
+    lda: *const blasint,
+    tau: *mut Complex32,
+    work: *mut Complex32,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgeqrfFnPtr`].
+pub type ZgeqrfFnPtr = unsafe extern "C" fn(
+    m: *const blasint,
+    n: *const blasint,
+    a: *mut Complex64,
+    lda: *const blasint,
+    tau: *mut Complex64,
+    work: *mut Complex64,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+/// Fortran `ssyev`/`dsyev` function pointer type: eigenvalues (and, if `jobz == 'V'`,
+/// eigenvectors) of a real symmetric `n x n` matrix. Supports the `lwork == -1`
+/// workspace query convention, same as [`SgeqrfFnPtr`].
+pub type SsyevFnPtr = unsafe extern "C" fn(
+    jobz: *const c_char,
+    uplo: *const c_char,
+    n: *const blasint,
+    a: *mut f32,
+    lda: *const blasint,
+    w: *mut f32,
+    work: *mut f32,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SsyevFnPtr`].
+pub type DsyevFnPtr = unsafe extern "C" fn(
+    jobz: *const c_char,
+    uplo: *const c_char,
+    n: *const blasint,
+    a: *mut f64,
+    lda: *const blasint,
+    w: *mut f64,
+    work: *mut f64,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+/// Fortran `cheev`/`zheev` function pointer type: eigenvalues (and, if `jobz == 'V'`,
+/// eigenvectors) of a complex Hermitian `n x n` matrix. `w` (the eigenvalues) and
+/// `rwork` are always real-valued even though `a`/`work` are complex.
+pub type CheevFnPtr = unsafe extern "C" fn(
+    jobz: *const c_char,
+    uplo: *const c_char,
+    n: *const blasint,
+    a: *mut Complex32,
+    lda: *const blasint,
+    w: *mut f32,
+    work: *mut Complex32,
+    lwork: *const blasint,
+    rwork: *mut f32,
+    info: *mut blasint,
+);
+
+/// See [`CheevFnPtr`].
+pub type ZheevFnPtr = unsafe extern "C" fn(
+    jobz: *const c_char,
+    uplo: *const c_char,
+    n: *const blasint,
+    a: *mut Complex64,
+    lda: *const blasint,
+    w: *mut f64,
+    work: *mut Complex64,
+    lwork: *const blasint,
+    rwork: *mut f64,
+    info: *mut blasint,
+);
+
+/// Fortran `sgbtrf`/`dgbtrf`/`cgbtrf`/`zgbtrf` function pointer type: LU factorization
+/// of a general banded `m x n` matrix with `kl` sub- and `ku` super-diagonals, stored
+/// in the usual LAPACK band layout (an `ldab x n` array, `ldab >= 2*kl + ku + 1`).
+pub type SgbtrfFnPtr = unsafe extern "C" fn(
+    m: *const blasint,
+    n: *const blasint,
+    kl: *const blasint,
+    ku: *const blasint,
+    ab: *mut f32,
+    ldab: *const blasint,
+    ipiv: *mut blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgbtrfFnPtr`].
+pub type DgbtrfFnPtr = unsafe extern "C" fn(
+    m: *const blasint,
+    n: *const blasint,
+    kl: *const blasint,
+    ku: *const blasint,
+    ab: *mut f64,
+    ldab: *const blasint,
+    ipiv: *mut blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgbtrfFnPtr`].
+pub type CgbtrfFnPtr = unsafe extern "C" fn(
+    m: *const blasint,
+    n: *const blasint,
+    kl: *const blasint,
+    ku: *const blasint,
+    ab: *mut Complex32,
+    ldab: *const blasint,
+    ipiv: *mut blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgbtrfFnPtr`].
+pub type ZgbtrfFnPtr = unsafe extern "C" fn(
+    m: *const blasint,
+    n: *const blasint,
+    kl: *const blasint,
+    ku: *const blasint,
+    ab: *mut Complex64,
+    ldab: *const blasint,
+    ipiv: *mut blasint,
+    info: *mut blasint,
+);
+
+/// Fortran `sgttrf`/`dgttrf`/`cgttrf`/`zgttrf` function pointer type: LU factorization
+/// of a general tridiagonal `n x n` matrix given as three diagonal vectors. `dl`/`du`
+/// have length `n - 1`, `d` has length `n`; on exit `du2` (length `n - 2`) holds the
+/// second superdiagonal fill-in produced by partial pivoting.
+pub type SgttrfFnPtr = unsafe extern "C" fn(
+    n: *const blasint,
+    dl: *mut f32,
+    d: *mut f32,
+    du: *mut f32,
+    du2: *mut f32,
+    ipiv: *mut blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgttrfFnPtr`].
+pub type DgttrfFnPtr = unsafe extern "C" fn(
+    n: *const blasint,
+    dl: *mut f64,
+    d: *mut f64,
+    du: *mut f64,
+    du2: *mut f64,
+    ipiv: *mut blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgttrfFnPtr`].
+pub type CgttrfFnPtr = unsafe extern "C" fn(
+    n: *const blasint,
+    dl: *mut Complex32,
+    d: *mut Complex32,
+    du: *mut Complex32,
+    du2: *mut Complex32,
+    ipiv: *mut blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgttrfFnPtr`].
+pub type ZgttrfFnPtr = unsafe extern "C" fn(
+    n: *const blasint,
+    dl: *mut Complex64,
+    d: *mut Complex64,
+    du: *mut Complex64,
+    du2: *mut Complex64,
+    ipiv: *mut blasint,
+    info: *mut blasint,
+);
+
+/// Fortran `sgetrs`/`dgetrs`/`cgetrs`/`zgetrs` function pointer type: solves
+/// `op(A)*X = B` (`op` per `trans`: `'N'` no-op, `'T'` transpose, `'C'` conjugate
+/// transpose) using the `L`/`U` factors and pivots a prior `getrf` call produced.
+pub type SgetrsFnPtr = unsafe extern "C" fn(
+    trans: *const c_char,
+    n: *const blasint,
+    nrhs: *const blasint,
+    a: *const f32,
+    lda: *const blasint,
+    ipiv: *const blasint,
+    b: *mut f32,
+    ldb: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgetrsFnPtr`].
+pub type DgetrsFnPtr = unsafe extern "C" fn(
+    trans: *const c_char,
+    n: *const blasint,
+    nrhs: *const blasint,
+    a: *const f64,
+    lda: *const blasint,
+    ipiv: *const blasint,
+    b: *mut f64,
+    ldb: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgetrsFnPtr`].
+pub type CgetrsFnPtr = unsafe extern "C" fn(
+    trans: *const c_char,
+    n: *const blasint,
+    nrhs: *const blasint,
+    a: *const Complex32,
+    lda: *const blasint,
+    ipiv: *const blasint,
+    b: *mut Complex32,
+    ldb: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgetrsFnPtr`].
+pub type ZgetrsFnPtr = unsafe extern "C" fn(
+    trans: *const c_char,
+    n: *const blasint,
+    nrhs: *const blasint,
+    a: *const Complex64,
+    lda: *const blasint,
+    ipiv: *const blasint,
+    b: *mut Complex64,
+    ldb: *const blasint,
+    info: *mut blasint,
+);
+
+/// Fortran `sgetri`/`dgetri`/`cgetri`/`zgetri` function pointer type: computes the
+/// inverse of a matrix given its `getrf` `L`/`U` factors and pivots, overwriting `a`.
+/// `work`/`lwork` follow the usual LAPACK workspace-query convention (`lwork == -1`
+/// sizes `work[0]` without computing).
+pub type SgetriFnPtr = unsafe extern "C" fn(
+    n: *const blasint,
+    a: *mut f32,
+    lda: *const blasint,
+    ipiv: *const blasint,
+    work: *mut f32,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgetriFnPtr`].
+pub type DgetriFnPtr = unsafe extern "C" fn(
+    n: *const blasint,
+    a: *mut f64,
+    lda: *const blasint,
+    ipiv: *const blasint,
+    work: *mut f64,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgetriFnPtr`].
+pub type CgetriFnPtr = unsafe extern "C" fn(
+    n: *const blasint,
+    a: *mut Complex32,
+    lda: *const blasint,
+    ipiv: *const blasint,
+    work: *mut Complex32,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgetriFnPtr`].
+pub type ZgetriFnPtr = unsafe extern "C" fn(
+    n: *const blasint,
+    a: *mut Complex64,
+    lda: *const blasint,
+    ipiv: *const blasint,
+    work: *mut Complex64,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+/// Fortran `sgels`/`dgels`/`cgels`/`zgels` function pointer type: solves the
+/// overdetermined/underdetermined linear least-squares problem `min ||B - op(A)*X||`
+/// (`op` per `trans`: `'N'` no-op, `'T'` transpose; complex variants only accept `'N'`
+/// or `'C'`) for a full-rank `m x n` matrix `A`, via a QR or LQ factorization. `work`/
+/// `lwork` follow the usual LAPACK workspace-query convention.
+pub type SgelsFnPtr = unsafe extern "C" fn(
+    trans: *const c_char,
+    m: *const blasint,
+    n: *const blasint,
+    nrhs: *const blasint,
+    a: *mut f32,
+    lda: *const blasint,
+    b: *mut f32,
+    ldb: *const blasint,
+    work: *mut f32,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgelsFnPtr`].
+pub type DgelsFnPtr = unsafe extern "C" fn(
+    trans: *const c_char,
+    m: *const blasint,
+    n: *const blasint,
+    nrhs: *const blasint,
+    a: *mut f64,
+    lda: *const blasint,
+    b: *mut f64,
+    ldb: *const blasint,
+    work: *mut f64,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgelsFnPtr`].
+pub type CgelsFnPtr = unsafe extern "C" fn(
+    trans: *const c_char,
+    m: *const blasint,
+    n: *const blasint,
+    nrhs: *const blasint,
+    a: *mut Complex32,
+    lda: *const blasint,
+    b: *mut Complex32,
+    ldb: *const blasint,
+    work: *mut Complex32,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+/// See [`SgelsFnPtr`].
+pub type ZgelsFnPtr = unsafe extern "C" fn(
+    trans: *const c_char,
+    m: *const blasint,
+    n: *const blasint,
+    nrhs: *const blasint,
+    a: *mut Complex64,
+    lda: *const blasint,
+    b: *mut Complex64,
+    ldb: *const blasint,
+    work: *mut Complex64,
+    lwork: *const blasint,
+    info: *mut blasint,
+);
+
+// =============================================================================
+// Function pointer storage (OnceLock per function)
+// =============================================================================
+
+static SGETRF: OnceLock<SgetrfFnPtr> = OnceLock::new();
+static DGETRF: OnceLock<DgetrfFnPtr> = OnceLock::new();
+static CGETRF: OnceLock<CgetrfFnPtr> = OnceLock::new();
+static ZGETRF: OnceLock<ZgetrfFnPtr> = OnceLock::new();
+
+static SPOTRF: OnceLock<SpotrfFnPtr> = OnceLock::new();
+static DPOTRF: OnceLock<DpotrfFnPtr> = OnceLock::new();
+static CPOTRF: OnceLock<CpotrfFnPtr> = OnceLock::new();
+static ZPOTRF: OnceLock<ZpotrfFnPtr> = OnceLock::new();
+
+static SGESV: OnceLock<SgesvFnPtr> = OnceLock::new();
+static DGESV: OnceLock<DgesvFnPtr> = OnceLock::new();
+static CGESV: OnceLock<CgesvFnPtr> = OnceLock::new();
+static ZGESV: OnceLock<ZgesvFnPtr> = OnceLock::new();
+
+static SGEQRF: OnceLock<SgeqrfFnPtr> = OnceLock::new();
+static DGEQRF: OnceLock<DgeqrfFnPtr> = OnceLock::new();
+static CGEQRF: OnceLock<CgeqrfFnPtr> = OnceLock::new();
+static ZGEQRF: OnceLock<ZgeqrfFnPtr> = OnceLock::new();
+
+static SSYEV: OnceLock<SsyevFnPtr> = OnceLock::new();
+static DSYEV: OnceLock<DsyevFnPtr> = OnceLock::new();
+static CHEEV: OnceLock<CheevFnPtr> = OnceLock::new();
+static ZHEEV: OnceLock<ZheevFnPtr> = OnceLock::new();
+
+static SGBTRF: OnceLock<SgbtrfFnPtr> = OnceLock::new();
+static DGBTRF: OnceLock<DgbtrfFnPtr> = OnceLock::new();
+static CGBTRF: OnceLock<CgbtrfFnPtr> = OnceLock::new();
+static ZGBTRF: OnceLock<ZgbtrfFnPtr> = OnceLock::new();
+
+static SGTTRF: OnceLock<SgttrfFnPtr> = OnceLock::new();
+static DGTTRF: OnceLock<DgttrfFnPtr> = OnceLock::new();
+static CGTTRF: OnceLock<CgttrfFnPtr> = OnceLock::new();
+static ZGTTRF: OnceLock<ZgttrfFnPtr> = OnceLock::new();
+
+static SGETRS: OnceLock<SgetrsFnPtr> = OnceLock::new();
+static DGETRS: OnceLock<DgetrsFnPtr> = OnceLock::new();
+static CGETRS: OnceLock<CgetrsFnPtr> = OnceLock::new();
+static ZGETRS: OnceLock<ZgetrsFnPtr> = OnceLock::new();
+
+static SGETRI: OnceLock<SgetriFnPtr> = OnceLock::new();
+static DGETRI: OnceLock<DgetriFnPtr> = OnceLock::new();
+static CGETRI: OnceLock<CgetriFnPtr> = OnceLock::new();
+static ZGETRI: OnceLock<ZgetriFnPtr> = OnceLock::new();
+
+static SGELS: OnceLock<SgelsFnPtr> = OnceLock::new();
+static DGELS: OnceLock<DgelsFnPtr> = OnceLock::new();
+static CGELS: OnceLock<CgelsFnPtr> = OnceLock::new();
+static ZGELS: OnceLock<ZgelsFnPtr> = OnceLock::new();
+
+// =============================================================================
+// Registration functions
+// =============================================================================
+
+/// Register the Fortran sgetrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `sgetrf` function pointer.
+pub unsafe fn register_sgetrf(f: SgetrfFnPtr) {
+    SGETRF
+        .set(f)
+        .expect("sgetrf already registered (can only be set once)");
+}
+
+/// Register the Fortran dgetrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `dgetrf` function pointer.
+pub unsafe fn register_dgetrf(f: DgetrfFnPtr) {
+    DGETRF
+        .set(f)
+        .expect("dgetrf already registered (can only be set once)");
+}
+
+/// Register the Fortran cgetrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `cgetrf` function pointer.
+pub unsafe fn register_cgetrf(f: CgetrfFnPtr) {
+    CGETRF
+        .set(f)
+        .expect("cgetrf already registered (can only be set once)");
+}
+
+/// Register the Fortran zgetrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `zgetrf` function pointer.
+pub unsafe fn register_zgetrf(f: ZgetrfFnPtr) {
+    ZGETRF
+        .set(f)
+        .expect("zgetrf already registered (can only be set once)");
+}
+
+/// Register the Fortran spotrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `spotrf` function pointer.
+pub unsafe fn register_spotrf(f: SpotrfFnPtr) {
+    SPOTRF
+        .set(f)
+        .expect("spotrf already registered (can only be set once)");
+}
+
+/// Register the Fortran dpotrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `dpotrf` function pointer.
+pub unsafe fn register_dpotrf(f: DpotrfFnPtr) {
+    DPOTRF
+        .set(f)
+        .expect("dpotrf already registered (can only be set once)");
+}
+
+/// Register the Fortran cpotrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `cpotrf` function pointer.
+pub unsafe fn register_cpotrf(f: CpotrfFnPtr) {
+    CPOTRF
+        .set(f)
+        .expect("cpotrf already registered (can only be set once)");
+}
+
+/// Register the Fortran zpotrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `zpotrf` function pointer.
+pub unsafe fn register_zpotrf(f: ZpotrfFnPtr) {
+    ZPOTRF
+        .set(f)
+        .expect("zpotrf already registered (can only be set once)");
+}
+
+/// Register the Fortran sgesv function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `sgesv` function pointer.
+pub unsafe fn register_sgesv(f: SgesvFnPtr) {
+    SGESV
+        .set(f)
+        .expect("sgesv already registered (can only be set once)");
+}
+
+/// Register the Fortran dgesv function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `dgesv` function pointer.
+pub unsafe fn register_dgesv(f: DgesvFnPtr) {
+    DGESV
+        .set(f)
+        .expect("dgesv already registered (can only be set once)");
+}
+
+/// Register the Fortran cgesv function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `cgesv` function pointer.
+pub unsafe fn register_cgesv(f: CgesvFnPtr) {
+    CGESV
+        .set(f)
+        .expect("cgesv already registered (can only be set once)");
+}
+
+/// Register the Fortran zgesv function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `zgesv` function pointer.
+pub unsafe fn register_zgesv(f: ZgesvFnPtr) {
+    ZGESV
+        .set(f)
+        .expect("zgesv already registered (can only be set once)");
+}
+
+/// Register the Fortran sgeqrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `sgeqrf` function pointer.
+pub unsafe fn register_sgeqrf(f: SgeqrfFnPtr) {
+    SGEQRF
+        .set(f)
+        .expect("sgeqrf already registered (can only be set once)");
+}
+
+/// Register the Fortran dgeqrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `dgeqrf` function pointer.
+pub unsafe fn register_dgeqrf(f: DgeqrfFnPtr) {
+    DGEQRF
+        .set(f)
+        .expect("dgeqrf already registered (can only be set once)");
+}
+
+/// Register the Fortran cgeqrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `cgeqrf` function pointer.
+pub unsafe fn register_cgeqrf(f: CgeqrfFnPtr) {
+    CGEQRF
+        .set(f)
+        .expect("cgeqrf already registered (can only be set once)");
+}
+
+/// Register the Fortran zgeqrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `zgeqrf` function pointer.
+pub unsafe fn register_zgeqrf(f: ZgeqrfFnPtr) {
+    ZGEQRF
+        .set(f)
+        .expect("zgeqrf already registered (can only be set once)");
+}
+
+/// Register the Fortran ssyev function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `ssyev` function pointer.
+pub unsafe fn register_ssyev(f: SsyevFnPtr) {
+    SSYEV
+        .set(f)
+        .expect("ssyev already registered (can only be set once)");
+}
+
+/// Register the Fortran dsyev function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `dsyev` function pointer.
+pub unsafe fn register_dsyev(f: DsyevFnPtr) {
+    DSYEV
+        .set(f)
+        .expect("dsyev already registered (can only be set once)");
+}
+
+/// Register the Fortran cheev function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `cheev` function pointer.
+pub unsafe fn register_cheev(f: CheevFnPtr) {
+    CHEEV
+        .set(f)
+        .expect("cheev already registered (can only be set once)");
+}
+
+/// Register the Fortran zheev function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `zheev` function pointer.
+pub unsafe fn register_zheev(f: ZheevFnPtr) {
+    ZHEEV
+        .set(f)
+        .expect("zheev already registered (can only be set once)");
+}
+
+/// Register the Fortran sgbtrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `sgbtrf` function pointer.
+pub unsafe fn register_sgbtrf(f: SgbtrfFnPtr) {
+    SGBTRF
+        .set(f)
+        .expect("sgbtrf already registered (can only be set once)");
+}
+
+/// Register the Fortran dgbtrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `dgbtrf` function pointer.
+pub unsafe fn register_dgbtrf(f: DgbtrfFnPtr) {
+    DGBTRF
+        .set(f)
+        .expect("dgbtrf already registered (can only be set once)");
+}
+
+/// Register the Fortran cgbtrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `cgbtrf` function pointer.
+pub unsafe fn register_cgbtrf(f: CgbtrfFnPtr) {
+    CGBTRF
+        .set(f)
+        .expect("cgbtrf already registered (can only be set once)");
+}
+
+/// Register the Fortran zgbtrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `zgbtrf` function pointer.
+pub unsafe fn register_zgbtrf(f: ZgbtrfFnPtr) {
+    ZGBTRF
+        .set(f)
+        .expect("zgbtrf already registered (can only be set once)");
+}
+
+/// Register the Fortran sgttrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `sgttrf` function pointer.
+pub unsafe fn register_sgttrf(f: SgttrfFnPtr) {
+    SGTTRF
+        .set(f)
+        .expect("sgttrf already registered (can only be set once)");
+}
+
+/// Register the Fortran dgttrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `dgttrf` function pointer.
+pub unsafe fn register_dgttrf(f: DgttrfFnPtr) {
+    DGTTRF
+        .set(f)
+        .expect("dgttrf already registered (can only be set once)");
+}
+
+/// Register the Fortran cgttrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `cgttrf` function pointer.
+pub unsafe fn register_cgttrf(f: CgttrfFnPtr) {
+    CGTTRF
+        .set(f)
+        .expect("cgttrf already registered (can only be set once)");
+}
+
+/// Register the Fortran zgttrf function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `zgttrf` function pointer.
+pub unsafe fn register_zgttrf(f: ZgttrfFnPtr) {
+    ZGTTRF
+        .set(f)
+        .expect("zgttrf already registered (can only be set once)");
+}
+
+/// Register the Fortran sgetrs function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `sgetrs` function pointer.
+pub unsafe fn register_sgetrs(f: SgetrsFnPtr) {
+    SGETRS
+        .set(f)
+        .expect("sgetrs already registered (can only be set once)");
+}
+
+/// Register the Fortran dgetrs function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `dgetrs` function pointer.
+pub unsafe fn register_dgetrs(f: DgetrsFnPtr) {
+    DGETRS
+        .set(f)
+        .expect("dgetrs already registered (can only be set once)");
+}
+
+/// Register the Fortran cgetrs function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `cgetrs` function pointer.
+pub unsafe fn register_cgetrs(f: CgetrsFnPtr) {
+    CGETRS
+        .set(f)
+        .expect("cgetrs already registered (can only be set once)");
+}
+
+/// Register the Fortran zgetrs function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `zgetrs` function pointer.
+pub unsafe fn register_zgetrs(f: ZgetrsFnPtr) {
+    ZGETRS
+        .set(f)
+        .expect("zgetrs already registered (can only be set once)");
+}
+
+/// Register the Fortran sgetri function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `sgetri` function pointer.
+pub unsafe fn register_sgetri(f: SgetriFnPtr) {
+    SGETRI
+        .set(f)
+        .expect("sgetri already registered (can only be set once)");
+}
+
+/// Register the Fortran dgetri function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `dgetri` function pointer.
+pub unsafe fn register_dgetri(f: DgetriFnPtr) {
+    DGETRI
+        .set(f)
+        .expect("dgetri already registered (can only be set once)");
+}
+
+/// Register the Fortran cgetri function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `cgetri` function pointer.
+pub unsafe fn register_cgetri(f: CgetriFnPtr) {
+    CGETRI
+        .set(f)
+        .expect("cgetri already registered (can only be set once)");
+}
+
+/// Register the Fortran zgetri function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `zgetri` function pointer.
+pub unsafe fn register_zgetri(f: ZgetriFnPtr) {
+    ZGETRI
+        .set(f)
+        .expect("zgetri already registered (can only be set once)");
+}
+
+/// Register the Fortran sgels function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `sgels` function pointer.
+pub unsafe fn register_sgels(f: SgelsFnPtr) {
+    SGELS
+        .set(f)
+        .expect("sgels already registered (can only be set once)");
+}
+
+/// Register the Fortran dgels function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `dgels` function pointer.
+pub unsafe fn register_dgels(f: DgelsFnPtr) {
+    DGELS
+        .set(f)
+        .expect("dgels already registered (can only be set once)");
+}
+
+/// Register the Fortran cgels function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `cgels` function pointer.
+pub unsafe fn register_cgels(f: CgelsFnPtr) {
+    CGELS
+        .set(f)
+        .expect("cgels already registered (can only be set once)");
+}
+
+/// Register the Fortran zgels function pointer.
+///
+/// # Safety
+///
+/// `f` must be a valid Fortran `zgels` function pointer.
+pub unsafe fn register_zgels(f: ZgelsFnPtr) {
+    ZGELS
+        .set(f)
+        .expect("zgels already registered (can only be set once)");
+}
+
+// =============================================================================
+// Internal getters (used by lapack/*.rs)
+// =============================================================================
+
+pub(crate) fn get_sgetrf() -> SgetrfFnPtr {
+    *SGETRF
+        .get()
+        .expect("sgetrf not registered: call register_sgetrf() first")
+}
+
+pub(crate) fn get_dgetrf() -> DgetrfFnPtr {
+    *DGETRF
+        .get()
+        .expect("dgetrf not registered: call register_dgetrf() first")
+}
+
+pub(crate) fn get_cgetrf() -> CgetrfFnPtr {
+    *CGETRF
+        .get()
+        .expect("cgetrf not registered: call register_cgetrf() first")
+}
+
+pub(crate) fn get_zgetrf() -> ZgetrfFnPtr {
+    *ZGETRF
+        .get()
+        .expect("zgetrf not registered: call register_zgetrf() first")
+}
+
+pub(crate) fn get_spotrf() -> SpotrfFnPtr {
+    *SPOTRF
+        .get()
+        .expect("spotrf not registered: call register_spotrf() first")
+}
+
+pub(crate) fn get_dpotrf() -> DpotrfFnPtr {
+    *DPOTRF
+        .get()
+        .expect("dpotrf not registered: call register_dpotrf() first")
+}
+
+pub(crate) fn get_cpotrf() -> CpotrfFnPtr {
+    *CPOTRF
+        .get()
+        .expect("cpotrf not registered: call register_cpotrf() first")
+}
+
+pub(crate) fn get_zpotrf() -> ZpotrfFnPtr {
+    *ZPOTRF
+        .get()
+        .expect("zpotrf not registered: call register_zpotrf() first")
+}
+
+pub(crate) fn get_sgesv() -> SgesvFnPtr {
+    *SGESV
+        .get()
+        .expect("sgesv not registered: call register_sgesv() first")
+}
+
+pub(crate) fn get_dgesv() -> DgesvFnPtr {
+    *DGESV
+        .get()
+        .expect("dgesv not registered: call register_dgesv() first")
+}
+
+pub(crate) fn get_cgesv() -> CgesvFnPtr {
+    *CGESV
+        .get()
+        .expect("cgesv not registered: call register_cgesv() first")
+}
+
+pub(crate) fn get_zgesv() -> ZgesvFnPtr {
+    *ZGESV
+        .get()
+        .expect("zgesv not registered: call register_zgesv() first")
+}
+
+pub(crate) fn get_sgeqrf() -> SgeqrfFnPtr {
+    *SGEQRF
+        .get()
+        .expect("sgeqrf not registered: call register_sgeqrf() first")
+}
+
+pub(crate) fn get_dgeqrf() -> DgeqrfFnPtr {
+    *DGEQRF
+        .get()
+        .expect("dgeqrf not registered: call register_dgeqrf() first")
+}
+
+pub(crate) fn get_cgeqrf() -> CgeqrfFnPtr {
+    *CGEQRF
+        .get()
+        .expect("cgeqrf not registered: call register_cgeqrf() first")
+}
+
+pub(crate) fn get_zgeqrf() -> ZgeqrfFnPtr {
+    *ZGEQRF
+        .get()
+        .expect("zgeqrf not registered: call register_zgeqrf() first")
+}
+
+pub(crate) fn get_ssyev() -> SsyevFnPtr {
+    *SSYEV
+        .get()
+        .expect("ssyev not registered: call register_ssyev() first")
+}
+
+pub(crate) fn get_dsyev() -> DsyevFnPtr {
+    *DSYEV
+        .get()
+        .expect("dsyev not registered: call register_dsyev() first")
+}
+
+pub(crate) fn get_cheev() -> CheevFnPtr {
+    *CHEEV
+        .get()
+        .expect("cheev not registered: call register_cheev() first")
+}
+
+pub(crate) fn get_zheev() -> ZheevFnPtr {
+    *ZHEEV
+        .get()
+        .expect("zheev not registered: call register_zheev() first")
+}
+
+pub(crate) fn get_sgbtrf() -> SgbtrfFnPtr {
+    *SGBTRF
+        .get()
+        .expect("sgbtrf not registered: call register_sgbtrf() first")
+}
+
+pub(crate) fn get_dgbtrf() -> DgbtrfFnPtr {
+    *DGBTRF
+        .get()
+        .expect("dgbtrf not registered: call register_dgbtrf() first")
+}
+
+pub(crate) fn get_cgbtrf() -> CgbtrfFnPtr {
+    *CGBTRF
+        .get()
+        .expect("cgbtrf not registered: call register_cgbtrf() first")
+}
+
+pub(crate) fn get_zgbtrf() -> ZgbtrfFnPtr {
+    *ZGBTRF
+        .get()
+        .expect("zgbtrf not registered: call register_zgbtrf() first")
+}
+
+pub(crate) fn get_sgttrf() -> SgttrfFnPtr {
+    *SGTTRF
+        .get()
+        .expect("sgttrf not registered: call register_sgttrf() first")
+}
+
+pub(crate) fn get_dgttrf() -> DgttrfFnPtr {
+    *DGTTRF
+        .get()
+        .expect("dgttrf not registered: call register_dgttrf() first")
+}
+
+pub(crate) fn get_cgttrf() -> CgttrfFnPtr {
+    *CGTTRF
+        .get()
+        .expect("cgttrf not registered: call register_cgttrf() first")
+}
+
+pub(crate) fn get_zgttrf() -> ZgttrfFnPtr {
+    *ZGTTRF
+        .get()
+        .expect("zgttrf not registered: call register_zgttrf() first")
+}
+
+pub(crate) fn get_sgetrs() -> SgetrsFnPtr {
+    *SGETRS
+        .get()
+        .expect("sgetrs not registered: call register_sgetrs() first")
+}
+
+pub(crate) fn get_dgetrs() -> DgetrsFnPtr {
+    *DGETRS
+        .get()
+        .expect("dgetrs not registered: call register_dgetrs() first")
+}
+
+pub(crate) fn get_cgetrs() -> CgetrsFnPtr {
+    *CGETRS
+        .get()
+        .expect("cgetrs not registered: call register_cgetrs() first")
+}
+
+pub(crate) fn get_zgetrs() -> ZgetrsFnPtr {
+    *ZGETRS
+        .get()
+        .expect("zgetrs not registered: call register_zgetrs() first")
+}
+
+pub(crate) fn get_sgetri() -> SgetriFnPtr {
+    *SGETRI
+        .get()
+        .expect("sgetri not registered: call register_sgetri() first")
+}
+
+pub(crate) fn get_dgetri() -> DgetriFnPtr {
+    *DGETRI
+        .get()
+        .expect("dgetri not registered: call register_dgetri() first")
+}
+
+pub(crate) fn get_cgetri() -> CgetriFnPtr {
+    *CGETRI
+        .get()
+        .expect("cgetri not registered: call register_cgetri() first")
+}
+
+pub(crate) fn get_zgetri() -> ZgetriFnPtr {
+    *ZGETRI
+        .get()
+        .expect("zgetri not registered: call register_zgetri() first")
+}
+
+pub(crate) fn get_sgels() -> SgelsFnPtr {
+    *SGELS
+        .get()
+        .expect("sgels not registered: call register_sgels() first")
+}
+
+pub(crate) fn get_dgels() -> DgelsFnPtr {
+    *DGELS
+        .get()
+        .expect("dgels not registered: call register_dgels() first")
+}
+
+pub(crate) fn get_cgels() -> CgelsFnPtr {
+    *CGELS
+        .get()
+        .expect("cgels not registered: call register_cgels() first")
+}
+
+pub(crate) fn get_zgels() -> ZgelsFnPtr {
+    *ZGELS
+        .get()
+        .expect("zgels not registered: call register_zgels() first")
+}
@@ -0,0 +1,184 @@
+//! Shared row-major/col-major parameter conversion for TRMM, GBMV, and TRSV.
+//!
+//! These three routines' per-routine conversions had drifted into copy-pasted
+//! `match order { ... }` blocks that each got a slightly different shape (TRMM
+//! swaps `m`/`n` and inverts `side`/`uplo` but leaves `trans` alone; GBMV swaps
+//! `m`/`n` and `kl`/`ku` and flips `trans`; TRSV inverts `uplo` and `trans`),
+//! making the individual wrappers hard to audit against each other. Centralizing
+//! just these three here — rather than every routine, per the crate-root doc
+//! comment — keeps the conversion tables in one place where a future routine
+//! needing the same shape can reuse them, and lets the table itself be unit
+//! tested independent of any registered BLAS backend.
+//!
+//! Complex-only `Conj*` handling (conjugating a copy and calling back in with
+//! plain `NoTrans`) stays in each routine's own module: it differs enough
+//! between TRMM/GBMV/TRSV/TRSM that folding it in here would just move the
+//! per-routine special-casing rather than remove it.
+
+use crate::types::{
+    blasint, flip_side, flip_transpose_real, flip_uplo, normalize_transpose_real, CblasColMajor,
+    CblasConjNoTrans, CblasConjTrans, CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_DIAG,
+    CBLAS_ORDER, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO,
+};
+
+/// Row-major/col-major parameter bundle for `?trmm`/`?trsm`: for `CblasRowMajor`,
+/// swaps `m`/`n` and inverts `side`/`uplo`; `trans`/`diag` pass through unchanged
+/// in both cases. Mirrors OpenBLAS's `interface/trmm.c`.
+pub(crate) fn trmm_convert(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blasint,
+    n: blasint,
+) -> (CBLAS_SIDE, CBLAS_UPLO, CBLAS_TRANSPOSE, CBLAS_DIAG, blasint, blasint) {
+    match order {
+        CblasColMajor => (side, uplo, trans, diag, m, n),
+        CblasRowMajor => (flip_side(side), flip_uplo(uplo), trans, diag, n, m),
+    }
+}
+
+/// Row-major/col-major parameter bundle for `?gbmv`: for `CblasRowMajor`, swaps
+/// `m`/`n` and `kl`/`ku` and flips `trans`. `CblasConjTrans` maps to
+/// `CblasNoTrans` here — real callers never pass it, and complex callers handle
+/// `CblasConjTrans` themselves before reaching this (conjugating into a scratch
+/// buffer and calling back in with plain `NoTrans`) — so by the time a complex
+/// wrapper calls this, `trans` is never `CblasConjTrans` either.
+///
+/// `CblasConjNoTrans` (`op(A) = conj(A)`, no transpose) needs no such scratch
+/// buffer: the swap above already reinterprets the stored data as its own
+/// transpose, and conjugation commutes with transposition elementwise
+/// (`conj(Aᵀ) = conj(A)ᵀ`), so the view's conjugate-transpose — exactly what
+/// Fortran's `'C'` code computes — already equals the desired `conj(A)`. Maps to
+/// `CblasConjTrans` directly, unlike `CblasConjTrans` itself (which needs the
+/// scratch-buffer workaround precisely because Fortran has no code for
+/// "conjugate without transpose").
+pub(crate) fn gbmv_convert(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: blasint,
+    n: blasint,
+    kl: blasint,
+    ku: blasint,
+) -> (CBLAS_TRANSPOSE, blasint, blasint, blasint, blasint) {
+    match order {
+        CblasColMajor => (trans, m, n, kl, ku),
+        CblasRowMajor => {
+            let flipped = match trans {
+                CblasNoTrans => CblasTrans,
+                CblasTrans => CblasNoTrans,
+                CblasConjTrans => CblasNoTrans,
+                CblasConjNoTrans => CblasConjTrans,
+            };
+            (flipped, n, m, ku, kl)
+        }
+    }
+}
+
+/// Leading dimension required to hold a band matrix with `kl` sub-diagonals and
+/// `ku` super-diagonals plus the main diagonal.
+pub(crate) fn band_lda(kl: blasint, ku: blasint) -> blasint {
+    kl + ku + 1
+}
+
+/// Row-major/col-major parameter bundle for real `?trsv`: normalizes `trans` via
+/// [`normalize_transpose_real`] first (folding `ConjTrans`/`ConjNoTrans` into
+/// `Trans`/`NoTrans`, since real TRSV has no conjugation to apply), then for
+/// `CblasRowMajor` inverts `uplo` and `trans`.
+pub(crate) fn trsv_convert(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blasint,
+) -> (CBLAS_UPLO, CBLAS_TRANSPOSE, blasint) {
+    let trans = normalize_transpose_real(trans);
+    match order {
+        CblasColMajor => (uplo, trans, n),
+        CblasRowMajor => (flip_uplo(uplo), flip_transpose_real(trans), n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        CblasConjNoTrans, CblasLeft, CblasLower, CblasNonUnit, CblasRight, CblasUnit, CblasUpper,
+    };
+
+    #[test]
+    fn trmm_convert_col_major_is_identity() {
+        assert_eq!(
+            trmm_convert(CblasColMajor, CblasLeft, CblasUpper, CblasTrans, CblasUnit, 3, 4),
+            (CblasLeft, CblasUpper, CblasTrans, CblasUnit, 3, 4)
+        );
+        assert_eq!(
+            trmm_convert(CblasColMajor, CblasRight, CblasLower, CblasNoTrans, CblasNonUnit, 2, 5),
+            (CblasRight, CblasLower, CblasNoTrans, CblasNonUnit, 2, 5)
+        );
+    }
+
+    #[test]
+    fn trmm_convert_row_major_swaps_and_inverts() {
+        assert_eq!(
+            trmm_convert(CblasRowMajor, CblasLeft, CblasUpper, CblasTrans, CblasUnit, 3, 4),
+            (CblasRight, CblasLower, CblasTrans, CblasUnit, 4, 3)
+        );
+        assert_eq!(
+            trmm_convert(CblasRowMajor, CblasRight, CblasLower, CblasConjNoTrans, CblasNonUnit, 2, 5),
+            (CblasLeft, CblasUpper, CblasConjNoTrans, CblasNonUnit, 5, 2)
+        );
+    }
+
+    #[test]
+    fn gbmv_convert_col_major_is_identity() {
+        assert_eq!(
+            gbmv_convert(CblasColMajor, CblasNoTrans, 5, 7, 1, 2),
+            (CblasNoTrans, 5, 7, 1, 2)
+        );
+    }
+
+    #[test]
+    fn gbmv_convert_row_major_swaps_and_flips() {
+        assert_eq!(
+            gbmv_convert(CblasRowMajor, CblasNoTrans, 5, 7, 1, 2),
+            (CblasTrans, 7, 5, 2, 1)
+        );
+        assert_eq!(
+            gbmv_convert(CblasRowMajor, CblasTrans, 5, 7, 1, 2),
+            (CblasNoTrans, 7, 5, 2, 1)
+        );
+        assert_eq!(
+            gbmv_convert(CblasRowMajor, CblasConjTrans, 5, 7, 1, 2),
+            (CblasNoTrans, 7, 5, 2, 1)
+        );
+        assert_eq!(
+            gbmv_convert(CblasRowMajor, CblasConjNoTrans, 5, 7, 1, 2),
+            (CblasConjTrans, 7, 5, 2, 1)
+        );
+    }
+
+    #[test]
+    fn band_lda_is_kl_plus_ku_plus_one() {
+        assert_eq!(band_lda(0, 0), 1);
+        assert_eq!(band_lda(2, 3), 6);
+    }
+
+    #[test]
+    fn trsv_convert_col_major_normalizes_but_does_not_flip() {
+        assert_eq!(trsv_convert(CblasColMajor, CblasUpper, CblasTrans, 9), (CblasUpper, CblasTrans, 9));
+        assert_eq!(
+            trsv_convert(CblasColMajor, CblasLower, CblasConjNoTrans, 9),
+            (CblasLower, CblasNoTrans, 9)
+        );
+    }
+
+    #[test]
+    fn trsv_convert_row_major_normalizes_then_inverts() {
+        assert_eq!(trsv_convert(CblasRowMajor, CblasUpper, CblasTrans, 9), (CblasLower, CblasNoTrans, 9));
+        assert_eq!(
+            trsv_convert(CblasRowMajor, CblasLower, CblasConjTrans, 9),
+            (CblasUpper, CblasNoTrans, 9)
+        );
+    }
+}
@@ -28,46 +28,122 @@
 //!
 //! ## Row-Major Handling
 //!
-//! For BLAS operations (GEMM, etc.), row-major data is handled via argument swapping
-//! without memory copy, following the same approach as OpenBLAS:
-//! <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/gemm.c#L489-L537>
+//! Every `cblas_*` entry point in [`blas1`]/[`blas2`]/[`blas3`] is this translation
+//! layer: a `CblasColMajor` call forwards straight to the registered Fortran pointer,
+//! while a `CblasRowMajor` call is rewritten into an algebraically equivalent
+//! column-major one, without copying the matrix. The identity used depends on the
+//! routine's shape:
+//! - GEMM and other rectangular multiplies swap operands and dimensions, following the
+//!   same approach as OpenBLAS:
+//!   <https://github.com/OpenMathLib/OpenBLAS/blob/develop/interface/gemm.c#L489-L537>
+//! - Routines with a `CBLAS_TRANSPOSE` argument (GEMV, GBMV, TRMV, ...) swap `m`/`n` and
+//!   flip `CblasNoTrans`/`CblasTrans`; TRMM/TRSM instead swap `m`/`n`, flip the side and
+//!   the triangle, and keep the transpose flag
+//! - Triangular/symmetric/Hermitian routines with a `CBLAS_UPLO` argument additionally
+//!   flip `CblasUpper`/`CblasLower`, since transposing the stored triangle reinterprets
+//!   which half of the matrix it names
+//! - `CblasConjNoTrans`/`CblasConjTrans` have no Fortran character code in either
+//!   layout; the complex routines realize them by conjugating a vector or matrix copy
+//!   around a plain `NoTrans`/`CblasTrans` call instead (see e.g. `blas2::trmv`'s module
+//!   doc for the row-major `ConjTrans` case)
+//! - `cblas_cdotu_sub`/`cblas_cdotc_sub`/`cblas_zdotu_sub`/`cblas_zdotc_sub` map the
+//!   CBLAS out-parameter form onto whichever [`ComplexReturnStyle`] the registered
+//!   `cdotu`/`cdotc`/`zdotu`/`zdotc` pointer actually uses (see [`backend::get_complex_return_style`])
+//!
+//! Most modules still implement the identity inline for their own routine shape,
+//! matching the argument-swap approach OpenBLAS itself uses rather than adding an
+//! indirection layer on top of it. A few routines (TRMM, GBMV, TRSV) pulled their
+//! conversion into [`layout`] instead, once copy-pasted per-routine variants of the
+//! same swap started drifting out of sync with each other; see that module's doc
+//! for why those three and not the rest.
 
+mod accumulate;
 mod backend;
+mod backend_trait;
+mod conj;
+pub mod counters;
+mod lapack_backend;
+mod layout;
+mod nrm2;
+pub mod ops;
+mod registry;
+mod simd;
+pub mod symbols;
+pub mod trace;
 mod types;
+mod validation;
 mod xerbla;
 
 #[cfg(feature = "openblas")]
 mod autoregister;
 
+#[cfg(feature = "openblas")]
+mod lapack_autoregister;
+
+#[cfg(feature = "reference")]
+mod reference;
+
+#[cfg(feature = "reference")]
+pub use reference::enable_reference_fallback;
+
+#[cfg(feature = "dlopen")]
+mod dlopen;
+
+#[cfg(feature = "ndarray")]
+pub mod ndarray_adapter;
+
 pub mod blas1;
 pub mod blas2;
 pub mod blas3;
+pub mod lapack;
 
 pub use backend::*;
+pub use backend_trait::{register_backend, BlasBackend, MockBackend};
+pub use symbols::BlasSymbol;
+pub use registry::{
+    is_registered, missing_symbols, register_all, register_all_detailed, register_by_name,
+    registered_symbols, register_all_report_missing, RegisterError, RegistrationReport,
+    ALL_SYMBOLS,
+};
 pub use types::*;
 
+#[cfg(feature = "dlopen")]
+pub use dlopen::{
+    load_backend, register_from_library, register_from_library_with_mangle, LoadError,
+    LoadReport,
+};
+
 // Re-export commonly used functions at crate root
 // BLAS Level 1
 pub use blas1::dot::{
     cblas_cdotc_sub, cblas_cdotu_sub, cblas_dasum, cblas_ddot, cblas_dnrm2, cblas_dsdot,
-    cblas_dzasum, cblas_dznrm2, cblas_icamax, cblas_idamax, cblas_isamax, cblas_izamax,
+    cblas_dzasum, cblas_dznrm2, cblas_icamax, cblas_icamin, cblas_idamax, cblas_idamin,
+    cblas_isamax, cblas_isamin, cblas_izamax, cblas_izamin,
     cblas_sasum, cblas_scasum, cblas_scnrm2, cblas_sdot, cblas_sdsdot, cblas_snrm2,
     cblas_zdotc_sub, cblas_zdotu_sub,
 };
 pub use blas1::rot::{
-    cblas_dcabs1, cblas_drot, cblas_drotg, cblas_drotm, cblas_drotmg, cblas_scabs1, cblas_srot,
-    cblas_srotg, cblas_srotm, cblas_srotmg,
+    cblas_crotg, cblas_csrot, cblas_dcabs1, cblas_drot, cblas_drotg, cblas_drotm, cblas_drotmg,
+    cblas_scabs1, cblas_srot, cblas_srotg, cblas_srotm, cblas_srotmg, cblas_zdrot, cblas_zrotg,
+    drotm_typed, drotmg_typed, srotm_typed, srotmg_typed, ModifiedGivensFlag, ModifiedGivensParams,
 };
 pub use blas1::vector::{
     cblas_caxpy, cblas_ccopy, cblas_cscal, cblas_csscal, cblas_cswap, cblas_daxpy, cblas_dcopy,
     cblas_dscal, cblas_dswap, cblas_saxpy, cblas_scopy, cblas_sscal, cblas_sswap, cblas_zaxpy,
-    cblas_zcopy, cblas_zdscal, cblas_zscal, cblas_zswap,
+    cblas_zcopy, cblas_zdscal, cblas_zscal, cblas_zswap, try_daxpy, try_dcopy, try_dscal,
+    try_dswap,
+};
+pub use blas1::level1f::{
+    cblas_caxpyf, cblas_cdotxf, cblas_daxpyf, cblas_ddotxf, cblas_saxpyf, cblas_sdotxf,
+    cblas_zaxpyf, cblas_zdotxf,
 };
 
 // BLAS Level 2
 pub use blas2::gbmv::{cblas_cgbmv, cblas_dgbmv, cblas_sgbmv, cblas_zgbmv};
-pub use blas2::gemv::{cblas_cgemv, cblas_dgemv, cblas_sgemv, cblas_zgemv};
-pub use blas2::ger::{cblas_cgerc, cblas_cgeru, cblas_dger, cblas_sger, cblas_zgerc, cblas_zgeru};
+pub use blas2::gemv::{cblas_cgemv, cblas_dgemv, cblas_sgemv, cblas_zgemv, try_dgemv};
+pub use blas2::ger::{
+    cblas_cgerc, cblas_cgeru, cblas_dger, cblas_sger, cblas_zgerc, cblas_zgeru, try_dger,
+};
 pub use blas2::sbmv::{cblas_chbmv, cblas_dsbmv, cblas_ssbmv, cblas_zhbmv};
 pub use blas2::symv::{cblas_chemv, cblas_dsymv, cblas_ssymv, cblas_zhemv};
 pub use blas2::syr::{
@@ -77,27 +153,63 @@ pub use blas2::syr::{
 pub use blas2::spmv::{cblas_chpmv, cblas_dspmv, cblas_sspmv, cblas_zhpmv};
 pub use blas2::spr::{
     cblas_chpr, cblas_chpr2, cblas_dspr, cblas_dspr2, cblas_sspr, cblas_sspr2, cblas_zhpr,
-    cblas_zhpr2,
+    cblas_zhpr2, try_chpr, try_chpr2, try_dspr, try_dspr2, try_sspr, try_sspr2, try_zhpr,
+    try_zhpr2,
+};
+pub use blas2::tbmv::{
+    cblas_ctbmv, cblas_dtbmv, cblas_stbmv, cblas_ztbmv, try_ctbmv, try_dtbmv, try_stbmv,
+    try_ztbmv,
 };
-pub use blas2::tbmv::{cblas_ctbmv, cblas_dtbmv, cblas_stbmv, cblas_ztbmv};
 pub use blas2::tbsv::{cblas_ctbsv, cblas_dtbsv, cblas_stbsv, cblas_ztbsv};
 pub use blas2::tpmv::{
-    cblas_ctpmv, cblas_ctpsv, cblas_dtpmv, cblas_dtpsv, cblas_stpmv, cblas_stpsv, cblas_ztpmv,
-    cblas_ztpsv,
+    cblas_ctpmv, cblas_ctpsv, cblas_ctpsv_refine, cblas_dtpmv, cblas_dtpsv, cblas_dtpsv_refine,
+    cblas_stpmv, cblas_stpsv, cblas_stpsv_refine, cblas_ztpmv, cblas_ztpsv, cblas_ztpsv_refine,
 };
-pub use blas2::trmv::{cblas_ctrmv, cblas_dtrmv, cblas_strmv, cblas_ztrmv};
+pub use blas2::trmv::{cblas_ctrmv, cblas_dtrmv, cblas_strmv, cblas_ztrmv, try_dtrmv};
 pub use blas2::trsv::{cblas_ctrsv, cblas_dtrsv, cblas_strsv, cblas_ztrsv};
 
 // BLAS Level 3
-pub use blas3::gemm::{cblas_cgemm, cblas_dgemm, cblas_sgemm, cblas_zgemm};
+pub use blas3::gemm::{cblas_cgemm, cblas_dgemm, cblas_sgemm, cblas_zgemm, try_dgemm};
+pub use blas3::gemm_lowp::{
+    gemmlowp_gemm_i8, gemmlowp_requantize, set_quant_gemm_params, QuantGemmParams,
+};
 pub use blas3::hemm::{cblas_chemm, cblas_zhemm};
 pub use blas3::her2k::{cblas_cher2k, cblas_zher2k};
 pub use blas3::herk::{cblas_cherk, cblas_zherk};
-pub use blas3::symm::{cblas_csymm, cblas_dsymm, cblas_ssymm, cblas_zsymm};
+pub use blas3::symm::{cblas_csymm, cblas_dsymm, cblas_ssymm, cblas_zsymm, try_dsymm};
 pub use blas3::syr2k::{cblas_csyr2k, cblas_dsyr2k, cblas_ssyr2k, cblas_zsyr2k};
-pub use blas3::syrk::{cblas_csyrk, cblas_dsyrk, cblas_ssyrk, cblas_zsyrk};
+pub use blas3::syrk::{cblas_csyrk, cblas_dsyrk, cblas_ssyrk, cblas_zsyrk, try_dsyrk};
 pub use blas3::trmm::{cblas_ctrmm, cblas_dtrmm, cblas_strmm, cblas_ztrmm};
-pub use blas3::trsm::{cblas_ctrsm, cblas_dtrsm, cblas_strsm, cblas_ztrsm};
+pub use blas3::trsm::{cblas_ctrsm, cblas_dtrsm, cblas_strsm, cblas_ztrsm, try_trsm};
+
+// LAPACK (LAPACKE-style interface)
+pub use lapack::{
+    LAPACKE_cgbtrf, LAPACKE_cgels, LAPACKE_cgesv, LAPACKE_cgeqrf, LAPACKE_cgetrf, LAPACKE_cgetri,
+    LAPACKE_cgetrs, LAPACKE_cgttrf, LAPACKE_cheev, LAPACKE_cpotrf, LAPACKE_dgbtrf, LAPACKE_dgels,
+    LAPACKE_dgesv, LAPACKE_dgeqrf, LAPACKE_dgetrf, LAPACKE_dgetri, LAPACKE_dgetrs, LAPACKE_dgttrf,
+    LAPACKE_dpotrf, LAPACKE_dsyev, LAPACKE_sgbtrf, LAPACKE_sgels, LAPACKE_sgesv, LAPACKE_sgeqrf,
+    LAPACKE_sgetrf, LAPACKE_sgetri, LAPACKE_sgetrs, LAPACKE_sgttrf, LAPACKE_spotrf, LAPACKE_ssyev,
+    LAPACKE_zgbtrf, LAPACKE_zgels, LAPACKE_zgesv, LAPACKE_zgeqrf, LAPACKE_zgetrf, LAPACKE_zgetri,
+    LAPACKE_zgetrs, LAPACKE_zgttrf, LAPACKE_zheev, LAPACKE_zpotrf,
+};
 
 // Error handling
-pub use xerbla::cblas_xerbla;
+pub use xerbla::{
+    cblas_xerbla, clear_last_xerbla_error, last_xerbla_error, register_xerbla, LastXerblaError,
+};
+
+// Opt-in argument validation (SYRK, HEMM, ...)
+pub use validation::{disable_validation, enable_validation, is_validation_enabled};
+
+// Opt-in compensated accumulation for the Level 1 reductions
+pub use accumulate::{accumulation_mode, set_accumulation_mode};
+
+// Call tracing / profiling
+pub use trace::{
+    call_trace_summary, disable_call_tracing, dump_call_trace, enable_call_tracing,
+    is_call_tracing_enabled, reset_call_trace, CallShape, RoutineStats, TraceEntry,
+};
+
+// Per-routine call counters / dispatch hook (currently GEMM + sdot/ddot only; see
+// `crate::counters`'s module doc)
+pub use counters::{call_counts, clear_call_hook, reset_counts, set_call_hook, CallHook, CallInfo, SymbolId};
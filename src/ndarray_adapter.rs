@@ -0,0 +1,170 @@
+//! Optional, safe `ndarray`-based wrapper layer over the `unsafe extern "C"` rotation
+//! and reduction entry points, gated behind the `ndarray` feature.
+//!
+//! Each function here derives `n`/`incx`/`incy` from the view's own length and
+//! stride instead of making the caller compute pointer/stride arithmetic by hand, and
+//! rejects a zero-stride (broadcast) view or a mismatched vector length with
+//! [`NdarrayLayoutError`] instead of reading or writing out of bounds. The raw
+//! `cblas_*` symbols this module calls into are untouched, so C consumers keep using
+//! them exactly as before.
+
+use ndarray::{ArrayView1, ArrayViewMut1};
+
+use crate::blas1::rot::{drotm_typed, srotm_typed, ModifiedGivensParams};
+use crate::types::blasint;
+use num_complex::{Complex32, Complex64};
+
+/// Why an `ndarray` view couldn't be turned into a `(n, incx)` pair for one of the
+/// `cblas_*` calls below.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NdarrayLayoutError {
+    /// The two vectors passed to a two-vector routine (e.g. `drot`) have different
+    /// lengths.
+    LengthMismatch {
+        /// Length of the first vector.
+        x_len: usize,
+        /// Length of the second vector.
+        y_len: usize,
+    },
+    /// The view has stride 0 along its only axis (a broadcast view): every element
+    /// would alias the same address, which `incx = 0` doesn't mean in BLAS (`incx`
+    /// must be non-zero).
+    ZeroStride,
+    /// The view's length or stride doesn't fit in a [`blasint`].
+    DimensionTooLarge,
+}
+
+impl std::fmt::Display for NdarrayLayoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NdarrayLayoutError::LengthMismatch { x_len, y_len } => {
+                write!(f, "vector length mismatch: x has {x_len} elements, y has {y_len}")
+            }
+            NdarrayLayoutError::ZeroStride => {
+                write!(f, "view has stride 0 (a broadcast view), which has no BLAS increment equivalent")
+            }
+            NdarrayLayoutError::DimensionTooLarge => {
+                write!(f, "view length or stride doesn't fit in a BLAS integer")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NdarrayLayoutError {}
+
+/// Derives the `(n, incx)` pair BLAS needs from a 1-D view: `n` is the view's length,
+/// `incx` is its stride in elements (possibly negative, never zero).
+fn view_params<T>(view: &ArrayView1<T>) -> Result<(blasint, blasint), NdarrayLayoutError> {
+    let stride = view.strides()[0];
+    if stride == 0 {
+        return Err(NdarrayLayoutError::ZeroStride);
+    }
+    let n = blasint::try_from(view.len()).map_err(|_| NdarrayLayoutError::DimensionTooLarge)?;
+    let incx = blasint::try_from(stride).map_err(|_| NdarrayLayoutError::DimensionTooLarge)?;
+    Ok((n, incx))
+}
+
+fn mut_view_params<T>(view: &ArrayViewMut1<T>) -> Result<(blasint, blasint), NdarrayLayoutError> {
+    let stride = view.strides()[0];
+    if stride == 0 {
+        return Err(NdarrayLayoutError::ZeroStride);
+    }
+    let n = blasint::try_from(view.len()).map_err(|_| NdarrayLayoutError::DimensionTooLarge)?;
+    let incx = blasint::try_from(stride).map_err(|_| NdarrayLayoutError::DimensionTooLarge)?;
+    Ok((n, incx))
+}
+
+fn check_same_len<T, U>(x: &ArrayViewMut1<T>, y: &ArrayViewMut1<U>) -> Result<(), NdarrayLayoutError> {
+    if x.len() != y.len() {
+        return Err(NdarrayLayoutError::LengthMismatch { x_len: x.len(), y_len: y.len() });
+    }
+    Ok(())
+}
+
+/// Safe `ndarray` wrapper for [`crate::cblas_drot`]: applies the plane rotation
+/// `(c, s)` to `x`/`y` in place.
+///
+/// # Errors
+///
+/// Returns [`NdarrayLayoutError::LengthMismatch`] if `x` and `y` have different
+/// lengths, or [`NdarrayLayoutError::ZeroStride`] if either view has stride 0.
+pub fn drot(mut x: ArrayViewMut1<'_, f64>, mut y: ArrayViewMut1<'_, f64>, c: f64, s: f64) -> Result<(), NdarrayLayoutError> {
+    check_same_len(&x, &y)?;
+    let (n, incx) = mut_view_params(&x)?;
+    let (_, incy) = mut_view_params(&y)?;
+    unsafe {
+        crate::cblas_drot(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy, c, s);
+    }
+    Ok(())
+}
+
+/// Safe `ndarray` wrapper for [`crate::cblas_srot`]; see [`drot`].
+pub fn srot(mut x: ArrayViewMut1<'_, f32>, mut y: ArrayViewMut1<'_, f32>, c: f32, s: f32) -> Result<(), NdarrayLayoutError> {
+    check_same_len(&x, &y)?;
+    let (n, incx) = mut_view_params(&x)?;
+    let (_, incy) = mut_view_params(&y)?;
+    unsafe {
+        crate::cblas_srot(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy, c, s);
+    }
+    Ok(())
+}
+
+/// Safe `ndarray` wrapper for [`drotm_typed`]: applies the modified-Givens rotation
+/// described by `params` to `x`/`y` in place.
+///
+/// # Errors
+///
+/// Same as [`drot`].
+pub fn drotm(
+    mut x: ArrayViewMut1<'_, f64>,
+    mut y: ArrayViewMut1<'_, f64>,
+    params: ModifiedGivensParams<f64>,
+) -> Result<(), NdarrayLayoutError> {
+    check_same_len(&x, &y)?;
+    let (n, incx) = mut_view_params(&x)?;
+    let (_, incy) = mut_view_params(&y)?;
+    unsafe {
+        drotm_typed(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy, params);
+    }
+    Ok(())
+}
+
+/// Safe `ndarray` wrapper for [`srotm_typed`]; see [`drotm`].
+pub fn srotm(
+    mut x: ArrayViewMut1<'_, f32>,
+    mut y: ArrayViewMut1<'_, f32>,
+    params: ModifiedGivensParams<f32>,
+) -> Result<(), NdarrayLayoutError> {
+    check_same_len(&x, &y)?;
+    let (n, incx) = mut_view_params(&x)?;
+    let (_, incy) = mut_view_params(&y)?;
+    unsafe {
+        srotm_typed(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy, params);
+    }
+    Ok(())
+}
+
+/// Safe `ndarray` wrapper for [`crate::cblas_scasum`].
+pub fn scasum(x: ArrayView1<'_, Complex32>) -> Result<f32, NdarrayLayoutError> {
+    let (n, incx) = view_params(&x)?;
+    Ok(unsafe { crate::cblas_scasum(n, x.as_ptr(), incx) })
+}
+
+/// Safe `ndarray` wrapper for [`crate::cblas_dzasum`].
+pub fn dzasum(x: ArrayView1<'_, Complex64>) -> Result<f64, NdarrayLayoutError> {
+    let (n, incx) = view_params(&x)?;
+    Ok(unsafe { crate::cblas_dzasum(n, x.as_ptr(), incx) })
+}
+
+/// Safe `ndarray` wrapper for [`crate::cblas_icamax`]. Returns a 0-based index, like
+/// the CBLAS routine it wraps.
+pub fn icamax(x: ArrayView1<'_, Complex32>) -> Result<usize, NdarrayLayoutError> {
+    let (n, incx) = view_params(&x)?;
+    Ok(unsafe { crate::cblas_icamax(n, x.as_ptr(), incx) } as usize)
+}
+
+/// Safe `ndarray` wrapper for [`crate::cblas_izamax`]; see [`icamax`].
+pub fn izamax(x: ArrayView1<'_, Complex64>) -> Result<usize, NdarrayLayoutError> {
+    let (n, incx) = view_params(&x)?;
+    Ok(unsafe { crate::cblas_izamax(n, x.as_ptr(), incx) } as usize)
+}
@@ -0,0 +1,180 @@
+//! Safe, layout-agnostic high-level wrappers over the injected CBLAS symbols.
+//!
+//! [`Matrix`]/[`MatrixMut`] pair a data slice with the leading dimension and
+//! row-/column-major [`Layout`] actually used to store it. [`gemm`] derives the
+//! `CBLAS_ORDER` and `CBLAS_TRANSPOSE` flags needed to multiply two matrices
+//! whatever layout they happen to be stored in, instead of requiring the caller to
+//! hand-derive those flags (or copy operands into a matching layout) for every
+//! combination — see [`try_dgemm`](crate::try_dgemm) for the lower-level slice API
+//! this builds on.
+
+use crate::blas3::gemm::cblas_dgemm;
+use crate::types::{blasint, CblasColMajor, CblasNoTrans, CblasRowMajor, CblasTrans};
+
+/// Row-major or column-major storage order for a [`Matrix`]/[`MatrixMut`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    RowMajor,
+    ColMajor,
+}
+
+/// Returns the minimum `lda` (row-major: `>= cols`, column-major: `>= rows`) and the
+/// outer dimension the storage is sized by (row-major: `rows`, column-major: `cols`).
+fn shape_bounds(rows: usize, cols: usize, layout: Layout) -> (usize, usize) {
+    match layout {
+        Layout::RowMajor => (cols, rows),
+        Layout::ColMajor => (rows, cols),
+    }
+}
+
+/// A borrowed read-only view over a matrix stored contiguously along one axis:
+/// row-major (contiguous within each row, `lda >= cols`) or column-major
+/// (contiguous within each column, `lda >= rows`).
+pub struct Matrix<'a> {
+    data: &'a [f64],
+    rows: usize,
+    cols: usize,
+    lda: usize,
+    layout: Layout,
+}
+
+impl<'a> Matrix<'a> {
+    /// Wraps `data` as a `rows x cols` matrix stored in `layout` with leading
+    /// dimension `lda`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `lda` is too small to hold one row (row-major) or one
+    /// column (column-major) of the matrix, or if `data` is too short for `lda`.
+    pub fn new(
+        data: &'a [f64],
+        rows: usize,
+        cols: usize,
+        lda: usize,
+        layout: Layout,
+    ) -> Result<Self, String> {
+        let (min_lda, outer) = shape_bounds(rows, cols, layout);
+        if lda < min_lda {
+            return Err(format!(
+                "Matrix: lda ({lda}) must be >= {min_lda} for a {rows}x{cols} {layout:?} matrix"
+            ));
+        }
+        if data.len() < lda * outer {
+            return Err(format!(
+                "Matrix: data has {} elements, but lda={lda} needs at least {}",
+                data.len(),
+                lda * outer
+            ));
+        }
+        Ok(Self {
+            data,
+            rows,
+            cols,
+            lda,
+            layout,
+        })
+    }
+}
+
+/// A borrowed mutable view over a matrix, following the same storage rules as
+/// [`Matrix`]. Used as the destination of [`gemm`].
+pub struct MatrixMut<'a> {
+    data: &'a mut [f64],
+    rows: usize,
+    cols: usize,
+    lda: usize,
+    layout: Layout,
+}
+
+impl<'a> MatrixMut<'a> {
+    /// Wraps `data` as a mutable `rows x cols` matrix stored in `layout` with
+    /// leading dimension `lda`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` under the same conditions as [`Matrix::new`].
+    pub fn new(
+        data: &'a mut [f64],
+        rows: usize,
+        cols: usize,
+        lda: usize,
+        layout: Layout,
+    ) -> Result<Self, String> {
+        let (min_lda, outer) = shape_bounds(rows, cols, layout);
+        if lda < min_lda {
+            return Err(format!(
+                "MatrixMut: lda ({lda}) must be >= {min_lda} for a {rows}x{cols} {layout:?} matrix"
+            ));
+        }
+        if data.len() < lda * outer {
+            return Err(format!(
+                "MatrixMut: data has {} elements, but lda={lda} needs at least {}",
+                data.len(),
+                lda * outer
+            ));
+        }
+        Ok(Self {
+            data,
+            rows,
+            cols,
+            lda,
+            layout,
+        })
+    }
+}
+
+/// Computes `c := alpha*a*b + beta*c`, choosing `CBLAS_ORDER` to match `c`'s
+/// storage layout and applying `CblasTrans` to whichever of `a`/`b` is stored in
+/// the other layout, so the caller never derives order/transpose flags by hand.
+/// `lda`/`ldb`/`ldc` are taken directly from each matrix's own leading dimension;
+/// none of the operands need to be freshly copied into a common layout.
+///
+/// # Errors
+///
+/// Returns `Err` if the shapes are incompatible (`a.cols != b.rows`,
+/// `a.rows != c.rows`, or `b.cols != c.cols`) or if `dgemm` hasn't been
+/// registered via `register_dgemm`.
+pub fn gemm(a: &Matrix, b: &Matrix, c: &mut MatrixMut, alpha: f64, beta: f64) -> Result<(), String> {
+    if !crate::backend::has_dgemm() {
+        return Err("ops::gemm: dgemm backend not registered: call register_dgemm first".to_string());
+    }
+    if a.cols != b.rows {
+        return Err(format!(
+            "ops::gemm: a is {}x{} but b is {}x{} (a.cols must equal b.rows)",
+            a.rows, a.cols, b.rows, b.cols
+        ));
+    }
+    if a.rows != c.rows || b.cols != c.cols {
+        return Err(format!(
+            "ops::gemm: a*b is {}x{} but c is {}x{}",
+            a.rows, b.cols, c.rows, c.cols
+        ));
+    }
+
+    let order = match c.layout {
+        Layout::RowMajor => CblasRowMajor,
+        Layout::ColMajor => CblasColMajor,
+    };
+    let transa = if a.layout == c.layout { CblasNoTrans } else { CblasTrans };
+    let transb = if b.layout == c.layout { CblasNoTrans } else { CblasTrans };
+
+    unsafe {
+        cblas_dgemm(
+            order,
+            transa,
+            transb,
+            a.rows as blasint,
+            b.cols as blasint,
+            a.cols as blasint,
+            alpha,
+            a.data.as_ptr(),
+            a.lda as blasint,
+            b.data.as_ptr(),
+            b.lda as blasint,
+            beta,
+            c.data.as_mut_ptr(),
+            c.lda as blasint,
+        );
+    }
+    Ok(())
+}
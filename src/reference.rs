@@ -0,0 +1,3187 @@
+//! Naive pure-Rust reference implementations of the Level 1 routines, GEMM, and a
+//! handful of Level 2/3 kernels (GEMV, GBMV, GER, SYMV/HEMV, HERK/HER2K, TPMV/TPSV, TBMV,
+//! TRSV, SPR/HPR/SPR2/HPR2, TRMM).
+//!
+//! Behind the `reference` feature, the `get_*` accessors in [`crate::backend`] fall back
+//! to these implementations instead of panicking when no backend has been registered.
+//! They favor obvious correctness over speed — no blocking, and (with the exceptions
+//! below) no SIMD or parallelism — so downstream crates can link `cblas-inject`, run
+//! their tests, and get correct-if-slow results without mandatorily wiring up
+//! OpenBLAS/MKL/Accelerate first. Anyone who wants real performance still registers a
+//! real backend; this is only ever a fallback.
+//!
+//! Two exceptions. [`ref_stpsv`]/[`ref_dtpsv`]'s contiguous-stride substitution loop
+//! dispatches to [`crate::simd`]'s runtime-CPU-feature-detected AVX2 kernel when
+//! available. And GEMM ([`ref_sgemm`]/[`ref_dgemm`]/[`ref_cgemm`]/[`ref_zgemm`]) and
+//! GER ([`ref_sger`]/[`ref_dger`]/[`ref_cgeru`]/[`ref_zgeru`]/[`ref_cgerc`]/
+//! [`ref_zgerc`]) write disjoint columns per outer-loop iteration, so under the
+//! `rayon` feature they run one thread per column instead of a sequential loop (see
+//! `SyncPtr`/`SyncConstPtr`). The other Level 2/3 kernels here (GEMV, GBMV, SYMV/HEMV,
+//! ...) accumulate into shared output elements across outer-loop iterations, so
+//! parallelizing them isn't this same one-line change — left sequential for now.
+//!
+//! Every function here matches the Fortran calling convention exactly (argument order,
+//! by-pointer scalars, 1-based `incx`/`incy` stride semantics, negative strides walking
+//! backwards from the first logical element) so it's interchangeable with the `FnPtr`
+//! type it fills in for.
+
+use num_complex::{Complex32, Complex64};
+
+use crate::blasint;
+
+/// Starting pointer and per-step offset for a length-`n` strided traversal, matching the
+/// Fortran convention that a negative `incx` walks backwards from the vector's first
+/// logical element (so the raw pointer must already point at the *last* element in
+/// memory).
+unsafe fn stride_start<T>(n: blasint, ptr: *mut T, inc: blasint) -> (*mut T, isize) {
+    if inc < 0 {
+        (ptr.offset(-(inc as isize) * (n as isize - 1)), inc as isize)
+    } else {
+        (ptr, inc as isize)
+    }
+}
+
+unsafe fn stride_start_const<T>(n: blasint, ptr: *const T, inc: blasint) -> (*const T, isize) {
+    if inc < 0 {
+        (ptr.offset(-(inc as isize) * (n as isize - 1)), inc as isize)
+    } else {
+        (ptr, inc as isize)
+    }
+}
+
+/// Wraps a raw pointer so it can be captured into a `rayon` closure run from multiple
+/// threads. Sound only where every thread's slice of work touches disjoint memory
+/// through this pointer (e.g. one column each of a column-major matrix) — callers are
+/// responsible for that disjointness, this type just opts the pointer back in to
+/// `Send`/`Sync` so the borrow checker doesn't have to see through the `isize` offset
+/// arithmetic each thread does to find its own slice.
+#[cfg(feature = "rayon")]
+#[derive(Clone, Copy)]
+struct SyncPtr<T>(*mut T);
+
+#[cfg(feature = "rayon")]
+unsafe impl<T> Send for SyncPtr<T> {}
+#[cfg(feature = "rayon")]
+unsafe impl<T> Sync for SyncPtr<T> {}
+
+/// Read-only counterpart of [`SyncPtr`], for the operands every thread only reads.
+#[cfg(feature = "rayon")]
+#[derive(Clone, Copy)]
+struct SyncConstPtr<T>(*const T);
+
+#[cfg(feature = "rayon")]
+unsafe impl<T> Send for SyncConstPtr<T> {}
+#[cfg(feature = "rayon")]
+unsafe impl<T> Sync for SyncConstPtr<T> {}
+
+macro_rules! ref_swap {
+    ($name:ident, $ty:ty) => {
+        pub(crate) unsafe extern "C" fn $name(
+            n: *const blasint,
+            x: *mut $ty,
+            incx: *const blasint,
+            y: *mut $ty,
+            incy: *const blasint,
+        ) {
+            let n = *n;
+            let (mut px, sx) = stride_start(n, x, *incx);
+            let (mut py, sy) = stride_start(n, y, *incy);
+            for _ in 0..n {
+                std::mem::swap(&mut *px, &mut *py);
+                px = px.offset(sx);
+                py = py.offset(sy);
+            }
+        }
+    };
+}
+
+ref_swap!(ref_sswap, f32);
+ref_swap!(ref_dswap, f64);
+ref_swap!(ref_cswap, Complex32);
+ref_swap!(ref_zswap, Complex64);
+
+macro_rules! ref_copy {
+    ($name:ident, $ty:ty) => {
+        pub(crate) unsafe extern "C" fn $name(
+            n: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *mut $ty,
+            incy: *const blasint,
+        ) {
+            let n = *n;
+            let (mut px, sx) = stride_start_const(n, x, *incx);
+            let (mut py, sy) = stride_start(n, y, *incy);
+            for _ in 0..n {
+                *py = *px;
+                px = px.offset(sx);
+                py = py.offset(sy);
+            }
+        }
+    };
+}
+
+ref_copy!(ref_scopy, f32);
+ref_copy!(ref_dcopy, f64);
+ref_copy!(ref_ccopy, Complex32);
+ref_copy!(ref_zcopy, Complex64);
+
+macro_rules! ref_axpy {
+    ($name:ident, $ty:ty) => {
+        pub(crate) unsafe extern "C" fn $name(
+            n: *const blasint,
+            alpha: *const $ty,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *mut $ty,
+            incy: *const blasint,
+        ) {
+            let n = *n;
+            let alpha = *alpha;
+            let (mut px, sx) = stride_start_const(n, x, *incx);
+            let (mut py, sy) = stride_start(n, y, *incy);
+            for _ in 0..n {
+                *py = *py + alpha * *px;
+                px = px.offset(sx);
+                py = py.offset(sy);
+            }
+        }
+    };
+}
+
+ref_axpy!(ref_saxpy, f32);
+ref_axpy!(ref_daxpy, f64);
+ref_axpy!(ref_caxpy, Complex32);
+ref_axpy!(ref_zaxpy, Complex64);
+
+macro_rules! ref_scal {
+    ($name:ident, $ty:ty, $alpha_ty:ty) => {
+        pub(crate) unsafe extern "C" fn $name(
+            n: *const blasint,
+            alpha: *const $alpha_ty,
+            x: *mut $ty,
+            incx: *const blasint,
+        ) {
+            let n = *n;
+            let alpha = *alpha;
+            let (mut px, sx) = stride_start(n, x, *incx);
+            for _ in 0..n {
+                *px = *px * alpha;
+                px = px.offset(sx);
+            }
+        }
+    };
+}
+
+ref_scal!(ref_sscal, f32, f32);
+ref_scal!(ref_dscal, f64, f64);
+ref_scal!(ref_cscal, Complex32, Complex32);
+ref_scal!(ref_zscal, Complex64, Complex64);
+ref_scal!(ref_csscal, Complex32, f32);
+ref_scal!(ref_zdscal, Complex64, f64);
+
+macro_rules! ref_rot {
+    ($name:ident, $ty:ty) => {
+        pub(crate) unsafe extern "C" fn $name(
+            n: *const blasint,
+            x: *mut $ty,
+            incx: *const blasint,
+            y: *mut $ty,
+            incy: *const blasint,
+            c: *const $ty,
+            s: *const $ty,
+        ) {
+            let n = *n;
+            let (c, s) = (*c, *s);
+            let (mut px, sx) = stride_start(n, x, *incx);
+            let (mut py, sy) = stride_start(n, y, *incy);
+            for _ in 0..n {
+                let xi = *px;
+                let yi = *py;
+                *px = c * xi + s * yi;
+                *py = c * yi - s * xi;
+                px = px.offset(sx);
+                py = py.offset(sy);
+            }
+        }
+    };
+}
+
+ref_rot!(ref_srot, f32);
+ref_rot!(ref_drot, f64);
+
+macro_rules! ref_crot {
+    ($name:ident, $ty:ty, $real_ty:ty) => {
+        /// Applies a real Givens rotation `(c, s)` to a complex vector pair, per the
+        /// `csrot`/`zdrot` convention: only `x`/`y` are complex, `c`/`s` stay real.
+        pub(crate) unsafe extern "C" fn $name(
+            n: *const blasint,
+            x: *mut $ty,
+            incx: *const blasint,
+            y: *mut $ty,
+            incy: *const blasint,
+            c: *const $real_ty,
+            s: *const $real_ty,
+        ) {
+            let n = *n;
+            let (c, s) = (*c, *s);
+            let (mut px, sx) = stride_start(n, x, *incx);
+            let (mut py, sy) = stride_start(n, y, *incy);
+            for _ in 0..n {
+                let xi = *px;
+                let yi = *py;
+                *px = xi * c + yi * s;
+                *py = yi * c - xi * s;
+                px = px.offset(sx);
+                py = py.offset(sy);
+            }
+        }
+    };
+}
+
+ref_crot!(ref_csrot, Complex32, f32);
+ref_crot!(ref_zdrot, Complex64, f64);
+
+macro_rules! ref_rotg {
+    ($name:ident, $ty:ty) => {
+        /// Generates a Givens rotation `(c, s)` zeroing the second component, following
+        /// the reference BLAS `?ROTG` convention (including the combined `r`/`z` packed
+        /// into `a`/`b` on return).
+        pub(crate) unsafe extern "C" fn $name(
+            a: *mut $ty,
+            b: *mut $ty,
+            c: *mut $ty,
+            s: *mut $ty,
+        ) {
+            let (av, bv) = (*a, *b);
+            if bv == 0.0 {
+                *c = 1.0;
+                *s = 0.0;
+                *a = av;
+                *b = 0.0;
+                return;
+            }
+            if av == 0.0 {
+                *c = 0.0;
+                *s = 1.0;
+                *a = bv;
+                *b = 1.0;
+                return;
+            }
+            let roe = if av.abs() > bv.abs() { av } else { bv };
+            let scale = av.abs() + bv.abs();
+            let r = roe.signum() * scale * ((av / scale).powi(2) + (bv / scale).powi(2)).sqrt();
+            let cv = av / r;
+            let sv = bv / r;
+            let z = if av.abs() > bv.abs() {
+                sv
+            } else if cv != 0.0 {
+                1.0 / cv
+            } else {
+                1.0
+            };
+            *a = r;
+            *b = z;
+            *c = cv;
+            *s = sv;
+        }
+    };
+}
+
+ref_rotg!(ref_srotg, f32);
+ref_rotg!(ref_drotg, f64);
+
+macro_rules! ref_rotg_complex {
+    ($name:ident, $ty:ty, $real_ty:ty) => {
+        /// Generates a complex Givens rotation `(c, s)` zeroing the second component of
+        /// `(a, b)`, following the reference BLAS complex `?ROTG` convention (`c` real,
+        /// `s` complex). `abs_a`/`norm`/`alpha` use the true complex modulus
+        /// `sqrt(re^2+im^2)` (`Complex::norm`) — the defining invariant `c^2 + |s|^2 == 1`
+        /// only holds for that quantity, not the cheaper `|re|+|im|` (`cabs1`) sum. `cabs1`
+        /// is still used for `scale`, since `scale` is just a positive denominator dividing
+        /// both operands down before they're squared so huge/tiny operands can't spuriously
+        /// overflow on the way to `norm` — any positive real works there, and `cabs1` is
+        /// cheaper to compute than the true modulus.
+        pub(crate) unsafe extern "C" fn $name(
+            a: *mut $ty,
+            b: *const $ty,
+            c: *mut $real_ty,
+            s: *mut $ty,
+        ) {
+            let av = *a;
+            let bv = *b;
+            let cabs1 = |z: $ty| -> $real_ty { z.re.abs() + z.im.abs() };
+            let abs_a = av.norm();
+            if abs_a == 0.0 {
+                *c = 0.0;
+                *s = <$ty>::new(1.0, 0.0);
+                *a = bv;
+                return;
+            }
+            let scale = cabs1(av) + cabs1(bv);
+            let norm = scale * ((av / scale).norm().powi(2) + (bv / scale).norm().powi(2)).sqrt();
+            let alpha = av / abs_a;
+            *c = abs_a / norm;
+            *s = alpha * bv.conj() / norm;
+            *a = alpha * norm;
+        }
+    };
+}
+
+ref_rotg_complex!(ref_crotg, Complex32, f32);
+ref_rotg_complex!(ref_zrotg, Complex64, f64);
+
+macro_rules! ref_rotm {
+    ($name:ident, $ty:ty) => {
+        /// Applies a modified (fast) Givens rotation described by the `flag`/`h` values
+        /// packed in `param` (reference BLAS `?ROTM` layout: `param[0]` is the flag,
+        /// `param[1..5]` are `h11, h21, h12, h22`).
+        pub(crate) unsafe extern "C" fn $name(
+            n: *const blasint,
+            x: *mut $ty,
+            incx: *const blasint,
+            y: *mut $ty,
+            incy: *const blasint,
+            param: *const $ty,
+        ) {
+            let n = *n;
+            let flag = *param.offset(0);
+            let (h11, h21, h12, h22): ($ty, $ty, $ty, $ty) = if flag == -1.0 {
+                (
+                    *param.offset(1),
+                    *param.offset(2),
+                    *param.offset(3),
+                    *param.offset(4),
+                )
+            } else if flag == 0.0 {
+                (1.0, *param.offset(2), *param.offset(3), 1.0)
+            } else if flag == 1.0 {
+                (*param.offset(1), -1.0, 1.0, *param.offset(4))
+            } else {
+                // flag == -2.0: identity, nothing to do.
+                return;
+            };
+            let (mut px, sx) = stride_start(n, x, *incx);
+            let (mut py, sy) = stride_start(n, y, *incy);
+            for _ in 0..n {
+                let xi = *px;
+                let yi = *py;
+                *px = h11 * xi + h12 * yi;
+                *py = h21 * xi + h22 * yi;
+                px = px.offset(sx);
+                py = py.offset(sy);
+            }
+        }
+    };
+}
+
+ref_rotm!(ref_srotm, f32);
+ref_rotm!(ref_drotm, f64);
+
+macro_rules! ref_rotmg {
+    ($name:ident, $ty:ty) => {
+        /// Generates the modified-Givens parameters consumed by `?ROTM`. Emits
+        /// `flag = -2` (identity) when `b2` is zero and the full `flag = -1` form
+        /// otherwise (no flag `0`/`1` fast paths, and no rescaling of `d1`/`d2` back
+        /// into range), which is a valid (if not maximally efficient) `?ROTMG` result.
+        pub(crate) unsafe extern "C" fn $name(
+            d1: *mut $ty,
+            d2: *mut $ty,
+            b1: *mut $ty,
+            b2: *const $ty,
+            param: *mut $ty,
+        ) {
+            let (dd1, dd2, bb1, bb2) = (*d1, *d2, *b1, *b2);
+            if dd1 < 0.0 {
+                *d1 = 0.0;
+                *d2 = 0.0;
+                *b1 = 0.0;
+                *param.offset(0) = -1.0;
+                for i in 1..5 {
+                    *param.offset(i) = 0.0;
+                }
+                return;
+            }
+            if bb2 == 0.0 {
+                *param.offset(0) = -2.0;
+                return;
+            }
+            let p1 = dd1 * bb1;
+            let p2 = dd2 * bb2;
+            let (h11, h21, h12, h22, new_d1, new_d2, new_b1);
+            if bb1.abs() * dd1 > bb2.abs() * dd2 {
+                h21 = -bb2 / bb1;
+                h12 = p2 / p1;
+                h11 = 1.0;
+                h22 = 1.0;
+                let u = 1.0 - h12 * h21;
+                new_d1 = dd1 / u;
+                new_d2 = dd2 / u;
+                new_b1 = bb1 * u;
+            } else {
+                h11 = p1 / p2;
+                h22 = bb1 / bb2;
+                h21 = -1.0;
+                h12 = 1.0;
+                let u = 1.0 + h11 * h22;
+                new_d1 = dd2 / u;
+                new_d2 = dd1 / u;
+                new_b1 = bb2 * u;
+            }
+            *d1 = new_d1;
+            *d2 = new_d2;
+            *b1 = new_b1;
+            *param.offset(0) = -1.0;
+            *param.offset(1) = h11;
+            *param.offset(2) = h21;
+            *param.offset(3) = h12;
+            *param.offset(4) = h22;
+        }
+    };
+}
+
+ref_rotmg!(ref_srotmg, f32);
+ref_rotmg!(ref_drotmg, f64);
+
+macro_rules! ref_dot {
+    ($name:ident, $ty:ty) => {
+        pub(crate) unsafe extern "C" fn $name(
+            n: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *const $ty,
+            incy: *const blasint,
+        ) -> $ty {
+            let n = *n;
+            let (mut px, sx) = stride_start_const(n, x, *incx);
+            let (mut py, sy) = stride_start_const(n, y, *incy);
+            let mut acc = 0.0;
+            for _ in 0..n {
+                acc += *px * *py;
+                px = px.offset(sx);
+                py = py.offset(sy);
+            }
+            acc
+        }
+    };
+}
+
+ref_dot!(ref_sdot, f32);
+ref_dot!(ref_ddot, f64);
+
+/// Single precision dot product accumulated in double precision (`sdsdot`), plus the
+/// additive scalar bias `sb` the routine adds to the result.
+pub(crate) unsafe extern "C" fn ref_sdsdot(
+    n: *const blasint,
+    sb: *const f32,
+    x: *const f32,
+    incx: *const blasint,
+    y: *const f32,
+    incy: *const blasint,
+) -> f32 {
+    let n = *n;
+    let (mut px, sx) = stride_start_const(n, x, *incx);
+    let (mut py, sy) = stride_start_const(n, y, *incy);
+    let mut acc = *sb as f64;
+    for _ in 0..n {
+        acc += (*px as f64) * (*py as f64);
+        px = px.offset(sx);
+        py = py.offset(sy);
+    }
+    acc as f32
+}
+
+/// Dot product of single precision vectors, accumulated and returned in double
+/// precision (`dsdot`).
+pub(crate) unsafe extern "C" fn ref_dsdot(
+    n: *const blasint,
+    x: *const f32,
+    incx: *const blasint,
+    y: *const f32,
+    incy: *const blasint,
+) -> f64 {
+    let n = *n;
+    let (mut px, sx) = stride_start_const(n, x, *incx);
+    let (mut py, sy) = stride_start_const(n, y, *incy);
+    let mut acc = 0.0f64;
+    for _ in 0..n {
+        acc += (*px as f64) * (*py as f64);
+        px = px.offset(sx);
+        py = py.offset(sy);
+    }
+    acc
+}
+
+macro_rules! ref_cdot {
+    ($name:ident, $ty:ty, $conj:expr) => {
+        pub(crate) unsafe extern "C" fn $name(
+            n: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *const $ty,
+            incy: *const blasint,
+        ) -> $ty {
+            let n = *n;
+            let (mut px, sx) = stride_start_const(n, x, *incx);
+            let (mut py, sy) = stride_start_const(n, y, *incy);
+            let conj: fn($ty) -> $ty = $conj;
+            let mut acc = <$ty>::new(0.0, 0.0);
+            for _ in 0..n {
+                acc += conj(*px) * *py;
+                px = px.offset(sx);
+                py = py.offset(sy);
+            }
+            acc
+        }
+    };
+}
+
+ref_cdot!(ref_cdotu, Complex32, |z| z);
+ref_cdot!(ref_zdotu, Complex64, |z| z);
+ref_cdot!(ref_cdotc, Complex32, |z: Complex32| z.conj());
+ref_cdot!(ref_zdotc, Complex64, |z: Complex64| z.conj());
+
+macro_rules! ref_nrm2 {
+    ($name:ident, $ty:ty) => {
+        /// Euclidean norm via the scaled sum-of-squares recurrence (avoids
+        /// overflow/underflow for vectors spanning extreme magnitudes), rather than a
+        /// naive `sqrt(sum(x^2))`; shares the fold step with `crate::nrm2`'s complex
+        /// norms.
+        pub(crate) unsafe extern "C" fn $name(
+            n: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+        ) -> $ty {
+            let n = *n;
+            let (mut px, sx) = stride_start_const(n, x, *incx);
+            let mut scale: $ty = 0.0;
+            let mut ssq: $ty = 1.0;
+            for _ in 0..n {
+                crate::nrm2::fold_component!(scale, ssq, *px);
+                px = px.offset(sx);
+            }
+            scale * ssq.sqrt()
+        }
+    };
+}
+
+ref_nrm2!(ref_snrm2, f32);
+ref_nrm2!(ref_dnrm2, f64);
+
+macro_rules! ref_asum {
+    ($name:ident, $ty:ty) => {
+        pub(crate) unsafe extern "C" fn $name(
+            n: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+        ) -> $ty {
+            let n = *n;
+            let (mut px, sx) = stride_start_const(n, x, *incx);
+            let mut acc: $ty = 0.0;
+            for _ in 0..n {
+                acc += (*px).abs();
+                px = px.offset(sx);
+            }
+            acc
+        }
+    };
+}
+
+ref_asum!(ref_sasum, f32);
+ref_asum!(ref_dasum, f64);
+
+macro_rules! ref_casum {
+    ($name:ident, $ty:ty, $real_ty:ty) => {
+        /// Sums `|Re(z)| + |Im(z)|` over the vector, matching the reference BLAS
+        /// `?casum`/`?zasum` convention (the cheaper 1-norm-of-components approximation,
+        /// not `|z|`).
+        pub(crate) unsafe extern "C" fn $name(
+            n: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+        ) -> $real_ty {
+            let n = *n;
+            let (mut px, sx) = stride_start_const(n, x, *incx);
+            let mut acc: $real_ty = 0.0;
+            for _ in 0..n {
+                acc += (*px).re.abs() + (*px).im.abs();
+                px = px.offset(sx);
+            }
+            acc
+        }
+    };
+}
+
+ref_casum!(ref_scasum, Complex32, f32);
+ref_casum!(ref_dzasum, Complex64, f64);
+
+macro_rules! ref_iamax {
+    ($name:ident, $ty:ty) => {
+        pub(crate) unsafe extern "C" fn $name(n: *const blasint, x: *const $ty, incx: *const blasint) -> blasint {
+            let n = *n;
+            if n < 1 {
+                return 0;
+            }
+            let (mut px, sx) = stride_start_const(n, x, *incx);
+            let mut best_idx: blasint = 1;
+            let mut best_val = (*px).abs();
+            for i in 1..n {
+                px = px.offset(sx);
+                let v = (*px).abs();
+                if v > best_val {
+                    best_val = v;
+                    best_idx = i + 1;
+                }
+            }
+            best_idx
+        }
+    };
+}
+
+ref_iamax!(ref_isamax, f32);
+ref_iamax!(ref_idamax, f64);
+
+macro_rules! ref_icamax {
+    ($name:ident, $ty:ty) => {
+        /// Index (1-based) of the element with the largest `|Re| + |Im|`, matching the
+        /// reference `ic/izamax` convention of ranking by component sum rather than
+        /// modulus.
+        pub(crate) unsafe extern "C" fn $name(n: *const blasint, x: *const $ty, incx: *const blasint) -> blasint {
+            let n = *n;
+            if n < 1 {
+                return 0;
+            }
+            let (mut px, sx) = stride_start_const(n, x, *incx);
+            let mut best_idx: blasint = 1;
+            let mut best_val = (*px).re.abs() + (*px).im.abs();
+            for i in 1..n {
+                px = px.offset(sx);
+                let v = (*px).re.abs() + (*px).im.abs();
+                if v > best_val {
+                    best_val = v;
+                    best_idx = i + 1;
+                }
+            }
+            best_idx
+        }
+    };
+}
+
+ref_icamax!(ref_icamax, Complex32);
+ref_icamax!(ref_izamax, Complex64);
+
+macro_rules! ref_iamin {
+    ($name:ident, $ty:ty) => {
+        pub(crate) unsafe extern "C" fn $name(n: *const blasint, x: *const $ty, incx: *const blasint) -> blasint {
+            let n = *n;
+            if n < 1 {
+                return 0;
+            }
+            let (mut px, sx) = stride_start_const(n, x, *incx);
+            let mut best_idx: blasint = 1;
+            let mut best_val = (*px).abs();
+            for i in 1..n {
+                px = px.offset(sx);
+                let v = (*px).abs();
+                if v < best_val {
+                    best_val = v;
+                    best_idx = i + 1;
+                }
+            }
+            best_idx
+        }
+    };
+}
+
+ref_iamin!(ref_isamin, f32);
+ref_iamin!(ref_idamin, f64);
+
+macro_rules! ref_icamin {
+    ($name:ident, $ty:ty) => {
+        /// Index (1-based) of the element with the smallest `|Re| + |Im|`, matching
+        /// [`ref_icamax`]'s component-sum ranking convention (mirrored for the minimum).
+        pub(crate) unsafe extern "C" fn $name(n: *const blasint, x: *const $ty, incx: *const blasint) -> blasint {
+            let n = *n;
+            if n < 1 {
+                return 0;
+            }
+            let (mut px, sx) = stride_start_const(n, x, *incx);
+            let mut best_idx: blasint = 1;
+            let mut best_val = (*px).re.abs() + (*px).im.abs();
+            for i in 1..n {
+                px = px.offset(sx);
+                let v = (*px).re.abs() + (*px).im.abs();
+                if v < best_val {
+                    best_val = v;
+                    best_idx = i + 1;
+                }
+            }
+            best_idx
+        }
+    };
+}
+
+ref_icamin!(ref_icamin, Complex32);
+ref_icamin!(ref_izamin, Complex64);
+
+macro_rules! ref_gemm_real {
+    ($name:ident, $ty:ty) => {
+        /// Naive triple-loop `C = alpha*op(A)*op(B) + beta*C`, column-major, `O(m*n*k)`
+        /// with no blocking/vectorization — a correctness oracle, not a performance one.
+        /// Columns of `C` are independent (each `j` only ever touches its own column),
+        /// so under the `rayon` feature the outer loop runs one thread per column
+        /// instead of sequentially; see [`SyncPtr`]/[`SyncConstPtr`].
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            transa: *const std::ffi::c_char,
+            transb: *const std::ffi::c_char,
+            m: *const blasint,
+            n: *const blasint,
+            k: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            b: *const $ty,
+            ldb: *const blasint,
+            beta: *const $ty,
+            c: *mut $ty,
+            ldc: *const blasint,
+        ) {
+            let (m, n, k) = (*m as isize, *n as isize, *k as isize);
+            let (lda, ldb, ldc) = (*lda as isize, *ldb as isize, *ldc as isize);
+            let (alpha, beta) = (*alpha, *beta);
+            let ta = *transa as u8 as char;
+            let tb = *transb as u8 as char;
+
+            let compute_col = move |j: isize, a: *const $ty, b: *const $ty, c: *mut $ty| {
+                let a_elem = |i: isize, p: isize| -> $ty {
+                    if ta == 'N' || ta == 'n' {
+                        *a.offset(i + p * lda)
+                    } else {
+                        *a.offset(p + i * lda)
+                    }
+                };
+                let b_elem = |p: isize| -> $ty {
+                    if tb == 'N' || tb == 'n' {
+                        *b.offset(p + j * ldb)
+                    } else {
+                        *b.offset(j + p * ldb)
+                    }
+                };
+                for i in 0..m {
+                    let mut acc: $ty = 0.0;
+                    for p in 0..k {
+                        acc += a_elem(i, p) * b_elem(p);
+                    }
+                    let c_ptr = c.offset(i + j * ldc);
+                    *c_ptr = if beta == 0.0 {
+                        alpha * acc
+                    } else {
+                        alpha * acc + beta * *c_ptr
+                    };
+                }
+            };
+
+            #[cfg(feature = "rayon")]
+            {
+                use rayon::prelude::*;
+                let (a, b, c) = (SyncConstPtr(a), SyncConstPtr(b), SyncPtr(c));
+                (0..n).into_par_iter().for_each(move |j| {
+                    let (a, b, c) = (a, b, c);
+                    compute_col(j, a.0, b.0, c.0)
+                });
+            }
+            #[cfg(not(feature = "rayon"))]
+            {
+                for j in 0..n {
+                    compute_col(j, a, b, c);
+                }
+            }
+        }
+    };
+}
+
+ref_gemm_real!(ref_sgemm, f32);
+ref_gemm_real!(ref_dgemm, f64);
+
+macro_rules! ref_gemm_complex {
+    ($name:ident, $ty:ty) => {
+        /// Naive triple-loop complex `C = alpha*op(A)*op(B) + beta*C`, column-major.
+        /// `transa`/`transb` of `'C'` conjugate-transpose the corresponding operand;
+        /// `'T'` transposes without conjugating; anything else is treated as `'N'`.
+        /// Columns of `C` are independent, same as [`ref_gemm_real`]; parallelized the
+        /// same way under the `rayon` feature.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            transa: *const std::ffi::c_char,
+            transb: *const std::ffi::c_char,
+            m: *const blasint,
+            n: *const blasint,
+            k: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            b: *const $ty,
+            ldb: *const blasint,
+            beta: *const $ty,
+            c: *mut $ty,
+            ldc: *const blasint,
+        ) {
+            let (m, n, k) = (*m as isize, *n as isize, *k as isize);
+            let (lda, ldb, ldc) = (*lda as isize, *ldb as isize, *ldc as isize);
+            let (alpha, beta) = (*alpha, *beta);
+            let ta = (*transa as u8 as char).to_ascii_uppercase();
+            let tb = (*transb as u8 as char).to_ascii_uppercase();
+            let zero = <$ty>::new(0.0, 0.0);
+
+            let compute_col = move |j: isize, a: *const $ty, b: *const $ty, c: *mut $ty| {
+                let a_elem = |i: isize, p: isize| -> $ty {
+                    match ta {
+                        'T' => *a.offset(p + i * lda),
+                        'C' => (*a.offset(p + i * lda)).conj(),
+                        _ => *a.offset(i + p * lda),
+                    }
+                };
+                let b_elem = |p: isize| -> $ty {
+                    match tb {
+                        'T' => *b.offset(j + p * ldb),
+                        'C' => (*b.offset(j + p * ldb)).conj(),
+                        _ => *b.offset(p + j * ldb),
+                    }
+                };
+                for i in 0..m {
+                    let mut acc = zero;
+                    for p in 0..k {
+                        acc += a_elem(i, p) * b_elem(p);
+                    }
+                    let c_ptr = c.offset(i + j * ldc);
+                    *c_ptr = if beta == zero {
+                        alpha * acc
+                    } else {
+                        alpha * acc + beta * *c_ptr
+                    };
+                }
+            };
+
+            #[cfg(feature = "rayon")]
+            {
+                use rayon::prelude::*;
+                let (a, b, c) = (SyncConstPtr(a), SyncConstPtr(b), SyncPtr(c));
+                (0..n).into_par_iter().for_each(move |j| {
+                    let (a, b, c) = (a, b, c);
+                    compute_col(j, a.0, b.0, c.0)
+                });
+            }
+            #[cfg(not(feature = "rayon"))]
+            {
+                for j in 0..n {
+                    compute_col(j, a, b, c);
+                }
+            }
+        }
+    };
+}
+
+ref_gemm_complex!(ref_cgemm, Complex32);
+ref_gemm_complex!(ref_zgemm, Complex64);
+
+macro_rules! ref_gemv_real {
+    ($name:ident, $ty:ty) => {
+        /// Naive general matrix-vector multiply `y := alpha*op(A)*x + beta*y`,
+        /// column-major, `op` is `NoTrans` or `Trans` per `trans`. `O(m*n)`, no
+        /// blocking/vectorization.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            trans: *const std::ffi::c_char,
+            m: *const blasint,
+            n: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        ) {
+            let (m, n) = (*m as isize, *n as isize);
+            let lda = *lda as isize;
+            let (alpha, beta) = (*alpha, *beta);
+            let is_trans = (*trans as u8 as char).to_ascii_uppercase() != 'N';
+            let (lenx, leny) = if is_trans { (m, n) } else { (n, m) };
+            let (px0, sx) = stride_start_const(lenx as blasint, x, *incx);
+            let (py0, sy) = stride_start(leny as blasint, y, *incy);
+
+            for i in 0..leny {
+                let p = py0.offset(sy * i);
+                *p = if beta == 0.0 { 0.0 } else { beta * *p };
+            }
+
+            if !is_trans {
+                for j in 0..n {
+                    let temp = alpha * *px0.offset(sx * j);
+                    if temp != 0.0 {
+                        for i in 0..m {
+                            let p = py0.offset(sy * i);
+                            *p += temp * *a.offset(i + j * lda);
+                        }
+                    }
+                }
+            } else {
+                for j in 0..n {
+                    let mut temp: $ty = 0.0;
+                    for i in 0..m {
+                        temp += *a.offset(i + j * lda) * *px0.offset(sx * i);
+                    }
+                    *py0.offset(sy * j) += alpha * temp;
+                }
+            }
+        }
+    };
+}
+
+ref_gemv_real!(ref_sgemv, f32);
+ref_gemv_real!(ref_dgemv, f64);
+
+macro_rules! ref_gemv_complex {
+    ($name:ident, $ty:ty) => {
+        /// Naive general matrix-vector multiply `y := alpha*op(A)*x + beta*y`, matching
+        /// [`ref_gemv_real`]'s layout. `op` is `NoTrans`/`Trans`/`ConjTrans` per `trans`;
+        /// `ConjTrans` conjugates each stored entry of `A` as it's read.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            trans: *const std::ffi::c_char,
+            m: *const blasint,
+            n: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        ) {
+            let (m, n) = (*m as isize, *n as isize);
+            let lda = *lda as isize;
+            let (alpha, beta) = (*alpha, *beta);
+            let trans_char = (*trans as u8 as char).to_ascii_uppercase();
+            let is_trans = trans_char != 'N';
+            let conj = trans_char == 'C';
+            let (lenx, leny) = if is_trans { (m, n) } else { (n, m) };
+            let (px0, sx) = stride_start_const(lenx as blasint, x, *incx);
+            let (py0, sy) = stride_start(leny as blasint, y, *incy);
+            let zero = <$ty>::new(0.0, 0.0);
+
+            for i in 0..leny {
+                let p = py0.offset(sy * i);
+                *p = if beta == zero { zero } else { beta * *p };
+            }
+
+            if !is_trans {
+                for j in 0..n {
+                    let temp = alpha * *px0.offset(sx * j);
+                    if temp != zero {
+                        for i in 0..m {
+                            let p = py0.offset(sy * i);
+                            *p += temp * *a.offset(i + j * lda);
+                        }
+                    }
+                }
+            } else {
+                for j in 0..n {
+                    let mut temp = zero;
+                    for i in 0..m {
+                        let aij = *a.offset(i + j * lda);
+                        let aij = if conj { aij.conj() } else { aij };
+                        temp += aij * *px0.offset(sx * i);
+                    }
+                    *py0.offset(sy * j) += alpha * temp;
+                }
+            }
+        }
+    };
+}
+
+ref_gemv_complex!(ref_cgemv, Complex32);
+ref_gemv_complex!(ref_zgemv, Complex64);
+
+macro_rules! ref_gbmv_real {
+    ($name:ident, $ty:ty) => {
+        /// Naive general band matrix-vector multiply `y := alpha*op(A)*x + beta*y`,
+        /// where `A` is an `m x n` band matrix with `kl` sub- and `ku` super-diagonals,
+        /// stored column-major: `A[i,j]` lives at `a[(ku+i-j)+j*lda]` for
+        /// `max(0,j-ku) <= i <= min(m-1,j+kl)`, all other `(i,j)` being implicitly zero.
+        /// Mirrors [`ref_gemv_real`]'s beta-scaling and `op` handling, restricted to the
+        /// band.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            trans: *const std::ffi::c_char,
+            m: *const blasint,
+            n: *const blasint,
+            kl: *const blasint,
+            ku: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        ) {
+            let (m, n) = (*m as isize, *n as isize);
+            let (kl, ku) = (*kl as isize, *ku as isize);
+            let lda = *lda as isize;
+            let (alpha, beta) = (*alpha, *beta);
+            let is_trans = (*trans as u8 as char).to_ascii_uppercase() != 'N';
+            let a_elem = |i: isize, j: isize| -> $ty { *a.offset((ku + i - j) + j * lda) };
+            let (lenx, leny) = if is_trans { (m, n) } else { (n, m) };
+            let (px0, sx) = stride_start_const(lenx as blasint, x, *incx);
+            let (py0, sy) = stride_start(leny as blasint, y, *incy);
+
+            for i in 0..leny {
+                let p = py0.offset(sy * i);
+                *p = if beta == 0.0 { 0.0 } else { beta * *p };
+            }
+
+            if !is_trans {
+                for j in 0..n {
+                    let temp = alpha * *px0.offset(sx * j);
+                    if temp != 0.0 {
+                        let lo = (j - ku).max(0);
+                        let hi = (j + kl).min(m - 1);
+                        for i in lo..=hi {
+                            let p = py0.offset(sy * i);
+                            *p += temp * a_elem(i, j);
+                        }
+                    }
+                }
+            } else {
+                for j in 0..n {
+                    let lo = (j - ku).max(0);
+                    let hi = (j + kl).min(m - 1);
+                    let mut temp: $ty = 0.0;
+                    for i in lo..=hi {
+                        temp += a_elem(i, j) * *px0.offset(sx * i);
+                    }
+                    *py0.offset(sy * j) += alpha * temp;
+                }
+            }
+        }
+    };
+}
+
+ref_gbmv_real!(ref_sgbmv, f32);
+ref_gbmv_real!(ref_dgbmv, f64);
+
+macro_rules! ref_gbmv_complex {
+    ($name:ident, $ty:ty) => {
+        /// Naive complex general band matrix-vector multiply, matching
+        /// [`ref_gbmv_real`]'s band layout. `trans` of `'C'` conjugate-transposes (no
+        /// separate conjugate-only character exists in the Fortran ABI); `'T'`
+        /// transposes without conjugating; anything else is treated as `'N'`.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            trans: *const std::ffi::c_char,
+            m: *const blasint,
+            n: *const blasint,
+            kl: *const blasint,
+            ku: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        ) {
+            let (m, n) = (*m as isize, *n as isize);
+            let (kl, ku) = (*kl as isize, *ku as isize);
+            let lda = *lda as isize;
+            let (alpha, beta) = (*alpha, *beta);
+            let trans_char = (*trans as u8 as char).to_ascii_uppercase();
+            let is_trans = trans_char != 'N';
+            let conj = trans_char == 'C';
+            let zero = <$ty>::new(0.0, 0.0);
+            let a_elem = |i: isize, j: isize| -> $ty {
+                let raw = *a.offset((ku + i - j) + j * lda);
+                if conj {
+                    raw.conj()
+                } else {
+                    raw
+                }
+            };
+            let (lenx, leny) = if is_trans { (m, n) } else { (n, m) };
+            let (px0, sx) = stride_start_const(lenx as blasint, x, *incx);
+            let (py0, sy) = stride_start(leny as blasint, y, *incy);
+
+            for i in 0..leny {
+                let p = py0.offset(sy * i);
+                *p = if beta == zero { zero } else { beta * *p };
+            }
+
+            if !is_trans {
+                for j in 0..n {
+                    let temp = alpha * *px0.offset(sx * j);
+                    if temp != zero {
+                        let lo = (j - ku).max(0);
+                        let hi = (j + kl).min(m - 1);
+                        for i in lo..=hi {
+                            let p = py0.offset(sy * i);
+                            *p += temp * a_elem(i, j);
+                        }
+                    }
+                }
+            } else {
+                for j in 0..n {
+                    let lo = (j - ku).max(0);
+                    let hi = (j + kl).min(m - 1);
+                    let mut temp = zero;
+                    for i in lo..=hi {
+                        temp += a_elem(i, j) * *px0.offset(sx * i);
+                    }
+                    *py0.offset(sy * j) += alpha * temp;
+                }
+            }
+        }
+    };
+}
+
+ref_gbmv_complex!(ref_cgbmv, Complex32);
+ref_gbmv_complex!(ref_zgbmv, Complex64);
+
+macro_rules! ref_ger_real {
+    ($name:ident, $ty:ty) => {
+        /// Naive outer-product rank-1 update `A += alpha*x*y^T`, column-major. Streamed
+        /// one column at a time (an `axpyf`-style fused pass: each column gets a single
+        /// scaled-add of `x` rather than being touched element-by-element twice).
+        /// Columns of `A` are independent, same as [`ref_gemm_real`]; parallelized the
+        /// same way under the `rayon` feature.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            m: *const blasint,
+            n: *const blasint,
+            alpha: *const $ty,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *const $ty,
+            incy: *const blasint,
+            a: *mut $ty,
+            lda: *const blasint,
+        ) {
+            let (m_blas, n) = (*m, *n as isize);
+            let alpha = *alpha;
+            let lda = *lda as isize;
+            let (px0, sx) = stride_start_const(m_blas, x, *incx);
+            let (py0, sy) = stride_start_const(n as blasint, y, *incy);
+
+            let update_col = move |j: isize, px0: *const $ty, py0: *const $ty, a: *mut $ty| {
+                let scale = alpha * *py0.offset(sy * j);
+                let col = a.offset(j * lda);
+                for i in 0..(m_blas as isize) {
+                    *col.offset(i) += scale * *px0.offset(sx * i);
+                }
+            };
+
+            #[cfg(feature = "rayon")]
+            {
+                use rayon::prelude::*;
+                let (px0, py0, a) = (SyncConstPtr(px0), SyncConstPtr(py0), SyncPtr(a));
+                (0..n).into_par_iter().for_each(move |j| {
+                    let (px0, py0, a) = (px0, py0, a);
+                    update_col(j, px0.0, py0.0, a.0)
+                });
+            }
+            #[cfg(not(feature = "rayon"))]
+            {
+                for j in 0..n {
+                    update_col(j, px0, py0, a);
+                }
+            }
+        }
+    };
+}
+
+ref_ger_real!(ref_sger, f32);
+ref_ger_real!(ref_dger, f64);
+
+macro_rules! ref_ger_complex {
+    ($name:ident, $ty:ty, $conj:expr) => {
+        /// Naive outer-product rank-1 update `A += alpha*x*y^T` (`$conj` applied to each
+        /// `y` element, so passing `Complex::conj` turns this into the `gerc` form).
+        /// Columns of `A` are independent, same as [`ref_gemm_real`]; parallelized the
+        /// same way under the `rayon` feature.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            m: *const blasint,
+            n: *const blasint,
+            alpha: *const $ty,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *const $ty,
+            incy: *const blasint,
+            a: *mut $ty,
+            lda: *const blasint,
+        ) {
+            let (m_blas, n) = (*m, *n as isize);
+            let alpha = *alpha;
+            let lda = *lda as isize;
+            let conj: fn($ty) -> $ty = $conj;
+            let (px0, sx) = stride_start_const(m_blas, x, *incx);
+            let (py0, sy) = stride_start_const(n as blasint, y, *incy);
+
+            let update_col = move |j: isize, px0: *const $ty, py0: *const $ty, a: *mut $ty| {
+                let scale = alpha * conj(*py0.offset(sy * j));
+                let col = a.offset(j * lda);
+                for i in 0..(m_blas as isize) {
+                    *col.offset(i) += scale * *px0.offset(sx * i);
+                }
+            };
+
+            #[cfg(feature = "rayon")]
+            {
+                use rayon::prelude::*;
+                let (px0, py0, a) = (SyncConstPtr(px0), SyncConstPtr(py0), SyncPtr(a));
+                (0..n).into_par_iter().for_each(move |j| {
+                    let (px0, py0, a) = (px0, py0, a);
+                    update_col(j, px0.0, py0.0, a.0)
+                });
+            }
+            #[cfg(not(feature = "rayon"))]
+            {
+                for j in 0..n {
+                    update_col(j, px0, py0, a);
+                }
+            }
+        }
+    };
+}
+
+ref_ger_complex!(ref_cgeru, Complex32, |z| z);
+ref_ger_complex!(ref_zgeru, Complex64, |z| z);
+ref_ger_complex!(ref_cgerc, Complex32, |z: Complex32| z.conj());
+ref_ger_complex!(ref_zgerc, Complex64, |z: Complex64| z.conj());
+
+macro_rules! ref_symv {
+    ($name:ident, $ty:ty) => {
+        /// Naive symmetric matrix-vector multiply `y = alpha*A*x + beta*y`, touching
+        /// only the triangle `uplo` selects. Each column `j` is walked once, fusing the
+        /// `axpy` of its stored entries into `y` with a `dot` accumulation of the same
+        /// entries against `x` for the mirrored (unstored) side — the reference BLAS
+        /// `?symv` "axpyf + dotxf" structure, rather than two separate passes.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            n: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let (alpha, beta) = (*alpha, *beta);
+            let lda = *lda as isize;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let (px0, sx) = stride_start_const(n_blas, x, *incx);
+            let (py0, sy) = stride_start(n_blas, y, *incy);
+            let xi = |i: isize| -> $ty { *px0.offset(sx * i) };
+            for i in 0..n {
+                let p = py0.offset(sy * i);
+                *p = *p * beta;
+            }
+            for j in 0..n {
+                let col = a.offset(j * lda);
+                let temp1 = alpha * xi(j);
+                let mut temp2: $ty = 0.0;
+                if upper {
+                    for i in 0..j {
+                        let aij = *col.offset(i);
+                        let p = py0.offset(sy * i);
+                        *p = *p + temp1 * aij;
+                        temp2 += aij * xi(i);
+                    }
+                    let p = py0.offset(sy * j);
+                    *p = *p + temp1 * *col.offset(j) + alpha * temp2;
+                } else {
+                    let p = py0.offset(sy * j);
+                    *p = *p + temp1 * *col.offset(j);
+                    for i in (j + 1)..n {
+                        let aij = *col.offset(i);
+                        let pi = py0.offset(sy * i);
+                        *pi = *pi + temp1 * aij;
+                        temp2 += aij * xi(i);
+                    }
+                    let p = py0.offset(sy * j);
+                    *p = *p + alpha * temp2;
+                }
+            }
+        }
+    };
+}
+
+ref_symv!(ref_ssymv, f32);
+ref_symv!(ref_dsymv, f64);
+
+macro_rules! ref_hemv {
+    ($name:ident, $ty:ty) => {
+        /// Naive Hermitian matrix-vector multiply `y = alpha*A*x + beta*y`, touching
+        /// only the triangle `uplo` selects. Same fused axpyf/dotxf column pass as
+        /// [`ref_symv`]'s real version, but the mirrored-side dot conjugates the stored
+        /// entry and the diagonal contributes only its real part, per `?hemv`.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            n: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let (alpha, beta) = (*alpha, *beta);
+            let lda = *lda as isize;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let (px0, sx) = stride_start_const(n_blas, x, *incx);
+            let (py0, sy) = stride_start(n_blas, y, *incy);
+            let xi = |i: isize| -> $ty { *px0.offset(sx * i) };
+            for i in 0..n {
+                let p = py0.offset(sy * i);
+                *p = *p * beta;
+            }
+            for j in 0..n {
+                let col = a.offset(j * lda);
+                let temp1 = alpha * xi(j);
+                let mut temp2 = <$ty>::new(0.0, 0.0);
+                if upper {
+                    for i in 0..j {
+                        let aij = *col.offset(i);
+                        let p = py0.offset(sy * i);
+                        *p = *p + temp1 * aij;
+                        temp2 += aij.conj() * xi(i);
+                    }
+                    let p = py0.offset(sy * j);
+                    *p = *p + temp1 * (*col.offset(j)).re + alpha * temp2;
+                } else {
+                    let p = py0.offset(sy * j);
+                    *p = *p + temp1 * (*col.offset(j)).re;
+                    for i in (j + 1)..n {
+                        let aij = *col.offset(i);
+                        let pi = py0.offset(sy * i);
+                        *pi = *pi + temp1 * aij;
+                        temp2 += aij.conj() * xi(i);
+                    }
+                    let p = py0.offset(sy * j);
+                    *p = *p + alpha * temp2;
+                }
+            }
+        }
+    };
+}
+
+ref_hemv!(ref_chemv, Complex32);
+ref_hemv!(ref_zhemv, Complex64);
+
+macro_rules! ref_spmv_real {
+    ($name:ident, $ty:ty) => {
+        /// Naive symmetric packed matrix-vector multiply `y = alpha*A*x + beta*y`,
+        /// where `A` is stored column-major packed (see `packed_index`). Same fused
+        /// axpyf/dotxf column pass as [`ref_symv`], reading the stored triangle through
+        /// `packed_index` instead of a dense `lda`-strided column.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            n: *const blasint,
+            alpha: *const $ty,
+            ap: *const $ty,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let (alpha, beta) = (*alpha, *beta);
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let (px0, sx) = stride_start_const(n_blas, x, *incx);
+            let (py0, sy) = stride_start(n_blas, y, *incy);
+            let xi = |i: isize| -> $ty { *px0.offset(sx * i) };
+            for i in 0..n {
+                let p = py0.offset(sy * i);
+                *p = *p * beta;
+            }
+            for j in 0..n {
+                let temp1 = alpha * xi(j);
+                let mut temp2: $ty = 0.0;
+                if upper {
+                    for i in 0..j {
+                        let aij = *ap.offset(packed_index(n, upper, i, j));
+                        let p = py0.offset(sy * i);
+                        *p = *p + temp1 * aij;
+                        temp2 += aij * xi(i);
+                    }
+                    let ajj = *ap.offset(packed_index(n, upper, j, j));
+                    let p = py0.offset(sy * j);
+                    *p = *p + temp1 * ajj + alpha * temp2;
+                } else {
+                    let ajj = *ap.offset(packed_index(n, upper, j, j));
+                    let p = py0.offset(sy * j);
+                    *p = *p + temp1 * ajj;
+                    for i in (j + 1)..n {
+                        let aij = *ap.offset(packed_index(n, upper, i, j));
+                        let pi = py0.offset(sy * i);
+                        *pi = *pi + temp1 * aij;
+                        temp2 += aij * xi(i);
+                    }
+                    let p = py0.offset(sy * j);
+                    *p = *p + alpha * temp2;
+                }
+            }
+        }
+    };
+}
+
+ref_spmv_real!(ref_sspmv, f32);
+ref_spmv_real!(ref_dspmv, f64);
+
+macro_rules! ref_hpmv {
+    ($name:ident, $ty:ty) => {
+        /// Naive Hermitian packed matrix-vector multiply `y = alpha*A*x + beta*y`,
+        /// where `A` is stored column-major packed (see `packed_index`). Same fused
+        /// axpyf/dotxf column pass as [`ref_hemv`]'s complex version, but reading the
+        /// stored triangle through `packed_index` instead of a dense `lda`-strided
+        /// column.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            n: *const blasint,
+            alpha: *const $ty,
+            ap: *const $ty,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let (alpha, beta) = (*alpha, *beta);
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let (px0, sx) = stride_start_const(n_blas, x, *incx);
+            let (py0, sy) = stride_start(n_blas, y, *incy);
+            let xi = |i: isize| -> $ty { *px0.offset(sx * i) };
+            for i in 0..n {
+                let p = py0.offset(sy * i);
+                *p = *p * beta;
+            }
+            for j in 0..n {
+                let temp1 = alpha * xi(j);
+                let mut temp2 = <$ty>::new(0.0, 0.0);
+                if upper {
+                    for i in 0..j {
+                        let aij = *ap.offset(packed_index(n, upper, i, j));
+                        let p = py0.offset(sy * i);
+                        *p = *p + temp1 * aij;
+                        temp2 += aij.conj() * xi(i);
+                    }
+                    let ajj = *ap.offset(packed_index(n, upper, j, j));
+                    let p = py0.offset(sy * j);
+                    *p = *p + temp1 * ajj.re + alpha * temp2;
+                } else {
+                    let ajj = *ap.offset(packed_index(n, upper, j, j));
+                    let p = py0.offset(sy * j);
+                    *p = *p + temp1 * ajj.re;
+                    for i in (j + 1)..n {
+                        let aij = *ap.offset(packed_index(n, upper, i, j));
+                        let pi = py0.offset(sy * i);
+                        *pi = *pi + temp1 * aij;
+                        temp2 += aij.conj() * xi(i);
+                    }
+                    let p = py0.offset(sy * j);
+                    *p = *p + alpha * temp2;
+                }
+            }
+        }
+    };
+}
+
+ref_hpmv!(ref_chpmv, Complex32);
+ref_hpmv!(ref_zhpmv, Complex64);
+
+macro_rules! ref_sbmv_real {
+    ($name:ident, $ty:ty) => {
+        /// Naive symmetric banded matrix-vector multiply `y = alpha*A*x + beta*y`. For
+        /// upper storage, element `A(i,j)` (`i<=j`, `j-i<=k`) lives at
+        /// `a[(k+i-j)+j*lda]`; for lower storage, element `A(i,j)` (`i>=j`, `i-j<=k`)
+        /// lives at `a[(i-j)+j*lda]` — the same band layout `cblas_ssbmv` documents.
+        /// Early-returns when `n == 0` or the multiply is a no-op
+        /// (`alpha == 0 && beta == 1`).
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            n: *const blasint,
+            k: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            if n == 0 {
+                return;
+            }
+            let (alpha, beta) = (*alpha, *beta);
+            if alpha == 0.0 && beta == 1.0 {
+                return;
+            }
+            let k = *k as isize;
+            let lda = *lda as isize;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let (px0, sx) = stride_start_const(n_blas, x, *incx);
+            let (py0, sy) = stride_start(n_blas, y, *incy);
+            let xi = |i: isize| -> $ty { *px0.offset(sx * i) };
+
+            for i in 0..n {
+                let p = py0.offset(sy * i);
+                *p = *p * beta;
+            }
+
+            if upper {
+                for j in 0..n {
+                    let col = a.offset(j * lda);
+                    let temp1 = alpha * xi(j);
+                    let mut temp2: $ty = 0.0;
+                    for i in (j - k).max(0)..j {
+                        let aij = *col.offset(k + i - j);
+                        let p = py0.offset(sy * i);
+                        *p = *p + temp1 * aij;
+                        temp2 += aij * xi(i);
+                    }
+                    let p = py0.offset(sy * j);
+                    *p = *p + temp1 * *col.offset(k) + alpha * temp2;
+                }
+            } else {
+                for j in 0..n {
+                    let col = a.offset(j * lda);
+                    let temp1 = alpha * xi(j);
+                    let mut temp2: $ty = 0.0;
+                    let p = py0.offset(sy * j);
+                    *p = *p + temp1 * *col.offset(0);
+                    for i in (j + 1)..=(j + k).min(n - 1) {
+                        let aij = *col.offset(i - j);
+                        let pi = py0.offset(sy * i);
+                        *pi = *pi + temp1 * aij;
+                        temp2 += aij * xi(i);
+                    }
+                    let p = py0.offset(sy * j);
+                    *p = *p + alpha * temp2;
+                }
+            }
+        }
+    };
+}
+
+ref_sbmv_real!(ref_ssbmv, f32);
+ref_sbmv_real!(ref_dsbmv, f64);
+
+macro_rules! ref_hbmv {
+    ($name:ident, $ty:ty) => {
+        /// Naive Hermitian banded matrix-vector multiply `y = alpha*A*x + beta*y`. Same
+        /// band layout as [`ref_sbmv_real`], but the mirrored-side dot conjugates the
+        /// stored entry and the diagonal contributes only its real part, per `?hbmv`.
+        /// Early-returns when `n == 0` or the multiply is a no-op
+        /// (`alpha == 0 && beta == 1`).
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            n: *const blasint,
+            k: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *const $ty,
+            incx: *const blasint,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            if n == 0 {
+                return;
+            }
+            let (alpha, beta) = (*alpha, *beta);
+            if alpha == <$ty>::new(0.0, 0.0) && beta == <$ty>::new(1.0, 0.0) {
+                return;
+            }
+            let k = *k as isize;
+            let lda = *lda as isize;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let (px0, sx) = stride_start_const(n_blas, x, *incx);
+            let (py0, sy) = stride_start(n_blas, y, *incy);
+            let xi = |i: isize| -> $ty { *px0.offset(sx * i) };
+
+            for i in 0..n {
+                let p = py0.offset(sy * i);
+                *p = *p * beta;
+            }
+
+            if upper {
+                for j in 0..n {
+                    let col = a.offset(j * lda);
+                    let temp1 = alpha * xi(j);
+                    let mut temp2 = <$ty>::new(0.0, 0.0);
+                    for i in (j - k).max(0)..j {
+                        let aij = *col.offset(k + i - j);
+                        let p = py0.offset(sy * i);
+                        *p = *p + temp1 * aij;
+                        temp2 += aij.conj() * xi(i);
+                    }
+                    let p = py0.offset(sy * j);
+                    *p = *p + temp1 * (*col.offset(k)).re + alpha * temp2;
+                }
+            } else {
+                for j in 0..n {
+                    let col = a.offset(j * lda);
+                    let temp1 = alpha * xi(j);
+                    let mut temp2 = <$ty>::new(0.0, 0.0);
+                    let p = py0.offset(sy * j);
+                    *p = *p + temp1 * (*col.offset(0)).re;
+                    for i in (j + 1)..=(j + k).min(n - 1) {
+                        let aij = *col.offset(i - j);
+                        let pi = py0.offset(sy * i);
+                        *pi = *pi + temp1 * aij;
+                        temp2 += aij.conj() * xi(i);
+                    }
+                    let p = py0.offset(sy * j);
+                    *p = *p + alpha * temp2;
+                }
+            }
+        }
+    };
+}
+
+ref_hbmv!(ref_chbmv, Complex32);
+ref_hbmv!(ref_zhbmv, Complex64);
+
+macro_rules! ref_her2k {
+    ($name:ident, $ty:ty, $real_ty:ty) => {
+        /// Naive two-term Hermitian rank-2k update
+        /// `C = alpha*op(A)*op(B)^H + conj(alpha)*op(B)*op(A)^H + beta*C`, touching only
+        /// the triangle `uplo` selects. `op` is `NoTrans` or `ConjTrans` per `trans`;
+        /// expressed through an `(i, l)` accessor so both forms share one loop. The
+        /// diagonal is forced real, matching the exact-arithmetic result (the two terms
+        /// are conjugates of each other there) up to rounding.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            trans: *const std::ffi::c_char,
+            n: *const blasint,
+            k: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            b: *const $ty,
+            ldb: *const blasint,
+            beta: *const $real_ty,
+            c: *mut $ty,
+            ldc: *const blasint,
+        ) {
+            let (n, k) = (*n as isize, *k as isize);
+            let (lda, ldb, ldc) = (*lda as isize, *ldb as isize, *ldc as isize);
+            let alpha = *alpha;
+            let beta = *beta;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let conj_trans = (*trans as u8 as char).to_ascii_uppercase() == 'C';
+            let a_op = |i: isize, l: isize| -> $ty {
+                if conj_trans {
+                    (*a.offset(l + i * lda)).conj()
+                } else {
+                    *a.offset(i + l * lda)
+                }
+            };
+            let b_op = |i: isize, l: isize| -> $ty {
+                if conj_trans {
+                    (*b.offset(l + i * ldb)).conj()
+                } else {
+                    *b.offset(i + l * ldb)
+                }
+            };
+            for j in 0..n {
+                for i in 0..n {
+                    if (upper && i > j) || (!upper && i < j) {
+                        continue;
+                    }
+                    let mut s1 = <$ty>::new(0.0, 0.0);
+                    let mut s2 = <$ty>::new(0.0, 0.0);
+                    for l in 0..k {
+                        s1 += a_op(i, l) * b_op(j, l).conj();
+                        s2 += b_op(i, l) * a_op(j, l).conj();
+                    }
+                    let c_ptr = c.offset(i + j * ldc);
+                    let mut val = alpha * s1 + alpha.conj() * s2 + <$ty>::new(beta, 0.0) * *c_ptr;
+                    if i == j {
+                        val.im = 0.0;
+                    }
+                    *c_ptr = val;
+                }
+            }
+        }
+    };
+}
+
+ref_her2k!(ref_cher2k, Complex32, f32);
+ref_her2k!(ref_zher2k, Complex64, f64);
+
+macro_rules! ref_syr2k_real {
+    ($name:ident, $ty:ty) => {
+        /// Naive two-term symmetric rank-2k update
+        /// `C = alpha*op(A)*op(B)^T + alpha*op(B)*op(A)^T + beta*C`, touching only the
+        /// triangle `uplo` selects. `op` is `NoTrans` or `Trans` per `trans`; expressed
+        /// through an `(i, l)` accessor so both forms share one loop.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            trans: *const std::ffi::c_char,
+            n: *const blasint,
+            k: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            b: *const $ty,
+            ldb: *const blasint,
+            beta: *const $ty,
+            c: *mut $ty,
+            ldc: *const blasint,
+        ) {
+            let (n, k) = (*n as isize, *k as isize);
+            let (lda, ldb, ldc) = (*lda as isize, *ldb as isize, *ldc as isize);
+            let alpha = *alpha;
+            let beta = *beta;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let transposed = (*trans as u8 as char).to_ascii_uppercase() == 'T';
+            let a_op = |i: isize, l: isize| -> $ty {
+                if transposed {
+                    *a.offset(l + i * lda)
+                } else {
+                    *a.offset(i + l * lda)
+                }
+            };
+            let b_op = |i: isize, l: isize| -> $ty {
+                if transposed {
+                    *b.offset(l + i * ldb)
+                } else {
+                    *b.offset(i + l * ldb)
+                }
+            };
+            for j in 0..n {
+                for i in 0..n {
+                    if (upper && i > j) || (!upper && i < j) {
+                        continue;
+                    }
+                    let mut s1: $ty = 0.0;
+                    let mut s2: $ty = 0.0;
+                    for l in 0..k {
+                        s1 += a_op(i, l) * b_op(j, l);
+                        s2 += b_op(i, l) * a_op(j, l);
+                    }
+                    let c_ptr = c.offset(i + j * ldc);
+                    *c_ptr = alpha * s1 + alpha * s2 + beta * *c_ptr;
+                }
+            }
+        }
+    };
+}
+
+ref_syr2k_real!(ref_ssyr2k, f32);
+ref_syr2k_real!(ref_dsyr2k, f64);
+
+macro_rules! ref_syr2k_complex {
+    ($name:ident, $ty:ty) => {
+        /// Complex counterpart of [`ref_ssyr2k`]/[`ref_dsyr2k`]: same symmetric (not
+        /// Hermitian) two-term update, `alpha`/`beta` complex, neither operand
+        /// conjugated — unlike [`ref_cher2k`]/[`ref_zher2k`], the diagonal isn't forced
+        /// real.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            trans: *const std::ffi::c_char,
+            n: *const blasint,
+            k: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            b: *const $ty,
+            ldb: *const blasint,
+            beta: *const $ty,
+            c: *mut $ty,
+            ldc: *const blasint,
+        ) {
+            let (n, k) = (*n as isize, *k as isize);
+            let (lda, ldb, ldc) = (*lda as isize, *ldb as isize, *ldc as isize);
+            let alpha = *alpha;
+            let beta = *beta;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let transposed = (*trans as u8 as char).to_ascii_uppercase() == 'T';
+            let a_op = |i: isize, l: isize| -> $ty {
+                if transposed {
+                    *a.offset(l + i * lda)
+                } else {
+                    *a.offset(i + l * lda)
+                }
+            };
+            let b_op = |i: isize, l: isize| -> $ty {
+                if transposed {
+                    *b.offset(l + i * ldb)
+                } else {
+                    *b.offset(i + l * ldb)
+                }
+            };
+            for j in 0..n {
+                for i in 0..n {
+                    if (upper && i > j) || (!upper && i < j) {
+                        continue;
+                    }
+                    let mut s1 = <$ty>::new(0.0, 0.0);
+                    let mut s2 = <$ty>::new(0.0, 0.0);
+                    for l in 0..k {
+                        s1 += a_op(i, l) * b_op(j, l);
+                        s2 += b_op(i, l) * a_op(j, l);
+                    }
+                    let c_ptr = c.offset(i + j * ldc);
+                    *c_ptr = alpha * s1 + alpha * s2 + beta * *c_ptr;
+                }
+            }
+        }
+    };
+}
+
+ref_syr2k_complex!(ref_csyr2k, Complex32);
+ref_syr2k_complex!(ref_zsyr2k, Complex64);
+
+macro_rules! ref_herk {
+    ($name:ident, $ty:ty, $real_ty:ty) => {
+        /// Naive Hermitian rank-k update `C = alpha*op(A)*op(A)^H + beta*C` (`alpha`/
+        /// `beta` real), touching only the triangle `uplo` selects. `op` is `NoTrans` or
+        /// `ConjTrans` per `trans`. The diagonal is forced real, matching the
+        /// exact-arithmetic result up to rounding.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            trans: *const std::ffi::c_char,
+            n: *const blasint,
+            k: *const blasint,
+            alpha: *const $real_ty,
+            a: *const $ty,
+            lda: *const blasint,
+            beta: *const $real_ty,
+            c: *mut $ty,
+            ldc: *const blasint,
+        ) {
+            let (n, k) = (*n as isize, *k as isize);
+            let (lda, ldc) = (*lda as isize, *ldc as isize);
+            let alpha = *alpha;
+            let beta = *beta;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let conj_trans = (*trans as u8 as char).to_ascii_uppercase() == 'C';
+            let a_op = |i: isize, l: isize| -> $ty {
+                if conj_trans {
+                    (*a.offset(l + i * lda)).conj()
+                } else {
+                    *a.offset(i + l * lda)
+                }
+            };
+            for j in 0..n {
+                for i in 0..n {
+                    if (upper && i > j) || (!upper && i < j) {
+                        continue;
+                    }
+                    let mut acc = <$ty>::new(0.0, 0.0);
+                    for l in 0..k {
+                        acc += a_op(i, l) * a_op(j, l).conj();
+                    }
+                    let c_ptr = c.offset(i + j * ldc);
+                    let mut val = <$ty>::new(alpha, 0.0) * acc + <$ty>::new(beta, 0.0) * *c_ptr;
+                    if i == j {
+                        val.im = 0.0;
+                    }
+                    *c_ptr = val;
+                }
+            }
+        }
+    };
+}
+
+ref_herk!(ref_cherk, Complex32, f32);
+ref_herk!(ref_zherk, Complex64, f64);
+
+/// Column-major packed index of `A(i,j)` (0-based) for the triangle `upper` selects.
+/// `Upper` packs column-by-column top-to-bottom (`i <= j`); `Lower` packs column-by-column
+/// starting at the diagonal (`i >= j`). `n` is only used by the `Lower` formula.
+#[inline]
+fn packed_index(n: isize, upper: bool, i: isize, j: isize) -> isize {
+    if upper {
+        i + j * (j + 1) / 2
+    } else {
+        i + (2 * n - j - 1) * j / 2
+    }
+}
+
+macro_rules! ref_tpmv_real {
+    ($name:ident, $ty:ty) => {
+        /// Naive triangular packed matrix-vector multiply `x := op(A) * x`, where `A` is
+        /// stored column-major packed (see `packed_index`). `CblasConjTrans`/
+        /// `CblasConjNoTrans` already collapse to `Trans`/`NoTrans` before reaching a real
+        /// backend, so only `N`/`T` are handled here.
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            trans: *const std::ffi::c_char,
+            diag: *const std::ffi::c_char,
+            n: *const blasint,
+            ap: *const $ty,
+            x: *mut $ty,
+            incx: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let is_trans = (*trans as u8 as char).to_ascii_uppercase() != 'N';
+            let unit = (*diag as u8 as char).to_ascii_uppercase() == 'U';
+            let a = |i: isize, j: isize| -> $ty { *ap.offset(packed_index(n, upper, i, j)) };
+            let (px0, sx) = stride_start(n_blas, x, *incx);
+
+            if !is_trans {
+                if upper {
+                    for j in 0..n {
+                        let xj = *px0.offset(sx * j);
+                        if xj != 0.0 {
+                            for i in 0..j {
+                                let p = px0.offset(sx * i);
+                                *p = *p + xj * a(i, j);
+                            }
+                            if !unit {
+                                *px0.offset(sx * j) = xj * a(j, j);
+                            }
+                        }
+                    }
+                } else {
+                    for j in (0..n).rev() {
+                        let xj = *px0.offset(sx * j);
+                        if xj != 0.0 {
+                            for i in ((j + 1)..n).rev() {
+                                let p = px0.offset(sx * i);
+                                *p = *p + xj * a(i, j);
+                            }
+                            if !unit {
+                                *px0.offset(sx * j) = xj * a(j, j);
+                            }
+                        }
+                    }
+                }
+            } else if upper {
+                for j in (0..n).rev() {
+                    let mut temp = *px0.offset(sx * j);
+                    if !unit {
+                        temp *= a(j, j);
+                    }
+                    for i in (0..j).rev() {
+                        temp += a(i, j) * *px0.offset(sx * i);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            } else {
+                for j in 0..n {
+                    let mut temp = *px0.offset(sx * j);
+                    if !unit {
+                        temp *= a(j, j);
+                    }
+                    for i in (j + 1)..n {
+                        temp += a(i, j) * *px0.offset(sx * i);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            }
+        }
+    };
+}
+
+ref_tpmv_real!(ref_stpmv, f32);
+ref_tpmv_real!(ref_dtpmv, f64);
+
+macro_rules! ref_tpmv_complex {
+    ($name:ident, $ty:ty) => {
+        /// Naive triangular packed matrix-vector multiply `x := op(A) * x`, where `A` is
+        /// stored column-major packed (see `packed_index`). `ConjTrans` conjugates each
+        /// stored entry of `A` as it's read, matching the reference BLAS `?TPMV` algorithm.
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            trans: *const std::ffi::c_char,
+            diag: *const std::ffi::c_char,
+            n: *const blasint,
+            ap: *const $ty,
+            x: *mut $ty,
+            incx: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let trans_char = (*trans as u8 as char).to_ascii_uppercase();
+            let is_trans = trans_char != 'N';
+            let conj = trans_char == 'C';
+            let unit = (*diag as u8 as char).to_ascii_uppercase() == 'U';
+            let a = |i: isize, j: isize| -> $ty {
+                let v = *ap.offset(packed_index(n, upper, i, j));
+                if conj {
+                    v.conj()
+                } else {
+                    v
+                }
+            };
+            let (px0, sx) = stride_start(n_blas, x, *incx);
+            let zero = <$ty>::new(0.0, 0.0);
+
+            if !is_trans {
+                if upper {
+                    for j in 0..n {
+                        let xj = *px0.offset(sx * j);
+                        if xj != zero {
+                            for i in 0..j {
+                                let p = px0.offset(sx * i);
+                                *p = *p + xj * a(i, j);
+                            }
+                            if !unit {
+                                *px0.offset(sx * j) = xj * a(j, j);
+                            }
+                        }
+                    }
+                } else {
+                    for j in (0..n).rev() {
+                        let xj = *px0.offset(sx * j);
+                        if xj != zero {
+                            for i in ((j + 1)..n).rev() {
+                                let p = px0.offset(sx * i);
+                                *p = *p + xj * a(i, j);
+                            }
+                            if !unit {
+                                *px0.offset(sx * j) = xj * a(j, j);
+                            }
+                        }
+                    }
+                }
+            } else if upper {
+                for j in (0..n).rev() {
+                    let mut temp = *px0.offset(sx * j);
+                    if !unit {
+                        temp *= a(j, j);
+                    }
+                    for i in (0..j).rev() {
+                        temp += a(i, j) * *px0.offset(sx * i);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            } else {
+                for j in 0..n {
+                    let mut temp = *px0.offset(sx * j);
+                    if !unit {
+                        temp *= a(j, j);
+                    }
+                    for i in (j + 1)..n {
+                        temp += a(i, j) * *px0.offset(sx * i);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            }
+        }
+    };
+}
+
+ref_tpmv_complex!(ref_ctpmv, Complex32);
+ref_tpmv_complex!(ref_ztpmv, Complex64);
+
+macro_rules! ref_tpsv_real {
+    ($name:ident, $ty:ty, $simd_axpy_sub:path) => {
+        /// Naive triangular packed solve `op(A) * x = b`, `x` overwriting `b` in place,
+        /// via forward/back substitution over the packed storage (see `packed_index`).
+        /// `CblasConjTrans`/`CblasConjNoTrans` already collapse to `Trans`/`NoTrans` before
+        /// reaching a real backend, so only `N`/`T` are handled here.
+        ///
+        /// The `!is_trans` substitution loop's innermost update (`x[i] -= temp * a(i,j)`
+        /// over a contiguous range of `i` at fixed `j`) is an AXPY with no
+        /// cross-iteration dependency, so when `incx == 1` it's dispatched to
+        /// [`crate::simd`]'s runtime-CPU-feature-detected kernel instead of a scalar
+        /// Rust loop; any other stride falls back to the scalar loop unchanged.
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            trans: *const std::ffi::c_char,
+            diag: *const std::ffi::c_char,
+            n: *const blasint,
+            ap: *const $ty,
+            x: *mut $ty,
+            incx: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let is_trans = (*trans as u8 as char).to_ascii_uppercase() != 'N';
+            let unit = (*diag as u8 as char).to_ascii_uppercase() == 'U';
+            let a = |i: isize, j: isize| -> $ty { *ap.offset(packed_index(n, upper, i, j)) };
+            let (px0, sx) = stride_start(n_blas, x, *incx);
+
+            if !is_trans {
+                if upper {
+                    for j in (0..n).rev() {
+                        let p = px0.offset(sx * j);
+                        if *p != 0.0 {
+                            if !unit {
+                                *p = *p / a(j, j);
+                            }
+                            let temp = *p;
+                            if sx == 1 {
+                                $simd_axpy_sub(
+                                    j as usize,
+                                    temp,
+                                    ap.offset(packed_index(n, upper, 0, j)),
+                                    px0,
+                                );
+                            } else {
+                                for i in (0..j).rev() {
+                                    let pi = px0.offset(sx * i);
+                                    *pi = *pi - temp * a(i, j);
+                                }
+                            }
+                        }
+                    }
+                } else {
+                    for j in 0..n {
+                        let p = px0.offset(sx * j);
+                        if *p != 0.0 {
+                            if !unit {
+                                *p = *p / a(j, j);
+                            }
+                            let temp = *p;
+                            if sx == 1 {
+                                $simd_axpy_sub(
+                                    (n - j - 1) as usize,
+                                    temp,
+                                    ap.offset(packed_index(n, upper, j + 1, j)),
+                                    px0.offset(j + 1),
+                                );
+                            } else {
+                                for i in (j + 1)..n {
+                                    let pi = px0.offset(sx * i);
+                                    *pi = *pi - temp * a(i, j);
+                                }
+                            }
+                        }
+                    }
+                }
+            } else if upper {
+                for j in 0..n {
+                    let mut temp = *px0.offset(sx * j);
+                    for i in 0..j {
+                        temp -= a(i, j) * *px0.offset(sx * i);
+                    }
+                    if !unit {
+                        temp /= a(j, j);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            } else {
+                for j in (0..n).rev() {
+                    let mut temp = *px0.offset(sx * j);
+                    for i in (j + 1)..n {
+                        temp -= a(i, j) * *px0.offset(sx * i);
+                    }
+                    if !unit {
+                        temp /= a(j, j);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            }
+        }
+    };
+}
+
+ref_tpsv_real!(ref_stpsv, f32, crate::simd::axpy_sub_f32);
+ref_tpsv_real!(ref_dtpsv, f64, crate::simd::axpy_sub_f64);
+
+macro_rules! ref_tpsv_complex {
+    ($name:ident, $ty:ty) => {
+        /// Naive triangular packed solve `op(A) * x = b`, `x` overwriting `b` in place,
+        /// via forward/back substitution over the packed storage (see `packed_index`).
+        /// `ConjTrans` conjugates each stored entry of `A` as it's read.
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            trans: *const std::ffi::c_char,
+            diag: *const std::ffi::c_char,
+            n: *const blasint,
+            ap: *const $ty,
+            x: *mut $ty,
+            incx: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let trans_char = (*trans as u8 as char).to_ascii_uppercase();
+            let is_trans = trans_char != 'N';
+            let conj = trans_char == 'C';
+            let unit = (*diag as u8 as char).to_ascii_uppercase() == 'U';
+            let a = |i: isize, j: isize| -> $ty {
+                let v = *ap.offset(packed_index(n, upper, i, j));
+                if conj {
+                    v.conj()
+                } else {
+                    v
+                }
+            };
+            let (px0, sx) = stride_start(n_blas, x, *incx);
+            let zero = <$ty>::new(0.0, 0.0);
+
+            if !is_trans {
+                if upper {
+                    for j in (0..n).rev() {
+                        let p = px0.offset(sx * j);
+                        if *p != zero {
+                            if !unit {
+                                *p = *p / a(j, j);
+                            }
+                            let temp = *p;
+                            for i in (0..j).rev() {
+                                let pi = px0.offset(sx * i);
+                                *pi = *pi - temp * a(i, j);
+                            }
+                        }
+                    }
+                } else {
+                    for j in 0..n {
+                        let p = px0.offset(sx * j);
+                        if *p != zero {
+                            if !unit {
+                                *p = *p / a(j, j);
+                            }
+                            let temp = *p;
+                            for i in (j + 1)..n {
+                                let pi = px0.offset(sx * i);
+                                *pi = *pi - temp * a(i, j);
+                            }
+                        }
+                    }
+                }
+            } else if upper {
+                for j in 0..n {
+                    let mut temp = *px0.offset(sx * j);
+                    for i in 0..j {
+                        temp -= a(i, j) * *px0.offset(sx * i);
+                    }
+                    if !unit {
+                        temp /= a(j, j);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            } else {
+                for j in (0..n).rev() {
+                    let mut temp = *px0.offset(sx * j);
+                    for i in (j + 1)..n {
+                        temp -= a(i, j) * *px0.offset(sx * i);
+                    }
+                    if !unit {
+                        temp /= a(j, j);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            }
+        }
+    };
+}
+
+ref_tpsv_complex!(ref_ctpsv, Complex32);
+ref_tpsv_complex!(ref_ztpsv, Complex64);
+
+macro_rules! ref_tbmv_real {
+    ($name:ident, $ty:ty) => {
+        /// Naive triangular band matrix-vector multiply `x := op(A) * x`, where `A` is
+        /// stored column-major with `k` super- (upper) or sub- (lower) diagonals: row
+        /// `i` of column `j` lives at `a[(k+i-j)+j*lda]` (upper) or `a[(i-j)+j*lda]`
+        /// (lower). `CblasConjTrans`/`CblasConjNoTrans` already collapse to
+        /// `Trans`/`NoTrans` before reaching a real backend, so only `N`/`T` are handled
+        /// here.
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            trans: *const std::ffi::c_char,
+            diag: *const std::ffi::c_char,
+            n: *const blasint,
+            k: *const blasint,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *mut $ty,
+            incx: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let k = *k as isize;
+            let lda = *lda as isize;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let is_trans = (*trans as u8 as char).to_ascii_uppercase() != 'N';
+            let unit = (*diag as u8 as char).to_ascii_uppercase() == 'U';
+            let band = |i: isize, j: isize| -> $ty {
+                *a.offset(if upper { k + i - j } else { i - j } + j * lda)
+            };
+            let (px0, sx) = stride_start(n_blas, x, *incx);
+
+            if !is_trans {
+                if upper {
+                    for j in 0..n {
+                        let xj = *px0.offset(sx * j);
+                        if xj != 0.0 {
+                            for i in (j - k).max(0)..j {
+                                let p = px0.offset(sx * i);
+                                *p = *p + xj * band(i, j);
+                            }
+                            if !unit {
+                                *px0.offset(sx * j) = xj * band(j, j);
+                            }
+                        }
+                    }
+                } else {
+                    for j in (0..n).rev() {
+                        let xj = *px0.offset(sx * j);
+                        if xj != 0.0 {
+                            for i in ((j + 1)..=(j + k).min(n - 1)).rev() {
+                                let p = px0.offset(sx * i);
+                                *p = *p + xj * band(i, j);
+                            }
+                            if !unit {
+                                *px0.offset(sx * j) = xj * band(j, j);
+                            }
+                        }
+                    }
+                }
+            } else if upper {
+                for j in (0..n).rev() {
+                    let mut temp = *px0.offset(sx * j);
+                    if !unit {
+                        temp *= band(j, j);
+                    }
+                    for i in ((j - k).max(0)..j).rev() {
+                        temp += band(i, j) * *px0.offset(sx * i);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            } else {
+                for j in 0..n {
+                    let mut temp = *px0.offset(sx * j);
+                    if !unit {
+                        temp *= band(j, j);
+                    }
+                    for i in (j + 1)..=(j + k).min(n - 1) {
+                        temp += band(i, j) * *px0.offset(sx * i);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            }
+        }
+    };
+}
+
+ref_tbmv_real!(ref_stbmv, f32);
+ref_tbmv_real!(ref_dtbmv, f64);
+
+macro_rules! ref_tbmv_complex {
+    ($name:ident, $ty:ty) => {
+        /// Naive triangular band matrix-vector multiply `x := op(A) * x`, matching
+        /// [`ref_tbmv_real`]'s banded index layout. `ConjTrans` conjugates each stored
+        /// entry of `A` as it's read.
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            trans: *const std::ffi::c_char,
+            diag: *const std::ffi::c_char,
+            n: *const blasint,
+            k: *const blasint,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *mut $ty,
+            incx: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let k = *k as isize;
+            let lda = *lda as isize;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let trans_char = (*trans as u8 as char).to_ascii_uppercase();
+            let is_trans = trans_char != 'N';
+            let conj = trans_char == 'C';
+            let unit = (*diag as u8 as char).to_ascii_uppercase() == 'U';
+            let band = |i: isize, j: isize| -> $ty {
+                let v = *a.offset(if upper { k + i - j } else { i - j } + j * lda);
+                if conj {
+                    v.conj()
+                } else {
+                    v
+                }
+            };
+            let (px0, sx) = stride_start(n_blas, x, *incx);
+            let zero = <$ty>::new(0.0, 0.0);
+
+            if !is_trans {
+                if upper {
+                    for j in 0..n {
+                        let xj = *px0.offset(sx * j);
+                        if xj != zero {
+                            for i in (j - k).max(0)..j {
+                                let p = px0.offset(sx * i);
+                                *p = *p + xj * band(i, j);
+                            }
+                            if !unit {
+                                *px0.offset(sx * j) = xj * band(j, j);
+                            }
+                        }
+                    }
+                } else {
+                    for j in (0..n).rev() {
+                        let xj = *px0.offset(sx * j);
+                        if xj != zero {
+                            for i in ((j + 1)..=(j + k).min(n - 1)).rev() {
+                                let p = px0.offset(sx * i);
+                                *p = *p + xj * band(i, j);
+                            }
+                            if !unit {
+                                *px0.offset(sx * j) = xj * band(j, j);
+                            }
+                        }
+                    }
+                }
+            } else if upper {
+                for j in (0..n).rev() {
+                    let mut temp = *px0.offset(sx * j);
+                    if !unit {
+                        temp *= band(j, j);
+                    }
+                    for i in ((j - k).max(0)..j).rev() {
+                        temp += band(i, j) * *px0.offset(sx * i);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            } else {
+                for j in 0..n {
+                    let mut temp = *px0.offset(sx * j);
+                    if !unit {
+                        temp *= band(j, j);
+                    }
+                    for i in (j + 1)..=(j + k).min(n - 1) {
+                        temp += band(i, j) * *px0.offset(sx * i);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            }
+        }
+    };
+}
+
+ref_tbmv_complex!(ref_ctbmv, Complex32);
+ref_tbmv_complex!(ref_ztbmv, Complex64);
+
+macro_rules! ref_trmm_real {
+    ($name:ident, $ty:ty) => {
+        /// Naive triangular matrix multiply `B := alpha*op(A)*B` (`side == 'L'`) or
+        /// `B := alpha*B*op(A)` (`side == 'R'`), `A` triangular (`uplo`/`diag`-aware),
+        /// both column-major full (`lda`/`ldb`-strided) storage. Computes into a scratch
+        /// `m x n` buffer rather than attempting an in-place update like
+        /// [`ref_tbmv_real`]'s banded kernel does, since here the whole `B` matrix (not a
+        /// single vector) is being overwritten and a scratch buffer is the simplest way
+        /// to keep reads of not-yet-overwritten `B` entries correct.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            side: *const std::ffi::c_char,
+            uplo: *const std::ffi::c_char,
+            transa: *const std::ffi::c_char,
+            diag: *const std::ffi::c_char,
+            m: *const blasint,
+            n: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            b: *mut $ty,
+            ldb: *const blasint,
+        ) {
+            let (m, n) = (*m as isize, *n as isize);
+            let lda = *lda as isize;
+            let ldb = *ldb as isize;
+            let alpha = *alpha;
+            let left = (*side as u8 as char).to_ascii_uppercase() == 'L';
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let trans = (*transa as u8 as char).to_ascii_uppercase() != 'N';
+            let unit = (*diag as u8 as char).to_ascii_uppercase() == 'U';
+            // A is k x k, k = m (side=Left) or n (side=Right).
+            let a_op = |row: isize, col: isize| -> $ty {
+                let (r, c) = if trans { (col, row) } else { (row, col) };
+                if r == c {
+                    if unit {
+                        1.0
+                    } else {
+                        *a.offset(r + c * lda)
+                    }
+                } else if (r < c) == upper {
+                    *a.offset(r + c * lda)
+                } else {
+                    0.0
+                }
+            };
+            let b_elem = |i: isize, j: isize| -> $ty { *b.offset(i + j * ldb) };
+
+            let mut scratch = vec![0.0 as $ty; (m * n) as usize];
+            if left {
+                // B := alpha * op(A) * B, A is m x m
+                for j in 0..n {
+                    for i in 0..m {
+                        let mut sum = 0.0;
+                        for k in 0..m {
+                            sum += a_op(i, k) * b_elem(k, j);
+                        }
+                        scratch[(i + j * m) as usize] = alpha * sum;
+                    }
+                }
+            } else {
+                // B := alpha * B * op(A), A is n x n
+                for j in 0..n {
+                    for i in 0..m {
+                        let mut sum = 0.0;
+                        for k in 0..n {
+                            sum += b_elem(i, k) * a_op(k, j);
+                        }
+                        scratch[(i + j * m) as usize] = alpha * sum;
+                    }
+                }
+            }
+            for j in 0..n {
+                for i in 0..m {
+                    *b.offset(i + j * ldb) = scratch[(i + j * m) as usize];
+                }
+            }
+        }
+    };
+}
+
+ref_trmm_real!(ref_strmm, f32);
+ref_trmm_real!(ref_dtrmm, f64);
+
+macro_rules! ref_trmm_complex {
+    ($name:ident, $ty:ty) => {
+        /// Naive complex triangular matrix multiply, matching [`ref_trmm_real`]'s
+        /// layout. `transa` of `'C'` conjugate-transposes `A` (Fortran `?TRMM` supports
+        /// `'C'` directly, unlike GEMV/TRSV/TRSM's row-major paths, so no separate
+        /// conjugate-without-transpose workaround is needed here).
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            side: *const std::ffi::c_char,
+            uplo: *const std::ffi::c_char,
+            transa: *const std::ffi::c_char,
+            diag: *const std::ffi::c_char,
+            m: *const blasint,
+            n: *const blasint,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: *const blasint,
+            b: *mut $ty,
+            ldb: *const blasint,
+        ) {
+            let (m, n) = (*m as isize, *n as isize);
+            let lda = *lda as isize;
+            let ldb = *ldb as isize;
+            let alpha = *alpha;
+            let left = (*side as u8 as char).to_ascii_uppercase() == 'L';
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let transa_char = (*transa as u8 as char).to_ascii_uppercase();
+            let trans = transa_char != 'N';
+            let conj = transa_char == 'C';
+            let unit = (*diag as u8 as char).to_ascii_uppercase() == 'U';
+            let zero = <$ty>::new(0.0, 0.0);
+            let one = <$ty>::new(1.0, 0.0);
+            let a_op = |row: isize, col: isize| -> $ty {
+                let (r, c) = if trans { (col, row) } else { (row, col) };
+                let raw = if r == c {
+                    if unit {
+                        one
+                    } else {
+                        *a.offset(r + c * lda)
+                    }
+                } else if (r < c) == upper {
+                    *a.offset(r + c * lda)
+                } else {
+                    zero
+                };
+                if conj {
+                    raw.conj()
+                } else {
+                    raw
+                }
+            };
+            let b_elem = |i: isize, j: isize| -> $ty { *b.offset(i + j * ldb) };
+
+            let mut scratch = vec![zero; (m * n) as usize];
+            if left {
+                for j in 0..n {
+                    for i in 0..m {
+                        let mut sum = zero;
+                        for k in 0..m {
+                            sum += a_op(i, k) * b_elem(k, j);
+                        }
+                        scratch[(i + j * m) as usize] = alpha * sum;
+                    }
+                }
+            } else {
+                for j in 0..n {
+                    for i in 0..m {
+                        let mut sum = zero;
+                        for k in 0..n {
+                            sum += b_elem(i, k) * a_op(k, j);
+                        }
+                        scratch[(i + j * m) as usize] = alpha * sum;
+                    }
+                }
+            }
+            for j in 0..n {
+                for i in 0..m {
+                    *b.offset(i + j * ldb) = scratch[(i + j * m) as usize];
+                }
+            }
+        }
+    };
+}
+
+ref_trmm_complex!(ref_ctrmm, Complex32);
+ref_trmm_complex!(ref_ztrmm, Complex64);
+
+macro_rules! ref_trsv_real {
+    ($name:ident, $ty:ty) => {
+        /// Naive triangular solve `op(A) * x = b`, `x` overwriting `b` in place via
+        /// forward/back substitution, column-major full (`lda`-strided) storage.
+        /// `CblasConjTrans`/`CblasConjNoTrans` already collapse to `Trans`/`NoTrans`
+        /// before reaching a real backend, so only `N`/`T` are handled here.
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            trans: *const std::ffi::c_char,
+            diag: *const std::ffi::c_char,
+            n: *const blasint,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *mut $ty,
+            incx: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let lda = *lda as isize;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let is_trans = (*trans as u8 as char).to_ascii_uppercase() != 'N';
+            let unit = (*diag as u8 as char).to_ascii_uppercase() == 'U';
+            let a = |i: isize, j: isize| -> $ty { *a.offset(i + j * lda) };
+            let (px0, sx) = stride_start(n_blas, x, *incx);
+
+            if !is_trans {
+                if upper {
+                    for j in (0..n).rev() {
+                        let p = px0.offset(sx * j);
+                        if *p != 0.0 {
+                            if !unit {
+                                *p = *p / a(j, j);
+                            }
+                            let temp = *p;
+                            for i in (0..j).rev() {
+                                let pi = px0.offset(sx * i);
+                                *pi = *pi - temp * a(i, j);
+                            }
+                        }
+                    }
+                } else {
+                    for j in 0..n {
+                        let p = px0.offset(sx * j);
+                        if *p != 0.0 {
+                            if !unit {
+                                *p = *p / a(j, j);
+                            }
+                            let temp = *p;
+                            for i in (j + 1)..n {
+                                let pi = px0.offset(sx * i);
+                                *pi = *pi - temp * a(i, j);
+                            }
+                        }
+                    }
+                }
+            } else if upper {
+                for j in 0..n {
+                    let mut temp = *px0.offset(sx * j);
+                    for i in 0..j {
+                        temp -= a(i, j) * *px0.offset(sx * i);
+                    }
+                    if !unit {
+                        temp /= a(j, j);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            } else {
+                for j in (0..n).rev() {
+                    let mut temp = *px0.offset(sx * j);
+                    for i in (j + 1)..n {
+                        temp -= a(i, j) * *px0.offset(sx * i);
+                    }
+                    if !unit {
+                        temp /= a(j, j);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            }
+        }
+    };
+}
+
+ref_trsv_real!(ref_strsv, f32);
+ref_trsv_real!(ref_dtrsv, f64);
+
+macro_rules! ref_trsv_complex {
+    ($name:ident, $ty:ty) => {
+        /// Naive triangular solve `op(A) * x = b`, matching [`ref_trsv_real`]'s layout.
+        /// `ConjTrans` conjugates each stored entry of `A` as it's read.
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            trans: *const std::ffi::c_char,
+            diag: *const std::ffi::c_char,
+            n: *const blasint,
+            a: *const $ty,
+            lda: *const blasint,
+            x: *mut $ty,
+            incx: *const blasint,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let lda = *lda as isize;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let trans_char = (*trans as u8 as char).to_ascii_uppercase();
+            let is_trans = trans_char != 'N';
+            let conj = trans_char == 'C';
+            let unit = (*diag as u8 as char).to_ascii_uppercase() == 'U';
+            let a = |i: isize, j: isize| -> $ty {
+                let v = *a.offset(i + j * lda);
+                if conj {
+                    v.conj()
+                } else {
+                    v
+                }
+            };
+            let (px0, sx) = stride_start(n_blas, x, *incx);
+            let zero = <$ty>::new(0.0, 0.0);
+
+            if !is_trans {
+                if upper {
+                    for j in (0..n).rev() {
+                        let p = px0.offset(sx * j);
+                        if *p != zero {
+                            if !unit {
+                                *p = *p / a(j, j);
+                            }
+                            let temp = *p;
+                            for i in (0..j).rev() {
+                                let pi = px0.offset(sx * i);
+                                *pi = *pi - temp * a(i, j);
+                            }
+                        }
+                    }
+                } else {
+                    for j in 0..n {
+                        let p = px0.offset(sx * j);
+                        if *p != zero {
+                            if !unit {
+                                *p = *p / a(j, j);
+                            }
+                            let temp = *p;
+                            for i in (j + 1)..n {
+                                let pi = px0.offset(sx * i);
+                                *pi = *pi - temp * a(i, j);
+                            }
+                        }
+                    }
+                }
+            } else if upper {
+                for j in 0..n {
+                    let mut temp = *px0.offset(sx * j);
+                    for i in 0..j {
+                        temp -= a(i, j) * *px0.offset(sx * i);
+                    }
+                    if !unit {
+                        temp /= a(j, j);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            } else {
+                for j in (0..n).rev() {
+                    let mut temp = *px0.offset(sx * j);
+                    for i in (j + 1)..n {
+                        temp -= a(i, j) * *px0.offset(sx * i);
+                    }
+                    if !unit {
+                        temp /= a(j, j);
+                    }
+                    *px0.offset(sx * j) = temp;
+                }
+            }
+        }
+    };
+}
+
+ref_trsv_complex!(ref_ctrsv, Complex32);
+ref_trsv_complex!(ref_ztrsv, Complex64);
+
+macro_rules! ref_spr_real {
+    ($name:ident, $ty:ty) => {
+        /// Naive symmetric packed rank-1 update `A := A + alpha * x * x^T`, where `A` is
+        /// stored column-major packed (see `packed_index`).
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            n: *const blasint,
+            alpha: *const $ty,
+            x: *const $ty,
+            incx: *const blasint,
+            ap: *mut $ty,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let alpha = *alpha;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let (px0, sx) = stride_start_const(n_blas, x, *incx);
+
+            for j in 0..n {
+                let xj = *px0.offset(sx * j);
+                if xj != 0.0 {
+                    let temp = alpha * xj;
+                    let range = if upper { 0..=j } else { j..=(n - 1) };
+                    for i in range {
+                        let xi = *px0.offset(sx * i);
+                        let p = ap.offset(packed_index(n, upper, i, j));
+                        *p = *p + xi * temp;
+                    }
+                }
+            }
+        }
+    };
+}
+
+ref_spr_real!(ref_sspr, f32);
+ref_spr_real!(ref_dspr, f64);
+
+macro_rules! ref_hpr {
+    ($name:ident, $ty:ty, $real_ty:ty) => {
+        /// Naive Hermitian packed rank-1 update `A := A + alpha * x * x^H` (`alpha`
+        /// real), where `A` is stored column-major packed (see `packed_index`). Forces
+        /// the imaginary part of each diagonal element to zero after the update,
+        /// matching the exact-arithmetic result up to rounding.
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            n: *const blasint,
+            alpha: *const $real_ty,
+            x: *const $ty,
+            incx: *const blasint,
+            ap: *mut $ty,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let alpha = <$ty>::new(*alpha, 0.0);
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let (px0, sx) = stride_start_const(n_blas, x, *incx);
+
+            for j in 0..n {
+                let xj = *px0.offset(sx * j);
+                let temp = xj.conj() * alpha;
+                let range = if upper { 0..j } else { (j + 1)..n };
+                for i in range {
+                    let xi = *px0.offset(sx * i);
+                    let p = ap.offset(packed_index(n, upper, i, j));
+                    *p = *p + xi * temp;
+                }
+                let diag = ap.offset(packed_index(n, upper, j, j));
+                let mut val = *diag + xj * temp;
+                val.im = 0.0;
+                *diag = val;
+            }
+        }
+    };
+}
+
+ref_hpr!(ref_chpr, Complex32, f32);
+ref_hpr!(ref_zhpr, Complex64, f64);
+
+macro_rules! ref_spr2_real {
+    ($name:ident, $ty:ty) => {
+        /// Naive symmetric packed rank-2 update `A := A + alpha*x*y^T + alpha*y*x^T`,
+        /// where `A` is stored column-major packed (see `packed_index`).
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            n: *const blasint,
+            alpha: *const $ty,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *const $ty,
+            incy: *const blasint,
+            ap: *mut $ty,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let alpha = *alpha;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let (px0, sx) = stride_start_const(n_blas, x, *incx);
+            let (py0, sy) = stride_start_const(n_blas, y, *incy);
+
+            for j in 0..n {
+                let xj = *px0.offset(sx * j);
+                let yj = *py0.offset(sy * j);
+                if xj != 0.0 || yj != 0.0 {
+                    let temp1 = alpha * yj;
+                    let temp2 = alpha * xj;
+                    let range = if upper { 0..=j } else { j..=(n - 1) };
+                    for i in range {
+                        let xi = *px0.offset(sx * i);
+                        let yi = *py0.offset(sy * i);
+                        let p = ap.offset(packed_index(n, upper, i, j));
+                        *p = *p + xi * temp1 + yi * temp2;
+                    }
+                }
+            }
+        }
+    };
+}
+
+ref_spr2_real!(ref_sspr2, f32);
+ref_spr2_real!(ref_dspr2, f64);
+
+macro_rules! ref_hpr2 {
+    ($name:ident, $ty:ty) => {
+        /// Naive Hermitian packed rank-2 update `A := A + alpha*x*y^H +
+        /// conj(alpha)*y*x^H`, where `A` is stored column-major packed (see
+        /// `packed_index`). Forces the imaginary part of each diagonal element to zero
+        /// after the update, matching the exact-arithmetic result up to rounding.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) unsafe extern "C" fn $name(
+            uplo: *const std::ffi::c_char,
+            n: *const blasint,
+            alpha: *const $ty,
+            x: *const $ty,
+            incx: *const blasint,
+            y: *const $ty,
+            incy: *const blasint,
+            ap: *mut $ty,
+        ) {
+            let n_blas = *n;
+            let n = n_blas as isize;
+            let alpha = *alpha;
+            let upper = (*uplo as u8 as char).to_ascii_uppercase() == 'U';
+            let (px0, sx) = stride_start_const(n_blas, x, *incx);
+            let (py0, sy) = stride_start_const(n_blas, y, *incy);
+
+            for j in 0..n {
+                let xj = *px0.offset(sx * j);
+                let yj = *py0.offset(sy * j);
+                let temp1 = alpha * yj.conj();
+                let temp2 = (alpha * xj).conj();
+                let range = if upper { 0..j } else { (j + 1)..n };
+                for i in range {
+                    let xi = *px0.offset(sx * i);
+                    let yi = *py0.offset(sy * i);
+                    let p = ap.offset(packed_index(n, upper, i, j));
+                    *p = *p + xi * temp1 + yi * temp2;
+                }
+                let diag = ap.offset(packed_index(n, upper, j, j));
+                let mut val = *diag + xj * temp1 + yj * temp2;
+                val.im = 0.0;
+                *diag = val;
+            }
+        }
+    };
+}
+
+ref_hpr2!(ref_chpr2, Complex32);
+ref_hpr2!(ref_zhpr2, Complex64);
+
+/// Eagerly installs this module's reference implementation into every backend slot that
+/// has one and isn't already registered, instead of waiting for the lazy fallback the
+/// `get_*` accessors already perform on first read (see the module docs above).
+///
+/// A slot already holding a real backend pointer (e.g. from an earlier
+/// `register_dgemm(openblas_dgemm)`, or [`crate::register_all`]) is left untouched — this
+/// only fills gaps, it never overrides an existing registration. Useful when a caller
+/// wants every covered slot observably registered up front, e.g. before calling
+/// [`crate::register_all_detailed`]-style introspection, or to avoid the first real call
+/// racing a `register_*` started on another thread.
+///
+/// Routines this module has no reference implementation for (TBSV and the rest of the
+/// packed/banded Level 2 kernels beyond TPMV/TPSV/TBMV/GBMV/SPR/HPR/SPR2/HPR2, SYRK/SYMM/
+/// TRSM and the rest of Level 3 beyond GEMM/HERK/HER2K/SYR2K/TRMM, the Givens-apply family
+/// beyond srot/drot/csrot/zdrot) are left exactly as unregistered as before; their
+/// `get_*` still panics until a real backend is registered.
+pub fn enable_reference_fallback() {
+    use crate::backend::*;
+
+    // An already-registered slot is an expected, non-exceptional outcome here, not a
+    // crash — silence the default panic hook for the duration of this call so it doesn't
+    // print a backtrace to stderr for every slot register_all/autoregister already filled
+    // in. Restored via `_hook_guard`'s `Drop` even if a `$register` call's panic somehow
+    // propagated past `catch_unwind`.
+    struct RestoreHook(Option<Box<dyn Fn(&std::panic::PanicHookInfo<'_>) + Sync + Send>>);
+    impl Drop for RestoreHook {
+        fn drop(&mut self) {
+            if let Some(hook) = self.0.take() {
+                std::panic::set_hook(hook);
+            }
+        }
+    }
+    let _hook_guard = RestoreHook(Some(std::panic::take_hook()));
+    std::panic::set_hook(Box::new(|_| {}));
+
+    macro_rules! fill {
+        ($register:ident, $reference:expr) => {
+            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
+                $register($reference)
+            }));
+        };
+    }
+
+    // BLAS Level 1 - Single
+    fill!(register_sswap, ref_sswap);
+    fill!(register_scopy, ref_scopy);
+    fill!(register_saxpy, ref_saxpy);
+    fill!(register_sscal, ref_sscal);
+    fill!(register_sdot, ref_sdot);
+    fill!(register_sdsdot, ref_sdsdot);
+    fill!(register_snrm2, ref_snrm2);
+    fill!(register_sasum, ref_sasum);
+    fill!(register_isamax, ref_isamax);
+    fill!(register_isamin, ref_isamin);
+    fill!(register_srot, ref_srot);
+    fill!(register_srotg, ref_srotg);
+    fill!(register_srotm, ref_srotm);
+    fill!(register_srotmg, ref_srotmg);
+
+    // BLAS Level 1 - Double
+    fill!(register_dswap, ref_dswap);
+    fill!(register_dcopy, ref_dcopy);
+    fill!(register_daxpy, ref_daxpy);
+    fill!(register_dscal, ref_dscal);
+    fill!(register_ddot, ref_ddot);
+    fill!(register_dsdot, ref_dsdot);
+    fill!(register_dnrm2, ref_dnrm2);
+    fill!(register_dasum, ref_dasum);
+    fill!(register_idamax, ref_idamax);
+    fill!(register_idamin, ref_idamin);
+    fill!(register_drot, ref_drot);
+    fill!(register_drotg, ref_drotg);
+    fill!(register_drotm, ref_drotm);
+    fill!(register_drotmg, ref_drotmg);
+
+    // BLAS Level 1 - Single complex
+    fill!(register_cswap, ref_cswap);
+    fill!(register_ccopy, ref_ccopy);
+    fill!(register_caxpy, ref_caxpy);
+    fill!(register_cscal, ref_cscal);
+    fill!(register_csscal, ref_csscal);
+    fill!(register_scasum, ref_scasum);
+    fill!(register_icamax, ref_icamax);
+    fill!(register_icamin, ref_icamin);
+    fill!(register_csrot, ref_csrot);
+    fill!(register_crotg, ref_crotg);
+
+    // BLAS Level 1 - Double complex
+    fill!(register_zswap, ref_zswap);
+    fill!(register_zcopy, ref_zcopy);
+    fill!(register_zaxpy, ref_zaxpy);
+    fill!(register_zscal, ref_zscal);
+    fill!(register_zdscal, ref_zdscal);
+    fill!(register_dzasum, ref_dzasum);
+    fill!(register_izamax, ref_izamax);
+    fill!(register_izamin, ref_izamin);
+    fill!(register_zdrot, ref_zdrot);
+    fill!(register_zrotg, ref_zrotg);
+
+    // BLAS Level 2 - GEMV/GBMV/GER/SYMV/HEMV/TPMV/TPSV
+    fill!(register_sgemv, ref_sgemv);
+    fill!(register_dgemv, ref_dgemv);
+    fill!(register_cgemv, ref_cgemv);
+    fill!(register_zgemv, ref_zgemv);
+    fill!(register_sgbmv, ref_sgbmv);
+    fill!(register_dgbmv, ref_dgbmv);
+    fill!(register_cgbmv, ref_cgbmv);
+    fill!(register_zgbmv, ref_zgbmv);
+    fill!(register_sger, ref_sger);
+    fill!(register_dger, ref_dger);
+    fill!(register_cgeru, ref_cgeru);
+    fill!(register_cgerc, ref_cgerc);
+    fill!(register_zgeru, ref_zgeru);
+    fill!(register_zgerc, ref_zgerc);
+    fill!(register_ssymv, ref_ssymv);
+    fill!(register_dsymv, ref_dsymv);
+    fill!(register_chemv, ref_chemv);
+    fill!(register_zhemv, ref_zhemv);
+    fill!(register_sspmv, ref_sspmv);
+    fill!(register_dspmv, ref_dspmv);
+    fill!(register_chpmv, ref_chpmv);
+    fill!(register_zhpmv, ref_zhpmv);
+    fill!(register_ssbmv, ref_ssbmv);
+    fill!(register_dsbmv, ref_dsbmv);
+    fill!(register_chbmv, ref_chbmv);
+    fill!(register_zhbmv, ref_zhbmv);
+    fill!(register_stpmv, ref_stpmv);
+    fill!(register_dtpmv, ref_dtpmv);
+    fill!(register_ctpmv, ref_ctpmv);
+    fill!(register_ztpmv, ref_ztpmv);
+    fill!(register_stpsv, ref_stpsv);
+    fill!(register_dtpsv, ref_dtpsv);
+    fill!(register_ctpsv, ref_ctpsv);
+    fill!(register_ztpsv, ref_ztpsv);
+    fill!(register_stbmv, ref_stbmv);
+    fill!(register_dtbmv, ref_dtbmv);
+    fill!(register_ctbmv, ref_ctbmv);
+    fill!(register_ztbmv, ref_ztbmv);
+    fill!(register_strsv, ref_strsv);
+    fill!(register_dtrsv, ref_dtrsv);
+    fill!(register_ctrsv, ref_ctrsv);
+    fill!(register_ztrsv, ref_ztrsv);
+    fill!(register_sspr, ref_sspr);
+    fill!(register_dspr, ref_dspr);
+    fill!(register_chpr, ref_chpr);
+    fill!(register_zhpr, ref_zhpr);
+    fill!(register_sspr2, ref_sspr2);
+    fill!(register_dspr2, ref_dspr2);
+    fill!(register_chpr2, ref_chpr2);
+    fill!(register_zhpr2, ref_zhpr2);
+
+    // BLAS Level 3 - GEMM/HERK/HER2K/TRMM
+    fill!(register_sgemm, ref_sgemm);
+    fill!(register_dgemm, ref_dgemm);
+    fill!(register_cgemm, ref_cgemm);
+    fill!(register_zgemm, ref_zgemm);
+    fill!(register_cherk, ref_cherk);
+    fill!(register_zherk, ref_zherk);
+    fill!(register_cher2k, ref_cher2k);
+    fill!(register_zher2k, ref_zher2k);
+    fill!(register_ssyr2k, ref_ssyr2k);
+    fill!(register_dsyr2k, ref_dsyr2k);
+    fill!(register_csyr2k, ref_csyr2k);
+    fill!(register_zsyr2k, ref_zsyr2k);
+    fill!(register_strmm, ref_strmm);
+    fill!(register_dtrmm, ref_dtrmm);
+    fill!(register_ctrmm, ref_ctrmm);
+    fill!(register_ztrmm, ref_ztrmm);
+}
@@ -0,0 +1,428 @@
+//! Bulk backend registration from a dynamic symbol resolver.
+//!
+//! Each Fortran routine has its own `register_*` setter in [`crate::backend`], which is
+//! convenient when pointers are obtained one at a time (e.g. hand-written `extern "C"`
+//! declarations, as in [`crate::autoregister`]) but tedious when the caller instead has
+//! a single symbol-lookup function — for example the result of `dlsym`/`libloading`, or
+//! a Python/Julia FFI capsule table keyed by name. This module bridges that gap: give it
+//! a `resolver` that maps a Fortran symbol name to its address, and it registers every
+//! backend slot this crate knows about in one call.
+//!
+//! Symbol names are the conventional gfortran/OpenBLAS external names, i.e. the routine
+//! name followed by a trailing underscore (`"dgemm_"`, `"ctrsv_"`, ...).
+
+use std::ffi::c_void;
+
+use crate::backend::*;
+use crate::types::BlasIntWidth;
+
+/// Resolves and registers every backend slot this crate defines a `register_*` function
+/// for, via `resolver`. Symbols `resolver` cannot find are left unregistered (a later
+/// `get_*` call on that slot still panics as usual); use
+/// [`register_all_report_missing`] if you need to know which ones were skipped.
+///
+/// # Safety
+///
+/// Every address `resolver` returns for a known symbol name must be a valid Fortran BLAS
+/// function pointer with the signature CBLAS expects for that routine, per the safety
+/// requirements of the corresponding `register_*` function in [`crate::backend`].
+pub unsafe fn register_all(resolver: impl Fn(&str) -> Option<*const c_void>) {
+    register_all_report_missing(resolver);
+}
+
+/// Like [`register_all`], but first checks `width` — the integer width `name`'s library
+/// is known (from whatever out-of-band source the caller has) to have been built
+/// with — against [`BlasIntWidth::CURRENT`], returning an error instead of registering
+/// anything if they don't match.
+///
+/// `blasint` is one compile-time type for this whole crate, so this cannot register an
+/// ILP64 backend and an LP64 backend side by side in the same process; see the ABI
+/// hazard note on [`crate::types::blasint`]. What this does is fail loudly, before any
+/// pointer is stored, instead of silently corrupting the stack the first time a
+/// width-mismatched routine is called through a resolver like `libloading`'s, which
+/// (unlike a `#[link]`-time mismatch) the Rust compiler cannot catch for you.
+///
+/// # Safety
+///
+/// Same requirement as [`register_all`]: every resolved address must be a valid Fortran
+/// BLAS function pointer for the corresponding routine, built at the checked width.
+pub unsafe fn register_all_with_width_check(
+    name: &str,
+    width: BlasIntWidth,
+    resolver: impl Fn(&str) -> Option<*const c_void>,
+) -> Result<(), String> {
+    width.check(name)?;
+    register_all_report_missing(resolver);
+    Ok(())
+}
+
+/// Like [`register_all`], but returns the symbol names `resolver` could not resolve
+/// instead of silently leaving those backend slots unregistered.
+///
+/// # Safety
+///
+/// Same requirement as [`register_all`]: every resolved address must be a valid Fortran
+/// BLAS function pointer for the corresponding routine.
+pub unsafe fn register_all_report_missing(
+    resolver: impl Fn(&str) -> Option<*const c_void>,
+) -> Vec<&'static str> {
+    register_all_detailed(resolver).missing
+}
+
+/// The outcome of resolving and registering every backend slot this crate knows about,
+/// symbol by symbol, from [`register_all_detailed`].
+#[derive(Debug, Clone, Default)]
+pub struct RegistrationReport {
+    /// Symbols `resolver` found and that were newly registered.
+    pub registered: Vec<&'static str>,
+    /// Symbols `resolver` could not find; those backend slots are left unregistered.
+    pub missing: Vec<&'static str>,
+    /// Symbols `resolver` found but whose backend slot already held a pointer from an
+    /// earlier registration call — `resolver`'s address for these was *not* stored,
+    /// since a `register_*` slot can only be set once.
+    pub already_registered: Vec<&'static str>,
+}
+
+impl RegistrationReport {
+    /// Whether every symbol this crate knows about was found and newly registered.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.already_registered.is_empty()
+    }
+}
+
+/// Like [`register_all_report_missing`], but distinguishes symbols that were newly
+/// registered from ones that were already registered (by an earlier call, e.g. the
+/// static-link ctor in [`crate::autoregister`]) instead of lumping both in with the
+/// ones `resolver` actually resolved.
+///
+/// Each `register_*` setter only allows setting its backend slot once and panics on a
+/// second attempt; this catches that specific panic with [`std::panic::catch_unwind`]
+/// and records it in
+/// [`RegistrationReport::already_registered`] rather than letting it propagate.
+///
+/// # Safety
+///
+/// Same requirement as [`register_all`]: every resolved address must be a valid Fortran
+/// BLAS function pointer for the corresponding routine.
+pub unsafe fn register_all_detailed(
+    resolver: impl Fn(&str) -> Option<*const c_void>,
+) -> RegistrationReport {
+    let mut report = RegistrationReport::default();
+
+    // Silence the default panic hook for the duration of this call: an
+    // already-registered slot is an expected, reported outcome here, not a crash, and
+    // without this every one would still print a panic backtrace to stderr. Restored
+    // via `_hook_guard`'s `Drop` even if a `$register` call's panic were to somehow
+    // propagate past `catch_unwind` (it shouldn't, but this way stderr noise from one
+    // caller never leaks into another's unrelated panics).
+    struct RestoreHook(Option<Box<dyn Fn(&std::panic::PanicHookInfo<'_>) + Sync + Send>>);
+    impl Drop for RestoreHook {
+        fn drop(&mut self) {
+            if let Some(hook) = self.0.take() {
+                std::panic::set_hook(hook);
+            }
+        }
+    }
+    let _hook_guard = RestoreHook(Some(std::panic::take_hook()));
+    std::panic::set_hook(Box::new(|_| {}));
+
+    macro_rules! reg {
+        ($name:literal, $register:ident) => {
+            match resolver($name) {
+                Some(p) => {
+                    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
+                        $register(std::mem::transmute(p))
+                    }));
+                    match result {
+                        Ok(()) => report.registered.push($name),
+                        Err(_) => report.already_registered.push($name),
+                    }
+                }
+                None => report.missing.push($name),
+            }
+        };
+    }
+
+    // BLAS Level 1 - Single
+    reg!("srot_", register_srot);
+    reg!("srotg_", register_srotg);
+    reg!("srotm_", register_srotm);
+    reg!("srotmg_", register_srotmg);
+    reg!("sswap_", register_sswap);
+    reg!("scopy_", register_scopy);
+    reg!("saxpy_", register_saxpy);
+    reg!("sscal_", register_sscal);
+    reg!("sdot_", register_sdot);
+    reg!("sdsdot_", register_sdsdot);
+    reg!("snrm2_", register_snrm2);
+    reg!("sasum_", register_sasum);
+    reg!("isamax_", register_isamax);
+    reg!("isamin_", register_isamin);
+
+    // BLAS Level 1 - Double
+    reg!("drot_", register_drot);
+    reg!("drotg_", register_drotg);
+    reg!("drotm_", register_drotm);
+    reg!("drotmg_", register_drotmg);
+    reg!("dswap_", register_dswap);
+    reg!("dcopy_", register_dcopy);
+    reg!("daxpy_", register_daxpy);
+    reg!("dscal_", register_dscal);
+    reg!("ddot_", register_ddot);
+    reg!("dsdot_", register_dsdot);
+    reg!("dnrm2_", register_dnrm2);
+    reg!("dasum_", register_dasum);
+    reg!("idamax_", register_idamax);
+    reg!("idamin_", register_idamin);
+
+    // BLAS Level 1 - Single complex
+    reg!("cswap_", register_cswap);
+    reg!("ccopy_", register_ccopy);
+    reg!("caxpy_", register_caxpy);
+    reg!("cscal_", register_cscal);
+    reg!("csscal_", register_csscal);
+    reg!("cdotu_", register_cdotu);
+    reg!("cdotc_", register_cdotc);
+    reg!("scnrm2_", register_scnrm2);
+    reg!("scasum_", register_scasum);
+    reg!("icamax_", register_icamax);
+    reg!("icamin_", register_icamin);
+    reg!("csrot_", register_csrot);
+    reg!("scabs1_", register_scabs1);
+
+    // BLAS Level 1 - Double complex
+    reg!("zswap_", register_zswap);
+    reg!("zcopy_", register_zcopy);
+    reg!("zaxpy_", register_zaxpy);
+    reg!("zscal_", register_zscal);
+    reg!("zdscal_", register_zdscal);
+    reg!("zdotu_", register_zdotu);
+    reg!("zdotc_", register_zdotc);
+    reg!("dznrm2_", register_dznrm2);
+    reg!("dzasum_", register_dzasum);
+    reg!("izamax_", register_izamax);
+    reg!("izamin_", register_izamin);
+    reg!("zdrot_", register_zdrot);
+    reg!("dcabs1_", register_dcabs1);
+
+    // BLAS Level 2 - GEMV/GBMV
+    reg!("sgemv_", register_sgemv);
+    reg!("dgemv_", register_dgemv);
+    reg!("cgemv_", register_cgemv);
+    reg!("zgemv_", register_zgemv);
+    reg!("sgbmv_", register_sgbmv);
+    reg!("dgbmv_", register_dgbmv);
+    reg!("cgbmv_", register_cgbmv);
+    reg!("zgbmv_", register_zgbmv);
+
+    // BLAS Level 2 - SYMV/HEMV/SBMV/HBMV
+    reg!("ssymv_", register_ssymv);
+    reg!("dsymv_", register_dsymv);
+    reg!("chemv_", register_chemv);
+    reg!("zhemv_", register_zhemv);
+    reg!("ssbmv_", register_ssbmv);
+    reg!("dsbmv_", register_dsbmv);
+    reg!("chbmv_", register_chbmv);
+    reg!("zhbmv_", register_zhbmv);
+
+    // BLAS Level 2 - TRMV/TRSV/TBMV/TBSV
+    reg!("strmv_", register_strmv);
+    reg!("dtrmv_", register_dtrmv);
+    reg!("ctrmv_", register_ctrmv);
+    reg!("ztrmv_", register_ztrmv);
+    reg!("strsv_", register_strsv);
+    reg!("dtrsv_", register_dtrsv);
+    reg!("ctrsv_", register_ctrsv);
+    reg!("ztrsv_", register_ztrsv);
+    reg!("stbmv_", register_stbmv);
+    reg!("dtbmv_", register_dtbmv);
+    reg!("ctbmv_", register_ctbmv);
+    reg!("ztbmv_", register_ztbmv);
+    reg!("stbsv_", register_stbsv);
+    reg!("dtbsv_", register_dtbsv);
+    reg!("ctbsv_", register_ctbsv);
+    reg!("ztbsv_", register_ztbsv);
+
+    // BLAS Level 2 - GER/SYR/SYR2
+    reg!("sger_", register_sger);
+    reg!("dger_", register_dger);
+    reg!("cgeru_", register_cgeru);
+    reg!("cgerc_", register_cgerc);
+    reg!("zgeru_", register_zgeru);
+    reg!("zgerc_", register_zgerc);
+    reg!("ssyr_", register_ssyr);
+    reg!("dsyr_", register_dsyr);
+    reg!("cher_", register_cher);
+    reg!("zher_", register_zher);
+    reg!("ssyr2_", register_ssyr2);
+    reg!("dsyr2_", register_dsyr2);
+    reg!("cher2_", register_cher2);
+    reg!("zher2_", register_zher2);
+
+    // BLAS Level 2 - SPMV/HPMV/TPMV/TPSV
+    reg!("sspmv_", register_sspmv);
+    reg!("dspmv_", register_dspmv);
+    reg!("chpmv_", register_chpmv);
+    reg!("zhpmv_", register_zhpmv);
+    reg!("stpmv_", register_stpmv);
+    reg!("dtpmv_", register_dtpmv);
+    reg!("ctpmv_", register_ctpmv);
+    reg!("ztpmv_", register_ztpmv);
+    reg!("stpsv_", register_stpsv);
+    reg!("dtpsv_", register_dtpsv);
+    reg!("ctpsv_", register_ctpsv);
+    reg!("ztpsv_", register_ztpsv);
+
+    // BLAS Level 2 - SPR/HPR/SPR2/HPR2
+    reg!("sspr_", register_sspr);
+    reg!("dspr_", register_dspr);
+    reg!("chpr_", register_chpr);
+    reg!("zhpr_", register_zhpr);
+    reg!("sspr2_", register_sspr2);
+    reg!("dspr2_", register_dspr2);
+    reg!("chpr2_", register_chpr2);
+    reg!("zhpr2_", register_zhpr2);
+
+    // BLAS Level 3
+    reg!("sgemm_", register_sgemm);
+    reg!("dgemm_", register_dgemm);
+    reg!("cgemm_", register_cgemm);
+    reg!("zgemm_", register_zgemm);
+    reg!("dsymm_", register_dsymm);
+    reg!("ssymm_", register_ssymm);
+    reg!("csymm_", register_csymm);
+    reg!("zsymm_", register_zsymm);
+    reg!("chemm_", register_chemm);
+    reg!("zhemm_", register_zhemm);
+    reg!("dsyrk_", register_dsyrk);
+    reg!("ssyrk_", register_ssyrk);
+    reg!("csyrk_", register_csyrk);
+    reg!("zsyrk_", register_zsyrk);
+    reg!("dsyr2k_", register_dsyr2k);
+    reg!("ssyr2k_", register_ssyr2k);
+    reg!("csyr2k_", register_csyr2k);
+    reg!("zsyr2k_", register_zsyr2k);
+    reg!("cherk_", register_cherk);
+    reg!("zherk_", register_zherk);
+    reg!("cher2k_", register_cher2k);
+    reg!("zher2k_", register_zher2k);
+    reg!("dtrmm_", register_dtrmm);
+    reg!("strmm_", register_strmm);
+    reg!("ctrmm_", register_ctrmm);
+    reg!("ztrmm_", register_ztrmm);
+    reg!("dtrsm_", register_dtrsm);
+    reg!("strsm_", register_strsm);
+    reg!("ctrsm_", register_ctrsm);
+    reg!("ztrsm_", register_ztrsm);
+
+    report
+}
+
+/// Like [`register_all_report_missing`], but every symbol `resolver` could not resolve
+/// is registered as a stub that panics naming the missing routine (see
+/// [`crate::dlopen`]), instead of being left unregistered. Symbols with no entry in
+/// `crate::dlopen::register_stub_by_name`'s table (the `i?amin` family, `scabs1`/`dcabs1`,
+/// and the single/complex SYRK/SYR2K/HERK/HER2K slots) stay unregistered. Useful when `resolver` is
+/// backed by a specific shared library that might not implement every routine this
+/// crate knows about (e.g. a BLAS provider that only covers a subset of LAPACK/BLAS),
+/// so a caller's first hint of a gap is a targeted panic rather than the generic
+/// "not registered: call register_x() first" message a bare missing slot produces.
+///
+/// # Safety
+///
+/// Same requirement as [`register_all_report_missing`]: every resolved address must be
+/// a valid Fortran BLAS function pointer for the corresponding routine.
+#[cfg(feature = "dlopen")]
+pub unsafe fn register_all_or_stub(resolver: impl Fn(&str) -> Option<*const c_void>) {
+    for name in register_all_report_missing(resolver) {
+        crate::dlopen::register_stub_by_name(name);
+    }
+}
+
+// =============================================================================
+// Name-keyed introspection and bulk registration
+// =============================================================================
+
+/// Every bare Fortran BLAS symbol name (no trailing underscore) this crate has a
+/// `register_*`/`get_*` slot for, in the same order [`register_all_detailed`] resolves
+/// them in.
+pub const ALL_SYMBOLS: &[&str] = &[
+    "srot", "srotg", "srotm", "srotmg", "sswap", "scopy", "saxpy", "sscal",
+    "sdot", "sdsdot", "snrm2", "sasum", "isamax", "isamin", "drot", "drotg", "drotm",
+    "drotmg", "dswap", "dcopy", "daxpy", "dscal", "ddot", "dsdot", "dnrm2",
+    "dasum", "idamax", "idamin", "cswap", "ccopy", "caxpy", "cscal", "csscal", "cdotu",
+    "cdotc", "scnrm2", "scasum", "icamax", "icamin", "csrot", "scabs1", "zswap", "zcopy",
+    "zaxpy", "zscal", "zdscal", "zdotu", "zdotc", "dznrm2", "dzasum", "izamax", "izamin",
+    "zdrot", "dcabs1", "sgemv", "dgemv", "cgemv", "zgemv", "sgbmv", "dgbmv",
+    "cgbmv", "zgbmv", "ssymv", "dsymv", "chemv", "zhemv", "ssbmv", "dsbmv",
+    "chbmv", "zhbmv", "strmv", "dtrmv", "ctrmv", "ztrmv", "strsv", "dtrsv",
+    "ctrsv", "ztrsv", "stbmv", "dtbmv", "ctbmv", "ztbmv", "stbsv", "dtbsv",
+    "ctbsv", "ztbsv", "sger", "dger", "cgeru", "cgerc", "zgeru", "zgerc",
+    "ssyr", "dsyr", "cher", "zher", "ssyr2", "dsyr2", "cher2", "zher2",
+    "sspmv", "dspmv", "chpmv", "zhpmv", "stpmv", "dtpmv", "ctpmv", "ztpmv",
+    "stpsv", "dtpsv", "ctpsv", "ztpsv", "sspr", "dspr", "chpr", "zhpr",
+    "sspr2", "dspr2", "chpr2", "zhpr2", "sgemm", "dgemm", "cgemm", "zgemm",
+    "dsymm", "ssymm", "csymm", "zsymm", "chemm", "zhemm", "dsyrk", "ssyrk",
+    "csyrk", "zsyrk", "dsyr2k", "ssyr2k", "csyr2k", "zsyr2k", "cherk", "zherk",
+    "cher2k", "zher2k", "dtrmm", "strmm", "ctrmm", "ztrmm", "dtrsm", "strsm",
+    "ctrsm", "ztrsm",
+];
+
+/// Whether `name` (a bare symbol from [`ALL_SYMBOLS`], e.g. `"dgemm"`) currently has a
+/// pointer registered. Returns `false`, not an error, for a name this crate doesn't know
+/// about — use `ALL_SYMBOLS.contains(&name)` first if distinguishing "unknown" from
+/// "known but unregistered" matters to the caller.
+pub fn is_registered(name: &str) -> bool {
+    crate::backend::is_registered_raw(name).unwrap_or(false)
+}
+
+/// Every symbol in [`ALL_SYMBOLS`] that currently has a pointer registered.
+pub fn registered_symbols() -> impl Iterator<Item = &'static str> {
+    ALL_SYMBOLS.iter().copied().filter(|name| is_registered(name))
+}
+
+/// Every symbol in [`ALL_SYMBOLS`] that does not yet have a pointer registered.
+pub fn missing_symbols() -> impl Iterator<Item = &'static str> {
+    ALL_SYMBOLS.iter().copied().filter(|name| !is_registered(name))
+}
+
+/// A [`register_by_name`] call failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegisterError {
+    /// `name` is not one of [`ALL_SYMBOLS`].
+    UnknownSymbol(String),
+    /// `name`'s backend slot already holds a pointer from an earlier registration.
+    AlreadyRegistered(String),
+}
+
+impl std::fmt::Display for RegisterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegisterError::UnknownSymbol(name) => write!(f, "unknown BLAS symbol: {name}"),
+            RegisterError::AlreadyRegistered(name) => {
+                write!(f, "{name} already registered (can only be set once)")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegisterError {}
+
+/// Registers the Fortran function pointer `ptr` for the bare symbol name `name` (e.g.
+/// `"dgemm"`, no trailing underscore), dispatching to that symbol's typed `register_*`
+/// setter internally. This is [`register_all_detailed`]'s per-symbol dispatch exposed
+/// directly, for callers who have one symbol name and address at a time (e.g. walking a
+/// Python/Julia FFI capsule table) rather than a bulk `resolver` closure.
+///
+/// # Safety
+///
+/// `ptr` must be a valid Fortran BLAS function pointer with the signature CBLAS expects
+/// for the routine `name` names, per the safety requirements of that routine's
+/// `register_*` function in [`crate::backend`].
+pub unsafe fn register_by_name(name: &str, ptr: *const c_void) -> Result<(), RegisterError> {
+    match crate::backend::register_by_name_raw(name, ptr as *const ()) {
+        Some(Ok(())) => Ok(()),
+        Some(Err(())) => Err(RegisterError::AlreadyRegistered(name.to_string())),
+        None => Err(RegisterError::UnknownSymbol(name.to_string())),
+    }
+}
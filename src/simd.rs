@@ -0,0 +1,115 @@
+//! Runtime CPU-feature-detected SIMD kernels for hot `crate::reference` inner loops.
+//!
+//! These are narrow, single-purpose kernels (not general vector math), not part of
+//! the public API: each checks `is_x86_feature_detected!` once (cached behind an
+//! atomic so repeated calls don't repeat the CPUID probe) and dispatches to an AVX2
+//! implementation when available, falling back to a portable scalar loop otherwise
+//! (including on non-x86_64 targets, where the AVX2 path doesn't exist at all).
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+const UNKNOWN: u8 = 0;
+const AVAILABLE: u8 = 1;
+const UNAVAILABLE: u8 = 2;
+
+static AVX2_STATUS: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+#[cfg(target_arch = "x86_64")]
+fn detect_avx2() -> bool {
+    is_x86_feature_detected!("avx2")
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn detect_avx2() -> bool {
+    false
+}
+
+#[inline]
+fn has_avx2() -> bool {
+    match AVX2_STATUS.load(Ordering::Relaxed) {
+        AVAILABLE => true,
+        UNAVAILABLE => false,
+        _ => {
+            let detected = detect_avx2();
+            AVX2_STATUS.store(if detected { AVAILABLE } else { UNAVAILABLE }, Ordering::Relaxed);
+            detected
+        }
+    }
+}
+
+/// `x[i] -= temp * a[i]` for `i in 0..n`, with `a`/`x` both stride-1 (contiguous).
+/// Used by [`crate::reference::ref_dtpsv`]'s substitution inner loop when `incx == 1`,
+/// where the range of `i` touched at a fixed column `j` is exactly this shape.
+///
+/// # Safety
+///
+/// `a` and `x` must each point to at least `n` valid, properly aligned `f64`s.
+#[inline]
+pub(crate) unsafe fn axpy_sub_f64(n: usize, temp: f64, a: *const f64, x: *mut f64) {
+    #[cfg(target_arch = "x86_64")]
+    if has_avx2() {
+        return axpy_sub_f64_avx2(n, temp, a, x);
+    }
+    axpy_sub_f64_scalar(n, temp, a, x);
+}
+
+#[inline]
+unsafe fn axpy_sub_f64_scalar(n: usize, temp: f64, a: *const f64, x: *mut f64) {
+    for i in 0..n {
+        *x.add(i) -= temp * *a.add(i);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn axpy_sub_f64_avx2(n: usize, temp: f64, a: *const f64, x: *mut f64) {
+    use std::arch::x86_64::{_mm256_loadu_pd, _mm256_mul_pd, _mm256_set1_pd, _mm256_storeu_pd, _mm256_sub_pd};
+
+    let temp_v = _mm256_set1_pd(temp);
+    let chunks = n / 4;
+    for c in 0..chunks {
+        let i = c * 4;
+        let av = _mm256_loadu_pd(a.add(i));
+        let xv = _mm256_loadu_pd(x.add(i));
+        _mm256_storeu_pd(x.add(i), _mm256_sub_pd(xv, _mm256_mul_pd(temp_v, av)));
+    }
+    axpy_sub_f64_scalar(n - chunks * 4, temp, a.add(chunks * 4), x.add(chunks * 4));
+}
+
+/// `x[i] -= temp * a[i]` for `i in 0..n`, with `a`/`x` both stride-1 (contiguous).
+/// Used by [`crate::reference::ref_stpsv`]'s substitution inner loop when `incx == 1`.
+///
+/// # Safety
+///
+/// `a` and `x` must each point to at least `n` valid, properly aligned `f32`s.
+#[inline]
+pub(crate) unsafe fn axpy_sub_f32(n: usize, temp: f32, a: *const f32, x: *mut f32) {
+    #[cfg(target_arch = "x86_64")]
+    if has_avx2() {
+        return axpy_sub_f32_avx2(n, temp, a, x);
+    }
+    axpy_sub_f32_scalar(n, temp, a, x);
+}
+
+#[inline]
+unsafe fn axpy_sub_f32_scalar(n: usize, temp: f32, a: *const f32, x: *mut f32) {
+    for i in 0..n {
+        *x.add(i) -= temp * *a.add(i);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn axpy_sub_f32_avx2(n: usize, temp: f32, a: *const f32, x: *mut f32) {
+    use std::arch::x86_64::{_mm256_loadu_ps, _mm256_mul_ps, _mm256_set1_ps, _mm256_storeu_ps, _mm256_sub_ps};
+
+    let temp_v = _mm256_set1_ps(temp);
+    let chunks = n / 8;
+    for c in 0..chunks {
+        let i = c * 8;
+        let av = _mm256_loadu_ps(a.add(i));
+        let xv = _mm256_loadu_ps(x.add(i));
+        _mm256_storeu_ps(x.add(i), _mm256_sub_ps(xv, _mm256_mul_ps(temp_v, av)));
+    }
+    axpy_sub_f32_scalar(n - chunks * 8, temp, a.add(chunks * 8), x.add(chunks * 8));
+}
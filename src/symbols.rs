@@ -0,0 +1,401 @@
+//! Enum-keyed introspection over the symbols in [`crate::registry::ALL_SYMBOLS`].
+//!
+//! [`crate::registry`] already offers `is_registered`/`registered_symbols`/
+//! `missing_symbols`, keyed by the bare symbol name as a `&str`. That's the right shape
+//! for callers juggling names from an external source (a resolver closure, a dynamic
+//! library's symbol table), but it means a typo in a string literal silently reports
+//! "not registered" instead of failing to compile. [`BlasSymbol`] is the same
+//! introspection surface keyed by a closed enum instead, for callers who know at compile
+//! time which routines they care about (e.g. "this application needs dgemm, dsyrk and
+//! daxpy, fail fast if any of them didn't come from the chosen backend").
+//!
+//! These functions live under `cblas_inject::symbols` rather than at the crate root: the
+//! names `is_registered`/`registered_symbols`/`missing_symbols` are already taken at the
+//! root by [`crate::registry`]'s `&str`-keyed versions, and Rust has no overloading to
+//! let both live under the same name. [`BlasSymbol`] itself is re-exported at the crate
+//! root since the type name doesn't collide with anything.
+
+/// Every Fortran BLAS routine this crate can inject, as a closed enum over
+/// [`crate::registry::ALL_SYMBOLS`] instead of a bare `&str`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlasSymbol {
+    Srot,
+    Srotg,
+    Srotm,
+    Srotmg,
+    Sswap,
+    Scopy,
+    Saxpy,
+    Sscal,
+    Sdot,
+    Sdsdot,
+    Snrm2,
+    Sasum,
+    Isamax,
+    Isamin,
+    Drot,
+    Drotg,
+    Drotm,
+    Drotmg,
+    Dswap,
+    Dcopy,
+    Daxpy,
+    Dscal,
+    Ddot,
+    Dsdot,
+    Dnrm2,
+    Dasum,
+    Idamax,
+    Idamin,
+    Cswap,
+    Ccopy,
+    Caxpy,
+    Cscal,
+    Csscal,
+    Cdotu,
+    Cdotc,
+    Scnrm2,
+    Scasum,
+    Icamax,
+    Icamin,
+    Csrot,
+    Scabs1,
+    Zswap,
+    Zcopy,
+    Zaxpy,
+    Zscal,
+    Zdscal,
+    Zdotu,
+    Zdotc,
+    Dznrm2,
+    Dzasum,
+    Izamax,
+    Izamin,
+    Zdrot,
+    Dcabs1,
+    Sgemv,
+    Dgemv,
+    Cgemv,
+    Zgemv,
+    Sgbmv,
+    Dgbmv,
+    Cgbmv,
+    Zgbmv,
+    Ssymv,
+    Dsymv,
+    Chemv,
+    Zhemv,
+    Ssbmv,
+    Dsbmv,
+    Chbmv,
+    Zhbmv,
+    Strmv,
+    Dtrmv,
+    Ctrmv,
+    Ztrmv,
+    Strsv,
+    Dtrsv,
+    Ctrsv,
+    Ztrsv,
+    Stbmv,
+    Dtbmv,
+    Ctbmv,
+    Ztbmv,
+    Stbsv,
+    Dtbsv,
+    Ctbsv,
+    Ztbsv,
+    Sger,
+    Dger,
+    Cgeru,
+    Cgerc,
+    Zgeru,
+    Zgerc,
+    Ssyr,
+    Dsyr,
+    Cher,
+    Zher,
+    Ssyr2,
+    Dsyr2,
+    Cher2,
+    Zher2,
+    Sspmv,
+    Dspmv,
+    Chpmv,
+    Zhpmv,
+    Stpmv,
+    Dtpmv,
+    Ctpmv,
+    Ztpmv,
+    Stpsv,
+    Dtpsv,
+    Ctpsv,
+    Ztpsv,
+    Sspr,
+    Dspr,
+    Chpr,
+    Zhpr,
+    Sspr2,
+    Dspr2,
+    Chpr2,
+    Zhpr2,
+    Sgemm,
+    Dgemm,
+    Cgemm,
+    Zgemm,
+    Dsymm,
+    Ssymm,
+    Csymm,
+    Zsymm,
+    Chemm,
+    Zhemm,
+    Dsyrk,
+    Ssyrk,
+    Csyrk,
+    Zsyrk,
+    Dsyr2k,
+    Ssyr2k,
+    Csyr2k,
+    Zsyr2k,
+    Cherk,
+    Zherk,
+    Cher2k,
+    Zher2k,
+    Dtrmm,
+    Strmm,
+    Ctrmm,
+    Ztrmm,
+    Dtrsm,
+    Strsm,
+    Ctrsm,
+    Ztrsm,
+}
+
+impl BlasSymbol {
+    /// Every variant, in the same order as [`crate::registry::ALL_SYMBOLS`].
+    pub const ALL: &'static [BlasSymbol] = &[
+    BlasSymbol::Srot, BlasSymbol::Srotg, BlasSymbol::Srotm, BlasSymbol::Srotmg,
+    BlasSymbol::Sswap, BlasSymbol::Scopy, BlasSymbol::Saxpy, BlasSymbol::Sscal,
+    BlasSymbol::Sdot, BlasSymbol::Sdsdot, BlasSymbol::Snrm2, BlasSymbol::Sasum,
+    BlasSymbol::Isamax, BlasSymbol::Isamin, BlasSymbol::Drot, BlasSymbol::Drotg, BlasSymbol::Drotm,
+    BlasSymbol::Drotmg, BlasSymbol::Dswap, BlasSymbol::Dcopy, BlasSymbol::Daxpy,
+    BlasSymbol::Dscal, BlasSymbol::Ddot, BlasSymbol::Dsdot, BlasSymbol::Dnrm2,
+    BlasSymbol::Dasum, BlasSymbol::Idamax, BlasSymbol::Idamin, BlasSymbol::Cswap, BlasSymbol::Ccopy,
+    BlasSymbol::Caxpy, BlasSymbol::Cscal, BlasSymbol::Csscal, BlasSymbol::Cdotu,
+    BlasSymbol::Cdotc, BlasSymbol::Scnrm2, BlasSymbol::Scasum, BlasSymbol::Icamax, BlasSymbol::Icamin,
+    BlasSymbol::Csrot, BlasSymbol::Scabs1, BlasSymbol::Zswap, BlasSymbol::Zcopy,
+    BlasSymbol::Zaxpy, BlasSymbol::Zscal, BlasSymbol::Zdscal, BlasSymbol::Zdotu,
+    BlasSymbol::Zdotc, BlasSymbol::Dznrm2, BlasSymbol::Dzasum, BlasSymbol::Izamax, BlasSymbol::Izamin,
+    BlasSymbol::Zdrot, BlasSymbol::Dcabs1, BlasSymbol::Sgemv, BlasSymbol::Dgemv,
+    BlasSymbol::Cgemv, BlasSymbol::Zgemv, BlasSymbol::Sgbmv, BlasSymbol::Dgbmv,
+    BlasSymbol::Cgbmv, BlasSymbol::Zgbmv, BlasSymbol::Ssymv, BlasSymbol::Dsymv,
+    BlasSymbol::Chemv, BlasSymbol::Zhemv, BlasSymbol::Ssbmv, BlasSymbol::Dsbmv,
+    BlasSymbol::Chbmv, BlasSymbol::Zhbmv, BlasSymbol::Strmv, BlasSymbol::Dtrmv,
+    BlasSymbol::Ctrmv, BlasSymbol::Ztrmv, BlasSymbol::Strsv, BlasSymbol::Dtrsv,
+    BlasSymbol::Ctrsv, BlasSymbol::Ztrsv, BlasSymbol::Stbmv, BlasSymbol::Dtbmv,
+    BlasSymbol::Ctbmv, BlasSymbol::Ztbmv, BlasSymbol::Stbsv, BlasSymbol::Dtbsv,
+    BlasSymbol::Ctbsv, BlasSymbol::Ztbsv, BlasSymbol::Sger, BlasSymbol::Dger,
+    BlasSymbol::Cgeru, BlasSymbol::Cgerc, BlasSymbol::Zgeru, BlasSymbol::Zgerc,
+    BlasSymbol::Ssyr, BlasSymbol::Dsyr, BlasSymbol::Cher, BlasSymbol::Zher, BlasSymbol::Ssyr2,
+    BlasSymbol::Dsyr2, BlasSymbol::Cher2, BlasSymbol::Zher2, BlasSymbol::Sspmv,
+    BlasSymbol::Dspmv, BlasSymbol::Chpmv, BlasSymbol::Zhpmv, BlasSymbol::Stpmv,
+    BlasSymbol::Dtpmv, BlasSymbol::Ctpmv, BlasSymbol::Ztpmv, BlasSymbol::Stpsv,
+    BlasSymbol::Dtpsv, BlasSymbol::Ctpsv, BlasSymbol::Ztpsv, BlasSymbol::Sspr,
+    BlasSymbol::Dspr, BlasSymbol::Chpr, BlasSymbol::Zhpr, BlasSymbol::Sspr2, BlasSymbol::Dspr2,
+    BlasSymbol::Chpr2, BlasSymbol::Zhpr2, BlasSymbol::Sgemm, BlasSymbol::Dgemm,
+    BlasSymbol::Cgemm, BlasSymbol::Zgemm, BlasSymbol::Dsymm, BlasSymbol::Ssymm,
+    BlasSymbol::Csymm, BlasSymbol::Zsymm, BlasSymbol::Chemm, BlasSymbol::Zhemm,
+    BlasSymbol::Dsyrk, BlasSymbol::Ssyrk, BlasSymbol::Csyrk, BlasSymbol::Zsyrk,
+    BlasSymbol::Dsyr2k, BlasSymbol::Ssyr2k, BlasSymbol::Csyr2k, BlasSymbol::Zsyr2k,
+    BlasSymbol::Cherk, BlasSymbol::Zherk, BlasSymbol::Cher2k, BlasSymbol::Zher2k,
+    BlasSymbol::Dtrmm, BlasSymbol::Strmm, BlasSymbol::Ctrmm, BlasSymbol::Ztrmm,
+    BlasSymbol::Dtrsm, BlasSymbol::Strsm, BlasSymbol::Ctrsm, BlasSymbol::Ztrsm,
+    ];
+
+    /// The bare symbol name (no trailing underscore) this variant names, e.g.
+    /// `BlasSymbol::Dgemm.as_str() == "dgemm"`. This is the same string
+    /// [`crate::registry::ALL_SYMBOLS`] and `crate::registry::is_registered` use.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BlasSymbol::Srot => "srot",
+            BlasSymbol::Srotg => "srotg",
+            BlasSymbol::Srotm => "srotm",
+            BlasSymbol::Srotmg => "srotmg",
+            BlasSymbol::Sswap => "sswap",
+            BlasSymbol::Scopy => "scopy",
+            BlasSymbol::Saxpy => "saxpy",
+            BlasSymbol::Sscal => "sscal",
+            BlasSymbol::Sdot => "sdot",
+            BlasSymbol::Sdsdot => "sdsdot",
+            BlasSymbol::Snrm2 => "snrm2",
+            BlasSymbol::Sasum => "sasum",
+            BlasSymbol::Isamax => "isamax",
+            BlasSymbol::Isamin => "isamin",
+            BlasSymbol::Drot => "drot",
+            BlasSymbol::Drotg => "drotg",
+            BlasSymbol::Drotm => "drotm",
+            BlasSymbol::Drotmg => "drotmg",
+            BlasSymbol::Dswap => "dswap",
+            BlasSymbol::Dcopy => "dcopy",
+            BlasSymbol::Daxpy => "daxpy",
+            BlasSymbol::Dscal => "dscal",
+            BlasSymbol::Ddot => "ddot",
+            BlasSymbol::Dsdot => "dsdot",
+            BlasSymbol::Dnrm2 => "dnrm2",
+            BlasSymbol::Dasum => "dasum",
+            BlasSymbol::Idamax => "idamax",
+            BlasSymbol::Idamin => "idamin",
+            BlasSymbol::Cswap => "cswap",
+            BlasSymbol::Ccopy => "ccopy",
+            BlasSymbol::Caxpy => "caxpy",
+            BlasSymbol::Cscal => "cscal",
+            BlasSymbol::Csscal => "csscal",
+            BlasSymbol::Cdotu => "cdotu",
+            BlasSymbol::Cdotc => "cdotc",
+            BlasSymbol::Scnrm2 => "scnrm2",
+            BlasSymbol::Scasum => "scasum",
+            BlasSymbol::Icamax => "icamax",
+            BlasSymbol::Icamin => "icamin",
+            BlasSymbol::Csrot => "csrot",
+            BlasSymbol::Scabs1 => "scabs1",
+            BlasSymbol::Zswap => "zswap",
+            BlasSymbol::Zcopy => "zcopy",
+            BlasSymbol::Zaxpy => "zaxpy",
+            BlasSymbol::Zscal => "zscal",
+            BlasSymbol::Zdscal => "zdscal",
+            BlasSymbol::Zdotu => "zdotu",
+            BlasSymbol::Zdotc => "zdotc",
+            BlasSymbol::Dznrm2 => "dznrm2",
+            BlasSymbol::Dzasum => "dzasum",
+            BlasSymbol::Izamax => "izamax",
+            BlasSymbol::Izamin => "izamin",
+            BlasSymbol::Zdrot => "zdrot",
+            BlasSymbol::Dcabs1 => "dcabs1",
+            BlasSymbol::Sgemv => "sgemv",
+            BlasSymbol::Dgemv => "dgemv",
+            BlasSymbol::Cgemv => "cgemv",
+            BlasSymbol::Zgemv => "zgemv",
+            BlasSymbol::Sgbmv => "sgbmv",
+            BlasSymbol::Dgbmv => "dgbmv",
+            BlasSymbol::Cgbmv => "cgbmv",
+            BlasSymbol::Zgbmv => "zgbmv",
+            BlasSymbol::Ssymv => "ssymv",
+            BlasSymbol::Dsymv => "dsymv",
+            BlasSymbol::Chemv => "chemv",
+            BlasSymbol::Zhemv => "zhemv",
+            BlasSymbol::Ssbmv => "ssbmv",
+            BlasSymbol::Dsbmv => "dsbmv",
+            BlasSymbol::Chbmv => "chbmv",
+            BlasSymbol::Zhbmv => "zhbmv",
+            BlasSymbol::Strmv => "strmv",
+            BlasSymbol::Dtrmv => "dtrmv",
+            BlasSymbol::Ctrmv => "ctrmv",
+            BlasSymbol::Ztrmv => "ztrmv",
+            BlasSymbol::Strsv => "strsv",
+            BlasSymbol::Dtrsv => "dtrsv",
+            BlasSymbol::Ctrsv => "ctrsv",
+            BlasSymbol::Ztrsv => "ztrsv",
+            BlasSymbol::Stbmv => "stbmv",
+            BlasSymbol::Dtbmv => "dtbmv",
+            BlasSymbol::Ctbmv => "ctbmv",
+            BlasSymbol::Ztbmv => "ztbmv",
+            BlasSymbol::Stbsv => "stbsv",
+            BlasSymbol::Dtbsv => "dtbsv",
+            BlasSymbol::Ctbsv => "ctbsv",
+            BlasSymbol::Ztbsv => "ztbsv",
+            BlasSymbol::Sger => "sger",
+            BlasSymbol::Dger => "dger",
+            BlasSymbol::Cgeru => "cgeru",
+            BlasSymbol::Cgerc => "cgerc",
+            BlasSymbol::Zgeru => "zgeru",
+            BlasSymbol::Zgerc => "zgerc",
+            BlasSymbol::Ssyr => "ssyr",
+            BlasSymbol::Dsyr => "dsyr",
+            BlasSymbol::Cher => "cher",
+            BlasSymbol::Zher => "zher",
+            BlasSymbol::Ssyr2 => "ssyr2",
+            BlasSymbol::Dsyr2 => "dsyr2",
+            BlasSymbol::Cher2 => "cher2",
+            BlasSymbol::Zher2 => "zher2",
+            BlasSymbol::Sspmv => "sspmv",
+            BlasSymbol::Dspmv => "dspmv",
+            BlasSymbol::Chpmv => "chpmv",
+            BlasSymbol::Zhpmv => "zhpmv",
+            BlasSymbol::Stpmv => "stpmv",
+            BlasSymbol::Dtpmv => "dtpmv",
+            BlasSymbol::Ctpmv => "ctpmv",
+            BlasSymbol::Ztpmv => "ztpmv",
+            BlasSymbol::Stpsv => "stpsv",
+            BlasSymbol::Dtpsv => "dtpsv",
+            BlasSymbol::Ctpsv => "ctpsv",
+            BlasSymbol::Ztpsv => "ztpsv",
+            BlasSymbol::Sspr => "sspr",
+            BlasSymbol::Dspr => "dspr",
+            BlasSymbol::Chpr => "chpr",
+            BlasSymbol::Zhpr => "zhpr",
+            BlasSymbol::Sspr2 => "sspr2",
+            BlasSymbol::Dspr2 => "dspr2",
+            BlasSymbol::Chpr2 => "chpr2",
+            BlasSymbol::Zhpr2 => "zhpr2",
+            BlasSymbol::Sgemm => "sgemm",
+            BlasSymbol::Dgemm => "dgemm",
+            BlasSymbol::Cgemm => "cgemm",
+            BlasSymbol::Zgemm => "zgemm",
+            BlasSymbol::Dsymm => "dsymm",
+            BlasSymbol::Ssymm => "ssymm",
+            BlasSymbol::Csymm => "csymm",
+            BlasSymbol::Zsymm => "zsymm",
+            BlasSymbol::Chemm => "chemm",
+            BlasSymbol::Zhemm => "zhemm",
+            BlasSymbol::Dsyrk => "dsyrk",
+            BlasSymbol::Ssyrk => "ssyrk",
+            BlasSymbol::Csyrk => "csyrk",
+            BlasSymbol::Zsyrk => "zsyrk",
+            BlasSymbol::Dsyr2k => "dsyr2k",
+            BlasSymbol::Ssyr2k => "ssyr2k",
+            BlasSymbol::Csyr2k => "csyr2k",
+            BlasSymbol::Zsyr2k => "zsyr2k",
+            BlasSymbol::Cherk => "cherk",
+            BlasSymbol::Zherk => "zherk",
+            BlasSymbol::Cher2k => "cher2k",
+            BlasSymbol::Zher2k => "zher2k",
+            BlasSymbol::Dtrmm => "dtrmm",
+            BlasSymbol::Strmm => "strmm",
+            BlasSymbol::Ctrmm => "ctrmm",
+            BlasSymbol::Ztrmm => "ztrmm",
+            BlasSymbol::Dtrsm => "dtrsm",
+            BlasSymbol::Strsm => "strsm",
+            BlasSymbol::Ctrsm => "ctrsm",
+            BlasSymbol::Ztrsm => "ztrsm",
+        }
+    }
+}
+
+impl std::fmt::Display for BlasSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Whether `sym` currently has a pointer registered.
+pub fn is_registered(sym: BlasSymbol) -> bool {
+    crate::registry::is_registered(sym.as_str())
+}
+
+/// Every [`BlasSymbol`] that currently has a pointer registered.
+pub fn registered_symbols() -> Vec<BlasSymbol> {
+    BlasSymbol::ALL.iter().copied().filter(|sym| is_registered(*sym)).collect()
+}
+
+/// The subset of `required` that does not yet have a pointer registered, so an
+/// application can assert up-front that the specific kernels it needs are all present
+/// and fail fast with a clear list of what the chosen backend did not provide, e.g.:
+///
+/// ```ignore
+/// let needed = [BlasSymbol::Dgemm, BlasSymbol::Dsyrk, BlasSymbol::Daxpy];
+/// let gaps = cblas_inject::symbols::missing_symbols(&needed);
+/// assert!(gaps.is_empty(), "backend is missing: {gaps:?}");
+/// ```
+pub fn missing_symbols(required: &[BlasSymbol]) -> Vec<BlasSymbol> {
+    required.iter().copied().filter(|sym| !is_registered(*sym)).collect()
+}
@@ -0,0 +1,157 @@
+//! Opt-in call-tracing and profiling over the registered BLAS entry points.
+//!
+//! Disabled by default (a single `AtomicBool` check, so the cost when off is
+//! negligible). Once [`enable_call_tracing`] is called, instrumented entry points push
+//! a [`TraceEntry`] — routine name, argument shape, wall-clock duration, and an
+//! estimated FLOP count — into a fixed-capacity ring buffer behind a `Mutex`, which
+//! [`dump_call_trace`] or [`call_trace_summary`] can read back at any time. This lets a
+//! caller see which kernels and sizes dominate a run, i.e. exactly the information
+//! [`crate::set_gpu_offload_threshold`] needs to be tuned usefully.
+//!
+//! Routines opt in with the [`trace_call!`] macro at their call site, so enabling
+//! tracing never changes the registered function pointer or the call site's control
+//! flow, only whether it times itself.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use crate::types::blasint;
+
+/// Ring buffer capacity: oldest entries are dropped once full.
+const TRACE_CAPACITY: usize = 4096;
+
+static TRACE_ENABLED: AtomicBool = AtomicBool::new(false);
+
+fn trace_buffer() -> &'static Mutex<VecDeque<TraceEntry>> {
+    static BUFFER: OnceLock<Mutex<VecDeque<TraceEntry>>> = OnceLock::new();
+    BUFFER.get_or_init(|| Mutex::new(VecDeque::with_capacity(TRACE_CAPACITY)))
+}
+
+/// The argument shape recorded for one traced call. Fields that don't apply to a given
+/// routine (e.g. `k` for a Level 1 call) are left `None`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CallShape {
+    pub m: Option<blasint>,
+    pub n: Option<blasint>,
+    pub k: Option<blasint>,
+    pub lda: Option<blasint>,
+    pub ldb: Option<blasint>,
+    pub ldc: Option<blasint>,
+    pub incx: Option<blasint>,
+    pub incy: Option<blasint>,
+}
+
+/// One recorded call: which routine, with what shape, how long it took, and an
+/// estimated FLOP count (routine-specific; see the `trace_call!` call sites).
+#[derive(Debug, Clone, Copy)]
+pub struct TraceEntry {
+    pub routine: &'static str,
+    pub shape: CallShape,
+    pub duration: Duration,
+    pub flops: u64,
+}
+
+/// Per-routine aggregate produced by [`call_trace_summary`].
+#[derive(Debug, Clone, Copy)]
+pub struct RoutineStats {
+    pub routine: &'static str,
+    pub calls: u64,
+    pub total_duration: Duration,
+    pub total_flops: u64,
+}
+
+/// Turns on call tracing. Cheap to call repeatedly; does not reset the buffer.
+pub fn enable_call_tracing() {
+    TRACE_ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Turns off call tracing. The buffer is left intact; use [`reset_call_trace`] to clear it.
+pub fn disable_call_tracing() {
+    TRACE_ENABLED.store(false, Ordering::Relaxed);
+}
+
+/// Whether call tracing is currently enabled.
+#[inline]
+pub fn is_call_tracing_enabled() -> bool {
+    TRACE_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Records one traced call, evicting the oldest entry if the ring buffer is full.
+///
+/// Called by the [`trace_call!`] macro; not meant to be called directly.
+pub(crate) fn record_call(routine: &'static str, shape: CallShape, duration: Duration, flops: u64) {
+    let mut buffer = trace_buffer().lock().unwrap_or_else(|e| e.into_inner());
+    if buffer.len() == TRACE_CAPACITY {
+        buffer.pop_front();
+    }
+    buffer.push_back(TraceEntry {
+        routine,
+        shape,
+        duration,
+        flops,
+    });
+}
+
+/// Returns a snapshot of every entry currently in the ring buffer, oldest first.
+pub fn dump_call_trace() -> Vec<TraceEntry> {
+    trace_buffer()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .copied()
+        .collect()
+}
+
+/// Clears the ring buffer without changing whether tracing is enabled.
+pub fn reset_call_trace() {
+    trace_buffer().lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// Aggregates the current ring buffer contents per routine: call count, summed
+/// duration, and summed estimated FLOPs. Order is unspecified.
+pub fn call_trace_summary() -> Vec<RoutineStats> {
+    let buffer = trace_buffer().lock().unwrap_or_else(|e| e.into_inner());
+    let mut stats: Vec<RoutineStats> = Vec::new();
+    for entry in buffer.iter() {
+        match stats.iter_mut().find(|s| s.routine == entry.routine) {
+            Some(s) => {
+                s.calls += 1;
+                s.total_duration += entry.duration;
+                s.total_flops += entry.flops;
+            }
+            None => stats.push(RoutineStats {
+                routine: entry.routine,
+                calls: 1,
+                total_duration: entry.duration,
+                total_flops: entry.flops,
+            }),
+        }
+    }
+    stats
+}
+
+/// Times `$call` and, if tracing is enabled, records it under `$routine` with shape
+/// `$shape` and estimated cost `$flops`. Evaluates to `$call`'s result either way, so
+/// wrapping a call site costs nothing but a branch when tracing is off.
+///
+/// ```ignore
+/// trace_call!("cblas_dgemm", CallShape { m: Some(m), n: Some(n), k: Some(k), ..Default::default() }, 2 * m as u64 * n as u64 * k as u64, {
+///     dgemm(&transa_char, &transb_char, &m, &n, &k, &alpha, a, &lda, b, &ldb, &beta, c, &ldc);
+/// })
+/// ```
+macro_rules! trace_call {
+    ($routine:expr, $shape:expr, $flops:expr, $call:block) => {{
+        if $crate::trace::is_call_tracing_enabled() {
+            let start = std::time::Instant::now();
+            let result = $call;
+            $crate::trace::record_call($routine, $shape, start.elapsed(), $flops);
+            result
+        } else {
+            $call
+        }
+    }};
+}
+
+pub(crate) use trace_call;
@@ -33,11 +33,18 @@ pub enum CBLAS_TRANSPOSE {
     CblasNoTrans = 111,
     /// Transpose
     CblasTrans = 112,
-    /// Conjugate transpose (Hermitian)
+    /// Conjugate transpose (Hermitian): `op(A) = A^H`
     CblasConjTrans = 113,
+    /// Conjugate, no transpose: `op(A) = conj(A)`.
+    ///
+    /// Fortran BLAS has no character code for this (only `N`/`T`/`C`), so routines
+    /// that honor it do so by explicitly conjugating their data rather than by
+    /// passing a different Fortran argument; see [`transpose_to_char`] and, e.g.,
+    /// `cblas_ctrsm`.
+    CblasConjNoTrans = 114,
 }
 
-pub use CBLAS_TRANSPOSE::{CblasConjTrans, CblasNoTrans, CblasTrans};
+pub use CBLAS_TRANSPOSE::{CblasConjNoTrans, CblasConjTrans, CblasNoTrans, CblasTrans};
 
 /// Upper/Lower triangle selector for symmetric/triangular operations.
 #[repr(C)]
@@ -76,23 +83,163 @@ pub enum CBLAS_SIDE {
 pub use CBLAS_SIDE::{CblasLeft, CblasRight};
 
 /// Integer type for BLAS operations (LP64: 32-bit).
+///
+/// # ABI hazard
+///
+/// `blasint` is selected at *compile time* of this crate via the `ilp64` feature. A
+/// Fortran BLAS pointer registered through `register_*` must have been built with the
+/// same integer width the caller compiled against: passing a 32-bit-compiled `&blasint`
+/// (i.e. `&i32`) to a function pointer that actually expects ILP64's 64-bit integers (or
+/// vice versa) reads/writes the wrong number of bytes for every `m`/`n`/`lda`/`incx`
+/// argument and is undefined behavior. There is no way to check this from inside the
+/// crate: a `DgemmFnPtr` is just a function pointer, and calling convention mismatches
+/// like this do not trap. Callers that mix libraries compiled against different
+/// `blasint` widths in the same process must ensure only LP64 pointers are registered
+/// into an LP64 build of this crate (and likewise for ILP64); see
+/// [`BlasIntWidth`] for a lightweight way to record which width a given registration
+/// expects, for sanity-checking at the point the pointer is obtained (e.g. from a
+/// `dlopen` symbol table) rather than discovering a corrupted stack at call time.
 #[cfg(not(feature = "ilp64"))]
 pub type blasint = i32;
 
 /// Integer type for BLAS operations (ILP64: 64-bit).
+///
+/// See the ABI hazard note on the LP64 definition of [`blasint`].
 #[cfg(feature = "ilp64")]
 pub type blasint = i64;
 
+/// The integer width `blasint` was compiled with, for runtime sanity checks.
+///
+/// This does not change any calling convention by itself; it exists so code that
+/// resolves Fortran BLAS symbols dynamically (e.g. from a `dlopen` handle or a
+/// capsule table) can record and compare the width a backend was built against
+/// before registering its pointers, rather than silently mis-registering an
+/// incompatible backend.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlasIntWidth {
+    /// 32-bit Fortran integers (LP64 BLAS, the common case).
+    Lp64 = 32,
+    /// 64-bit Fortran integers (ILP64 BLAS, e.g. OpenBLAS `INTERFACE64=1` or MKL ILP64).
+    Ilp64 = 64,
+}
+
+impl BlasIntWidth {
+    /// The width this crate was actually compiled with, based on the `ilp64` feature.
+    #[cfg(not(feature = "ilp64"))]
+    pub const CURRENT: BlasIntWidth = BlasIntWidth::Lp64;
+
+    /// The width this crate was actually compiled with, based on the `ilp64` feature.
+    #[cfg(feature = "ilp64")]
+    pub const CURRENT: BlasIntWidth = BlasIntWidth::Ilp64;
+
+    /// Checks `self` (the width `backend` is known to have been built with, from
+    /// whatever out-of-band source the caller has — an env var, a config file, a
+    /// `_64`-suffixed symbol name) against [`BlasIntWidth::CURRENT`].
+    ///
+    /// `blasint` is a single compile-time type for this whole crate (see the ABI hazard
+    /// note on [`blasint`]), so there is no way to register an ILP64 backend and an
+    /// LP64 backend side by side in one process build of this crate — only one width is
+    /// ever correct for every `register_*` call made against it. This check cannot add
+    /// that capability; what it can do is turn a silent stack-corrupting mismatch into
+    /// an explicit, named error at the point the pointer was resolved, rather than at
+    /// the first call through it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` naming `backend` and both widths if `self != BlasIntWidth::CURRENT`.
+    pub fn check(self, backend: &str) -> Result<(), String> {
+        if self == Self::CURRENT {
+            Ok(())
+        } else {
+            Err(format!(
+                "{backend}: resolved as {self:?} Fortran integers, but this build of \
+                 cblas-inject uses {:?} (blasint = {}-bit). Registering it would \
+                 corrupt the stack on every call through it; rebuild against the \
+                 matching `ilp64` feature setting instead.",
+                Self::CURRENT,
+                std::mem::size_of::<blasint>() * 8,
+            ))
+        }
+    }
+}
+
 /// Convert CBLAS_TRANSPOSE to Fortran character.
+///
+/// `CblasConjNoTrans` has no Fortran character of its own (BLAS only knows `N`/`T`/`C`);
+/// it maps here to `'N'` as a best-effort default for callers that pass `trans` straight
+/// through without handling conjugation themselves. Routines that need correct
+/// `ConjNoTrans` semantics (e.g. `cblas_ctrsm`/`cblas_ztrsm`, `cblas_ctbsv`/`cblas_ztbsv`)
+/// must not rely on this mapping alone — they conjugate their data explicitly and then
+/// call the backend with plain `NoTrans`.
 #[inline]
 pub(crate) fn transpose_to_char(trans: CBLAS_TRANSPOSE) -> c_char {
     match trans {
-        CblasNoTrans => b'N' as c_char,
+        CblasNoTrans | CblasConjNoTrans => b'N' as c_char,
         CblasTrans => b'T' as c_char,
         CblasConjTrans => b'C' as c_char,
     }
 }
 
+/// Collapses `CblasConjNoTrans` to `CblasNoTrans` and `CblasConjTrans` to `CblasTrans`,
+/// leaving `CblasNoTrans`/`CblasTrans` unchanged.
+///
+/// For real-valued routines conjugation is a no-op, so the two conjugated variants
+/// degenerate to their plain counterparts; this lets `s`/`d` wrappers normalize `trans`
+/// once up front instead of matching on all four variants themselves.
+#[inline]
+pub(crate) fn normalize_transpose_real(trans: CBLAS_TRANSPOSE) -> CBLAS_TRANSPOSE {
+    match trans {
+        CblasNoTrans | CblasConjNoTrans => CblasNoTrans,
+        CblasTrans | CblasConjTrans => CblasTrans,
+    }
+}
+
+/// Flips `CblasUpper`/`CblasLower`.
+///
+/// Row-major Level-2/3 wrappers for symmetric/Hermitian/triangular routines call this
+/// to invert `uplo` before dispatching to the (always column-major) Fortran backend,
+/// since the triangle stored in the upper half of a row-major matrix is the lower half
+/// when read as column-major, and vice versa.
+#[inline]
+pub(crate) fn flip_uplo(uplo: CBLAS_UPLO) -> CBLAS_UPLO {
+    match uplo {
+        CblasUpper => CblasLower,
+        CblasLower => CblasUpper,
+    }
+}
+
+/// Flips `CblasLeft`/`CblasRight`.
+///
+/// Row-major Level-3 wrappers (SYMM, HEMM, TRMM, TRSM) call this alongside
+/// [`flip_uplo`] to invert `side` before dispatching to the column-major Fortran
+/// backend, since `op(A) * B` in row-major becomes `B^T * op(A)^T` once the operands
+/// are reinterpreted as column-major.
+#[inline]
+pub(crate) fn flip_side(side: CBLAS_SIDE) -> CBLAS_SIDE {
+    match side {
+        CblasLeft => CblasRight,
+        CblasRight => CblasLeft,
+    }
+}
+
+/// Flips `CblasNoTrans`/`CblasTrans`.
+///
+/// Row-major Level-2/3 wrappers that need the plain (non-conjugating) transpose flip
+/// call this on an already-[`normalize_transpose_real`]-d value; the `ConjNoTrans`/
+/// `ConjTrans` variants are handled separately by each wrapper since flipping them also
+/// requires conjugating the data, which this function knows nothing about.
+#[inline]
+pub(crate) fn flip_transpose_real(trans: CBLAS_TRANSPOSE) -> CBLAS_TRANSPOSE {
+    match trans {
+        CblasNoTrans => CblasTrans,
+        CblasTrans => CblasNoTrans,
+        CblasConjNoTrans | CblasConjTrans => {
+            unreachable!("flip_transpose_real expects a normalized NoTrans/Trans value")
+        }
+    }
+}
+
 /// Convert CBLAS_UPLO to Fortran character.
 #[inline]
 #[allow(dead_code)]
@@ -140,5 +287,55 @@ pub enum ComplexReturnStyle {
     HiddenArgument = 1,
 }
 
+/// Global accumulation strategy for the Level 1 reductions (`cblas_sdot`/`cblas_ddot`,
+/// the complex dots, and the asum/nrm2 reductions).
+///
+/// `cblas_dsdot`/`cblas_sdsdot` already show that some callers care enough about
+/// precision to pay for double-precision accumulation in a single-precision dot.
+/// Setting this to [`Compensated`](AccumulationMode::Compensated) generalizes that idea
+/// into an opt-in mode that reroutes every Level 1 reduction through a pure-Rust
+/// Kahan–Babuška–Neumaier compensated sum instead of the registered backend, trading a
+/// little speed for substantially better accuracy on ill-conditioned or
+/// cancellation-heavy inputs. See [`crate::accumulate`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AccumulationMode {
+    /// Forward straight to the registered backend (the existing behavior).
+    #[default]
+    Native = 0,
+    /// Accumulate in Rust via Kahan–Babuška–Neumaier compensated summation.
+    Compensated = 1,
+}
+
+/// Fortran hidden character-length ABI convention.
+///
+/// Every CHARACTER argument this crate passes as `*const c_char` (`trans`, `uplo`,
+/// `diag`, ...) is, in the Fortran source, just a one-byte flag — but many compilers
+/// pass its length alongside it as an extra integer argument the Fortran signature
+/// never declares. Where that length argument lands differs by toolchain:
+/// - **None**: no hidden length argument at all (the ABI this crate otherwise assumes)
+/// - **Trailing**: g77/f2c/gfortran append one `usize` per CHARACTER argument, in
+///   argument order, after every declared argument
+/// - **Interspersed**: Cray/Intel CXML insert a `usize` immediately after each
+///   CHARACTER argument's pointer (value is always 1 for a single-char flag)
+///
+/// Registering against a library that expects `Trailing`/`Interspersed` while this is
+/// left at `None` corrupts the stack rather than erroring, so get this right before
+/// registering any routine with a CHARACTER argument.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CharLenConvention {
+    /// No hidden length argument (most BLAS/LAPACK distributions built with a modern
+    /// Fortran compiler's C-interop mode).
+    #[default]
+    None = 0,
+    /// One `usize` per CHARACTER argument, appended after all declared arguments, in
+    /// argument order (g77/f2c/gfortran convention).
+    Trailing = 1,
+    /// A `usize` immediately after each CHARACTER argument's pointer (Cray/Intel CXML
+    /// convention).
+    Interspersed = 2,
+}
+
 /// Index type returned by iamax functions
 pub type CBLAS_INDEX = blasint;
@@ -0,0 +1,101 @@
+//! Shared CBLAS argument validation (xerbla dispatch).
+//!
+//! Reference CBLAS validates its arguments before touching the backend and reports the
+//! first illegal one through `cblas_xerbla`, which takes the 1-based position of the
+//! offending parameter *in the CBLAS argument list the caller used* (including the
+//! leading `order` argument). This module centralizes that bookkeeping so each wrapper
+//! only needs to list its own legality conditions in argument order.
+//!
+//! Some routines (GEMV, TRSV, HERK, TBMV, TBSV, SPR/HPR, TRSM, ...) call [`validate`]/
+//! [`validate_layout`] unconditionally — their checks are cheap and always run. For
+//! routines where validation is opt-in instead (SYRK, HEMM), wrappers call
+//! [`validate_if_enabled`]/[`validate_layout_if_enabled`], which no-op unless
+//! [`enable_validation`] has been called, following the same disabled-by-default
+//! `AtomicBool` pattern as [`crate::trace`]'s call tracing.
+
+use crate::types::{blasint, CblasRowMajor, CBLAS_ORDER};
+use crate::xerbla::cblas_xerbla;
+use std::ffi::CString;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static VALIDATION_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turns on the opt-in validation checks gated by [`validate_if_enabled`]/
+/// [`validate_layout_if_enabled`] (e.g. for SYRK/HEMM). Unconditional validation on
+/// routines that call [`validate`]/[`validate_layout`] directly is unaffected.
+pub fn enable_validation() {
+    VALIDATION_ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Turns off the opt-in validation checks enabled by [`enable_validation`].
+pub fn disable_validation() {
+    VALIDATION_ENABLED.store(false, Ordering::Relaxed);
+}
+
+/// Whether the opt-in validation checks are currently enabled.
+#[inline]
+pub fn is_validation_enabled() -> bool {
+    VALIDATION_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Reports parameter `param` (1-based) of `routine` as illegal via `cblas_xerbla`.
+unsafe fn report(routine: &str, param: blasint) {
+    let name = CString::new(routine).unwrap_or_default();
+    cblas_xerbla(param, name.as_ptr(), std::ptr::null());
+}
+
+/// Runs `checks` in argument order and, on the first failing `(condition, param)` pair,
+/// reports `param` via `cblas_xerbla` and returns `true`. Callers should bail out
+/// without touching the backend when this returns `true`:
+///
+/// ```ignore
+/// if validate("cblas_dtrsm", &[(m >= 0, 6), (n >= 0, 7)]) {
+///     return;
+/// }
+/// ```
+pub(crate) unsafe fn validate(routine: &str, checks: &[(bool, blasint)]) -> bool {
+    for &(ok, param) in checks {
+        if !ok {
+            report(routine, param);
+            return true;
+        }
+    }
+    false
+}
+
+/// Like [`validate`], but `checks` gives each condition's *logical* argument position
+/// (1-based, counting the leading `order` argument as position 1). Reference CBLAS
+/// reports a different position depending on `order` for some routines — under
+/// `CblasRowMajor` the position reported to `cblas_xerbla` is one past the logical
+/// position given here. Callers list the logical position once per routine; this
+/// applies the per-layout shift so they don't have to maintain two copies of the table.
+pub(crate) unsafe fn validate_layout(
+    routine: &str,
+    order: CBLAS_ORDER,
+    checks: &[(bool, blasint)],
+) -> bool {
+    let shift: blasint = if order == CblasRowMajor { 1 } else { 0 };
+    for &(ok, param) in checks {
+        if !ok {
+            report(routine, param + shift);
+            return true;
+        }
+    }
+    false
+}
+
+/// Like [`validate`], but a no-op (always returns `false`) unless [`enable_validation`]
+/// has been called.
+pub(crate) unsafe fn validate_if_enabled(routine: &str, checks: &[(bool, blasint)]) -> bool {
+    is_validation_enabled() && validate(routine, checks)
+}
+
+/// Like [`validate_layout`], but a no-op (always returns `false`) unless
+/// [`enable_validation`] has been called.
+pub(crate) unsafe fn validate_layout_if_enabled(
+    routine: &str,
+    order: CBLAS_ORDER,
+    checks: &[(bool, blasint)],
+) -> bool {
+    is_validation_enabled() && validate_layout(routine, order, checks)
+}
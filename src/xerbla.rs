@@ -3,25 +3,66 @@
 //! Note: The standard cblas_xerbla is variadic, but Rust stable doesn't support
 //! C variadic functions. We provide a non-variadic version that handles the
 //! common case.
+//!
+//! The handler itself is registerable, following the same `OnceLock` set-once
+//! pattern as the Fortran backend pointers in `crate::backend`, so an injected
+//! caller can intercept illegal-argument reports (e.g. to raise instead of print)
+//! instead of being stuck with the default stderr message.
 
 use crate::types::blasint;
 use std::ffi::c_char;
+use std::sync::{Mutex, OnceLock};
 
-/// CBLAS error handler.
+/// Signature for a CBLAS error handler: `(1-based parameter index, routine name,
+/// format string)`, matching the historical `cblas_xerbla` ABI.
+pub type XerblaFnPtr = unsafe extern "C" fn(p: blasint, rout: *const c_char, form: *const c_char);
+
+static XERBLA: OnceLock<XerblaFnPtr> = OnceLock::new();
+
+/// The most recent illegal-argument report passed to [`cblas_xerbla`]: the offending
+/// routine name and its 1-based parameter index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LastXerblaError {
+    pub routine: String,
+    pub param: blasint,
+}
+
+static LAST_ERROR: Mutex<Option<LastXerblaError>> = Mutex::new(None);
+
+/// Returns the most recent illegal-argument report recorded by [`cblas_xerbla`], for FFI
+/// callers that can't install a handler via `register_xerbla`. `None` if no validation
+/// failure has occurred yet.
+pub fn last_xerbla_error() -> Option<LastXerblaError> {
+    LAST_ERROR.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Clears the error recorded by [`last_xerbla_error`].
+pub fn clear_last_xerbla_error() {
+    *LAST_ERROR.lock().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Registers a custom CBLAS error handler, overriding the default stderr report.
 ///
-/// This function is called when an illegal parameter is detected.
-/// It prints an error message to stderr.
+/// # Panics
 ///
-/// Note: This is a simplified version that ignores the format string and
-/// variadic arguments. The standard signature is:
-/// `void cblas_xerbla(int p, char *rout, char *form, ...)`
+/// Panics if a handler has already been registered (can only be set once).
+pub fn register_xerbla(f: XerblaFnPtr) {
+    XERBLA
+        .set(f)
+        .expect("xerbla handler already registered (can only be set once)");
+}
+
+fn get_xerbla() -> XerblaFnPtr {
+    *XERBLA.get_or_init(|| default_xerbla as XerblaFnPtr)
+}
+
+/// Default error handler: prints an error message to stderr.
 ///
 /// # Safety
 ///
 /// - `rout` must be a valid null-terminated C string or null
 /// - `_form` is ignored in this implementation
-#[no_mangle]
-pub unsafe extern "C" fn cblas_xerbla(p: blasint, rout: *const c_char, _form: *const c_char) {
+unsafe extern "C" fn default_xerbla(p: blasint, rout: *const c_char, _form: *const c_char) {
     let routine = if rout.is_null() {
         "<unknown>"
     } else {
@@ -34,3 +75,35 @@ pub unsafe extern "C" fn cblas_xerbla(p: blasint, rout: *const c_char, _form: *c
         routine, p
     );
 }
+
+/// CBLAS error handler.
+///
+/// This function is called when an illegal parameter is detected. It dispatches to
+/// whatever handler was registered via `register_xerbla`, defaulting to printing an
+/// error message to stderr.
+///
+/// Note: This is a simplified version that takes no variadic arguments; the format
+/// string is passed unchanged to the handler (the default handler ignores it). The
+/// standard signature is:
+/// `void cblas_xerbla(int p, char *rout, char *form, ...)`
+///
+/// # Safety
+///
+/// - `rout` must be a valid null-terminated C string or null
+/// - `form` is passed to the handler as is
+#[no_mangle]
+pub unsafe extern "C" fn cblas_xerbla(p: blasint, rout: *const c_char, form: *const c_char) {
+    let routine = if rout.is_null() {
+        "<unknown>".to_string()
+    } else {
+        std::ffi::CStr::from_ptr(rout)
+            .to_str()
+            .unwrap_or("<invalid>")
+            .to_string()
+    };
+    *LAST_ERROR.lock().unwrap_or_else(|e| e.into_inner()) =
+        Some(LastXerblaError { routine, param: p });
+
+    let handler = get_xerbla();
+    handler(p, rout, form);
+}
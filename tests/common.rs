@@ -293,6 +293,186 @@ pub fn assert_complex32_eq_strided(
     }
 }
 
+// =============================================================================
+// Property-based generation of valid BLAS call parameters
+// =============================================================================
+
+/// A small, dependency-free splitmix64 generator so parameter generation stays
+/// reproducible from a plain `u64` seed without pulling in `rand`.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Returns the next pseudo-random `u64` and advances the generator.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `lo..=hi` (inclusive on both ends).
+    pub fn next_range(&mut self, lo: usize, hi: usize) -> usize {
+        assert!(lo <= hi, "Rng::next_range: lo ({lo}) must be <= hi ({hi})");
+        lo + (self.next_u64() as usize) % (hi - lo + 1)
+    }
+
+    pub fn next_bool(&mut self) -> bool {
+        self.next_u64() & 1 == 0
+    }
+
+    /// Returns `magnitude` or `-magnitude`, chosen at random; used for increments,
+    /// which must never be zero.
+    pub fn next_signed_nonzero(&mut self, magnitude: blasint) -> blasint {
+        if self.next_bool() {
+            magnitude
+        } else {
+            -magnitude
+        }
+    }
+}
+
+/// A fully consistent parameter set for a GEMV-shaped CBLAS call: every storage
+/// size below (`lda`, `x`/`y` lengths) satisfies the constraints `calc_lda_gemv`/
+/// `calc_output_len_gemv`/`x_len_gemv`/`y_len_gemv`/`calc_vector_storage_size`
+/// document, so it can be fed directly to a `cblas_?gemv` call without further
+/// derivation.
+#[derive(Debug, Clone, Copy)]
+pub struct GemvParams {
+    pub order: CBLAS_ORDER,
+    pub trans: CBLAS_TRANSPOSE,
+    pub m: usize,
+    pub n: usize,
+    pub lda: blasint,
+    pub incx: blasint,
+    pub incy: blasint,
+}
+
+impl GemvParams {
+    /// Storage size required for `a` under this parameter set's `order`/`lda`.
+    pub fn a_storage_len(&self) -> usize {
+        let outer = match self.order {
+            CblasRowMajor => self.m,
+            CblasColMajor => self.n,
+        };
+        self.lda as usize * outer
+    }
+
+    pub fn x_len(&self) -> usize {
+        x_len_gemv(self.trans, self.m, self.n)
+    }
+
+    pub fn y_len(&self) -> usize {
+        y_len_gemv(self.trans, self.m, self.n)
+    }
+
+    pub fn x_storage_len(&self) -> usize {
+        calc_vector_storage_size(self.x_len(), self.incx)
+    }
+
+    pub fn y_storage_len(&self) -> usize {
+        calc_vector_storage_size(self.y_len(), self.incy)
+    }
+}
+
+/// Generates a fully consistent [`GemvParams`] from `seed`: order, trans, `m`/`n`
+/// in `1..=8`, a `lda` that is the tight minimum half the time and over-padded by
+/// up to 4 extra elements the other half, and `incx`/`incy` in `1..=3` with a
+/// randomly chosen sign (never zero).
+pub fn arbitrary_gemv_params(seed: u64) -> GemvParams {
+    let mut rng = Rng::new(seed);
+    let order = if rng.next_bool() { CblasRowMajor } else { CblasColMajor };
+    let trans = match rng.next_range(0, 2) {
+        0 => cblas_inject::CblasNoTrans,
+        1 => cblas_inject::CblasTrans,
+        _ => cblas_inject::CblasConjTrans,
+    };
+    let m = rng.next_range(1, 8);
+    let n = rng.next_range(1, 8);
+    let min_lda = calc_lda_gemv(order, trans, m, n);
+    let lda = if rng.next_bool() {
+        min_lda
+    } else {
+        min_lda + rng.next_range(1, 4) as blasint
+    };
+    let incx_mag = rng.next_range(1, 3) as blasint;
+    let incx = rng.next_signed_nonzero(incx_mag);
+    let incy_mag = rng.next_range(1, 3) as blasint;
+    let incy = rng.next_signed_nonzero(incy_mag);
+    GemvParams {
+        order,
+        trans,
+        m,
+        n,
+        lda,
+        incx,
+        incy,
+    }
+}
+
+/// Shrinks a failing [`GemvParams`] toward a smaller, simpler reproducer: halves
+/// `m`/`n` (floor, never below 1) before snapping `incx`/`incy` to unit stride and
+/// `lda` to its tight minimum. Returns `None` once the params are already
+/// minimal, so callers can stop shrinking.
+pub fn shrink_gemv_params(p: &GemvParams) -> Option<GemvParams> {
+    let mut shrunk = *p;
+    let mut changed = false;
+    if shrunk.m > 1 {
+        shrunk.m = (shrunk.m / 2).max(1);
+        changed = true;
+    }
+    if shrunk.n > 1 {
+        shrunk.n = (shrunk.n / 2).max(1);
+        changed = true;
+    }
+    if shrunk.incx.abs() != 1 {
+        shrunk.incx = shrunk.incx.signum();
+        changed = true;
+    }
+    if shrunk.incy.abs() != 1 {
+        shrunk.incy = shrunk.incy.signum();
+        changed = true;
+    }
+    let min_lda = calc_lda_gemv(shrunk.order, shrunk.trans, shrunk.m, shrunk.n);
+    if shrunk.lda > min_lda {
+        shrunk.lda = min_lda;
+        changed = true;
+    }
+    changed.then_some(shrunk)
+}
+
+/// Repeatedly generates [`GemvParams`] from consecutive seeds starting at
+/// `start_seed` and calls `is_failing` on each; once a failing case is found, it
+/// is shrunk (via [`shrink_gemv_params`]) for as long as the shrunk case still
+/// fails, returning the smallest reproducer found. Returns `None` if no seed in
+/// `start_seed..start_seed + attempts` fails.
+pub fn find_minimal_failing_gemv(
+    start_seed: u64,
+    attempts: u64,
+    is_failing: impl Fn(&GemvParams) -> bool,
+) -> Option<GemvParams> {
+    let mut failing = None;
+    for seed in start_seed..start_seed + attempts {
+        let params = arbitrary_gemv_params(seed);
+        if is_failing(&params) {
+            failing = Some(params);
+            break;
+        }
+    }
+    let mut case = failing?;
+    while let Some(candidate) = shrink_gemv_params(&case) {
+        if !is_failing(&candidate) {
+            break;
+        }
+        case = candidate;
+    }
+    Some(case)
+}
+
 // =============================================================================
 // Macro helpers
 // =============================================================================
@@ -644,3 +824,445 @@ pub fn create_triangular_packed_matrix_row<T: Copy + Default>(
     };
     create_triangular_packed_matrix_col(n, swapped_uplo, |i, j| fill(j, i))
 }
+
+// =============================================================================
+// Sparse (CSR/CSC) storage helpers
+// =============================================================================
+
+/// Compressed Sparse Row representation: `row_ptr[i]..row_ptr[i+1]` indexes into
+/// `col_idx`/`values` for the nonzeros of row `i`. `row_ptr` always has `rows + 1`
+/// entries; an empty row has `row_ptr[i] == row_ptr[i+1]`.
+#[derive(Debug, Clone)]
+pub struct Csr<T> {
+    pub rows: usize,
+    pub cols: usize,
+    pub row_ptr: Vec<usize>,
+    pub col_idx: Vec<usize>,
+    pub values: Vec<T>,
+}
+
+/// Compressed Sparse Column representation, the transposed layout of [`Csr`]:
+/// `col_ptr[j]..col_ptr[j+1]` indexes into `row_idx`/`values` for the nonzeros of
+/// column `j`.
+#[derive(Debug, Clone)]
+pub struct Csc<T> {
+    pub rows: usize,
+    pub cols: usize,
+    pub col_ptr: Vec<usize>,
+    pub row_idx: Vec<usize>,
+    pub values: Vec<T>,
+}
+
+/// Builds a [`Csr`] from a dense `rows x cols` column-major matrix: scans each row
+/// in order, appending `(col_index, value)` for every entry `is_nonzero` accepts
+/// and recording a `row_ptr` offset per row.
+pub fn dense_to_csr<T: Copy>(
+    rows: usize,
+    cols: usize,
+    dense: &[T],
+    is_nonzero: impl Fn(T) -> bool,
+) -> Csr<T> {
+    let mut row_ptr = Vec::with_capacity(rows + 1);
+    let mut col_idx = Vec::new();
+    let mut values = Vec::new();
+    row_ptr.push(0);
+    for i in 0..rows {
+        for j in 0..cols {
+            let v = dense[i + j * rows];
+            if is_nonzero(v) {
+                col_idx.push(j);
+                values.push(v);
+            }
+        }
+        row_ptr.push(col_idx.len());
+    }
+    Csr {
+        rows,
+        cols,
+        row_ptr,
+        col_idx,
+        values,
+    }
+}
+
+/// Converts a [`Csr`] to the equivalent dense column-major matrix.
+pub fn csr_to_dense<T: Copy + Default>(csr: &Csr<T>) -> Vec<T> {
+    let mut dense = vec![T::default(); csr.rows * csr.cols];
+    for i in 0..csr.rows {
+        for k in csr.row_ptr[i]..csr.row_ptr[i + 1] {
+            let j = csr.col_idx[k];
+            dense[i + j * csr.rows] = csr.values[k];
+        }
+    }
+    dense
+}
+
+/// Converts a [`Csr`] to a [`Csc`] via a counting-sort transpose: tally nonzero
+/// counts per column, prefix-sum them into `col_ptr`, then scatter each entry into
+/// its column's slot using a copy of `col_ptr` as per-column write cursors.
+pub fn csr_to_csc<T: Copy + Default>(csr: &Csr<T>) -> Csc<T> {
+    let mut col_ptr = vec![0usize; csr.cols + 1];
+    for &j in &csr.col_idx {
+        col_ptr[j + 1] += 1;
+    }
+    for j in 0..csr.cols {
+        col_ptr[j + 1] += col_ptr[j];
+    }
+    let mut cursor = col_ptr.clone();
+    let mut row_idx = vec![0usize; csr.values.len()];
+    let mut values = vec![T::default(); csr.values.len()];
+    for i in 0..csr.rows {
+        for k in csr.row_ptr[i]..csr.row_ptr[i + 1] {
+            let j = csr.col_idx[k];
+            let dest = cursor[j];
+            row_idx[dest] = i;
+            values[dest] = csr.values[k];
+            cursor[j] += 1;
+        }
+    }
+    Csc {
+        rows: csr.rows,
+        cols: csr.cols,
+        col_ptr,
+        row_idx,
+        values,
+    }
+}
+
+/// Converts a [`Csc`] back to [`Csr`], reusing [`csr_to_csc`]'s counting-sort
+/// transpose by treating `csc` as the `Csr` of the transposed matrix (swap
+/// rows/cols and `col_ptr`/`row_idx` for `row_ptr`/`col_idx`) and transposing
+/// back.
+pub fn csc_to_csr<T: Copy + Default>(csc: &Csc<T>) -> Csr<T> {
+    let as_csr = Csr {
+        rows: csc.cols,
+        cols: csc.rows,
+        row_ptr: csc.col_ptr.clone(),
+        col_idx: csc.row_idx.clone(),
+        values: csc.values.clone(),
+    };
+    let transposed = csr_to_csc(&as_csr);
+    Csr {
+        rows: csc.rows,
+        cols: csc.cols,
+        row_ptr: transposed.col_ptr,
+        col_idx: transposed.row_idx,
+        values: transposed.values,
+    }
+}
+
+/// Reference sparse GEMV oracle: `y := alpha * A * x + beta * y` where `A` is
+/// given in CSR format, for validating any sparse GEMV symbol a user registers.
+/// Honors strided `x`/`y` (as produced by [`StridedVec`]), the `beta == 0`
+/// overwrite special case (does not read the old `y` value), and empty rows
+/// (`row_ptr[i] == row_ptr[i+1]`), which contribute nothing to `y[i]`.
+///
+/// # Panics
+///
+/// Panics if `x.len() != A.cols` or `y.len() != A.rows`.
+pub fn ref_csr_gemv(csr: &Csr<f64>, alpha: f64, x: &StridedVec<f64>, beta: f64, y: &mut StridedVec<f64>) {
+    assert_eq!(x.len(), csr.cols, "ref_csr_gemv: x length must match A's column count");
+    assert_eq!(y.len(), csr.rows, "ref_csr_gemv: y length must match A's row count");
+    let x_vals = x.to_vec();
+    let inc_abs = y.inc.unsigned_abs() as usize;
+    for i in 0..csr.rows {
+        let mut acc = 0.0;
+        for k in csr.row_ptr[i]..csr.row_ptr[i + 1] {
+            acc += csr.values[k] * x_vals[csr.col_idx[k]];
+        }
+        let idx = if y.inc < 0 {
+            (csr.rows - 1 - i) * inc_abs
+        } else {
+            i * inc_abs
+        };
+        y.data[idx] = if beta == 0.0 {
+            alpha * acc
+        } else {
+            alpha * acc + beta * y.data[idx]
+        };
+    }
+}
+
+// =============================================================================
+// Matrix Market I/O (behind the `io` feature)
+// =============================================================================
+//
+// Parses/emits `.mtx` fixtures into the dense column-major layout the helpers
+// above already consume, so large reference problems can be checked in as files
+// instead of synthesized by `generate_matrix_f64`/`generate_matrix_complex64`.
+// Symmetric/Hermitian files are expanded into both triangles of the returned
+// dense matrix on read; feed that straight into `create_triangular_packed_matrix_col`
+// to get the packed view for SPMV/HPMV-style routines.
+
+/// Matrix Market symmetry qualifier.
+#[cfg(feature = "io")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MtxSymmetry {
+    General,
+    Symmetric,
+    Hermitian,
+}
+
+#[cfg(feature = "io")]
+fn mtx_data_lines(contents: &str) -> impl Iterator<Item = &str> {
+    contents
+        .lines()
+        .skip(1) // header
+        .filter(|l| !l.trim_start().starts_with('%') && !l.trim().is_empty())
+}
+
+#[cfg(feature = "io")]
+fn mtx_header(contents: &str) -> Result<(&str, &str, &str), String> {
+    let header = contents.lines().next().ok_or("mtx: empty file")?;
+    let lower = header.trim();
+    if !lower.to_ascii_lowercase().starts_with("%%matrixmarket matrix") {
+        return Err(format!("mtx: unrecognized header: {header}"));
+    }
+    let fields: Vec<&str> = lower.split_whitespace().collect();
+    if fields.len() < 5 {
+        return Err(format!("mtx: malformed header: {header}"));
+    }
+    Ok((fields[2], fields[3], fields[4]))
+}
+
+#[cfg(feature = "io")]
+fn mtx_symmetry(tag: &str, allow_hermitian: bool) -> Result<MtxSymmetry, String> {
+    match tag.to_ascii_lowercase().as_str() {
+        "general" => Ok(MtxSymmetry::General),
+        "symmetric" => Ok(MtxSymmetry::Symmetric),
+        "hermitian" if allow_hermitian => Ok(MtxSymmetry::Hermitian),
+        other => Err(format!("mtx: unsupported symmetry qualifier '{other}'")),
+    }
+}
+
+/// Parses a Matrix Market `.mtx` file (coordinate or array format, real field)
+/// into a dense column-major `rows x cols` matrix plus its declared symmetry.
+/// Symmetric entries are read once and mirrored into both triangles.
+#[cfg(feature = "io")]
+pub fn read_mtx_f64(contents: &str) -> Result<(usize, usize, Vec<f64>, MtxSymmetry), String> {
+    let (format, field, symmetry_tag) = mtx_header(contents)?;
+    if field.to_ascii_lowercase() != "real" {
+        return Err(format!(
+            "mtx: field '{field}' is not 'real' (use read_mtx_complex64 for complex)"
+        ));
+    }
+    let symmetry = mtx_symmetry(symmetry_tag, false)?;
+    let mut data_lines = mtx_data_lines(contents);
+    let size_line = data_lines.next().ok_or("mtx: missing size line")?;
+    let mut size = size_line.split_whitespace();
+    let rows: usize = size
+        .next()
+        .ok_or("mtx: missing rows")?
+        .parse()
+        .map_err(|e| format!("mtx: bad rows: {e}"))?;
+    let cols: usize = size
+        .next()
+        .ok_or("mtx: missing cols")?
+        .parse()
+        .map_err(|e| format!("mtx: bad cols: {e}"))?;
+    let mut data = vec![0.0f64; rows * cols];
+
+    match format.to_ascii_lowercase().as_str() {
+        "array" => {
+            for (i, j) in match symmetry {
+                MtxSymmetry::General => (0..cols).flat_map(|j| (0..rows).map(move |i| (i, j))).collect::<Vec<_>>(),
+                _ => (0..cols).flat_map(|j| (j..rows).map(move |i| (i, j))).collect::<Vec<_>>(),
+            } {
+                let line = data_lines.next().ok_or("mtx: truncated array data")?;
+                let v: f64 = line
+                    .trim()
+                    .parse()
+                    .map_err(|e| format!("mtx: bad value at ({i},{j}): {e}"))?;
+                data[i + j * rows] = v;
+                if symmetry != MtxSymmetry::General && i != j {
+                    data[j + i * rows] = v;
+                }
+            }
+        }
+        "coordinate" => {
+            let nnz: usize = size
+                .next()
+                .ok_or("mtx: missing nnz")?
+                .parse()
+                .map_err(|e| format!("mtx: bad nnz: {e}"))?;
+            for (idx, line) in data_lines.take(nnz).enumerate() {
+                let mut p = line.split_whitespace();
+                let i: usize = p
+                    .next()
+                    .ok_or_else(|| format!("mtx: entry {idx}: missing row"))?
+                    .parse::<usize>()
+                    .map_err(|e| format!("mtx: entry {idx}: bad row: {e}"))?
+                    - 1;
+                let j: usize = p
+                    .next()
+                    .ok_or_else(|| format!("mtx: entry {idx}: missing col"))?
+                    .parse::<usize>()
+                    .map_err(|e| format!("mtx: entry {idx}: bad col: {e}"))?
+                    - 1;
+                let v: f64 = p
+                    .next()
+                    .ok_or_else(|| format!("mtx: entry {idx}: missing value"))?
+                    .parse()
+                    .map_err(|e| format!("mtx: entry {idx}: bad value: {e}"))?;
+                data[i + j * rows] = v;
+                if symmetry != MtxSymmetry::General && i != j {
+                    data[j + i * rows] = v;
+                }
+            }
+        }
+        other => return Err(format!("mtx: unsupported format '{other}'")),
+    }
+    Ok((rows, cols, data, symmetry))
+}
+
+/// Parses a Matrix Market `.mtx` file (coordinate or array format, complex field)
+/// into a dense column-major `rows x cols` matrix plus its declared symmetry.
+/// `Hermitian` entries are read once and mirrored as `conj` into the other
+/// triangle.
+#[cfg(feature = "io")]
+pub fn read_mtx_complex64(
+    contents: &str,
+) -> Result<(usize, usize, Vec<Complex64>, MtxSymmetry), String> {
+    let (format, field, symmetry_tag) = mtx_header(contents)?;
+    if field.to_ascii_lowercase() != "complex" {
+        return Err(format!(
+            "mtx: field '{field}' is not 'complex' (use read_mtx_f64 for real)"
+        ));
+    }
+    let symmetry = mtx_symmetry(symmetry_tag, true)?;
+    let mut data_lines = mtx_data_lines(contents);
+    let size_line = data_lines.next().ok_or("mtx: missing size line")?;
+    let mut size = size_line.split_whitespace();
+    let rows: usize = size
+        .next()
+        .ok_or("mtx: missing rows")?
+        .parse()
+        .map_err(|e| format!("mtx: bad rows: {e}"))?;
+    let cols: usize = size
+        .next()
+        .ok_or("mtx: missing cols")?
+        .parse()
+        .map_err(|e| format!("mtx: bad cols: {e}"))?;
+    let mut data = vec![Complex64::new(0.0, 0.0); rows * cols];
+
+    let parse_complex = |p: &mut std::str::SplitWhitespace, ctx: &str| -> Result<Complex64, String> {
+        let re: f64 = p
+            .next()
+            .ok_or_else(|| format!("{ctx}: missing real part"))?
+            .parse()
+            .map_err(|e| format!("{ctx}: bad real part: {e}"))?;
+        let im: f64 = p
+            .next()
+            .ok_or_else(|| format!("{ctx}: missing imaginary part"))?
+            .parse()
+            .map_err(|e| format!("{ctx}: bad imaginary part: {e}"))?;
+        Ok(Complex64::new(re, im))
+    };
+
+    match format.to_ascii_lowercase().as_str() {
+        "array" => {
+            for (i, j) in match symmetry {
+                MtxSymmetry::General => (0..cols).flat_map(|j| (0..rows).map(move |i| (i, j))).collect::<Vec<_>>(),
+                _ => (0..cols).flat_map(|j| (j..rows).map(move |i| (i, j))).collect::<Vec<_>>(),
+            } {
+                let line = data_lines.next().ok_or("mtx: truncated array data")?;
+                let mut p = line.split_whitespace();
+                let v = parse_complex(&mut p, &format!("mtx: entry ({i},{j})"))?;
+                data[i + j * rows] = v;
+                if i != j {
+                    match symmetry {
+                        MtxSymmetry::Symmetric => data[j + i * rows] = v,
+                        MtxSymmetry::Hermitian => data[j + i * rows] = v.conj(),
+                        MtxSymmetry::General => {}
+                    }
+                }
+            }
+        }
+        "coordinate" => {
+            let nnz: usize = size
+                .next()
+                .ok_or("mtx: missing nnz")?
+                .parse()
+                .map_err(|e| format!("mtx: bad nnz: {e}"))?;
+            for (idx, line) in data_lines.take(nnz).enumerate() {
+                let mut p = line.split_whitespace();
+                let i: usize = p
+                    .next()
+                    .ok_or_else(|| format!("mtx: entry {idx}: missing row"))?
+                    .parse::<usize>()
+                    .map_err(|e| format!("mtx: entry {idx}: bad row: {e}"))?
+                    - 1;
+                let j: usize = p
+                    .next()
+                    .ok_or_else(|| format!("mtx: entry {idx}: missing col"))?
+                    .parse::<usize>()
+                    .map_err(|e| format!("mtx: entry {idx}: bad col: {e}"))?
+                    - 1;
+                let v = parse_complex(&mut p, &format!("mtx: entry {idx}"))?;
+                data[i + j * rows] = v;
+                if i != j {
+                    match symmetry {
+                        MtxSymmetry::Symmetric => data[j + i * rows] = v,
+                        MtxSymmetry::Hermitian => data[j + i * rows] = v.conj(),
+                        MtxSymmetry::General => {}
+                    }
+                }
+            }
+        }
+        other => return Err(format!("mtx: unsupported format '{other}'")),
+    }
+    Ok((rows, cols, data, symmetry))
+}
+
+/// Emits a dense column-major `rows x cols` real matrix as a Matrix Market
+/// array-format file. For `Symmetric`, only the lower triangle is written.
+///
+/// # Panics
+///
+/// Panics if `symmetry` is `Hermitian` (only valid for complex data — see
+/// [`write_mtx_complex64`]).
+#[cfg(feature = "io")]
+pub fn write_mtx_f64(rows: usize, cols: usize, data: &[f64], symmetry: MtxSymmetry) -> String {
+    let tag = match symmetry {
+        MtxSymmetry::General => "general",
+        MtxSymmetry::Symmetric => "symmetric",
+        MtxSymmetry::Hermitian => panic!("write_mtx_f64: hermitian is only valid for complex data"),
+    };
+    let mut out = format!("%%MatrixMarket matrix array real {tag}\n{rows} {cols}\n");
+    let rows_iter: Box<dyn Iterator<Item = (usize, usize)>> = match symmetry {
+        MtxSymmetry::General => Box::new((0..cols).flat_map(move |j| (0..rows).map(move |i| (i, j)))),
+        _ => Box::new((0..cols).flat_map(move |j| (j..rows).map(move |i| (i, j)))),
+    };
+    for (i, j) in rows_iter {
+        out.push_str(&format!("{}\n", data[i + j * rows]));
+    }
+    out
+}
+
+/// Emits a dense column-major `rows x cols` complex matrix as a Matrix Market
+/// array-format file. For `Symmetric`/`Hermitian`, only the lower triangle is
+/// written (the diagonal of a Hermitian matrix is always real, but this writes
+/// whatever value is stored without re-checking that invariant).
+#[cfg(feature = "io")]
+pub fn write_mtx_complex64(
+    rows: usize,
+    cols: usize,
+    data: &[Complex64],
+    symmetry: MtxSymmetry,
+) -> String {
+    let tag = match symmetry {
+        MtxSymmetry::General => "general",
+        MtxSymmetry::Symmetric => "symmetric",
+        MtxSymmetry::Hermitian => "hermitian",
+    };
+    let mut out = format!("%%MatrixMarket matrix array complex {tag}\n{rows} {cols}\n");
+    let rows_iter: Box<dyn Iterator<Item = (usize, usize)>> = match symmetry {
+        MtxSymmetry::General => Box::new((0..cols).flat_map(move |j| (0..rows).map(move |i| (i, j)))),
+        _ => Box::new((0..cols).flat_map(move |j| (j..rows).map(move |i| (i, j)))),
+    };
+    for (i, j) in rows_iter {
+        let v = data[i + j * rows];
+        out.push_str(&format!("{} {}\n", v.re, v.im));
+    }
+    out
+}
@@ -0,0 +1,81 @@
+//! Tests for the complex-input Level-1 reductions `cblas_scasum`/`cblas_dzasum`
+//! and `cblas_icamax`/`cblas_izamax` against the raw Fortran symbols from
+//! OpenBLAS, the same way `dot_sub.rs` compares the complex dot products.
+//!
+//! `cblas_scnrm2`/`cblas_dznrm2` aren't covered here: they compute their result
+//! directly (see `nrm2.rs`) rather than through a `register_*` hook, so they
+//! already have their own dedicated test file.
+
+extern crate blas_src;
+
+use cblas_inject::{blasint, register_dzasum, register_icamax, register_izamax, register_scasum};
+use num_complex::{Complex32, Complex64};
+
+#[macro_use]
+mod common;
+
+extern "C" {
+    fn scasum_(n: *const blasint, x: *const Complex32, incx: *const blasint) -> f32;
+    fn dzasum_(n: *const blasint, x: *const Complex64, incx: *const blasint) -> f64;
+    fn icamax_(n: *const blasint, x: *const Complex32, incx: *const blasint) -> blasint;
+    fn izamax_(n: *const blasint, x: *const Complex64, incx: *const blasint) -> blasint;
+}
+
+setup_once!(setup_scasum, register_scasum, scasum_);
+setup_once!(setup_dzasum, register_dzasum, dzasum_);
+setup_once!(setup_icamax, register_icamax, icamax_);
+setup_once!(setup_izamax, register_izamax, izamax_);
+
+fn complex32_case() -> Vec<Complex32> {
+    vec![
+        Complex32::new(1.0, -2.0),
+        Complex32::new(-3.5, 0.5),
+        Complex32::new(0.0, 4.0),
+        Complex32::new(-1.0, -1.0),
+    ]
+}
+
+fn complex64_case() -> Vec<Complex64> {
+    vec![
+        Complex64::new(1.0, -2.0),
+        Complex64::new(-3.5, 0.5),
+        Complex64::new(0.0, 4.0),
+        Complex64::new(-1.0, -1.0),
+    ]
+}
+
+#[test]
+fn scasum_agrees_with_fortran() {
+    setup_scasum();
+    let x = complex32_case();
+    let got = unsafe { cblas_inject::cblas_scasum(x.len() as blasint, x.as_ptr(), 1) };
+    let expected = unsafe { scasum_(&(x.len() as blasint), x.as_ptr(), &1) };
+    assert!((got - expected).abs() < 1e-5, "got {got}, expected {expected}");
+}
+
+#[test]
+fn dzasum_agrees_with_fortran() {
+    setup_dzasum();
+    let x = complex64_case();
+    let got = unsafe { cblas_inject::cblas_dzasum(x.len() as blasint, x.as_ptr(), 1) };
+    let expected = unsafe { dzasum_(&(x.len() as blasint), x.as_ptr(), &1) };
+    assert!((got - expected).abs() < 1e-12, "got {got}, expected {expected}");
+}
+
+#[test]
+fn icamax_agrees_with_fortran() {
+    setup_icamax();
+    let x = complex32_case();
+    let got = unsafe { cblas_inject::cblas_icamax(x.len() as blasint, x.as_ptr(), 1) };
+    let fortran_idx = unsafe { icamax_(&(x.len() as blasint), x.as_ptr(), &1) };
+    assert_eq!(got, fortran_idx - 1, "cblas_icamax should be 0-based");
+}
+
+#[test]
+fn izamax_agrees_with_fortran() {
+    setup_izamax();
+    let x = complex64_case();
+    let got = unsafe { cblas_inject::cblas_izamax(x.len() as blasint, x.as_ptr(), 1) };
+    let fortran_idx = unsafe { izamax_(&(x.len() as blasint), x.as_ptr(), &1) };
+    assert_eq!(got, fortran_idx - 1, "cblas_izamax should be 0-based");
+}
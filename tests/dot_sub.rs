@@ -0,0 +1,154 @@
+//! Tests for the complex dot-product routines with output-pointer ABI:
+//! `cblas_cdotu_sub`/`cblas_cdotc_sub`/`cblas_zdotu_sub`/`cblas_zdotc_sub`.
+//!
+//! These compare against the raw Fortran `cdotu_`/`cdotc_`/`zdotu_`/`zdotc_` symbols
+//! from OpenBLAS directly, including negative increments, since the conjugation
+//! convention (which operand gets conjugated) is a common source of bugs.
+
+extern crate blas_src;
+
+use cblas_inject::blasint;
+use num_complex::{Complex32, Complex64};
+
+mod openblas {
+    use super::*;
+
+    extern "C" {
+        pub fn cdotu_(
+            n: *const blasint,
+            x: *const Complex32,
+            incx: *const blasint,
+            y: *const Complex32,
+            incy: *const blasint,
+        ) -> Complex32;
+        pub fn cdotc_(
+            n: *const blasint,
+            x: *const Complex32,
+            incx: *const blasint,
+            y: *const Complex32,
+            incy: *const blasint,
+        ) -> Complex32;
+        pub fn zdotu_(
+            n: *const blasint,
+            x: *const Complex64,
+            incx: *const blasint,
+            y: *const Complex64,
+            incy: *const blasint,
+        ) -> Complex64;
+        pub fn zdotc_(
+            n: *const blasint,
+            x: *const Complex64,
+            incx: *const blasint,
+            y: *const Complex64,
+            incy: *const blasint,
+        ) -> Complex64;
+    }
+}
+
+fn offset_ptr<T>(base: *const T, inc: blasint, n: blasint) -> *const T {
+    if inc >= 0 {
+        base
+    } else {
+        unsafe { base.offset(((1 - n) * inc) as isize) }
+    }
+}
+
+#[test]
+fn cdotu_sub_matches_fortran_including_negative_incs() {
+    let x = [
+        Complex32::new(1.0, 2.0),
+        Complex32::new(-3.0, 0.5),
+        Complex32::new(0.25, -4.0),
+    ];
+    let y = [
+        Complex32::new(2.0, -1.0),
+        Complex32::new(1.5, 1.5),
+        Complex32::new(-2.0, 3.0),
+    ];
+
+    for (incx, incy) in [(1, 1), (-1, 1), (1, -1), (-1, -1)] {
+        let n = x.len() as blasint;
+        let xp = offset_ptr(x.as_ptr(), incx, n);
+        let yp = offset_ptr(y.as_ptr(), incy, n);
+
+        let mut got = Complex32::new(0.0, 0.0);
+        unsafe { cblas_inject::cblas_cdotu_sub(n, xp, incx, yp, incy, &mut got) };
+        let expected = unsafe { openblas::cdotu_(&n, xp, &incx, yp, &incy) };
+        assert!((got - expected).norm() < 1e-5, "cdotu_sub mismatch for incx={incx}, incy={incy}: got {got:?}, expected {expected:?}");
+    }
+}
+
+#[test]
+fn cdotc_sub_matches_fortran_including_negative_incs() {
+    let x = [
+        Complex32::new(1.0, 2.0),
+        Complex32::new(-3.0, 0.5),
+        Complex32::new(0.25, -4.0),
+    ];
+    let y = [
+        Complex32::new(2.0, -1.0),
+        Complex32::new(1.5, 1.5),
+        Complex32::new(-2.0, 3.0),
+    ];
+
+    for (incx, incy) in [(1, 1), (-1, 1), (1, -1), (-1, -1)] {
+        let n = x.len() as blasint;
+        let xp = offset_ptr(x.as_ptr(), incx, n);
+        let yp = offset_ptr(y.as_ptr(), incy, n);
+
+        let mut got = Complex32::new(0.0, 0.0);
+        unsafe { cblas_inject::cblas_cdotc_sub(n, xp, incx, yp, incy, &mut got) };
+        let expected = unsafe { openblas::cdotc_(&n, xp, &incx, yp, &incy) };
+        assert!((got - expected).norm() < 1e-5, "cdotc_sub mismatch for incx={incx}, incy={incy}: got {got:?}, expected {expected:?}");
+    }
+}
+
+#[test]
+fn zdotu_sub_matches_fortran_including_negative_incs() {
+    let x = [
+        Complex64::new(1.0, 2.0),
+        Complex64::new(-3.0, 0.5),
+        Complex64::new(0.25, -4.0),
+    ];
+    let y = [
+        Complex64::new(2.0, -1.0),
+        Complex64::new(1.5, 1.5),
+        Complex64::new(-2.0, 3.0),
+    ];
+
+    for (incx, incy) in [(1, 1), (-1, 1), (1, -1), (-1, -1)] {
+        let n = x.len() as blasint;
+        let xp = offset_ptr(x.as_ptr(), incx, n);
+        let yp = offset_ptr(y.as_ptr(), incy, n);
+
+        let mut got = Complex64::new(0.0, 0.0);
+        unsafe { cblas_inject::cblas_zdotu_sub(n, xp, incx, yp, incy, &mut got) };
+        let expected = unsafe { openblas::zdotu_(&n, xp, &incx, yp, &incy) };
+        assert!((got - expected).norm() < 1e-12, "zdotu_sub mismatch for incx={incx}, incy={incy}: got {got:?}, expected {expected:?}");
+    }
+}
+
+#[test]
+fn zdotc_sub_matches_fortran_including_negative_incs() {
+    let x = [
+        Complex64::new(1.0, 2.0),
+        Complex64::new(-3.0, 0.5),
+        Complex64::new(0.25, -4.0),
+    ];
+    let y = [
+        Complex64::new(2.0, -1.0),
+        Complex64::new(1.5, 1.5),
+        Complex64::new(-2.0, 3.0),
+    ];
+
+    for (incx, incy) in [(1, 1), (-1, 1), (1, -1), (-1, -1)] {
+        let n = x.len() as blasint;
+        let xp = offset_ptr(x.as_ptr(), incx, n);
+        let yp = offset_ptr(y.as_ptr(), incy, n);
+
+        let mut got = Complex64::new(0.0, 0.0);
+        unsafe { cblas_inject::cblas_zdotc_sub(n, xp, incx, yp, incy, &mut got) };
+        let expected = unsafe { openblas::zdotc_(&n, xp, &incx, yp, &incy) };
+        assert!((got - expected).norm() < 1e-12, "zdotc_sub mismatch for incx={incx}, incy={incy}: got {got:?}, expected {expected:?}");
+    }
+}
@@ -13,8 +13,8 @@
 extern crate blas_src;
 
 use cblas_inject::{
-    blasint, register_cgemv, register_zgemv, CblasColMajor, CblasConjTrans, CblasNoTrans,
-    CblasRowMajor, CblasTrans,
+    blasint, register_cgemv, register_zgemv, CblasColMajor, CblasConjNoTrans, CblasConjTrans,
+    CblasNoTrans, CblasRowMajor, CblasTrans,
 };
 use num_complex::{Complex32, Complex64};
 use std::ffi::c_char;
@@ -69,7 +69,7 @@ fn cgemv_row_vs_col_agree() {
         (3, 2),
         (5, 7),
     ];
-    let transposes = [CblasNoTrans, CblasTrans, CblasConjTrans];
+    let transposes = [CblasNoTrans, CblasTrans, CblasConjTrans, CblasConjNoTrans];
     let alpha = Complex32::new(0.7, 0.3);
     let beta = Complex32::new(1.3, -0.5);
 
@@ -142,7 +142,7 @@ fn zgemv_row_vs_col_agree() {
         (3, 2),
         (5, 7),
     ];
-    let transposes = [CblasNoTrans, CblasTrans, CblasConjTrans];
+    let transposes = [CblasNoTrans, CblasTrans, CblasConjTrans, CblasConjNoTrans];
     let alpha = Complex64::new(0.7, 0.3);
     let beta = Complex64::new(1.3, -0.5);
 
@@ -0,0 +1,806 @@
+//! Exhaustive integration tests for GEMV functions.
+//!
+//! These tests compare cblas-trampoline results with cblas-sys (OpenBLAS) to
+//! verify correctness across parameter combinations, analogous to the exhaustive
+//! GEMM harness in `tests/gemm.rs`. Unlike `tests/gemv.rs` (which only checks
+//! RowMajor/ColMajor self-consistency for the complex precisions at `incx =
+//! incy = 1`), this file adds dgemv/sgemv coverage and exercises `incx`/`incy`
+//! strides other than 1.
+
+extern crate blas_src;
+
+use cblas_inject::{
+    blasint, register_cgemv, register_dgemv, register_sgemv, register_zgemv, CblasColMajor,
+    CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_ORDER, CBLAS_TRANSPOSE,
+};
+use num_complex::{Complex32, Complex64};
+use std::ffi::c_char;
+
+// Fortran BLAS function declarations (from cblas-sys's underlying BLAS)
+extern "C" {
+    fn dgemv_(
+        trans: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        alpha: *const f64,
+        a: *const f64,
+        lda: *const blasint,
+        x: *const f64,
+        incx: *const blasint,
+        beta: *const f64,
+        y: *mut f64,
+        incy: *const blasint,
+    );
+
+    fn sgemv_(
+        trans: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        alpha: *const f32,
+        a: *const f32,
+        lda: *const blasint,
+        x: *const f32,
+        incx: *const blasint,
+        beta: *const f32,
+        y: *mut f32,
+        incy: *const blasint,
+    );
+
+    fn cgemv_(
+        trans: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        alpha: *const Complex32,
+        a: *const Complex32,
+        lda: *const blasint,
+        x: *const Complex32,
+        incx: *const blasint,
+        beta: *const Complex32,
+        y: *mut Complex32,
+        incy: *const blasint,
+    );
+
+    fn zgemv_(
+        trans: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        alpha: *const Complex64,
+        a: *const Complex64,
+        lda: *const blasint,
+        x: *const Complex64,
+        incx: *const blasint,
+        beta: *const Complex64,
+        y: *mut Complex64,
+        incy: *const blasint,
+    );
+}
+
+// CBLAS declarations from OpenBLAS for direct comparison (reference implementation)
+mod openblas {
+    use super::*;
+
+    extern "C" {
+        pub fn cblas_dgemv(
+            order: u32,
+            trans: u32,
+            m: blasint,
+            n: blasint,
+            alpha: f64,
+            a: *const f64,
+            lda: blasint,
+            x: *const f64,
+            incx: blasint,
+            beta: f64,
+            y: *mut f64,
+            incy: blasint,
+        );
+
+        pub fn cblas_sgemv(
+            order: u32,
+            trans: u32,
+            m: blasint,
+            n: blasint,
+            alpha: f32,
+            a: *const f32,
+            lda: blasint,
+            x: *const f32,
+            incx: blasint,
+            beta: f32,
+            y: *mut f32,
+            incy: blasint,
+        );
+
+        pub fn cblas_cgemv(
+            order: u32,
+            trans: u32,
+            m: blasint,
+            n: blasint,
+            alpha: *const Complex32,
+            a: *const Complex32,
+            lda: blasint,
+            x: *const Complex32,
+            incx: blasint,
+            beta: *const Complex32,
+            y: *mut Complex32,
+            incy: blasint,
+        );
+
+        pub fn cblas_zgemv(
+            order: u32,
+            trans: u32,
+            m: blasint,
+            n: blasint,
+            alpha: *const Complex64,
+            a: *const Complex64,
+            lda: blasint,
+            x: *const Complex64,
+            incx: blasint,
+            beta: *const Complex64,
+            y: *mut Complex64,
+            incy: blasint,
+        );
+    }
+}
+
+fn setup_dgemv() {
+    #[cfg(not(feature = "openblas"))]
+    {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| unsafe {
+            register_dgemv(dgemv_);
+        });
+    }
+}
+
+fn setup_sgemv() {
+    #[cfg(not(feature = "openblas"))]
+    {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| unsafe {
+            register_sgemv(sgemv_);
+        });
+    }
+}
+
+fn setup_cgemv() {
+    #[cfg(not(feature = "openblas"))]
+    {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| unsafe {
+            register_cgemv(cgemv_);
+        });
+    }
+}
+
+fn setup_zgemv() {
+    #[cfg(not(feature = "openblas"))]
+    {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| unsafe {
+            register_zgemv(zgemv_);
+        });
+    }
+}
+
+/// Generate random-ish test data
+fn generate_vector(len: usize, inc: blasint, seed: usize) -> Vec<f64> {
+    let storage = vector_storage_len(len, inc);
+    (0..storage)
+        .map(|i| ((i + seed) as f64 * 0.1).sin())
+        .collect()
+}
+
+fn generate_vector_f32(len: usize, inc: blasint, seed: usize) -> Vec<f32> {
+    let storage = vector_storage_len(len, inc);
+    (0..storage)
+        .map(|i| ((i + seed) as f32 * 0.1).sin())
+        .collect()
+}
+
+fn generate_complex_vector(len: usize, inc: blasint, seed: usize) -> Vec<Complex64> {
+    let storage = vector_storage_len(len, inc);
+    (0..storage)
+        .map(|i| {
+            Complex64::new(
+                ((i + seed) as f64 * 0.1).sin(),
+                ((i + seed) as f64 * 0.2).cos(),
+            )
+        })
+        .collect()
+}
+
+fn generate_complex_vector_f32(len: usize, inc: blasint, seed: usize) -> Vec<Complex32> {
+    let storage = vector_storage_len(len, inc);
+    (0..storage)
+        .map(|i| {
+            Complex32::new(
+                ((i + seed) as f32 * 0.1).sin(),
+                ((i + seed) as f32 * 0.2).cos(),
+            )
+        })
+        .collect()
+}
+
+fn generate_matrix(rows: usize, cols: usize, seed: usize) -> Vec<f64> {
+    (0..rows * cols)
+        .map(|i| ((i + seed) as f64 * 0.1).sin())
+        .collect()
+}
+
+fn generate_matrix_f32(rows: usize, cols: usize, seed: usize) -> Vec<f32> {
+    (0..rows * cols)
+        .map(|i| ((i + seed) as f32 * 0.1).sin())
+        .collect()
+}
+
+fn generate_complex_matrix(rows: usize, cols: usize, seed: usize) -> Vec<Complex64> {
+    (0..rows * cols)
+        .map(|i| {
+            Complex64::new(
+                ((i + seed) as f64 * 0.1).sin(),
+                ((i + seed) as f64 * 0.2).cos(),
+            )
+        })
+        .collect()
+}
+
+fn generate_complex_matrix_f32(rows: usize, cols: usize, seed: usize) -> Vec<Complex32> {
+    (0..rows * cols)
+        .map(|i| {
+            Complex32::new(
+                ((i + seed) as f32 * 0.1).sin(),
+                ((i + seed) as f32 * 0.2).cos(),
+            )
+        })
+        .collect()
+}
+
+/// Storage size required for a vector of logical length `len` with stride `inc`.
+fn vector_storage_len(len: usize, inc: blasint) -> usize {
+    if len == 0 {
+        0
+    } else {
+        1 + (len - 1) * (inc.unsigned_abs() as usize)
+    }
+}
+
+/// Calculate leading dimension for a GEMV matrix: `lda >= max(1,m)` for
+/// ColMajor, `lda >= max(1,n)` for RowMajor, independent of `trans`.
+fn calc_lda(order: CBLAS_ORDER, m: usize, n: usize) -> blasint {
+    match order {
+        CblasColMajor => m.max(1) as blasint,
+        CblasRowMajor => n.max(1) as blasint,
+    }
+}
+
+fn x_len(trans: CBLAS_TRANSPOSE, m: usize, n: usize) -> usize {
+    match trans {
+        CblasNoTrans => n,
+        CblasTrans => m,
+        _ => unreachable!("only NoTrans/Trans are exercised here"),
+    }
+}
+
+fn y_len(trans: CBLAS_TRANSPOSE, m: usize, n: usize) -> usize {
+    match trans {
+        CblasNoTrans => m,
+        CblasTrans => n,
+        _ => unreachable!("only NoTrans/Trans are exercised here"),
+    }
+}
+
+/// For a negative increment, BLAS walks backward from the pointer it's handed,
+/// so the pointer must be offset to the last logically-accessed element (as
+/// `tests/dot_sub.rs` already does for the dot-product routines); for a
+/// non-negative increment the base pointer is used unchanged.
+fn offset_ptr<T>(base: *const T, inc: blasint, n: blasint) -> *const T {
+    if inc >= 0 {
+        base
+    } else {
+        unsafe { base.offset(((1 - n) * inc) as isize) }
+    }
+}
+
+fn offset_mut_ptr<T>(base: *mut T, inc: blasint, n: blasint) -> *mut T {
+    if inc >= 0 {
+        base
+    } else {
+        unsafe { base.offset(((1 - n) * inc) as isize) }
+    }
+}
+
+/// Compare two f64 slices with tolerance
+fn assert_f64_eq(got: &[f64], expected: &[f64], tol: f64, context: &str) {
+    assert_eq!(got.len(), expected.len(), "{}: length mismatch", context);
+    for (i, (g, e)) in got.iter().zip(expected.iter()).enumerate() {
+        let diff = (g - e).abs();
+        let scale = e.abs().max(1.0);
+        assert!(
+            diff < tol * scale,
+            "{}: mismatch at index {}: got {}, expected {}, diff {}",
+            context,
+            i,
+            g,
+            e,
+            diff
+        );
+    }
+}
+
+/// Compare two f32 slices with tolerance
+fn assert_f32_eq(got: &[f32], expected: &[f32], tol: f32, context: &str) {
+    assert_eq!(got.len(), expected.len(), "{}: length mismatch", context);
+    for (i, (g, e)) in got.iter().zip(expected.iter()).enumerate() {
+        let diff = (g - e).abs();
+        let scale = e.abs().max(1.0);
+        assert!(
+            diff < tol * scale,
+            "{}: mismatch at index {}: got {}, expected {}, diff {}",
+            context,
+            i,
+            g,
+            e,
+            diff
+        );
+    }
+}
+
+/// Compare two Complex64 slices with tolerance
+fn assert_c64_eq(got: &[Complex64], expected: &[Complex64], tol: f64, context: &str) {
+    assert_eq!(got.len(), expected.len(), "{}: length mismatch", context);
+    for (i, (g, e)) in got.iter().zip(expected.iter()).enumerate() {
+        let diff = (g - e).norm();
+        let scale = e.norm().max(1.0);
+        assert!(
+            diff < tol * scale,
+            "{}: mismatch at index {}: got {:?}, expected {:?}, diff {}",
+            context,
+            i,
+            g,
+            e,
+            diff
+        );
+    }
+}
+
+/// Compare two Complex32 slices with tolerance
+fn assert_c32_eq(got: &[Complex32], expected: &[Complex32], tol: f32, context: &str) {
+    assert_eq!(got.len(), expected.len(), "{}: length mismatch", context);
+    for (i, (g, e)) in got.iter().zip(expected.iter()).enumerate() {
+        let diff = (g - e).norm();
+        let scale = e.norm().max(1.0);
+        assert!(
+            diff < tol * scale,
+            "{}: mismatch at index {}: got {:?}, expected {:?}, diff {}",
+            context,
+            i,
+            g,
+            e,
+            diff
+        );
+    }
+}
+
+// =============================================================================
+// Exhaustive DGEMV tests - compare cblas-trampoline with cblas-sys
+// =============================================================================
+
+#[test]
+fn test_dgemv_exhaustive() {
+    setup_dgemv();
+
+    let orders = [CblasRowMajor, CblasColMajor];
+    let transposes = [CblasNoTrans, CblasTrans];
+    let dims = [1, 2, 3, 5, 7];
+    let alphas = [0.0, 1.0, 0.7, -1.0];
+    let betas = [0.0, 1.0, 1.3, -0.5];
+    let incs = [1, 2, -1, -3];
+
+    let mut test_count = 0;
+
+    for &order in &orders {
+        for &trans in &transposes {
+            for &m in &dims {
+                for &n in &dims {
+                    for &alpha in &alphas {
+                        for &beta in &betas {
+                            for &incx in &incs {
+                                for &incy in &incs {
+                                    test_dgemv_case(order, trans, m, n, alpha, beta, incx, incy);
+                                    test_count += 1;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    println!("Ran {} DGEMV test cases", test_count);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn test_dgemv_case(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: usize,
+    n: usize,
+    alpha: f64,
+    beta: f64,
+    incx: blasint,
+    incy: blasint,
+) {
+    let xn = x_len(trans, m, n) as blasint;
+    let yn = y_len(trans, m, n) as blasint;
+    let a = generate_matrix(m, n, 42);
+    let x = generate_vector(xn as usize, incx, 123);
+    let y_init = generate_vector(yn as usize, incy, 456);
+    let xp = offset_ptr(x.as_ptr(), incx, xn);
+
+    let lda = calc_lda(order, m, n);
+
+    let mut y_trampoline = y_init.clone();
+    unsafe {
+        cblas_inject::cblas_dgemv(
+            order,
+            trans,
+            m as blasint,
+            n as blasint,
+            alpha,
+            a.as_ptr(),
+            lda,
+            xp,
+            incx,
+            beta,
+            offset_mut_ptr(y_trampoline.as_mut_ptr(), incy, yn),
+            incy,
+        );
+    }
+
+    let mut y_reference = y_init.clone();
+    unsafe {
+        openblas::cblas_dgemv(
+            order as u32,
+            trans as u32,
+            m as blasint,
+            n as blasint,
+            alpha,
+            a.as_ptr(),
+            lda,
+            xp,
+            incx,
+            beta,
+            offset_mut_ptr(y_reference.as_mut_ptr(), incy, yn),
+            incy,
+        );
+    }
+
+    let context = format!(
+        "order={:?}, trans={:?}, m={}, n={}, alpha={}, beta={}, incx={}, incy={}",
+        order, trans, m, n, alpha, beta, incx, incy
+    );
+    assert_f64_eq(&y_trampoline, &y_reference, 1e-12, &context);
+}
+
+// =============================================================================
+// Exhaustive SGEMV tests - compare cblas-trampoline with cblas-sys
+// =============================================================================
+
+#[test]
+fn test_sgemv_exhaustive() {
+    setup_sgemv();
+
+    let orders = [CblasRowMajor, CblasColMajor];
+    let transposes = [CblasNoTrans, CblasTrans];
+    let dims = [1, 2, 3, 5, 7];
+    let alphas = [0.0, 1.0, 0.7, -1.0];
+    let betas = [0.0, 1.0, 1.3, -0.5];
+    let incs = [1, 2, -1, -3];
+
+    let mut test_count = 0;
+
+    for &order in &orders {
+        for &trans in &transposes {
+            for &m in &dims {
+                for &n in &dims {
+                    for &alpha in &alphas {
+                        for &beta in &betas {
+                            for &incx in &incs {
+                                for &incy in &incs {
+                                    test_sgemv_case(order, trans, m, n, alpha, beta, incx, incy);
+                                    test_count += 1;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    println!("Ran {} SGEMV test cases", test_count);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn test_sgemv_case(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: usize,
+    n: usize,
+    alpha: f32,
+    beta: f32,
+    incx: blasint,
+    incy: blasint,
+) {
+    let xn = x_len(trans, m, n) as blasint;
+    let yn = y_len(trans, m, n) as blasint;
+    let a = generate_matrix_f32(m, n, 42);
+    let x = generate_vector_f32(xn as usize, incx, 123);
+    let y_init = generate_vector_f32(yn as usize, incy, 456);
+    let xp = offset_ptr(x.as_ptr(), incx, xn);
+
+    let lda = calc_lda(order, m, n);
+
+    let mut y_trampoline = y_init.clone();
+    unsafe {
+        cblas_inject::cblas_sgemv(
+            order,
+            trans,
+            m as blasint,
+            n as blasint,
+            alpha,
+            a.as_ptr(),
+            lda,
+            xp,
+            incx,
+            beta,
+            offset_mut_ptr(y_trampoline.as_mut_ptr(), incy, yn),
+            incy,
+        );
+    }
+
+    let mut y_reference = y_init.clone();
+    unsafe {
+        openblas::cblas_sgemv(
+            order as u32,
+            trans as u32,
+            m as blasint,
+            n as blasint,
+            alpha,
+            a.as_ptr(),
+            lda,
+            xp,
+            incx,
+            beta,
+            offset_mut_ptr(y_reference.as_mut_ptr(), incy, yn),
+            incy,
+        );
+    }
+
+    let context = format!(
+        "order={:?}, trans={:?}, m={}, n={}, alpha={}, beta={}, incx={}, incy={}",
+        order, trans, m, n, alpha, beta, incx, incy
+    );
+    assert_f32_eq(&y_trampoline, &y_reference, 1e-4, &context);
+}
+
+// =============================================================================
+// Exhaustive CGEMV tests - compare cblas-trampoline with cblas-sys
+// =============================================================================
+
+#[test]
+fn test_cgemv_exhaustive() {
+    setup_cgemv();
+
+    let orders = [CblasRowMajor, CblasColMajor];
+    let transposes = [CblasNoTrans, CblasTrans];
+    let dims = [1, 2, 3, 5];
+    let alphas = [
+        Complex32::new(0.0, 0.0),
+        Complex32::new(1.0, 0.0),
+        Complex32::new(0.7, 0.3),
+    ];
+    let betas = [
+        Complex32::new(0.0, 0.0),
+        Complex32::new(1.0, 0.0),
+        Complex32::new(-0.5, 0.2),
+    ];
+    let incs = [1, 2, -1, -2];
+
+    let mut test_count = 0;
+
+    for &order in &orders {
+        for &trans in &transposes {
+            for &m in &dims {
+                for &n in &dims {
+                    for &alpha in &alphas {
+                        for &beta in &betas {
+                            for &incx in &incs {
+                                for &incy in &incs {
+                                    test_cgemv_case(order, trans, m, n, alpha, beta, incx, incy);
+                                    test_count += 1;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    println!("Ran {} CGEMV test cases", test_count);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn test_cgemv_case(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: usize,
+    n: usize,
+    alpha: Complex32,
+    beta: Complex32,
+    incx: blasint,
+    incy: blasint,
+) {
+    let xn = x_len(trans, m, n) as blasint;
+    let yn = y_len(trans, m, n) as blasint;
+    let a = generate_complex_matrix_f32(m, n, 42);
+    let x = generate_complex_vector_f32(xn as usize, incx, 123);
+    let y_init = generate_complex_vector_f32(yn as usize, incy, 456);
+    let xp = offset_ptr(x.as_ptr(), incx, xn);
+
+    let lda = calc_lda(order, m, n);
+
+    let mut y_trampoline = y_init.clone();
+    unsafe {
+        cblas_inject::cblas_cgemv(
+            order,
+            trans,
+            m as blasint,
+            n as blasint,
+            &alpha,
+            a.as_ptr(),
+            lda,
+            xp,
+            incx,
+            &beta,
+            offset_mut_ptr(y_trampoline.as_mut_ptr(), incy, yn),
+            incy,
+        );
+    }
+
+    let mut y_reference = y_init.clone();
+    unsafe {
+        openblas::cblas_cgemv(
+            order as u32,
+            trans as u32,
+            m as blasint,
+            n as blasint,
+            &alpha,
+            a.as_ptr(),
+            lda,
+            xp,
+            incx,
+            &beta,
+            offset_mut_ptr(y_reference.as_mut_ptr(), incy, yn),
+            incy,
+        );
+    }
+
+    let context = format!(
+        "order={:?}, trans={:?}, m={}, n={}, alpha={:?}, beta={:?}, incx={}, incy={}",
+        order, trans, m, n, alpha, beta, incx, incy
+    );
+    assert_c32_eq(&y_trampoline, &y_reference, 1e-3, &context);
+}
+
+// =============================================================================
+// Exhaustive ZGEMV tests - compare cblas-trampoline with cblas-sys
+// =============================================================================
+
+#[test]
+fn test_zgemv_exhaustive() {
+    setup_zgemv();
+
+    let orders = [CblasRowMajor, CblasColMajor];
+    let transposes = [CblasNoTrans, CblasTrans];
+    let dims = [1, 2, 3, 5];
+    let alphas = [
+        Complex64::new(0.0, 0.0),
+        Complex64::new(1.0, 0.0),
+        Complex64::new(0.7, 0.3),
+    ];
+    let betas = [
+        Complex64::new(0.0, 0.0),
+        Complex64::new(1.0, 0.0),
+        Complex64::new(-0.5, 0.2),
+    ];
+    let incs = [1, 2, -1, -2];
+
+    let mut test_count = 0;
+
+    for &order in &orders {
+        for &trans in &transposes {
+            for &m in &dims {
+                for &n in &dims {
+                    for &alpha in &alphas {
+                        for &beta in &betas {
+                            for &incx in &incs {
+                                for &incy in &incs {
+                                    test_zgemv_case(order, trans, m, n, alpha, beta, incx, incy);
+                                    test_count += 1;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    println!("Ran {} ZGEMV test cases", test_count);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn test_zgemv_case(
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: usize,
+    n: usize,
+    alpha: Complex64,
+    beta: Complex64,
+    incx: blasint,
+    incy: blasint,
+) {
+    let xn = x_len(trans, m, n) as blasint;
+    let yn = y_len(trans, m, n) as blasint;
+    let a = generate_complex_matrix(m, n, 42);
+    let x = generate_complex_vector(xn as usize, incx, 123);
+    let y_init = generate_complex_vector(yn as usize, incy, 456);
+    let xp = offset_ptr(x.as_ptr(), incx, xn);
+
+    let lda = calc_lda(order, m, n);
+
+    let mut y_trampoline = y_init.clone();
+    unsafe {
+        cblas_inject::cblas_zgemv(
+            order,
+            trans,
+            m as blasint,
+            n as blasint,
+            &alpha,
+            a.as_ptr(),
+            lda,
+            xp,
+            incx,
+            &beta,
+            offset_mut_ptr(y_trampoline.as_mut_ptr(), incy, yn),
+            incy,
+        );
+    }
+
+    let mut y_reference = y_init.clone();
+    unsafe {
+        openblas::cblas_zgemv(
+            order as u32,
+            trans as u32,
+            m as blasint,
+            n as blasint,
+            &alpha,
+            a.as_ptr(),
+            lda,
+            xp,
+            incx,
+            &beta,
+            offset_mut_ptr(y_reference.as_mut_ptr(), incy, yn),
+            incy,
+        );
+    }
+
+    let context = format!(
+        "order={:?}, trans={:?}, m={}, n={}, alpha={:?}, beta={:?}, incx={}, incy={}",
+        order, trans, m, n, alpha, beta, incx, incy
+    );
+    assert_c64_eq(&y_trampoline, &y_reference, 1e-10, &context);
+}
@@ -0,0 +1,161 @@
+//! Pure-Rust layout tests for HER2K (complex single/double precision).
+//!
+//! Policy:
+//! - Do NOT modify existing OpenBLAS-derived tests.
+//! - Add additional tests that validate row-major conversion logic by comparing
+//!   `order=RowMajor` vs `order=ColMajor` results for the *same logical matrices*.
+
+extern crate blas_src;
+
+use cblas_inject::{
+    blasint, register_cher2k, register_zher2k, CblasColMajor, CblasConjTrans, CblasLower,
+    CblasNoTrans, CblasRowMajor, CblasUpper,
+};
+use num_complex::{Complex32, Complex64};
+use std::ffi::c_char;
+
+#[macro_use]
+mod common;
+use common::{assert_f64_eq, Layout, Matrix};
+
+// Fortran BLAS function declarations (provided by linked OpenBLAS)
+extern "C" {
+    fn cher2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const blasint,
+        k: *const blasint,
+        alpha: *const Complex32,
+        a: *const Complex32,
+        lda: *const blasint,
+        b: *const Complex32,
+        ldb: *const blasint,
+        beta: *const f32,
+        c: *mut Complex32,
+        ldc: *const blasint,
+    );
+
+    fn zher2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const blasint,
+        k: *const blasint,
+        alpha: *const Complex64,
+        a: *const Complex64,
+        lda: *const blasint,
+        b: *const Complex64,
+        ldb: *const blasint,
+        beta: *const f64,
+        c: *mut Complex64,
+        ldc: *const blasint,
+    );
+}
+
+setup_once!(setup_cher2k, register_cher2k, cher2k_);
+setup_once!(setup_zher2k, register_zher2k, zher2k_);
+
+/// Read the logical contents of a complex matrix out of its internal storage.
+fn extract(m: &Matrix<Complex64>, rows: usize, cols: usize) -> Vec<Complex64> {
+    (0..rows)
+        .flat_map(|i| (0..cols).map(move |j| m.get(i, j)))
+        .collect()
+}
+
+fn assert_complex_eq(got: &[Complex64], expected: &[Complex64], tol: f64, context: &str) {
+    assert_f64_eq(
+        &got.iter().flat_map(|c| [c.re, c.im]).collect::<Vec<_>>(),
+        &expected.iter().flat_map(|c| [c.re, c.im]).collect::<Vec<_>>(),
+        tol,
+        context,
+    );
+}
+
+#[test]
+fn zher2k_row_vs_col_agree() {
+    setup_zher2k();
+
+    let uplos = [CblasUpper, CblasLower];
+    let transposes = [CblasNoTrans, CblasConjTrans];
+    let dims = [(2usize, 3usize), (3, 2), (4, 4)];
+    let alphas = [Complex64::new(1.0, 0.0), Complex64::new(0.7, -0.3)];
+    let betas = [0.0, 1.0, -0.5];
+
+    for &uplo in &uplos {
+        for &trans in &transposes {
+            for &(n, k) in &dims {
+                // A and B are n x k for NoTrans, k x n for ConjTrans.
+                let (ab_rows, ab_cols) = match trans {
+                    CblasNoTrans => (n, k),
+                    CblasConjTrans => (k, n),
+                    _ => unreachable!(),
+                };
+                let a_row = Matrix::new_row_major(ab_rows, ab_cols, ab_cols + 1, |i, j| {
+                    Complex64::new(((i + 3 * j) as f64 * 0.1).sin(), ((i + j) as f64 * 0.05).cos())
+                });
+                let a_col = a_row.to_layout(Layout::ColMajor, ab_rows + 1);
+                let b_row = Matrix::new_row_major(ab_rows, ab_cols, ab_cols + 1, |i, j| {
+                    Complex64::new(((2 * i + j) as f64 * 0.15).cos(), ((i + 2 * j) as f64 * 0.07).sin())
+                });
+                let b_col = b_row.to_layout(Layout::ColMajor, ab_rows + 1);
+
+                for &alpha in &alphas {
+                    for &beta in &betas {
+                        // C is Hermitian n x n; only the referenced triangle is written and
+                        // the diagonal must be real, so seed both layouts identically.
+                        let c_row = Matrix::new_row_major(n, n, n + 2, |i, j| {
+                            if i == j {
+                                Complex64::new(((i as f64) * 0.2).cos(), 0.0)
+                            } else {
+                                let (lo, hi) = (i.min(j), i.max(j));
+                                Complex64::new(((lo + 2 * hi) as f64 * 0.2).cos(), ((lo + hi) as f64 * 0.11).sin())
+                            }
+                        });
+                        let c_col = c_row.to_layout(Layout::ColMajor, n + 2);
+
+                        let mut c_row = c_row;
+                        let mut c_col = c_col;
+
+                        unsafe {
+                            cblas_inject::cblas_zher2k(
+                                CblasRowMajor,
+                                uplo,
+                                trans,
+                                n as blasint,
+                                k as blasint,
+                                &alpha,
+                                a_row.as_ptr(),
+                                a_row.lda_blasint(),
+                                b_row.as_ptr(),
+                                b_row.lda_blasint(),
+                                beta,
+                                c_row.as_mut_ptr(),
+                                c_row.lda_blasint(),
+                            );
+                            cblas_inject::cblas_zher2k(
+                                CblasColMajor,
+                                uplo,
+                                trans,
+                                n as blasint,
+                                k as blasint,
+                                &alpha,
+                                a_col.as_ptr(),
+                                a_col.lda_blasint(),
+                                b_col.as_ptr(),
+                                b_col.lda_blasint(),
+                                beta,
+                                c_col.as_mut_ptr(),
+                                c_col.lda_blasint(),
+                            );
+                        }
+
+                        let context = format!(
+                            "zher2k row-vs-col: uplo={:?}, trans={:?}, n={}, k={}, alpha={}, beta={}",
+                            uplo, trans, n, k, alpha, beta
+                        );
+                        assert_complex_eq(&extract(&c_row, n, n), &extract(&c_col, n, n), 1e-9, &context);
+                    }
+                }
+            }
+        }
+    }
+}
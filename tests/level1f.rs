@@ -0,0 +1,164 @@
+//! Tests for the fused Level-1f kernels `cblas_?dotxf`/`cblas_?axpyf`
+//! (`cblas_sdotxf`/`cblas_ddotxf`/`cblas_cdotxf`/`cblas_zdotxf` and
+//! `cblas_saxpyf`/`cblas_daxpyf`/`cblas_caxpyf`/`cblas_zaxpyf`).
+//!
+//! These don't forward to a raw Fortran symbol (see the module doc on
+//! `cblas_inject::cblas_sdotxf`), so there's nothing from OpenBLAS to compare
+//! against; instead each test checks the result against the `b`-column formula
+//! directly, computed by hand from the same `m x b` column block.
+
+use cblas_inject::{
+    blasint, cblas_caxpyf, cblas_cdotxf, cblas_daxpyf, cblas_ddotxf, cblas_saxpyf, cblas_sdotxf,
+    cblas_zaxpyf, cblas_zdotxf, CblasConjTrans, CblasNoTrans,
+};
+use num_complex::{Complex32, Complex64};
+
+const M: blasint = 3;
+const B: blasint = 2;
+// Column-major m x b block: column j occupies a[j*m .. j*m+m].
+const A_REAL: [f64; 6] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+#[test]
+fn sdotxf_matches_per_column_dot() {
+    let x: [f32; 3] = [1.0, -2.0, 0.5];
+    let a: Vec<f32> = A_REAL.iter().map(|&v| v as f32).collect();
+    let mut y = [0.0f32; 2];
+    unsafe {
+        cblas_sdotxf(M, x.as_ptr(), 1, a.as_ptr(), M, B, y.as_mut_ptr(), 1);
+    }
+    for j in 0..B as usize {
+        let col = &a[j * M as usize..j * M as usize + M as usize];
+        let expected: f32 = x.iter().zip(col).map(|(xi, ai)| xi * ai).sum();
+        assert!((y[j] - expected).abs() < 1e-5, "y[{j}]: got {}, expected {expected}", y[j]);
+    }
+}
+
+#[test]
+fn ddotxf_matches_per_column_dot() {
+    let x: [f64; 3] = [1.0, -2.0, 0.5];
+    let mut y = [0.0f64; 2];
+    unsafe {
+        cblas_ddotxf(M, x.as_ptr(), 1, A_REAL.as_ptr(), M, B, y.as_mut_ptr(), 1);
+    }
+    for j in 0..B as usize {
+        let col = &A_REAL[j * M as usize..j * M as usize + M as usize];
+        let expected: f64 = x.iter().zip(col).map(|(xi, ai)| xi * ai).sum();
+        assert!((y[j] - expected).abs() < 1e-12, "y[{j}]: got {}, expected {expected}", y[j]);
+    }
+}
+
+#[test]
+fn saxpyf_matches_per_column_accumulation() {
+    let alpha = [2.0f32, -1.0f32];
+    let a: Vec<f32> = A_REAL.iter().map(|&v| v as f32).collect();
+    let mut y = [10.0f32, 20.0, 30.0];
+    let y0 = y;
+    unsafe {
+        cblas_saxpyf(M, alpha.as_ptr(), a.as_ptr(), M, B, y.as_mut_ptr(), 1);
+    }
+    for i in 0..M as usize {
+        let mut expected = y0[i];
+        for j in 0..B as usize {
+            expected += alpha[j] * a[j * M as usize + i];
+        }
+        assert!((y[i] - expected).abs() < 1e-5, "y[{i}]: got {}, expected {expected}", y[i]);
+    }
+}
+
+#[test]
+fn daxpyf_matches_per_column_accumulation() {
+    let alpha = [2.0f64, -1.0f64];
+    let mut y = [10.0f64, 20.0, 30.0];
+    let y0 = y;
+    unsafe {
+        cblas_daxpyf(M, alpha.as_ptr(), A_REAL.as_ptr(), M, B, y.as_mut_ptr(), 1);
+    }
+    for i in 0..M as usize {
+        let mut expected = y0[i];
+        for j in 0..B as usize {
+            expected += alpha[j] * A_REAL[j * M as usize + i];
+        }
+        assert!((y[i] - expected).abs() < 1e-12, "y[{i}]: got {}, expected {expected}", y[i]);
+    }
+}
+
+fn complex_block() -> Vec<Complex64> {
+    vec![
+        Complex64::new(1.0, 1.0),
+        Complex64::new(2.0, -1.0),
+        Complex64::new(0.0, 3.0),
+        Complex64::new(-1.0, 0.5),
+        Complex64::new(4.0, -2.0),
+        Complex64::new(1.5, 1.5),
+    ]
+}
+
+#[test]
+fn zdotxf_no_trans_matches_unconjugated_dot() {
+    let x = [Complex64::new(1.0, -1.0), Complex64::new(0.5, 2.0), Complex64::new(-1.0, 0.0)];
+    let a = complex_block();
+    let mut y = [Complex64::new(0.0, 0.0); 2];
+    unsafe {
+        cblas_zdotxf(CblasNoTrans, M, x.as_ptr(), 1, a.as_ptr(), M, B, y.as_mut_ptr(), 1);
+    }
+    for j in 0..B as usize {
+        let col = &a[j * M as usize..j * M as usize + M as usize];
+        let expected: Complex64 = x.iter().zip(col).map(|(xi, ai)| xi * ai).sum();
+        assert!((y[j] - expected).norm() < 1e-12, "y[{j}]: got {}, expected {expected}", y[j]);
+    }
+}
+
+#[test]
+fn cdotxf_conj_trans_matches_conjugated_dot() {
+    let x: Vec<Complex32> = vec![
+        Complex32::new(1.0, -1.0),
+        Complex32::new(0.5, 2.0),
+        Complex32::new(-1.0, 0.0),
+    ];
+    let a: Vec<Complex32> = complex_block().iter().map(|c| Complex32::new(c.re as f32, c.im as f32)).collect();
+    let mut y = [Complex32::new(0.0, 0.0); 2];
+    unsafe {
+        cblas_cdotxf(CblasConjTrans, M, x.as_ptr(), 1, a.as_ptr(), M, B, y.as_mut_ptr(), 1);
+    }
+    for j in 0..B as usize {
+        let col = &a[j * M as usize..j * M as usize + M as usize];
+        let expected: Complex32 = x.iter().zip(col).map(|(xi, ai)| ai.conj() * xi).sum();
+        assert!((y[j] - expected).norm() < 1e-5, "y[{j}]: got {}, expected {expected}", y[j]);
+    }
+}
+
+#[test]
+fn zaxpyf_matches_per_column_accumulation() {
+    let alpha = [Complex64::new(2.0, 0.5), Complex64::new(-1.0, 1.0)];
+    let a = complex_block();
+    let mut y = [Complex64::new(10.0, -1.0), Complex64::new(20.0, 0.0), Complex64::new(30.0, 2.0)];
+    let y0 = y;
+    unsafe {
+        cblas_zaxpyf(M, alpha.as_ptr(), a.as_ptr(), M, B, y.as_mut_ptr(), 1);
+    }
+    for i in 0..M as usize {
+        let mut expected = y0[i];
+        for j in 0..B as usize {
+            expected += alpha[j] * a[j * M as usize + i];
+        }
+        assert!((y[i] - expected).norm() < 1e-12, "y[{i}]: got {}, expected {expected}", y[i]);
+    }
+}
+
+#[test]
+fn caxpyf_matches_per_column_accumulation() {
+    let alpha = [Complex32::new(2.0, 0.5), Complex32::new(-1.0, 1.0)];
+    let a: Vec<Complex32> = complex_block().iter().map(|c| Complex32::new(c.re as f32, c.im as f32)).collect();
+    let mut y = [Complex32::new(10.0, -1.0), Complex32::new(20.0, 0.0), Complex32::new(30.0, 2.0)];
+    let y0 = y;
+    unsafe {
+        cblas_caxpyf(M, alpha.as_ptr(), a.as_ptr(), M, B, y.as_mut_ptr(), 1);
+    }
+    for i in 0..M as usize {
+        let mut expected = y0[i];
+        for j in 0..B as usize {
+            expected += alpha[j] * a[j * M as usize + i];
+        }
+        assert!((y[i] - expected).norm() < 1e-5, "y[{i}]: got {}, expected {expected}", y[i]);
+    }
+}
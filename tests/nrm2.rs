@@ -0,0 +1,92 @@
+//! Adversarial-magnitude tests for the complex Euclidean norm routines.
+//!
+//! `cblas_scnrm2`/`cblas_dznrm2` compute their result via a scaled sum-of-squares
+//! recurrence instead of delegating to the registered backend (see `src/nrm2.rs`),
+//! so these tests compare against OpenBLAS directly rather than through a
+//! `register_*` hook.
+
+extern crate blas_src;
+
+use cblas_inject::blasint;
+use num_complex::{Complex32, Complex64};
+
+mod openblas {
+    use super::*;
+
+    extern "C" {
+        pub fn cblas_scnrm2(n: blasint, x: *const Complex32, incx: blasint) -> f32;
+        pub fn cblas_dznrm2(n: blasint, x: *const Complex64, incx: blasint) -> f64;
+    }
+}
+
+#[test]
+fn scnrm2_agrees_on_adversarial_magnitudes() {
+    let cases: Vec<Vec<Complex32>> = vec![
+        vec![Complex32::new(1e30, 1e30), Complex32::new(1e-30, 0.0)],
+        vec![Complex32::new(1e30, 0.0); 4],
+        vec![Complex32::new(1e-30, 1e-30); 5],
+        vec![Complex32::new(0.0, 0.0); 3],
+        vec![Complex32::new(3.0, 4.0)],
+        vec![
+            Complex32::new(1e20, 0.0),
+            Complex32::new(0.0, 1e-20),
+            Complex32::new(-1e20, 2.0),
+        ],
+    ];
+
+    for x in &cases {
+        let got = unsafe { cblas_inject::cblas_scnrm2(x.len() as blasint, x.as_ptr(), 1) };
+        let expected = unsafe { openblas::cblas_scnrm2(x.len() as blasint, x.as_ptr(), 1) };
+        let scale = expected.abs().max(1.0);
+        assert!(
+            (got - expected).abs() < 1e-5 * scale,
+            "scnrm2 mismatch for {:?}: got {}, expected {}",
+            x,
+            got,
+            expected
+        );
+    }
+}
+
+#[test]
+fn dznrm2_agrees_on_adversarial_magnitudes() {
+    let cases: Vec<Vec<Complex64>> = vec![
+        vec![Complex64::new(1e200, 1e200), Complex64::new(1e-200, 0.0)],
+        vec![Complex64::new(1e200, 0.0); 4],
+        vec![Complex64::new(1e-200, 1e-200); 5],
+        vec![Complex64::new(0.0, 0.0); 3],
+        vec![Complex64::new(3.0, 4.0)],
+        vec![
+            Complex64::new(1e150, 0.0),
+            Complex64::new(0.0, 1e-150),
+            Complex64::new(-1e150, 2.0),
+        ],
+    ];
+
+    for x in &cases {
+        let got = unsafe { cblas_inject::cblas_dznrm2(x.len() as blasint, x.as_ptr(), 1) };
+        let expected = unsafe { openblas::cblas_dznrm2(x.len() as blasint, x.as_ptr(), 1) };
+        let scale = expected.abs().max(1.0);
+        assert!(
+            (got - expected).abs() < 1e-12 * scale,
+            "dznrm2 mismatch for {:?}: got {}, expected {}",
+            x,
+            got,
+            expected
+        );
+    }
+}
+
+#[test]
+fn scnrm2_zero_length_is_zero() {
+    let x: Vec<Complex32> = vec![];
+    let got = unsafe { cblas_inject::cblas_scnrm2(0, x.as_ptr(), 1) };
+    assert_eq!(got, 0.0);
+}
+
+#[test]
+fn dznrm2_non_positive_incx_is_zero() {
+    let x = [Complex64::new(3.0, 4.0)];
+    let got = unsafe { cblas_inject::cblas_dznrm2(1, x.as_ptr(), 0) };
+    assert_eq!(got, 0.0);
+}
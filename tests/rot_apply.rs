@@ -0,0 +1,68 @@
+//! Coverage for applying a real Givens rotation to complex vectors via
+//! `cblas_csrot`/`cblas_zdrot`.
+//!
+//! Like `rotg.rs`, these don't compare against a linked OpenBLAS: there's no
+//! `order` argument to convert, so a direct comparison against the documented
+//! `x[i] = c*x[i] + s*y[i]`, `y[i] = -s*x[i] + c*y[i]` formula (real `c`/`s`,
+//! applied componentwise to the complex entries) is enough.
+
+use cblas_inject::{cblas_csrot, cblas_zdrot};
+use num_complex::{Complex32, Complex64};
+
+#[test]
+fn csrot_matches_componentwise_formula() {
+    let mut x: Vec<Complex32> = vec![
+        Complex32::new(1.0, 2.0),
+        Complex32::new(-3.0, 0.5),
+        Complex32::new(0.0, -4.0),
+    ];
+    let mut y: Vec<Complex32> = vec![
+        Complex32::new(5.0, -1.0),
+        Complex32::new(2.0, 2.0),
+        Complex32::new(-1.0, 1.0),
+    ];
+    let (c, s) = (0.6f32, 0.8f32);
+
+    let x0 = x.clone();
+    let y0 = y.clone();
+    unsafe {
+        cblas_csrot(x.len() as i32, x.as_mut_ptr(), 1, y.as_mut_ptr(), 1, c, s);
+    }
+
+    for i in 0..x0.len() {
+        let expected_x = x0[i] * c + y0[i] * s;
+        let expected_y = -x0[i] * s + y0[i] * c;
+        assert!((x[i] - expected_x).norm() < 1e-5, "x[{i}]: got {}, expected {}", x[i], expected_x);
+        assert!((y[i] - expected_y).norm() < 1e-5, "y[{i}]: got {}, expected {}", y[i], expected_y);
+    }
+}
+
+#[test]
+fn zdrot_matches_componentwise_formula() {
+    let mut x: Vec<Complex64> = vec![Complex64::new(1.0, 2.0), Complex64::new(-3.0, 0.5)];
+    let mut y: Vec<Complex64> = vec![Complex64::new(5.0, -1.0), Complex64::new(2.0, 2.0)];
+    let (c, s) = (0.28f64, 0.96f64);
+
+    let x0 = x.clone();
+    let y0 = y.clone();
+    unsafe {
+        cblas_zdrot(x.len() as i32, x.as_mut_ptr(), 1, y.as_mut_ptr(), 1, c, s);
+    }
+
+    for i in 0..x0.len() {
+        let expected_x = x0[i] * c + y0[i] * s;
+        let expected_y = -x0[i] * s + y0[i] * c;
+        assert!((x[i] - expected_x).norm() < 1e-12, "x[{i}]: got {}, expected {}", x[i], expected_x);
+        assert!((y[i] - expected_y).norm() < 1e-12, "y[{i}]: got {}, expected {}", y[i], expected_y);
+    }
+}
+
+#[test]
+fn csrot_zero_length_is_noop() {
+    let mut x: Vec<Complex32> = vec![];
+    let mut y: Vec<Complex32> = vec![];
+    unsafe {
+        cblas_csrot(0, x.as_mut_ptr(), 1, y.as_mut_ptr(), 1, 0.6, 0.8);
+    }
+    assert!(x.is_empty() && y.is_empty());
+}
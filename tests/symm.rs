@@ -0,0 +1,201 @@
+//! Pure-Rust layout tests for SYMM (real double precision).
+//!
+//! Policy:
+//! - Do NOT modify existing OpenBLAS-derived tests.
+//! - Add additional tests that validate row-major conversion logic by comparing
+//!   `order=RowMajor` vs `order=ColMajor` results for the *same logical matrices*.
+
+extern crate blas_src;
+
+use cblas_inject::{
+    blasint, register_dsymm, CblasColMajor, CblasLeft, CblasLower, CblasRight, CblasRowMajor,
+    CblasUpper,
+};
+use std::ffi::c_char;
+
+#[macro_use]
+mod common;
+use common::{assert_f64_eq, Layout, Matrix};
+
+// Fortran BLAS function declaration (provided by linked OpenBLAS)
+extern "C" {
+    fn dsymm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        alpha: *const f64,
+        a: *const f64,
+        lda: *const blasint,
+        b: *const f64,
+        ldb: *const blasint,
+        beta: *const f64,
+        c: *mut f64,
+        ldc: *const blasint,
+    );
+}
+
+setup_once!(setup_dsymm, register_dsymm, dsymm_);
+
+/// Read the logical (row-major order) contents of a matrix out of its internal storage.
+fn extract(m: &Matrix<f64>, rows: usize, cols: usize) -> Vec<f64> {
+    (0..rows)
+        .flat_map(|i| (0..cols).map(move |j| m.get(i, j)))
+        .collect()
+}
+
+#[test]
+fn dsymm_row_vs_col_agree() {
+    setup_dsymm();
+
+    let sides = [CblasLeft, CblasRight];
+    let uplos = [CblasUpper, CblasLower];
+    let dims = [(2usize, 3usize), (3, 2), (4, 4), (1, 5)];
+    let alphas = [1.0, 0.7, -1.3];
+    let betas = [0.0, 1.0, -0.5];
+
+    for &side in &sides {
+        for &uplo in &uplos {
+            for &(m, n) in &dims {
+                let k = if side == CblasLeft { m } else { n };
+
+                // A is symmetric k x k: fill(i, j) == fill(j, i).
+                let a_row = Matrix::new_row_major(k, k, k + 1, |i, j| {
+                    let (lo, hi) = (i.min(j), i.max(j));
+                    ((lo + 3 * hi) as f64 * 0.1).sin() + if i == j { 2.0 } else { 0.0 }
+                });
+                let a_col = a_row.to_layout(Layout::ColMajor, k + 1);
+
+                let b_row =
+                    Matrix::new_row_major(m, n, n + 2, |i, j| ((i + 5 * j) as f64 * 0.2).cos());
+                let b_col = b_row.to_layout(Layout::ColMajor, m + 2);
+
+                for &alpha in &alphas {
+                    for &beta in &betas {
+                        let c_row = Matrix::new_row_major(m, n, n + 3, |i, j| {
+                            ((2 * i + j) as f64 * 0.3).sin()
+                        });
+                        let c_col = c_row.to_layout(Layout::ColMajor, m + 3);
+
+                        let mut c_row = c_row;
+                        let mut c_col = c_col;
+
+                        unsafe {
+                            cblas_inject::cblas_dsymm(
+                                CblasRowMajor,
+                                side,
+                                uplo,
+                                m as blasint,
+                                n as blasint,
+                                alpha,
+                                a_row.as_ptr(),
+                                a_row.lda_blasint(),
+                                b_row.as_ptr(),
+                                b_row.lda_blasint(),
+                                beta,
+                                c_row.as_mut_ptr(),
+                                c_row.lda_blasint(),
+                            );
+                            cblas_inject::cblas_dsymm(
+                                CblasColMajor,
+                                side,
+                                uplo,
+                                m as blasint,
+                                n as blasint,
+                                alpha,
+                                a_col.as_ptr(),
+                                a_col.lda_blasint(),
+                                b_col.as_ptr(),
+                                b_col.lda_blasint(),
+                                beta,
+                                c_col.as_mut_ptr(),
+                                c_col.lda_blasint(),
+                            );
+                        }
+
+                        let context = format!(
+                            "dsymm row-vs-col: side={:?}, uplo={:?}, m={}, n={}, alpha={}, beta={}",
+                            side, uplo, m, n, alpha, beta
+                        );
+                        assert_f64_eq(&extract(&c_row, m, n), &extract(&c_col, m, n), 1e-10, &context);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn try_dsymm_matches_unsafe_dsymm() {
+    setup_dsymm();
+
+    let m = 2usize;
+    let n = 3usize;
+    let a = vec![2.0, 0.3, 0.3, 2.0]; // symmetric 2x2, column-major, lda=2
+    let b = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]; // 2x3, column-major, ldb=2
+    let mut c_safe = vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+    let mut c_unsafe = c_safe.clone();
+
+    let result = cblas_inject::try_dsymm(
+        CblasColMajor,
+        CblasLeft,
+        CblasUpper,
+        m as blasint,
+        n as blasint,
+        1.0,
+        &a,
+        2,
+        &b,
+        2,
+        1.0,
+        &mut c_safe,
+        2,
+    );
+    assert!(result.is_ok(), "try_dsymm failed: {result:?}");
+
+    unsafe {
+        cblas_inject::cblas_dsymm(
+            CblasColMajor,
+            CblasLeft,
+            CblasUpper,
+            m as blasint,
+            n as blasint,
+            1.0,
+            a.as_ptr(),
+            2,
+            b.as_ptr(),
+            2,
+            1.0,
+            c_unsafe.as_mut_ptr(),
+            2,
+        );
+    }
+
+    assert_f64_eq(&c_safe, &c_unsafe, 1e-9, "try_dsymm vs cblas_dsymm");
+}
+
+#[test]
+fn try_dsymm_rejects_short_c_slice() {
+    setup_dsymm();
+
+    let a = vec![2.0, 0.3, 0.3, 2.0];
+    let b = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let mut c_too_short = vec![0.1, 0.2];
+
+    let result = cblas_inject::try_dsymm(
+        CblasColMajor,
+        CblasLeft,
+        CblasUpper,
+        2,
+        3,
+        1.0,
+        &a,
+        2,
+        &b,
+        2,
+        1.0,
+        &mut c_too_short,
+        2,
+    );
+    assert!(result.is_err(), "expected try_dsymm to reject a too-short `c` slice");
+}
@@ -0,0 +1,131 @@
+//! Pure-Rust layout tests for SYR2K (real double precision).
+//!
+//! Policy:
+//! - Do NOT modify existing OpenBLAS-derived tests.
+//! - Add additional tests that validate row-major conversion logic by comparing
+//!   `order=RowMajor` vs `order=ColMajor` results for the *same logical matrices*.
+
+extern crate blas_src;
+
+use cblas_inject::{
+    blasint, register_dsyr2k, CblasColMajor, CblasLower, CblasNoTrans, CblasRowMajor, CblasTrans,
+    CblasUpper,
+};
+use std::ffi::c_char;
+
+#[macro_use]
+mod common;
+use common::{assert_f64_eq, Layout, Matrix};
+
+// Fortran BLAS function declaration (provided by linked OpenBLAS)
+extern "C" {
+    fn dsyr2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const blasint,
+        k: *const blasint,
+        alpha: *const f64,
+        a: *const f64,
+        lda: *const blasint,
+        b: *const f64,
+        ldb: *const blasint,
+        beta: *const f64,
+        c: *mut f64,
+        ldc: *const blasint,
+    );
+}
+
+setup_once!(setup_dsyr2k, register_dsyr2k, dsyr2k_);
+
+/// Read the logical contents of a matrix out of its internal storage.
+fn extract(m: &Matrix<f64>, rows: usize, cols: usize) -> Vec<f64> {
+    (0..rows)
+        .flat_map(|i| (0..cols).map(move |j| m.get(i, j)))
+        .collect()
+}
+
+#[test]
+fn dsyr2k_row_vs_col_agree() {
+    setup_dsyr2k();
+
+    let uplos = [CblasUpper, CblasLower];
+    let transposes = [CblasNoTrans, CblasTrans];
+    let dims = [(2usize, 3usize), (3, 2), (4, 4), (1, 5)];
+    let alphas = [1.0, 0.7, -1.3];
+    let betas = [0.0, 1.0, -0.5];
+
+    for &uplo in &uplos {
+        for &trans in &transposes {
+            for &(n, k) in &dims {
+                // A and B are n x k for NoTrans, k x n for Trans.
+                let (ab_rows, ab_cols) = match trans {
+                    CblasNoTrans => (n, k),
+                    CblasTrans => (k, n),
+                    _ => unreachable!(),
+                };
+                let a_row = Matrix::new_row_major(ab_rows, ab_cols, ab_cols + 1, |i, j| {
+                    ((i + 3 * j) as f64 * 0.1).sin()
+                });
+                let a_col = a_row.to_layout(Layout::ColMajor, ab_rows + 1);
+                let b_row = Matrix::new_row_major(ab_rows, ab_cols, ab_cols + 1, |i, j| {
+                    ((2 * i + j) as f64 * 0.15).cos()
+                });
+                let b_col = b_row.to_layout(Layout::ColMajor, ab_rows + 1);
+
+                for &alpha in &alphas {
+                    for &beta in &betas {
+                        // C is symmetric n x n; only the referenced triangle is written,
+                        // so seed both layouts identically for a meaningful comparison.
+                        let c_row = Matrix::new_row_major(n, n, n + 2, |i, j| {
+                            let (lo, hi) = (i.min(j), i.max(j));
+                            ((lo + 2 * hi) as f64 * 0.2).cos()
+                        });
+                        let c_col = c_row.to_layout(Layout::ColMajor, n + 2);
+
+                        let mut c_row = c_row;
+                        let mut c_col = c_col;
+
+                        unsafe {
+                            cblas_inject::cblas_dsyr2k(
+                                CblasRowMajor,
+                                uplo,
+                                trans,
+                                n as blasint,
+                                k as blasint,
+                                alpha,
+                                a_row.as_ptr(),
+                                a_row.lda_blasint(),
+                                b_row.as_ptr(),
+                                b_row.lda_blasint(),
+                                beta,
+                                c_row.as_mut_ptr(),
+                                c_row.lda_blasint(),
+                            );
+                            cblas_inject::cblas_dsyr2k(
+                                CblasColMajor,
+                                uplo,
+                                trans,
+                                n as blasint,
+                                k as blasint,
+                                alpha,
+                                a_col.as_ptr(),
+                                a_col.lda_blasint(),
+                                b_col.as_ptr(),
+                                b_col.lda_blasint(),
+                                beta,
+                                c_col.as_mut_ptr(),
+                                c_col.lda_blasint(),
+                            );
+                        }
+
+                        let context = format!(
+                            "dsyr2k row-vs-col: uplo={:?}, trans={:?}, n={}, k={}, alpha={}, beta={}",
+                            uplo, trans, n, k, alpha, beta
+                        );
+                        assert_f64_eq(&extract(&c_row, n, n), &extract(&c_col, n, n), 1e-10, &context);
+                    }
+                }
+            }
+        }
+    }
+}
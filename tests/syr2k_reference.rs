@@ -0,0 +1,125 @@
+//! Coverage for the pure-Rust SYR2K reference fallback (`ref_dsyr2k`/`ref_ssyr2k`,
+//! reached through `cblas_dsyr2k`/`cblas_ssyr2k` via the `blas_routine!`-generated
+//! `try_get_*` fallback once the `reference` feature is enabled and no real backend has
+//! been registered).
+//!
+//! Deliberately its own binary, unlike `syr2k.rs`: the `dsyr2k` slot is process-global,
+//! so a test that relies on the *lazy* reference fallback must run in a process where
+//! nothing else has registered a real backend first.
+
+#![cfg(feature = "reference")]
+
+use cblas_inject::{blasint, CblasColMajor, CblasLower, CblasNoTrans, CblasTrans, CblasUpper};
+
+/// Manually computes the symmetric rank-2k update
+/// `C = alpha*op(A)*op(B)^T + alpha*op(B)*op(A)^T + beta*C`, touching only the
+/// triangle `uplo` selects, for column-major `n x n` `C` and `op(A)`/`op(B)` either
+/// `n x k` (`NoTrans`) or `k x n` (`Trans`).
+#[allow(clippy::too_many_arguments)]
+fn expected_dsyr2k(
+    upper: bool,
+    transposed: bool,
+    n: usize,
+    k: usize,
+    alpha: f64,
+    a: &[f64],
+    lda: usize,
+    b: &[f64],
+    ldb: usize,
+    beta: f64,
+    c: &mut [f64],
+    ldc: usize,
+) {
+    let a_op = |i: usize, l: usize| -> f64 {
+        if transposed {
+            a[l + i * lda]
+        } else {
+            a[i + l * lda]
+        }
+    };
+    let b_op = |i: usize, l: usize| -> f64 {
+        if transposed {
+            b[l + i * ldb]
+        } else {
+            b[i + l * ldb]
+        }
+    };
+    for j in 0..n {
+        for i in 0..n {
+            if (upper && i > j) || (!upper && i < j) {
+                continue;
+            }
+            let mut sum = 0.0;
+            for l in 0..k {
+                sum += a_op(i, l) * b_op(j, l) + b_op(i, l) * a_op(j, l);
+            }
+            c[i + j * ldc] = alpha * sum + beta * c[i + j * ldc];
+        }
+    }
+}
+
+#[test]
+fn dsyr2k_reference_fallback_matches_formula() {
+    let uplos = [CblasUpper, CblasLower];
+    let transposes = [CblasNoTrans, CblasTrans];
+    let (n, k) = (3usize, 2usize);
+
+    for &uplo in &uplos {
+        for &trans in &transposes {
+            let (ab_rows, ab_cols) = match trans {
+                CblasNoTrans => (n, k),
+                CblasTrans => (k, n),
+                _ => unreachable!(),
+            };
+            let a: Vec<f64> = (0..ab_rows * ab_cols).map(|i| (i as f64 * 0.3).sin()).collect();
+            let b: Vec<f64> = (0..ab_rows * ab_cols).map(|i| (i as f64 * 0.4).cos()).collect();
+            let (alpha, beta) = (1.3, 0.5);
+
+            let mut c_got: Vec<f64> = (0..n * n).map(|i| (i as f64 * 0.2).cos()).collect();
+            let mut c_expected = c_got.clone();
+
+            unsafe {
+                cblas_inject::cblas_dsyr2k(
+                    CblasColMajor,
+                    uplo,
+                    trans,
+                    n as blasint,
+                    k as blasint,
+                    alpha,
+                    a.as_ptr(),
+                    ab_rows as blasint,
+                    b.as_ptr(),
+                    ab_rows as blasint,
+                    beta,
+                    c_got.as_mut_ptr(),
+                    n as blasint,
+                );
+            }
+
+            expected_dsyr2k(
+                uplo == CblasUpper,
+                trans == CblasTrans,
+                n,
+                k,
+                alpha,
+                &a,
+                ab_rows,
+                &b,
+                ab_rows,
+                beta,
+                &mut c_expected,
+                n,
+            );
+
+            for idx in 0..n * n {
+                let diff = (c_got[idx] - c_expected[idx]).abs();
+                assert!(
+                    diff < 1e-10,
+                    "dsyr2k reference fallback mismatch at idx {idx} (uplo={uplo:?}, trans={trans:?}): got {}, expected {}",
+                    c_got[idx],
+                    c_expected[idx]
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! Pure-Rust layout tests for SYRK (real double precision).
+//!
+//! Policy:
+//! - Do NOT modify existing OpenBLAS-derived tests.
+//! - Add additional tests that validate row-major conversion logic by comparing
+//!   `order=RowMajor` vs `order=ColMajor` results for the *same logical matrices*.
+
+extern crate blas_src;
+
+use cblas_inject::{
+    blasint, register_dsyrk, CblasColMajor, CblasLower, CblasNoTrans, CblasRowMajor, CblasTrans,
+    CblasUpper,
+};
+use std::ffi::c_char;
+
+#[macro_use]
+mod common;
+use common::{assert_f64_eq, Layout, Matrix};
+
+// Fortran BLAS function declaration (provided by linked OpenBLAS)
+extern "C" {
+    fn dsyrk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const blasint,
+        k: *const blasint,
+        alpha: *const f64,
+        a: *const f64,
+        lda: *const blasint,
+        beta: *const f64,
+        c: *mut f64,
+        ldc: *const blasint,
+    );
+}
+
+setup_once!(setup_dsyrk, register_dsyrk, dsyrk_);
+
+/// Read the logical contents of a matrix out of its internal storage.
+fn extract(m: &Matrix<f64>, rows: usize, cols: usize) -> Vec<f64> {
+    (0..rows)
+        .flat_map(|i| (0..cols).map(move |j| m.get(i, j)))
+        .collect()
+}
+
+#[test]
+fn dsyrk_row_vs_col_agree() {
+    setup_dsyrk();
+
+    let uplos = [CblasUpper, CblasLower];
+    let transposes = [CblasNoTrans, CblasTrans];
+    let dims = [(2usize, 3usize), (3, 2), (4, 4), (1, 5)];
+    let alphas = [1.0, 0.7, -1.3];
+    let betas = [0.0, 1.0, -0.5];
+
+    for &uplo in &uplos {
+        for &trans in &transposes {
+            for &(n, k) in &dims {
+                // A is n x k for NoTrans, k x n for Trans.
+                let (a_rows, a_cols) = match trans {
+                    CblasNoTrans => (n, k),
+                    CblasTrans => (k, n),
+                    _ => unreachable!(),
+                };
+                let a_row = Matrix::new_row_major(a_rows, a_cols, a_cols + 1, |i, j| {
+                    ((i + 3 * j) as f64 * 0.1).sin()
+                });
+                let a_col = a_row.to_layout(Layout::ColMajor, a_rows + 1);
+
+                for &alpha in &alphas {
+                    for &beta in &betas {
+                        // C is symmetric n x n; only the referenced triangle is written,
+                        // so seed both layouts identically for a meaningful comparison.
+                        let c_row = Matrix::new_row_major(n, n, n + 2, |i, j| {
+                            let (lo, hi) = (i.min(j), i.max(j));
+                            ((lo + 2 * hi) as f64 * 0.2).cos()
+                        });
+                        let c_col = c_row.to_layout(Layout::ColMajor, n + 2);
+
+                        let mut c_row = c_row;
+                        let mut c_col = c_col;
+
+                        unsafe {
+                            cblas_inject::cblas_dsyrk(
+                                CblasRowMajor,
+                                uplo,
+                                trans,
+                                n as blasint,
+                                k as blasint,
+                                alpha,
+                                a_row.as_ptr(),
+                                a_row.lda_blasint(),
+                                beta,
+                                c_row.as_mut_ptr(),
+                                c_row.lda_blasint(),
+                            );
+                            cblas_inject::cblas_dsyrk(
+                                CblasColMajor,
+                                uplo,
+                                trans,
+                                n as blasint,
+                                k as blasint,
+                                alpha,
+                                a_col.as_ptr(),
+                                a_col.lda_blasint(),
+                                beta,
+                                c_col.as_mut_ptr(),
+                                c_col.lda_blasint(),
+                            );
+                        }
+
+                        let context = format!(
+                            "dsyrk row-vs-col: uplo={:?}, trans={:?}, n={}, k={}, alpha={}, beta={}",
+                            uplo, trans, n, k, alpha, beta
+                        );
+                        assert_f64_eq(&extract(&c_row, n, n), &extract(&c_col, n, n), 1e-10, &context);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn try_dsyrk_matches_unsafe_dsyrk() {
+    setup_dsyrk();
+
+    let n = 3usize;
+    let k = 2usize;
+    let a = vec![1.0, 0.5, 0.2, 2.0, 1.0, 0.3]; // n x k column-major, lda=3
+    let mut c_safe = vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+    let mut c_unsafe = c_safe.clone();
+
+    let result = cblas_inject::try_dsyrk(
+        CblasColMajor,
+        CblasUpper,
+        CblasNoTrans,
+        n as blasint,
+        k as blasint,
+        1.0,
+        &a,
+        3,
+        1.0,
+        &mut c_safe,
+        3,
+    );
+    assert!(result.is_ok(), "try_dsyrk failed: {result:?}");
+
+    unsafe {
+        cblas_inject::cblas_dsyrk(
+            CblasColMajor,
+            CblasUpper,
+            CblasNoTrans,
+            n as blasint,
+            k as blasint,
+            1.0,
+            a.as_ptr(),
+            3,
+            1.0,
+            c_unsafe.as_mut_ptr(),
+            3,
+        );
+    }
+
+    assert_f64_eq(&c_safe, &c_unsafe, 1e-9, "try_dsyrk vs cblas_dsyrk");
+}
+
+#[test]
+fn try_dsyrk_rejects_short_a_slice() {
+    setup_dsyrk();
+
+    let a_too_short = vec![1.0, 0.5];
+    let mut c = vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+
+    let result = cblas_inject::try_dsyrk(
+        CblasColMajor,
+        CblasUpper,
+        CblasNoTrans,
+        3,
+        2,
+        1.0,
+        &a_too_short,
+        3,
+        1.0,
+        &mut c,
+        3,
+    );
+    assert!(result.is_err(), "expected try_dsyrk to reject a too-short `a` slice");
+}
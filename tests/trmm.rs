@@ -0,0 +1,245 @@
+//! Pure-Rust layout tests for TRMM (real double precision, single precision complex).
+//!
+//! Policy:
+//! - Do NOT modify existing OpenBLAS-derived tests.
+//! - Add additional tests that validate row-major conversion logic by comparing
+//!   `order=RowMajor` vs `order=ColMajor` results for the *same logical matrices*.
+
+extern crate blas_src;
+
+use cblas_inject::{
+    blasint, register_ctrmm, register_dtrmm, CblasColMajor, CblasConjNoTrans, CblasConjTrans,
+    CblasLeft, CblasLower, CblasNoTrans, CblasNonUnit, CblasRight, CblasRowMajor, CblasTrans,
+    CblasUpper,
+};
+use num_complex::Complex32;
+use std::ffi::c_char;
+
+#[macro_use]
+mod common;
+use common::{assert_complex32_eq, assert_f64_eq, Layout, Matrix};
+
+// Fortran BLAS function declarations (provided by linked OpenBLAS)
+extern "C" {
+    fn dtrmm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        alpha: *const f64,
+        a: *const f64,
+        lda: *const blasint,
+        b: *mut f64,
+        ldb: *const blasint,
+    );
+
+    fn ctrmm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        alpha: *const Complex32,
+        a: *const Complex32,
+        lda: *const blasint,
+        b: *mut Complex32,
+        ldb: *const blasint,
+    );
+}
+
+setup_once!(setup_dtrmm, register_dtrmm, dtrmm_);
+setup_once!(setup_ctrmm, register_ctrmm, ctrmm_);
+
+/// Read the logical contents of a matrix out of its internal storage.
+fn extract(m: &Matrix<f64>, rows: usize, cols: usize) -> Vec<f64> {
+    (0..rows)
+        .flat_map(|i| (0..cols).map(move |j| m.get(i, j)))
+        .collect()
+}
+
+/// Read the logical contents of a complex matrix out of its internal storage.
+fn extract_c32(m: &Matrix<Complex32>, rows: usize, cols: usize) -> Vec<Complex32> {
+    (0..rows)
+        .flat_map(|i| (0..cols).map(move |j| m.get(i, j)))
+        .collect()
+}
+
+#[test]
+fn dtrmm_row_vs_col_agree() {
+    setup_dtrmm();
+
+    let sides = [CblasLeft, CblasRight];
+    let uplos = [CblasUpper, CblasLower];
+    let transposes = [CblasNoTrans, CblasTrans];
+    let diags = [CblasNonUnit, cblas_inject::CblasUnit];
+    let dims = [(2usize, 3usize), (3, 2), (4, 4), (1, 5)];
+    let alphas = [1.0, 0.7, -1.3];
+
+    for &side in &sides {
+        for &uplo in &uplos {
+            for &trans in &transposes {
+                for &diag in &diags {
+                    for &(m, n) in &dims {
+                        let k = if side == CblasLeft { m } else { n };
+
+                        let a_row = Matrix::new_row_major(k, k, k + 1, |i, j| {
+                            let is_triangular = match uplo {
+                                CblasUpper => i <= j,
+                                CblasLower => i >= j,
+                            };
+                            if is_triangular && (diag != cblas_inject::CblasUnit || i != j) {
+                                ((i + 3 * j) as f64 * 0.1).sin() + if i == j { 2.0 } else { 0.0 }
+                            } else {
+                                0.0
+                            }
+                        });
+                        let a_col = a_row.to_layout(Layout::ColMajor, k + 1);
+
+                        for &alpha in &alphas {
+                            let b_row = Matrix::new_row_major(m, n, n + 2, |i, j| {
+                                ((i + 5 * j) as f64 * 0.2).cos()
+                            });
+                            let b_col = b_row.to_layout(Layout::ColMajor, m + 2);
+
+                            let mut b_row = b_row;
+                            let mut b_col = b_col;
+
+                            unsafe {
+                                cblas_inject::cblas_dtrmm(
+                                    CblasRowMajor,
+                                    side,
+                                    uplo,
+                                    trans,
+                                    diag,
+                                    m as blasint,
+                                    n as blasint,
+                                    alpha,
+                                    a_row.as_ptr(),
+                                    a_row.lda_blasint(),
+                                    b_row.as_mut_ptr(),
+                                    b_row.lda_blasint(),
+                                );
+                                cblas_inject::cblas_dtrmm(
+                                    CblasColMajor,
+                                    side,
+                                    uplo,
+                                    trans,
+                                    diag,
+                                    m as blasint,
+                                    n as blasint,
+                                    alpha,
+                                    a_col.as_ptr(),
+                                    a_col.lda_blasint(),
+                                    b_col.as_mut_ptr(),
+                                    b_col.lda_blasint(),
+                                );
+                            }
+
+                            let context = format!(
+                                "dtrmm row-vs-col: side={:?}, uplo={:?}, trans={:?}, diag={:?}, m={}, n={}, alpha={}",
+                                side, uplo, trans, diag, m, n, alpha
+                            );
+                            assert_f64_eq(&extract(&b_row, m, n), &extract(&b_col, m, n), 1e-10, &context);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn ctrmm_row_vs_col_agree() {
+    setup_ctrmm();
+
+    let sides = [CblasLeft, CblasRight];
+    let uplos = [CblasUpper, CblasLower];
+    let transposes = [CblasNoTrans, CblasTrans, CblasConjTrans, CblasConjNoTrans];
+    let diags = [CblasNonUnit, cblas_inject::CblasUnit];
+    let dims = [(2usize, 3usize), (3, 2), (4, 4)];
+    let alpha = Complex32::new(0.7, -0.3);
+
+    for &side in &sides {
+        for &uplo in &uplos {
+            for &trans in &transposes {
+                for &diag in &diags {
+                    for &(m, n) in &dims {
+                        let k = if side == CblasLeft { m } else { n };
+
+                        let a_row = Matrix::new_row_major(k, k, k + 1, |i, j| {
+                            let is_triangular = match uplo {
+                                CblasUpper => i <= j,
+                                CblasLower => i >= j,
+                            };
+                            if is_triangular && (diag != cblas_inject::CblasUnit || i != j) {
+                                Complex32::new(
+                                    ((i + 3 * j) as f32 * 0.1).sin() + if i == j { 2.0 } else { 0.0 },
+                                    ((i + 2 * j) as f32 * 0.15).cos(),
+                                )
+                            } else {
+                                Complex32::new(0.0, 0.0)
+                            }
+                        });
+                        let a_col = a_row.to_layout(Layout::ColMajor, k + 1);
+
+                        let b_row = Matrix::new_row_major(m, n, n + 2, |i, j| {
+                            Complex32::new(
+                                ((i + 5 * j) as f32 * 0.2).cos(),
+                                ((i + 2 * j) as f32 * 0.3).sin(),
+                            )
+                        });
+                        let b_col = b_row.to_layout(Layout::ColMajor, m + 2);
+
+                        let mut b_row = b_row;
+                        let mut b_col = b_col;
+
+                        unsafe {
+                            cblas_inject::cblas_ctrmm(
+                                CblasRowMajor,
+                                side,
+                                uplo,
+                                trans,
+                                diag,
+                                m as blasint,
+                                n as blasint,
+                                &alpha,
+                                a_row.as_ptr(),
+                                a_row.lda_blasint(),
+                                b_row.as_mut_ptr(),
+                                b_row.lda_blasint(),
+                            );
+                            cblas_inject::cblas_ctrmm(
+                                CblasColMajor,
+                                side,
+                                uplo,
+                                trans,
+                                diag,
+                                m as blasint,
+                                n as blasint,
+                                &alpha,
+                                a_col.as_ptr(),
+                                a_col.lda_blasint(),
+                                b_col.as_mut_ptr(),
+                                b_col.lda_blasint(),
+                            );
+                        }
+
+                        let context = format!(
+                            "ctrmm row-vs-col: side={:?}, uplo={:?}, trans={:?}, diag={:?}, m={}, n={}",
+                            side, uplo, trans, diag, m, n
+                        );
+                        assert_complex32_eq(
+                            &extract_c32(&b_row, m, n),
+                            &extract_c32(&b_col, m, n),
+                            1e-4,
+                            &context,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,330 @@
+//! Pure-Rust layout tests for TRSM (real double precision, single precision complex).
+//!
+//! Policy:
+//! - Do NOT modify existing OpenBLAS-derived tests.
+//! - Add additional tests that validate row-major conversion logic by comparing
+//!   `order=RowMajor` vs `order=ColMajor` results for the *same logical matrices*.
+
+extern crate blas_src;
+
+use cblas_inject::{
+    blasint, register_ctrsm, register_dtrsm, CblasColMajor, CblasConjNoTrans, CblasLeft,
+    CblasLower, CblasNoTrans, CblasNonUnit, CblasRight, CblasRowMajor, CblasTrans, CblasUpper,
+};
+use num_complex::Complex32;
+use std::ffi::c_char;
+
+#[macro_use]
+mod common;
+use common::{assert_complex32_eq, assert_f64_eq, Layout, Matrix};
+
+// Fortran BLAS function declarations (provided by linked OpenBLAS)
+extern "C" {
+    fn dtrsm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        alpha: *const f64,
+        a: *const f64,
+        lda: *const blasint,
+        b: *mut f64,
+        ldb: *const blasint,
+    );
+
+    fn ctrsm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        alpha: *const Complex32,
+        a: *const Complex32,
+        lda: *const blasint,
+        b: *mut Complex32,
+        ldb: *const blasint,
+    );
+}
+
+setup_once!(setup_dtrsm, register_dtrsm, dtrsm_);
+setup_once!(setup_ctrsm, register_ctrsm, ctrsm_);
+
+/// Read the logical contents of a matrix out of its internal storage.
+fn extract(m: &Matrix<f64>, rows: usize, cols: usize) -> Vec<f64> {
+    (0..rows)
+        .flat_map(|i| (0..cols).map(move |j| m.get(i, j)))
+        .collect()
+}
+
+/// Read the logical contents of a complex matrix out of its internal storage.
+fn extract_c32(m: &Matrix<Complex32>, rows: usize, cols: usize) -> Vec<Complex32> {
+    (0..rows)
+        .flat_map(|i| (0..cols).map(move |j| m.get(i, j)))
+        .collect()
+}
+
+#[test]
+fn dtrsm_row_vs_col_agree() {
+    setup_dtrsm();
+
+    let sides = [CblasLeft, CblasRight];
+    let uplos = [CblasUpper, CblasLower];
+    let transposes = [CblasNoTrans, CblasTrans];
+    let diags = [CblasNonUnit, cblas_inject::CblasUnit];
+    let dims = [(2usize, 3usize), (3, 2), (4, 4), (1, 5)];
+    let alphas = [1.0, 0.7, -1.3];
+
+    for &side in &sides {
+        for &uplo in &uplos {
+            for &trans in &transposes {
+                for &diag in &diags {
+                    for &(m, n) in &dims {
+                        let k = if side == CblasLeft { m } else { n };
+
+                        // Diagonally dominant triangular A keeps the solve well-conditioned.
+                        let a_row = Matrix::new_row_major(k, k, k + 1, |i, j| {
+                            let is_triangular = match uplo {
+                                CblasUpper => i <= j,
+                                CblasLower => i >= j,
+                            };
+                            if is_triangular && (diag != cblas_inject::CblasUnit || i != j) {
+                                if i == j {
+                                    4.0 + (i as f64 * 0.1)
+                                } else {
+                                    ((i + 3 * j) as f64 * 0.1).sin()
+                                }
+                            } else {
+                                0.0
+                            }
+                        });
+                        let a_col = a_row.to_layout(Layout::ColMajor, k + 1);
+
+                        for &alpha in &alphas {
+                            let b_row = Matrix::new_row_major(m, n, n + 2, |i, j| {
+                                ((i + 5 * j) as f64 * 0.2).cos()
+                            });
+                            let b_col = b_row.to_layout(Layout::ColMajor, m + 2);
+
+                            let mut b_row = b_row;
+                            let mut b_col = b_col;
+
+                            unsafe {
+                                cblas_inject::cblas_dtrsm(
+                                    CblasRowMajor,
+                                    side,
+                                    uplo,
+                                    trans,
+                                    diag,
+                                    m as blasint,
+                                    n as blasint,
+                                    alpha,
+                                    a_row.as_ptr(),
+                                    a_row.lda_blasint(),
+                                    b_row.as_mut_ptr(),
+                                    b_row.lda_blasint(),
+                                );
+                                cblas_inject::cblas_dtrsm(
+                                    CblasColMajor,
+                                    side,
+                                    uplo,
+                                    trans,
+                                    diag,
+                                    m as blasint,
+                                    n as blasint,
+                                    alpha,
+                                    a_col.as_ptr(),
+                                    a_col.lda_blasint(),
+                                    b_col.as_mut_ptr(),
+                                    b_col.lda_blasint(),
+                                );
+                            }
+
+                            let context = format!(
+                                "dtrsm row-vs-col: side={:?}, uplo={:?}, trans={:?}, diag={:?}, m={}, n={}, alpha={}",
+                                side, uplo, trans, diag, m, n, alpha
+                            );
+                            assert_f64_eq(&extract(&b_row, m, n), &extract(&b_col, m, n), 1e-9, &context);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn ctrsm_row_vs_col_agree() {
+    setup_ctrsm();
+
+    let sides = [CblasLeft, CblasRight];
+    let uplos = [CblasUpper, CblasLower];
+    let transposes = [CblasNoTrans, CblasTrans, CblasConjNoTrans];
+    let diags = [CblasNonUnit, cblas_inject::CblasUnit];
+    let dims = [(2usize, 3usize), (3, 2), (4, 4)];
+    let alpha = Complex32::new(0.7, -0.3);
+
+    for &side in &sides {
+        for &uplo in &uplos {
+            for &trans in &transposes {
+                for &diag in &diags {
+                    for &(m, n) in &dims {
+                        let k = if side == CblasLeft { m } else { n };
+
+                        // Diagonally dominant triangular A keeps the solve well-conditioned.
+                        let a_row = Matrix::new_row_major(k, k, k + 1, |i, j| {
+                            let is_triangular = match uplo {
+                                CblasUpper => i <= j,
+                                CblasLower => i >= j,
+                            };
+                            if is_triangular && (diag != cblas_inject::CblasUnit || i != j) {
+                                if i == j {
+                                    Complex32::new(4.0 + (i as f32 * 0.1), 0.2)
+                                } else {
+                                    Complex32::new(
+                                        ((i + 3 * j) as f32 * 0.1).sin(),
+                                        ((i + 2 * j) as f32 * 0.15).cos(),
+                                    )
+                                }
+                            } else {
+                                Complex32::new(0.0, 0.0)
+                            }
+                        });
+                        let a_col = a_row.to_layout(Layout::ColMajor, k + 1);
+
+                        let b_row = Matrix::new_row_major(m, n, n + 2, |i, j| {
+                            Complex32::new(
+                                ((i + 5 * j) as f32 * 0.2).cos(),
+                                ((i + 2 * j) as f32 * 0.3).sin(),
+                            )
+                        });
+                        let b_col = b_row.to_layout(Layout::ColMajor, m + 2);
+
+                        let mut b_row = b_row;
+                        let mut b_col = b_col;
+
+                        unsafe {
+                            cblas_inject::cblas_ctrsm(
+                                CblasRowMajor,
+                                side,
+                                uplo,
+                                trans,
+                                diag,
+                                m as blasint,
+                                n as blasint,
+                                &alpha,
+                                a_row.as_ptr(),
+                                a_row.lda_blasint(),
+                                b_row.as_mut_ptr(),
+                                b_row.lda_blasint(),
+                            );
+                            cblas_inject::cblas_ctrsm(
+                                CblasColMajor,
+                                side,
+                                uplo,
+                                trans,
+                                diag,
+                                m as blasint,
+                                n as blasint,
+                                &alpha,
+                                a_col.as_ptr(),
+                                a_col.lda_blasint(),
+                                b_col.as_mut_ptr(),
+                                b_col.lda_blasint(),
+                            );
+                        }
+
+                        let context = format!(
+                            "ctrsm row-vs-col: side={:?}, uplo={:?}, trans={:?}, diag={:?}, m={}, n={}",
+                            side, uplo, trans, diag, m, n
+                        );
+                        assert_complex32_eq(
+                            &extract_c32(&b_row, m, n),
+                            &extract_c32(&b_col, m, n),
+                            1e-4,
+                            &context,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn try_dtrsm_matches_unsafe_dtrsm() {
+    setup_dtrsm();
+
+    // Diagonally dominant 3x3 upper-triangular A, column-major, lda = m = 3.
+    let m = 3usize;
+    let n = 2usize;
+    let a = vec![
+        4.0, 0.0, 0.0, //
+        0.3, 5.0, 0.0, //
+        0.2, 0.4, 6.0, //
+    ];
+    let b = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let mut b_safe = b.clone();
+    let mut b_unsafe = b.clone();
+
+    let result = cblas_inject::try_trsm(
+        CblasColMajor,
+        CblasLeft,
+        CblasUpper,
+        CblasNoTrans,
+        CblasNonUnit,
+        m as blasint,
+        n as blasint,
+        1.0,
+        &a,
+        m as blasint,
+        &mut b_safe,
+        m as blasint,
+    );
+    assert!(result.is_ok(), "try_trsm failed: {result:?}");
+
+    unsafe {
+        cblas_inject::cblas_dtrsm(
+            CblasColMajor,
+            CblasLeft,
+            CblasUpper,
+            CblasNoTrans,
+            CblasNonUnit,
+            m as blasint,
+            n as blasint,
+            1.0,
+            a.as_ptr(),
+            m as blasint,
+            b_unsafe.as_mut_ptr(),
+            m as blasint,
+        );
+    }
+
+    assert_f64_eq(&b_safe, &b_unsafe, 1e-9, "try_trsm vs cblas_dtrsm");
+}
+
+#[test]
+fn try_dtrsm_rejects_short_b_slice() {
+    setup_dtrsm();
+
+    let a = vec![4.0, 0.0, 0.0, 0.3, 5.0, 0.0, 0.2, 0.4, 6.0];
+    let mut b_too_short = vec![1.0, 2.0]; // needs 3 elements (m=3, ldb=3, one column)
+
+    let result = cblas_inject::try_trsm(
+        CblasColMajor,
+        CblasLeft,
+        CblasUpper,
+        CblasNoTrans,
+        CblasNonUnit,
+        3,
+        1,
+        1.0,
+        &a,
+        3,
+        &mut b_too_short,
+        3,
+    );
+    assert!(result.is_err(), "expected try_trsm to reject a too-short `b` slice");
+}